@@ -0,0 +1,82 @@
+//! Benchmarks of the parse, validate and report phases on synthetic documents.
+//!
+//! The typical document has 10k components, the extreme one 1M components, one in a hundred
+//! of them invalid. The documents are generated on first use, so filtering by the size, e.g.
+//! `cargo bench -- typical`, skips generating the extreme one.
+use std::cell::OnceCell;
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use validation_tree::{
+    options::ValidationOptions,
+    report::{validate_report, ValidationReport},
+    testing::SyntheticBom,
+    validate_bom,
+    validation::SpecVersion,
+    Bom,
+};
+
+const SIZES: &[(&str, usize)] = &[("typical", 10_000), ("extreme", 1_000_000)];
+
+/// The inputs of all phases for one document size, each generated on first use.
+struct Fixture {
+    generator: SyntheticBom,
+    json: OnceCell<String>,
+    bom: OnceCell<Bom>,
+    report: OnceCell<ValidationReport>,
+}
+
+impl Fixture {
+    fn new(components: usize) -> Self {
+        Self {
+            generator: SyntheticBom::new(components).invalid_every(100),
+            json: OnceCell::new(),
+            bom: OnceCell::new(),
+            report: OnceCell::new(),
+        }
+    }
+
+    fn json(&self) -> &str {
+        self.json.get_or_init(|| self.generator.to_json())
+    }
+
+    fn bom(&self) -> &Bom {
+        self.bom.get_or_init(|| self.generator.build())
+    }
+
+    fn report(&self, options: &ValidationOptions) -> &ValidationReport {
+        self.report
+            .get_or_init(|| validate_report(SpecVersion::V1_5, self.bom(), options))
+    }
+}
+
+fn bench_phases(c: &mut Criterion) {
+    let options = ValidationOptions::new();
+
+    for (label, components) in SIZES {
+        let fixture = Fixture::new(*components);
+        let mut group = c.benchmark_group(*label);
+        group.sample_size(10);
+        group.throughput(Throughput::Elements(*components as u64));
+
+        group.bench_function(BenchmarkId::new("parse", components), |b| {
+            let json = fixture.json();
+            b.iter(|| Bom::parse_json(black_box(json)).expect("Failed to parse BOM"))
+        });
+        group.bench_function(BenchmarkId::new("validate", components), |b| {
+            let bom = fixture.bom();
+            b.iter(|| validate_bom(SpecVersion::V1_5, black_box(bom), &options))
+        });
+        group.bench_function(BenchmarkId::new("report", components), |b| {
+            let report = fixture.report(&options);
+            b.iter(|| {
+                black_box(report)
+                    .to_json()
+                    .expect("Failed to serialize report")
+            })
+        });
+        group.finish();
+    }
+}
+
+criterion_group!(benches, bench_phases);
+criterion_main!(benches);
@@ -0,0 +1,311 @@
+//! Generates the path constants of the `paths` module and the field table of the `coverage`
+//! module from the model structs in `src/lib.rs`.
+use std::{collections::HashMap, env, fs, path::Path};
+
+use quote::ToTokens;
+use syn::{Attribute, Fields, GenericArgument, Item, LitStr, PathArguments, Type};
+
+/// The model struct all paths start from.
+const ROOT: &str = "Bom";
+
+/// Fields written by a custom serializer below a key of the serialized object, by the name of
+/// the serializer, e.g. the tool components stored at `metadata.tools.components`.
+const NESTED_WIRE_NAMES: &[(&str, &str)] = &[("serialize_tool_components", "components")];
+
+/// Returns the name of the model type a field refers to and whether it is a list.
+fn model_type(ty: &Type) -> Option<(String, bool)> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    let inner = match &segment.arguments {
+        PathArguments::AngleBracketed(arguments) => match arguments.args.first() {
+            Some(GenericArgument::Type(inner)) => Some(inner),
+            _ => None,
+        },
+        _ => None,
+    };
+
+    match (segment.ident.to_string().as_str(), inner) {
+        ("Option", Some(inner)) => model_type(inner),
+        ("Vec", Some(inner)) => model_type(inner).map(|(name, _)| (name, true)),
+        (name, _) => Some((name.to_string(), false)),
+    }
+}
+
+/// Returns the value of the `#[serde(...)]` attribute with the given key, e.g. `rename`.
+fn serde_attribute(attrs: &[Attribute], key: &str) -> Option<String> {
+    let mut value = None;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("serde")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident(key) {
+                value = Some(meta.value()?.parse::<LitStr>()?.value());
+            } else if meta.input.peek(syn::Token![=]) {
+                meta.value()?.parse::<syn::Expr>()?;
+            }
+            Ok(())
+        })
+        .expect("Failed to parse serde attribute");
+    }
+    value
+}
+
+/// Returns the serialized name of a field, following serde's `rename` and `rename_all`.
+fn wire_name(field: &str, rename: Option<String>, rename_all: Option<&str>) -> String {
+    if let Some(rename) = rename {
+        return rename;
+    }
+    match rename_all {
+        Some("camelCase") => {
+            let mut words = field.split('_');
+            let first = words.next().unwrap_or_default().to_string();
+            words.fold(first, |name, word| {
+                let mut chars = word.chars();
+                match chars.next() {
+                    Some(c) => format!("{}{}{}", name, c.to_ascii_uppercase(), chars.as_str()),
+                    None => name,
+                }
+            })
+        }
+        Some("kebab-case") => field.replace('_', "-"),
+        _ => field.to_string(),
+    }
+}
+
+/// Returns the first spec version of a field and the checks of its `#[validate(...)]`
+/// attribute, i.e. the names of the custom validators and `deprecated`.
+fn validate_attribute(attrs: &[Attribute]) -> (Option<String>, Vec<String>) {
+    let mut since = None;
+    let mut checks = Vec::new();
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("validate")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("since") {
+                since = Some(meta.value()?.parse::<LitStr>()?.value());
+            } else if meta.path.is_ident("custom") {
+                checks.push(meta.value()?.parse::<LitStr>()?.value());
+            } else if meta.path.is_ident("deprecated") {
+                checks.push(String::from("deprecated"));
+            } else if meta.input.peek(syn::Token![=]) {
+                meta.value()?.parse::<syn::Expr>()?;
+            }
+            Ok(())
+        })
+        .expect("Failed to parse validate attribute");
+    }
+    (since, checks)
+}
+
+/// A field of a model struct.
+struct Field {
+    name: String,
+    wire: String,
+    ty: Type,
+    since: Option<String>,
+    checks: Vec<String>,
+}
+
+/// A path of the model, formatted with field names and serialized names.
+struct ModelPath {
+    path: String,
+    wire: String,
+    model: String,
+    since: Option<String>,
+    checks: Vec<String>,
+}
+
+/// Collects the paths of all fields of the struct, recursive structs, e.g. nested components,
+/// are only descended into once.
+fn collect(
+    structs: &HashMap<String, Vec<Field>>,
+    name: &str,
+    prefix: &ModelPath,
+    ancestors: &mut Vec<String>,
+    output: &mut Vec<ModelPath>,
+) {
+    let join = |prefix: &str, field: &str| {
+        if prefix.is_empty() {
+            field.to_string()
+        } else {
+            format!("{}.{}", prefix, field)
+        }
+    };
+
+    for field in &structs[name] {
+        let path = ModelPath {
+            path: join(&prefix.path, &field.name),
+            wire: join(&prefix.wire, &field.wire),
+            model: name.to_string(),
+            since: prefix.since.clone().max(field.since.clone()),
+            checks: field.checks.clone(),
+        };
+
+        if let Some((inner, is_list)) = model_type(&field.ty) {
+            if structs.contains_key(&inner) && !ancestors.contains(&inner) {
+                let suffix = if is_list { "[]" } else { "" };
+                let nested = ModelPath {
+                    path: format!("{}{}", path.path, suffix),
+                    wire: format!("{}{}", path.wire, suffix),
+                    model: inner.clone(),
+                    since: path.since.clone(),
+                    checks: Vec::new(),
+                };
+                output.push(path);
+                ancestors.push(inner.clone());
+                collect(structs, &inner, &nested, ancestors, output);
+                ancestors.pop();
+                continue;
+            }
+        }
+        output.push(path);
+    }
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/lib.rs");
+
+    let source = fs::read_to_string("src/lib.rs").expect("Failed to read src/lib.rs");
+    let file = syn::parse_file(&source).expect("Failed to parse src/lib.rs");
+
+    // Hand written `Validate` impls, the fields they check are looked up by `self.field`.
+    let impls = file
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            Item::Impl(item) => Some(item),
+            _ => None,
+        })
+        .filter(|item| {
+            item.trait_
+                .as_ref()
+                .is_some_and(|(_, path, _)| path.is_ident("Validate"))
+        })
+        .filter_map(|item| match &*item.self_ty {
+            Type::Path(path) => Some((
+                path.path.get_ident()?.to_string(),
+                item.to_token_stream().to_string(),
+            )),
+            _ => None,
+        })
+        .collect::<HashMap<_, _>>();
+
+    let structs = file
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            Item::Struct(item) => Some(item),
+            _ => None,
+        })
+        .filter_map(|item| match &item.fields {
+            Fields::Named(fields) => {
+                let rename_all = serde_attribute(&item.attrs, "rename_all");
+                let model = item.ident.to_string();
+                let manual = impls.get(&model);
+                Some((
+                    model.clone(),
+                    fields
+                        .named
+                        .iter()
+                        .filter_map(|field| {
+                            let name = field.ident.as_ref()?.to_string();
+                            let (since, mut checks) = validate_attribute(&field.attrs);
+                            if manual.is_some_and(|body| body.contains(&format!("self . {}", name)))
+                            {
+                                checks.push(format!("{}::validate", model));
+                            }
+                            let mut wire = wire_name(
+                                &name,
+                                serde_attribute(&field.attrs, "rename"),
+                                rename_all.as_deref(),
+                            );
+                            let serializer = serde_attribute(&field.attrs, "serialize_with");
+                            if let Some((_, key)) = NESTED_WIRE_NAMES
+                                .iter()
+                                .find(|(with, _)| serializer.as_deref() == Some(*with))
+                            {
+                                wire = format!("{}.{}", wire, key);
+                            }
+                            Some(Field {
+                                wire,
+                                name,
+                                ty: field.ty.clone(),
+                                since,
+                                checks,
+                            })
+                        })
+                        .collect::<Vec<_>>(),
+                ))
+            }
+            _ => None,
+        })
+        .collect::<HashMap<_, _>>();
+
+    let root = ModelPath {
+        path: String::new(),
+        wire: String::new(),
+        model: ROOT.to_string(),
+        since: None,
+        checks: Vec::new(),
+    };
+    let mut paths = Vec::new();
+    collect(
+        &structs,
+        ROOT,
+        &root,
+        &mut vec![ROOT.to_string()],
+        &mut paths,
+    );
+
+    let mut constants = paths
+        .iter()
+        .map(|ModelPath { path, .. }| {
+            format!(
+                "/// `{}`\npub const {}: &str = {:?};\n",
+                path,
+                path.replace("[]", "")
+                    .replace('.', "_")
+                    .to_ascii_uppercase(),
+                path
+            )
+        })
+        .collect::<String>();
+
+    constants.push_str(&format!(
+        "\n/// The model struct all paths start from.\nconst ROOT_MODEL: &str = {:?};\n",
+        ROOT
+    ));
+    constants.push_str("\n/// The fields of every model struct with their serialized names.\n");
+    constants.push_str("const WIRE_FIELDS: &[WireField] = &[\n");
+    let mut models = structs.keys().collect::<Vec<_>>();
+    models.sort();
+    for model in models {
+        for field in &structs[model] {
+            let nested = model_type(&field.ty)
+                .map(|(inner, _)| inner)
+                .filter(|inner| structs.contains_key(inner));
+            constants.push_str(&format!(
+                "    WireField {{ model: {:?}, name: {:?}, wire: {:?}, nested: {:?} }},\n",
+                model, field.name, field.wire, nested
+            ));
+        }
+    }
+    constants.push_str("];\n");
+
+    let mut fields =
+        String::from("/// Every field of the model with the checks of its validator.\n");
+    fields.push_str("const FIELDS: &[ModelField] = &[\n");
+    for path in &paths {
+        let since = match &path.since {
+            Some(since) => format!("Some(SpecVersion::V{})", since.replace('.', "_")),
+            None => String::from("None"),
+        };
+        fields.push_str(&format!(
+            "    ModelField {{ path: {:?}, model: {:?}, since: {}, checks: &{:?} }},\n",
+            path.path, path.model, since, path.checks
+        ));
+    }
+    fields.push_str("];\n");
+
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR is not set");
+    fs::write(Path::new(&out_dir).join("paths.rs"), constants).expect("Failed to write paths");
+    fs::write(Path::new(&out_dir).join("coverage.rs"), fields).expect("Failed to write fields");
+}
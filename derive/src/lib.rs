@@ -0,0 +1,346 @@
+//! Provides `#[derive(Validate)]` for the SBOM models of `validation-tree`.
+//!
+//! The derived implementation chains all annotated fields into a `ValidationContext`,
+//! fields without `#[validate(...)]` attribute are not validated.
+//!
+//! * `#[validate(custom = "validate_purl")]` validates the field with the given function,
+//!   which receives a reference to the (inner) value
+//! * `#[validate(custom = "validate_kind", enum)]` reports the error of an enum field
+//! * `#[validate(custom = "validate_timestamp", version)]` passes the spec version as second
+//!   argument to the function
+//! * `#[validate(custom = "validate_timestamp", options)]` additionally passes the
+//!   `ValidationOptions` as last argument to the function
+//! * `#[validate(nested)]` validates a field that implements `Validate` itself
+//! * `#[validate(each)]` validates every element of a list field
+//! * `#[validate(since = "1.4")]` reports the field if present in an earlier spec version
+//! * `#[validate(until = "1.4")]` warns about the field if present in a later spec version,
+//!   both cannot be combined with `nested` or `each`
+//! * `#[validate(until = "1.5", replaced_by = "authors")]` additionally names the field that
+//!   replaces the deprecated one
+//! * `#[validate(deprecated)]` reports the field according to its entry in the deprecation
+//!   registry of `validation_tree::deprecations`, looked up by struct and field name
+//!
+//! Fields of type `Option<T>` are only validated if they are present. Fields and nested
+//! elements outside of the paths selected by the `ValidationOptions` are skipped.
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    parse_macro_input, spanned::Spanned, Data, DeriveInput, Error, Fields, GenericArgument, LitStr,
+    Path, PathArguments, Type,
+};
+
+/// How a single field gets validated.
+enum Check {
+    Custom {
+        function: Path,
+        is_enum: bool,
+        with_version: bool,
+        with_options: bool,
+    },
+    Nested,
+    Each,
+    Since(syn::Ident),
+    Until {
+        until: syn::Ident,
+        replaced_by: Option<LitStr>,
+    },
+    Deprecated,
+}
+
+#[proc_macro_derive(Validate, attributes(validate))]
+pub fn derive_validate(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new(
+            input.span(),
+            "Validate can only be derived for structs",
+        ));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(Error::new(
+            input.span(),
+            "Validate can only be derived for structs with named fields",
+        ));
+    };
+
+    let mut chain = Vec::new();
+    for field in &fields.named {
+        let ident = field.ident.as_ref().expect("Named field without ident");
+        for check in parse_checks(field)? {
+            chain.push(expand_check(&input.ident, ident, &field.ty, check));
+        }
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::validation_tree::validation::Validate for #name #ty_generics #where_clause {
+            fn validate(
+                &self,
+                version: ::validation_tree::validation::SpecVersion,
+                options: &::validation_tree::options::ValidationOptions,
+            ) -> ::validation_tree::validation::ValidationResult {
+                self.validate_at(version, options, "")
+            }
+
+            #[allow(unused_variables)]
+            fn validate_at(
+                &self,
+                version: ::validation_tree::validation::SpecVersion,
+                options: &::validation_tree::options::ValidationOptions,
+                path: &str,
+            ) -> ::validation_tree::validation::ValidationResult {
+                let scope = ::validation_tree::validation::PathScope::new(options, path);
+                ::validation_tree::validation::ValidationContext::new()
+                    #(#chain)*
+                    .into()
+            }
+        }
+    })
+}
+
+fn parse_checks(field: &syn::Field) -> syn::Result<Vec<Check>> {
+    let mut checks = Vec::new();
+
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("validate"))
+    {
+        let mut function = None;
+        let mut is_enum = false;
+        let mut with_version = false;
+        let mut with_options = false;
+        let mut replaced_by = None;
+
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("custom") {
+                let value: LitStr = meta.value()?.parse()?;
+                function = Some(value.parse::<Path>()?);
+            } else if meta.path.is_ident("enum") {
+                is_enum = true;
+            } else if meta.path.is_ident("version") {
+                with_version = true;
+            } else if meta.path.is_ident("options") {
+                with_options = true;
+            } else if meta.path.is_ident("since") {
+                checks.push(Check::Since(parse_version(meta.value()?.parse()?)?));
+            } else if meta.path.is_ident("until") {
+                checks.push(Check::Until {
+                    until: parse_version(meta.value()?.parse()?)?,
+                    replaced_by: None,
+                });
+            } else if meta.path.is_ident("deprecated") {
+                checks.push(Check::Deprecated);
+            } else if meta.path.is_ident("replaced_by") {
+                replaced_by = Some(meta.value()?.parse::<LitStr>()?);
+            } else if meta.path.is_ident("nested") {
+                checks.push(Check::Nested);
+            } else if meta.path.is_ident("each") {
+                checks.push(Check::Each);
+            } else {
+                return Err(meta.error("Unknown validate attribute"));
+            }
+            Ok(())
+        })?;
+
+        if let Some(replacement) = replaced_by {
+            match checks.last_mut() {
+                Some(Check::Until { replaced_by, .. }) => *replaced_by = Some(replacement),
+                _ => return Err(Error::new(attr.span(), "`replaced_by` requires `until`")),
+            }
+        }
+
+        match function {
+            Some(function) => checks.push(Check::Custom {
+                function,
+                is_enum,
+                with_version,
+                with_options,
+            }),
+            None if is_enum || with_version || with_options => {
+                return Err(Error::new(
+                    attr.span(),
+                    "`enum`, `version` and `options` require a `custom` function",
+                ))
+            }
+            None => {}
+        }
+    }
+
+    let gated = checks.iter().any(|check| {
+        matches!(
+            check,
+            Check::Since(_) | Check::Until { .. } | Check::Deprecated
+        )
+    });
+    let structured = checks
+        .iter()
+        .any(|check| matches!(check, Check::Nested | Check::Each));
+    if gated && structured {
+        return Err(Error::new(
+            field.span(),
+            "`since`, `until` and `deprecated` cannot be combined with `nested` or `each`",
+        ));
+    }
+
+    Ok(checks)
+}
+
+/// Maps a spec version like `"1.5"` to the name of its `SpecVersion` variant.
+fn parse_version(version: LitStr) -> syn::Result<syn::Ident> {
+    match version.value().as_str() {
+        "1.3" => Ok(syn::Ident::new("V1_3", version.span())),
+        "1.4" => Ok(syn::Ident::new("V1_4", version.span())),
+        "1.5" => Ok(syn::Ident::new("V1_5", version.span())),
+        "1.6" => Ok(syn::Ident::new("V1_6", version.span())),
+        _ => Err(Error::new(version.span(), "Unknown spec version")),
+    }
+}
+
+fn expand_check(model: &syn::Ident, ident: &syn::Ident, ty: &Type, check: Check) -> TokenStream2 {
+    let key = ident.to_string();
+    let optional = option_inner(ty).is_some();
+
+    // wraps an expression evaluated for the (inner) value `value` into an `Option`
+    let apply = |body: TokenStream2| {
+        if optional {
+            quote! { self.#ident.as_ref().map(|value| #body) }
+        } else {
+            quote! { Some({ let value = &self.#ident; #body }) }
+        }
+    };
+
+    match check {
+        Check::Custom {
+            function,
+            is_enum,
+            with_version,
+            with_options,
+        } => {
+            let call = match (with_version, with_options) {
+                (true, true) => apply(quote! { #function(value, version, options) }),
+                (true, false) => apply(quote! { #function(value, version) }),
+                (false, true) => apply(quote! { #function(value, options) }),
+                (false, false) => apply(quote! { #function(value) }),
+            };
+            let call = quote! { scope.selects(#key).then(|| #call).flatten() };
+            if is_enum {
+                quote! { .add_enum(#key, #call) }
+            } else {
+                quote! { .add_field(#key, #call) }
+            }
+        }
+        Check::Since(since) => {
+            let present = present(ident, optional);
+            quote! {
+                .add_field(#key, ::validation_tree::validation::validate_since(
+                    #key,
+                    #present && scope.selects(#key),
+                    version,
+                    ::validation_tree::validation::SpecVersion::#since,
+                ))
+            }
+        }
+        Check::Until {
+            until,
+            replaced_by: Some(replacement),
+        } => {
+            let present = present(ident, optional);
+            quote! {
+                .add_field(#key, ::validation_tree::validation::validate_replaced(
+                    #key,
+                    #replacement,
+                    #present && scope.selects(#key),
+                    version,
+                    ::validation_tree::validation::SpecVersion::#until,
+                ))
+            }
+        }
+        Check::Until {
+            until,
+            replaced_by: None,
+        } => {
+            let present = present(ident, optional);
+            quote! {
+                .add_field(#key, ::validation_tree::validation::validate_until(
+                    #key,
+                    #present && scope.selects(#key),
+                    version,
+                    ::validation_tree::validation::SpecVersion::#until,
+                ))
+            }
+        }
+        Check::Deprecated => {
+            let model = model.to_string();
+            let present = present(ident, optional);
+            quote! {
+                .add_field(#key, ::validation_tree::deprecations::validate_deprecated(
+                    #model,
+                    #key,
+                    #present && scope.selects(#key),
+                    version,
+                ))
+            }
+        }
+        Check::Nested => {
+            let call = apply(quote! {
+                ::validation_tree::validation::validate_nested(
+                    #key,
+                    value,
+                    version,
+                    options,
+                    &scope.child(#key),
+                )
+            });
+            quote! { .add_struct(#key, scope.traverses(#key).then(|| #call).flatten()) }
+        }
+        Check::Each => {
+            let call = apply(quote! {
+                ::validation_tree::validation::validate_each(
+                    #key,
+                    value,
+                    version,
+                    options,
+                    &scope.child(#key),
+                )
+            });
+            quote! { .add_list(#key, scope.traverses(#key).then(|| #call).flatten()) }
+        }
+    }
+}
+
+/// Returns an expression that checks whether the field is set.
+fn present(ident: &syn::Ident, optional: bool) -> TokenStream2 {
+    if optional {
+        quote! { self.#ident.is_some() }
+    } else {
+        quote! { true }
+    }
+}
+
+/// Returns the inner type if the given type is an `Option<T>`.
+fn option_inner(ty: &Type) -> Option<&Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+        return None;
+    };
+    match arguments.args.first()? {
+        GenericArgument::Type(inner) => Some(inner),
+        _ => None,
+    }
+}
@@ -0,0 +1,16 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use validation_tree::{options::ValidationOptions, validation::SpecVersion, xml::validate_xml};
+
+fuzz_target!(|data: &[u8]| {
+    let options = ValidationOptions::new();
+    for version in [
+        SpecVersion::V1_3,
+        SpecVersion::V1_4,
+        SpecVersion::V1_5,
+        SpecVersion::V1_6,
+    ] {
+        let _ = validate_xml(version, data, &options);
+    }
+});
@@ -0,0 +1,291 @@
+//! Validates many documents at once, e.g. for a batch mode of the CLI or a bulk endpoint of
+//! a service, and aggregates statistics over all of them.
+//!
+//! Documents are validated independently of each other, a document that cannot be read or
+//! parsed is reported as error at [`DOCUMENT_KEY`](crate::error::DOCUMENT_KEY) like
+//! [`validate_bytes`] does.
+use std::{
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    sync::Mutex,
+    thread,
+};
+
+use crate::{
+    error::Error,
+    options::ValidationOptions,
+    validate_bom, validate_bytes,
+    validation::{Severity, SpecVersion, ValidationResult},
+    Bom, MAX_INPUT_LEN,
+};
+
+/// A single document of a batch.
+#[derive(Debug)]
+pub enum BomSource {
+    /// An already parsed document.
+    Bom { name: String, bom: Box<Bom> },
+    /// An untrusted JSON document, see [`validate_bytes`].
+    Bytes { name: String, bytes: Vec<u8> },
+    /// A JSON document read from a file, named by its path.
+    File(PathBuf),
+}
+
+impl BomSource {
+    pub fn bom<T: ToString>(name: T, bom: Bom) -> Self {
+        BomSource::Bom {
+            name: name.to_string(),
+            bom: Box::new(bom),
+        }
+    }
+
+    pub fn bytes<T: ToString>(name: T, bytes: Vec<u8>) -> Self {
+        BomSource::Bytes {
+            name: name.to_string(),
+            bytes,
+        }
+    }
+
+    pub fn file<P: Into<PathBuf>>(path: P) -> Self {
+        BomSource::File(path.into())
+    }
+
+    /// Returns the name the document is reported with.
+    pub fn name(&self) -> String {
+        match self {
+            BomSource::Bom { name, .. } | BomSource::Bytes { name, .. } => name.clone(),
+            BomSource::File(path) => path.display().to_string(),
+        }
+    }
+
+    fn validate(&self, version: SpecVersion, options: &ValidationOptions) -> ValidationResult {
+        match self {
+            BomSource::Bom { bom, .. } => validate_bom(version, bom, options),
+            BomSource::Bytes { bytes, .. } => validate_bytes(version, bytes, options),
+            BomSource::File(path) => match read_file(path) {
+                Ok(bytes) => validate_bytes(version, &bytes, options),
+                Err(error) => Err(Error::from(error).into()),
+            },
+        }
+    }
+}
+
+/// Reads at most one byte more than [`MAX_INPUT_LEN`], enough for [`validate_bytes`] to
+/// reject larger documents without reading them whole.
+fn read_file(path: &Path) -> io::Result<Vec<u8>> {
+    let mut bytes = Vec::new();
+    fs::File::open(path)?
+        .take(MAX_INPUT_LEN as u64 + 1)
+        .read_to_end(&mut bytes)?;
+    Ok(bytes)
+}
+
+/// The result of a single document of a batch.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BatchResult {
+    /// The name of the [`BomSource`].
+    pub name: String,
+    pub result: ValidationResult,
+}
+
+impl BatchResult {
+    /// Returns `true` if the document has no unsuppressed findings.
+    pub fn is_valid(&self) -> bool {
+        match &self.result {
+            Ok(()) => true,
+            Err(errors) => !errors.has_unsuppressed(),
+        }
+    }
+}
+
+/// Statistics over all documents of a batch, suppressed findings are only counted as such.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct BatchStatistics {
+    pub documents: usize,
+    /// The number of documents without unsuppressed findings.
+    pub valid: usize,
+    pub invalid: usize,
+    pub errors: usize,
+    pub warnings: usize,
+    pub infos: usize,
+    pub suppressed: usize,
+}
+
+impl BatchStatistics {
+    fn add(&mut self, result: &BatchResult) {
+        self.documents += 1;
+        if result.is_valid() {
+            self.valid += 1;
+        } else {
+            self.invalid += 1;
+        }
+
+        let Err(errors) = &result.result else {
+            return;
+        };
+        for (_, error) in errors.errors() {
+            let count = match (error.suppressed, error.severity) {
+                (true, _) => &mut self.suppressed,
+                (false, Severity::Info) => &mut self.infos,
+                (false, Severity::Warning) => &mut self.warnings,
+                (false, Severity::Error) => &mut self.errors,
+            };
+            *count += 1;
+        }
+    }
+}
+
+/// The results of all documents of a batch, in the order of the sources.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct BatchReport {
+    pub results: Vec<BatchResult>,
+    pub statistics: BatchStatistics,
+}
+
+impl BatchReport {
+    fn new(results: Vec<BatchResult>) -> Self {
+        let mut statistics = BatchStatistics::default();
+        results.iter().for_each(|result| statistics.add(result));
+        Self {
+            results,
+            statistics,
+        }
+    }
+
+    /// Returns all documents with unsuppressed findings.
+    pub fn failures(&self) -> impl Iterator<Item = &BatchResult> {
+        self.results.iter().filter(|result| !result.is_valid())
+    }
+}
+
+/// Validates all documents one after another.
+pub fn validate_many<I>(
+    version: SpecVersion,
+    sources: I,
+    options: &ValidationOptions,
+) -> BatchReport
+where
+    I: IntoIterator<Item = BomSource>,
+{
+    let results = sources
+        .into_iter()
+        .map(|source| BatchResult {
+            name: source.name(),
+            result: source.validate(version, options),
+        })
+        .collect();
+
+    BatchReport::new(results)
+}
+
+/// Validates all documents on up to `threads` threads, the results keep the order of the
+/// sources.
+pub fn validate_many_parallel<I>(
+    version: SpecVersion,
+    sources: I,
+    options: &ValidationOptions,
+    threads: usize,
+) -> BatchReport
+where
+    I: IntoIterator<Item = BomSource>,
+{
+    let sources = sources.into_iter().enumerate().collect::<Vec<_>>();
+    let threads = threads.clamp(1, sources.len().max(1));
+    let queue = Mutex::new(sources);
+    let results = Mutex::new(Vec::new());
+
+    thread::scope(|scope| {
+        for _ in 0..threads {
+            scope.spawn(|| loop {
+                let next = queue
+                    .lock()
+                    .unwrap_or_else(|error| error.into_inner())
+                    .pop();
+                let Some((index, source)) = next else {
+                    break;
+                };
+                let result = BatchResult {
+                    name: source.name(),
+                    result: source.validate(version, options),
+                };
+                results
+                    .lock()
+                    .unwrap_or_else(|error| error.into_inner())
+                    .push((index, result));
+            });
+        }
+    });
+
+    let mut results = results
+        .into_inner()
+        .unwrap_or_else(|error| error.into_inner());
+    results.sort_by_key(|(index, _)| *index);
+    BatchReport::new(results.into_iter().map(|(_, result)| result).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use crate::{
+        options::ValidationOptions, testing::errors_at, validation::SpecVersion, Bom, MAX_INPUT_LEN,
+    };
+
+    use super::{validate_many, validate_many_parallel, BomSource};
+
+    fn sources() -> Vec<BomSource> {
+        vec![
+            BomSource::bom("parsed", Bom::default()),
+            BomSource::bytes(
+                "purl",
+                br#"{ "components": [{ "type": "library", "name": "a", "purl": "a" }] }"#.to_vec(),
+            ),
+            BomSource::bytes("broken", b"{".to_vec()),
+            BomSource::file("does/not/exist.json"),
+        ]
+    }
+
+    #[test]
+    fn validates_batch_with_statistics() {
+        let report = validate_many(SpecVersion::V1_5, sources(), &ValidationOptions::new());
+
+        let names = report
+            .results
+            .iter()
+            .map(|result| result.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            vec!["parsed", "purl", "broken", "does/not/exist.json"]
+        );
+        assert_eq!(report.statistics.documents, 4);
+        assert_eq!(report.statistics.valid, 1);
+        assert_eq!(report.statistics.invalid, 3);
+        assert_eq!(report.failures().count(), 3);
+        assert!(report.statistics.errors >= 3);
+    }
+
+    #[test]
+    fn parallel_batch_keeps_order() {
+        let options = ValidationOptions::new();
+        let sequential = validate_many(SpecVersion::V1_5, sources(), &options);
+        let parallel = validate_many_parallel(SpecVersion::V1_5, sources(), &options, 3);
+
+        assert_eq!(sequential, parallel);
+    }
+
+    #[test]
+    fn rejects_too_large_file() {
+        let path = std::env::temp_dir().join("validation-tree-batch-too-large.json");
+        fs::write(&path, vec![b' '; MAX_INPUT_LEN + 1]).expect("Failed to write document");
+
+        let report = validate_many(
+            SpecVersion::V1_5,
+            vec![BomSource::file(&path)],
+            &ValidationOptions::new(),
+        );
+        let errors = errors_at(&report.results[0].result, "document");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code.as_deref(), Some("input-too-large"));
+    }
+}
@@ -0,0 +1,311 @@
+use std::{env, fs, path::Path, process::ExitCode, thread};
+
+use validation_tree::{
+    batch::{validate_many_parallel, BomSource},
+    corpus::run_corpus,
+    coverage::{coverage, Validator},
+    diff::diff,
+    graph::BomGraph,
+    lint::lint,
+    options::{Profile, ValidationOptions},
+    patch::{apply_patch, suggested_fixes, Document},
+    report::validate_report,
+    validate_bom,
+    validation::SpecVersion,
+    Bom,
+};
+
+const USAGE: &str = "\
+Usage: cyclonedx-validate <COMMAND>
+       cyclonedx-validate --corpus <DIR>
+
+Commands:
+  diff <OLD> <NEW>  Compares two JSON BOMs and prints the change set as JSON
+  batch <FILE>... [--spec-version <VERSION>]
+                    Validates many JSON BOMs in parallel and prints the verdict of
+                    each and statistics over all, validated against 1.6 by default
+  report <FILE> [--format json|csv|html] [--spec-version <VERSION>] [--apply-fixes]
+                    Validates a JSON BOM and prints all findings as JSON report
+                    (default), CSV or HTML page, validated against 1.6 by default.
+                    --apply-fixes first writes the safe suggested fixes to FILE
+  lint <FILE> [--spec-version <VERSION>]
+                    Prints advisory findings about recommended practices, one per
+                    line, validated against 1.6 by default
+  graph <FILE> [--format dot|graphml] [--spec-version <VERSION>]
+                    Prints the dependency graph with the validation findings on its
+                    nodes, as DOT (default) or GraphML, validated against 1.6 by default
+  coverage [--spec-version <VERSION>]
+                    Prints which fields are checked by which validators and which
+                    fields are unchecked, for 1.6 by default
+
+Options:
+  --corpus <DIR>    Validates a corpus of example documents, one sub directory per
+                    spec version, valid-*.json must pass, invalid-*.json must fail";
+
+fn read_bom(path: &str) -> Result<Bom, String> {
+    let input = fs::read_to_string(path)
+        .map_err(|error| format!("Failed to read '{}': {}", path, error))?;
+
+    Bom::parse_json(&input).map_err(|error| format!("Failed to parse '{}': {}", path, error))
+}
+
+fn run_diff(args: &[String]) -> Result<(), String> {
+    let [old, new] = args else {
+        return Err(USAGE.to_string());
+    };
+
+    let change_set = diff(&read_bom(old)?, &read_bom(new)?);
+    let json = serde_json::to_string_pretty(&change_set).map_err(|error| error.to_string())?;
+    println!("{}", json);
+
+    Ok(())
+}
+
+/// Parses the `--format` and `--spec-version` options, the spec version defaults to 1.6.
+fn parse_options<'a>(
+    mut options: &'a [String],
+    default_format: &'a str,
+) -> Result<(&'a str, SpecVersion), String> {
+    let mut format = default_format;
+    let mut version = SpecVersion::V1_6;
+    while let Some((option, rest)) = options.split_first() {
+        let Some((value, rest)) = rest.split_first() else {
+            return Err(USAGE.to_string());
+        };
+        match option.as_str() {
+            "--format" => format = value,
+            "--spec-version" => {
+                version = SpecVersion::parse(value)
+                    .ok_or_else(|| format!("Unknown spec version '{}'", value))?
+            }
+            _ => return Err(USAGE.to_string()),
+        }
+        options = rest;
+    }
+    Ok((format, version))
+}
+
+/// Applies the safe suggested fixes of all findings to the file and returns the fixed BOM.
+fn apply_fixes(path: &str, version: SpecVersion) -> Result<Bom, String> {
+    let input = fs::read_to_string(path)
+        .map_err(|error| format!("Failed to read '{}': {}", path, error))?;
+    let document = Document::parse(&input)
+        .map_err(|error| format!("Failed to parse '{}': {}", path, error))?;
+
+    let options = ValidationOptions::new();
+    let fixes = match validate_bom(version, document.bom(), &options) {
+        Ok(()) => Vec::new(),
+        Err(errors) => suggested_fixes(&errors, true),
+    };
+    if fixes.is_empty() {
+        return read_bom(path);
+    }
+
+    let outcome = apply_patch(&document, &fixes, version, &options)
+        .map_err(|error| format!("Failed to apply fixes to '{}': {}", path, error))?;
+    let json = serde_json::to_string_pretty(outcome.document.value())
+        .map_err(|error| error.to_string())?;
+    fs::write(path, json).map_err(|error| format!("Failed to write '{}': {}", path, error))?;
+    eprintln!("Applied {} fixes to '{}'", fixes.len(), path);
+
+    read_bom(path)
+}
+
+fn run_report(args: &[String]) -> Result<(), String> {
+    let Some((path, options)) = args.split_first() else {
+        return Err(USAGE.to_string());
+    };
+    let fix = options.iter().any(|option| option == "--apply-fixes");
+    let options = options
+        .iter()
+        .filter(|option| *option != "--apply-fixes")
+        .cloned()
+        .collect::<Vec<_>>();
+    let (format, version) = parse_options(&options, "json")?;
+
+    let bom = if fix {
+        apply_fixes(path, version)?
+    } else {
+        read_bom(path)?
+    };
+    let report = validate_report(version, &bom, &ValidationOptions::new());
+    match format {
+        "json" => println!("{}", report.to_json().map_err(|error| error.to_string())?),
+        "csv" => print!("{}", report.to_csv(&bom)),
+        #[cfg(feature = "report-html")]
+        "html" => print!("{}", validation_tree::html::render_html(&report, &bom)),
+        #[cfg(not(feature = "report-html"))]
+        "html" => return Err(String::from("HTML reports require the report-html feature")),
+        _ => return Err(format!("Unknown report format '{}'", format)),
+    }
+
+    if report.failed.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("{} checks failed", report.failed.len()))
+    }
+}
+
+fn run_lint(args: &[String]) -> Result<(), String> {
+    let Some((path, options)) = args.split_first() else {
+        return Err(USAGE.to_string());
+    };
+    let (_, version) = parse_options(options, "text")?;
+
+    let bom = read_bom(path)?;
+    if let Err(errors) = lint(version, &bom, &ValidationOptions::new()) {
+        println!("{}", errors.to_snapshot_string());
+    }
+
+    Ok(())
+}
+
+fn run_graph(args: &[String]) -> Result<(), String> {
+    let Some((path, options)) = args.split_first() else {
+        return Err(USAGE.to_string());
+    };
+    let (format, version) = parse_options(options, "dot")?;
+
+    let bom = read_bom(path)?;
+    let graph = BomGraph::new(&bom);
+    let result = validate_bom(version, &bom, &ValidationOptions::new());
+    let findings = match &result {
+        Ok(()) => Default::default(),
+        Err(errors) => graph.findings(&bom, errors),
+    };
+
+    match format {
+        "dot" => print!("{}", graph.to_dot_with_findings(&findings)),
+        "graphml" => print!("{}", graph.to_graphml(&findings)),
+        _ => return Err(format!("Unknown graph format '{}'", format)),
+    }
+
+    Ok(())
+}
+
+fn run_batch(args: &[String]) -> Result<(), String> {
+    let split = args
+        .iter()
+        .position(|arg| arg.starts_with("--"))
+        .unwrap_or(args.len());
+    let (paths, options) = args.split_at(split);
+    if paths.is_empty() {
+        return Err(USAGE.to_string());
+    }
+    let (_, version) = parse_options(options, "text")?;
+
+    let threads = thread::available_parallelism().map_or(1, |threads| threads.get());
+    let report = validate_many_parallel(
+        version,
+        paths.iter().map(BomSource::file),
+        &ValidationOptions::new(),
+        threads,
+    );
+    for result in &report.results {
+        match &result.result {
+            Err(errors) if !result.is_valid() => {
+                println!("FAIL  {}\n{}", result.name, errors.to_snapshot_string())
+            }
+            _ => println!("ok    {}", result.name),
+        }
+    }
+
+    let statistics = report.statistics;
+    println!(
+        "{} documents, {} valid, {} invalid, {} errors, {} warnings, {} suppressed",
+        statistics.documents,
+        statistics.valid,
+        statistics.invalid,
+        statistics.errors,
+        statistics.warnings,
+        statistics.suppressed
+    );
+
+    if statistics.invalid > 0 {
+        return Err(format!(
+            "{} of {} documents failed",
+            statistics.invalid, statistics.documents
+        ));
+    }
+
+    Ok(())
+}
+
+fn run_corpus_check(args: &[String]) -> Result<(), String> {
+    let [dir] = args else {
+        return Err(USAGE.to_string());
+    };
+
+    let report = run_corpus(Path::new(dir), &ValidationOptions::new())
+        .map_err(|error| format!("Failed to read corpus '{}': {}", dir, error))?;
+
+    for result in &report.results {
+        match &result.outcome {
+            Ok(()) => println!("ok    {}", result.path.display()),
+            Err(message) => println!("FAIL  {}\n{}", result.path.display(), message),
+        }
+    }
+
+    let failures = report.failures().count();
+    if failures > 0 {
+        return Err(format!(
+            "{} of {} corpus documents failed",
+            failures,
+            report.results.len()
+        ));
+    }
+
+    Ok(())
+}
+
+fn run_coverage(args: &[String]) -> Result<(), String> {
+    let (_, version) = parse_options(args, "text")?;
+
+    let report = coverage(version, Profile::Strict);
+    for field in &report.covered {
+        let validators = field
+            .validators
+            .iter()
+            .map(|validator| match validator {
+                Validator::Model(name) => name.to_string(),
+                Validator::Deprecation => String::from("deprecation"),
+                Validator::Rule(id) => format!("rule {}", id),
+            })
+            .collect::<Vec<_>>();
+        println!("{}: {}", field.path, validators.join(", "));
+    }
+    for path in &report.unchecked {
+        println!("{}: unchecked", path);
+    }
+    println!(
+        "{} of {} fields covered ({:.0}%)",
+        report.covered.len(),
+        report.covered.len() + report.unchecked.len(),
+        report.ratio() * 100.0
+    );
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+
+    let result = match args.split_first() {
+        Some((command, args)) if command == "diff" => run_diff(args),
+        Some((command, args)) if command == "batch" => run_batch(args),
+        Some((command, args)) if command == "report" => run_report(args),
+        Some((command, args)) if command == "lint" => run_lint(args),
+        Some((command, args)) if command == "graph" => run_graph(args),
+        Some((command, args)) if command == "coverage" => run_coverage(args),
+        Some((flag, args)) if flag == "--corpus" => run_corpus_check(args),
+        _ => Err(USAGE.to_string()),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("{}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,282 @@
+//! Builders to assemble a [`Bom`] programmatically, validating it on completion.
+use crate::{
+    options::ValidationOptions,
+    prelude::*,
+    validate_bom,
+    validation::{SpecVersion, Validate, ValidationErrors},
+    Bom, Component, ComponentType, Dependency, ExternalReference, Hash, LicenseChoice, Metadata,
+    OrganizationalEntity, Property, Tool, Vulnerability,
+};
+
+/// Builds a [`Component`].
+#[derive(Debug)]
+pub struct ComponentBuilder {
+    component: Component,
+}
+
+impl ComponentBuilder {
+    pub fn new<T: ToString>(component_type: ComponentType, name: T) -> Self {
+        Self {
+            component: Component::new(component_type, name),
+        }
+    }
+
+    pub fn bom_ref<T: ToString>(mut self, bom_ref: T) -> Self {
+        self.component.bom_ref = Some(bom_ref.to_string());
+        self
+    }
+
+    pub fn version<T: ToString>(mut self, version: T) -> Self {
+        self.component.version = Some(version.to_string());
+        self
+    }
+
+    pub fn supplier<T: ToString>(mut self, name: T) -> Self {
+        self.component.supplier = Some(OrganizationalEntity {
+            name: Some(name.to_string()),
+            contact: None,
+        });
+        self
+    }
+
+    pub fn author<T: ToString>(mut self, author: T) -> Self {
+        self.component.author = Some(author.to_string());
+        self
+    }
+
+    pub fn publisher<T: ToString>(mut self, publisher: T) -> Self {
+        self.component.publisher = Some(publisher.to_string());
+        self
+    }
+
+    pub fn license(mut self, license: LicenseChoice) -> Self {
+        self.component
+            .licenses
+            .get_or_insert_with(Vec::new)
+            .push(license);
+        self
+    }
+
+    pub fn purl<T: ToString>(mut self, purl: T) -> Self {
+        self.component.purl = Some(purl.to_string());
+        self
+    }
+
+    pub fn hash(mut self, hash: Hash) -> Self {
+        self.component
+            .hashes
+            .get_or_insert_with(Vec::new)
+            .push(hash);
+        self
+    }
+
+    pub fn external_reference(mut self, reference: ExternalReference) -> Self {
+        self.component
+            .external_references
+            .get_or_insert_with(Vec::new)
+            .push(reference);
+        self
+    }
+
+    pub fn property<N: ToString, V: ToString>(mut self, name: N, value: V) -> Self {
+        self.component
+            .properties
+            .get_or_insert_with(Vec::new)
+            .push(Property {
+                name: name.to_string(),
+                value: Some(value.to_string()),
+            });
+        self
+    }
+
+    /// Returns the [`Component`] without validating it.
+    pub fn build(self) -> Component {
+        self.component
+    }
+
+    /// Validates the [`Component`] and returns it, or the error tree if it is invalid.
+    pub fn try_build(self, version: SpecVersion) -> Result<Component, ValidationErrors> {
+        self.component
+            .validate(version, &ValidationOptions::new())
+            .map(|()| self.component)
+    }
+}
+
+/// Builds a [`Metadata`].
+#[derive(Debug, Default)]
+pub struct MetadataBuilder {
+    metadata: Metadata,
+}
+
+impl MetadataBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn timestamp<T: ToString>(mut self, timestamp: T) -> Self {
+        self.metadata.timestamp = Some(timestamp.to_string());
+        self
+    }
+
+    pub fn tool(mut self, tool: Tool) -> Self {
+        self.metadata.tools.get_or_insert_with(Vec::new).push(tool);
+        self
+    }
+
+    pub fn component(mut self, component: Component) -> Self {
+        self.metadata.component = Some(component);
+        self
+    }
+
+    /// Returns the [`Metadata`] without validating it.
+    pub fn build(self) -> Metadata {
+        self.metadata
+    }
+
+    /// Validates the [`Metadata`] and returns it, or the error tree if it is invalid.
+    pub fn try_build(self, version: SpecVersion) -> Result<Metadata, ValidationErrors> {
+        self.metadata
+            .validate(version, &ValidationOptions::new())
+            .map(|()| self.metadata)
+    }
+}
+
+/// Builds a [`Bom`].
+#[derive(Debug, Default)]
+pub struct BomBuilder {
+    bom: Bom,
+}
+
+impl BomBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn serial_number<T: ToString>(mut self, serial_number: T) -> Self {
+        self.bom.serial_number = Some(serial_number.to_string());
+        self
+    }
+
+    pub fn version(mut self, version: u32) -> Self {
+        self.bom.version = Some(version);
+        self
+    }
+
+    pub fn metadata(mut self, metadata: Metadata) -> Self {
+        self.bom.meta_data = Some(metadata);
+        self
+    }
+
+    pub fn component(mut self, component: Component) -> Self {
+        self.bom
+            .components
+            .get_or_insert_with(Vec::new)
+            .push(component);
+        self
+    }
+
+    /// Adds a dependency of `dependency_ref` on all components in `depends_on`.
+    pub fn dependency<T: ToString>(mut self, dependency_ref: T, depends_on: &[&str]) -> Self {
+        self.bom
+            .dependencies
+            .get_or_insert_with(Vec::new)
+            .push(Dependency {
+                dependency_ref: dependency_ref.to_string(),
+                depends_on: (!depends_on.is_empty()).then(|| {
+                    depends_on
+                        .iter()
+                        .map(|bom_ref| bom_ref.to_string())
+                        .collect()
+                }),
+            });
+        self
+    }
+
+    pub fn vulnerability<T: ToString>(mut self, id: T) -> Self {
+        self.bom
+            .vulnerabilities
+            .get_or_insert_with(Vec::new)
+            .push(Vulnerability {
+                id: Some(id.to_string()),
+                ..Default::default()
+            });
+        self
+    }
+
+    pub fn property<N: ToString, V: ToString>(mut self, name: N, value: V) -> Self {
+        self.bom
+            .properties
+            .get_or_insert_with(Vec::new)
+            .push(Property {
+                name: name.to_string(),
+                value: Some(value.to_string()),
+            });
+        self
+    }
+
+    /// Returns the [`Bom`] without validating it.
+    pub fn build(self) -> Bom {
+        self.bom
+    }
+
+    /// Validates the [`Bom`] including all rules and returns it, or the error tree if it is
+    /// invalid.
+    pub fn try_build(self, version: SpecVersion) -> Result<Bom, ValidationErrors> {
+        self.try_build_with_options(version, &ValidationOptions::new())
+    }
+
+    /// Same as [`BomBuilder::try_build`], additionally running the rules of `options`.
+    pub fn try_build_with_options(
+        self,
+        version: SpecVersion,
+        options: &ValidationOptions,
+    ) -> Result<Bom, ValidationErrors> {
+        validate_bom(version, &self.bom, options).map(|()| self.bom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{validation::SpecVersion, ComponentType};
+
+    use super::{BomBuilder, ComponentBuilder, MetadataBuilder};
+
+    #[test]
+    fn build_valid_bom() {
+        let application = ComponentBuilder::new(ComponentType::Application, "app")
+            .bom_ref("app")
+            .version("1.0.0")
+            .purl("pkg:cargo/app@1.0.0")
+            .build();
+        let metadata = MetadataBuilder::new()
+            .timestamp("2024-01-02T00:00:00Z")
+            .component(application)
+            .try_build(SpecVersion::V1_5)
+            .expect("Expected valid metadata");
+
+        let bom = BomBuilder::new()
+            .version(1)
+            .metadata(metadata)
+            .component(
+                ComponentBuilder::new(ComponentType::Library, "serde")
+                    .bom_ref("serde")
+                    .purl("pkg:cargo/serde@1.0.196")
+                    .build(),
+            )
+            .dependency("app", &["serde"])
+            .try_build(SpecVersion::V1_5)
+            .expect("Expected valid BOM");
+
+        assert_eq!(bom.components.map(|components| components.len()), Some(1));
+    }
+
+    #[test]
+    fn fails_to_build_invalid_component() {
+        let errors = ComponentBuilder::new(ComponentType::Library, "serde")
+            .purl("cargo/serde")
+            .try_build(SpecVersion::V1_5)
+            .expect_err("Expected invalid component");
+
+        assert!(errors.contains_key("purl"));
+    }
+}
@@ -0,0 +1,168 @@
+//! Canonical serialization of JSON documents, following the JSON Canonicalization Scheme
+//! ([RFC 8785](https://www.rfc-editor.org/rfc/rfc8785)).
+//!
+//! The canonical form has no whitespace, object keys sorted by their UTF-16 code units,
+//! numbers formatted like ECMAScript and strings with minimal escaping. Signatures and
+//! digests computed over it are reproducible, no matter how the document was formatted.
+use core::fmt::Write;
+
+use serde_json::Value;
+
+use crate::prelude::*;
+
+/// Returns the canonical serialization of the JSON value.
+pub fn canonicalize(value: &Value) -> String {
+    let mut output = String::new();
+    write_value(&mut output, value);
+    output
+}
+
+/// Parses the JSON document and returns its canonical serialization.
+pub fn canonicalize_json(input: &str) -> Result<String, serde_json::Error> {
+    serde_json::from_str::<Value>(input).map(|value| canonicalize(&value))
+}
+
+fn write_value(output: &mut String, value: &Value) {
+    match value {
+        Value::Null => output.push_str("null"),
+        Value::Bool(value) => output.push_str(if *value { "true" } else { "false" }),
+        Value::Number(number) => {
+            output.push_str(&format_number(number.as_f64().unwrap_or_default()))
+        }
+        Value::String(string) => write_string(output, string),
+        Value::Array(values) => {
+            output.push('[');
+            for (index, value) in values.iter().enumerate() {
+                if index > 0 {
+                    output.push(',');
+                }
+                write_value(output, value);
+            }
+            output.push(']');
+        }
+        Value::Object(object) => {
+            let mut entries = object.iter().collect::<Vec<_>>();
+            entries.sort_by(|(a, _), (b, _)| a.encode_utf16().cmp(b.encode_utf16()));
+
+            output.push('{');
+            for (index, (key, value)) in entries.into_iter().enumerate() {
+                if index > 0 {
+                    output.push(',');
+                }
+                write_string(output, key);
+                output.push(':');
+                write_value(output, value);
+            }
+            output.push('}');
+        }
+    }
+}
+
+fn write_string(output: &mut String, string: &str) {
+    output.push('"');
+    for c in string.chars() {
+        match c {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\u{08}' => output.push_str("\\b"),
+            '\t' => output.push_str("\\t"),
+            '\n' => output.push_str("\\n"),
+            '\u{0c}' => output.push_str("\\f"),
+            '\r' => output.push_str("\\r"),
+            c if c < ' ' => {
+                let _ = write!(output, "\\u{:04x}", c as u32);
+            }
+            c => output.push(c),
+        }
+    }
+    output.push('"');
+}
+
+/// Formats the number like `Number.prototype.toString` of ECMAScript.
+fn format_number(number: f64) -> String {
+    if number == 0.0 {
+        return String::from("0");
+    }
+
+    // the shortest digits that round trip, e.g. `-1.2345e3`
+    let scientific = format!("{:e}", number);
+    let (mantissa, exponent) = scientific.split_once('e').unwrap_or((&scientific, "0"));
+    let exponent = exponent.parse::<i32>().unwrap_or_default();
+    let (sign, mantissa) = match mantissa.strip_prefix('-') {
+        Some(mantissa) => ("-", mantissa),
+        None => ("", mantissa),
+    };
+    let digits = mantissa.replace('.', "");
+
+    // the value is 0.<digits> * 10^point
+    let len = digits.len() as i32;
+    let point = exponent + 1;
+    let formatted = if len <= point && point <= 21 {
+        format!("{}{}", digits, "0".repeat((point - len) as usize))
+    } else if 0 < point && point <= 21 {
+        let (integer, fraction) = digits.split_at(point as usize);
+        format!("{}.{}", integer, fraction)
+    } else if -6 < point && point <= 0 {
+        format!("0.{}{}", "0".repeat(-point as usize), digits)
+    } else {
+        let exponent = point - 1;
+        let sign = if exponent < 0 { '-' } else { '+' };
+        let (first, rest) = digits.split_at(1);
+        let fraction = if rest.is_empty() {
+            String::new()
+        } else {
+            format!(".{}", rest)
+        };
+        format!("{}{}e{}{}", first, fraction, sign, exponent.abs())
+    };
+    format!("{}{}", sign, formatted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{canonicalize_json, format_number};
+
+    #[test]
+    fn canonicalizes_rfc_8785_example() {
+        let input = r#"{
+            "numbers": [333333333.33333329, 1E30, 4.50, 2e-3, 0.000000000000000000000000001],
+            "string": "€$\u000F\u000aA'\u0042\u0022\u005c\\\"\/",
+            "literals": [null, true, false]
+        }"#;
+
+        assert_eq!(
+            canonicalize_json(input).expect("Failed to parse JSON"),
+            r#"{"literals":[null,true,false],"numbers":[333333333.3333333,1e+30,4.5,0.002,1e-27],"string":"€$\u000f\nA'B\"\\\\\"/"}"#
+        );
+    }
+
+    #[test]
+    fn sorts_keys_by_utf16_code_units() {
+        // U+FB01 is sorted after U+1F600, which is encoded as surrogate pair 0xD83D 0xDE00
+        let input = r#"{ "\ufb01": 0, "€": 1, "😀": 2, "a": 3, "A": { "b": [], "a": {} } }"#;
+
+        assert_eq!(
+            canonicalize_json(input).expect("Failed to parse JSON"),
+            "{\"A\":{\"a\":{},\"b\":[]},\"a\":3,\"\u{20ac}\":1,\"\u{1F600}\":2,\"\u{fb01}\":0}"
+        );
+    }
+
+    #[test]
+    fn formats_numbers_like_ecmascript() {
+        let cases = [
+            (-0.0, "0"),
+            (1.0, "1"),
+            (-1.5, "-1.5"),
+            (1e21, "1e+21"),
+            (1e20, "100000000000000000000"),
+            (1e-7, "1e-7"),
+            (0.000001, "0.000001"),
+            (123456789012.5, "123456789012.5"),
+            (9007199254740993.0, "9007199254740992"),
+            (5e-324, "5e-324"),
+        ];
+        for (number, expected) in cases {
+            assert_eq!(format_number(number), expected, "{}", number);
+        }
+    }
+}
@@ -0,0 +1,207 @@
+use std::{fmt, fs, io, path::Path};
+
+use indexmap::IndexMap;
+use serde::Deserialize;
+
+use crate::{
+    rules::{GroupConventionRule, GroupPattern, PurlQualifierRule},
+    suppress::Suppression,
+    validation::Severity,
+    HashAlgorithm,
+};
+
+/// The validator configuration, usually read from a TOML file, e.g.
+///
+/// ```toml
+/// suppress = ["missing-supplier", "CDX-LIC-002 at components[12]"]
+///
+/// [severity]
+/// missing-author = "error"
+///
+/// [purl]
+/// internal-namespaces = ["com.acme"]
+/// required-qualifiers = { maven = ["type"] }
+///
+/// [groups]
+/// cargo = "acme-*"
+///
+/// [hashes]
+/// allowed = ["SHA-256", "SHA-384", "SHA-512"]
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    /// Findings to suppress in the form `CODE` or `CODE at PATH`.
+    pub suppress: Vec<String>,
+    /// Overrides the severity of findings by their code.
+    pub severity: IndexMap<String, Severity>,
+    /// Policies for purl qualifiers, enables the [`PurlQualifierRule`] if set.
+    pub purl: Option<PurlConfig>,
+    /// Group conventions per purl type, enables the [`GroupConventionRule`] if set.
+    ///
+    /// A convention is `reverse-dns`, `scope` or a glob, it replaces the built-in one.
+    pub groups: Option<IndexMap<String, GroupPattern>>,
+    /// The hash algorithm policy, see
+    /// [`ValidationOptions::allow_hash_algorithms`](crate::options::ValidationOptions::allow_hash_algorithms).
+    pub hashes: Option<HashConfig>,
+}
+
+/// The hash algorithm policy of a [`Config`].
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct HashConfig {
+    /// Components declaring digests must declare one of these algorithms, e.g. `SHA-256`.
+    pub allowed: Vec<HashAlgorithm>,
+}
+
+/// The purl qualifier policies of a [`Config`].
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct PurlConfig {
+    /// Namespaces of internal registries, their purls require a `repository_url` qualifier.
+    pub internal_namespaces: Vec<String>,
+    /// The qualifiers required per purl type.
+    pub required_qualifiers: IndexMap<String, Vec<String>>,
+}
+
+impl PurlConfig {
+    /// Returns the [`PurlQualifierRule`] enforcing these policies.
+    pub fn rule(&self) -> PurlQualifierRule {
+        self.required_qualifiers.iter().fold(
+            PurlQualifierRule::new().internal_namespaces(&self.internal_namespaces),
+            |rule, (purl_type, qualifiers)| rule.require_qualifiers(purl_type, qualifiers),
+        )
+    }
+}
+
+/// The error returned when a [`Config`] cannot be loaded.
+#[derive(Debug)]
+pub enum ConfigError {
+    Io(io::Error),
+    Parse(toml::de::Error),
+    /// The file parsed, but its content is inconsistent, e.g. a rule without any condition.
+    Invalid(String),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Io(error) => write!(f, "Failed to read config: {}", error),
+            ConfigError::Parse(error) => write!(f, "Failed to parse config: {}", error),
+            ConfigError::Invalid(reason) => write!(f, "Invalid config: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+impl Config {
+    /// Parses the configuration from a TOML string.
+    pub fn from_toml(input: &str) -> Result<Self, ConfigError> {
+        toml::from_str(input).map_err(ConfigError::Parse)
+    }
+
+    /// Reads the configuration from a TOML file.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, ConfigError> {
+        let input = fs::read_to_string(path).map_err(ConfigError::Io)?;
+        Self::from_toml(&input)
+    }
+
+    /// Returns the [`GroupConventionRule`] with the configured conventions, if enabled.
+    pub fn group_rule(&self) -> Option<GroupConventionRule> {
+        self.groups.as_ref().map(|groups| {
+            groups
+                .iter()
+                .fold(GroupConventionRule::new(), |rule, (purl_type, pattern)| {
+                    rule.pattern(purl_type, pattern.clone())
+                })
+        })
+    }
+
+    /// Returns the parsed suppressions.
+    pub fn suppressions(&self) -> Vec<Suppression> {
+        self.suppress
+            .iter()
+            .map(|suppression| Suppression::parse(suppression))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        options::ValidationOptions, rules::GroupPattern, suppress::Suppression,
+        validation::Severity, HashAlgorithm,
+    };
+
+    use super::Config;
+
+    #[test]
+    fn parse_suppressions() {
+        let config = Config::from_toml(r#"suppress = ["CDX-LIC-002 at components[12]"]"#)
+            .expect("Failed to parse config");
+
+        assert_eq!(
+            config.suppressions(),
+            vec![Suppression::new("CDX-LIC-002").at("components[12]")]
+        );
+    }
+
+    #[test]
+    fn parse_severity_overrides() {
+        let config = Config::from_toml("[severity]\nmissing-supplier = \"error\"")
+            .expect("Failed to parse config");
+
+        assert_eq!(config.severity["missing-supplier"], Severity::Error);
+    }
+
+    #[test]
+    fn parse_purl_policies() {
+        let config = Config::from_toml(
+            "[purl]\ninternal-namespaces = [\"com.acme\"]\nrequired-qualifiers = { maven = [\"type\"] }",
+        )
+        .expect("Failed to parse config");
+
+        let purl = config.purl.expect("Expected purl policies");
+        assert_eq!(purl.internal_namespaces, vec![String::from("com.acme")]);
+        assert_eq!(
+            purl.required_qualifiers["maven"],
+            vec![String::from("type")]
+        );
+    }
+
+    #[test]
+    fn parse_group_conventions() {
+        let config = Config::from_toml("[groups]\nnpm = \"scope\"\ncargo = \"acme-*\"")
+            .expect("Failed to parse config");
+
+        let groups = config.groups.as_ref().expect("Expected group conventions");
+        assert_eq!(groups["npm"], GroupPattern::Scope);
+        assert_eq!(groups["cargo"], GroupPattern::Glob(String::from("acme-*")));
+        assert!(config.group_rule().is_some());
+    }
+
+    #[test]
+    fn parse_hash_policy() {
+        let config = Config::from_toml("[hashes]\nallowed = [\"SHA-256\", \"SHA-512\"]")
+            .expect("Failed to parse config");
+
+        let options = ValidationOptions::new().with_config(&config);
+        assert_eq!(
+            options.allowed_hash_algorithms(),
+            Some(&[HashAlgorithm::Sha256, HashAlgorithm::Sha512][..])
+        );
+    }
+
+    #[test]
+    fn parse_empty_config() {
+        let config = Config::from_toml("").expect("Failed to parse config");
+
+        assert_eq!(config, Config::default());
+    }
+
+    #[test]
+    fn fails_on_invalid_config() {
+        assert!(Config::from_toml("suppress = 1").is_err());
+    }
+}
@@ -0,0 +1,219 @@
+//! Runs the validation across a corpus of example documents, e.g. the examples and test
+//! suite of the CycloneDX specification, and checks the verdicts.
+//!
+//! The corpus is a directory with one sub directory per spec version, e.g. `1.5/`, holding
+//! JSON documents. Documents whose file name starts with `valid` must pass the validation,
+//! documents starting with `invalid` must fail. An optional `<file name>.expected` file next
+//! to an invalid document lists the codes of the expected findings, one per line.
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    options::ValidationOptions,
+    validate_bytes,
+    validation::{SpecVersion, ValidationErrors},
+};
+
+/// The verdict for a single document of the corpus.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CorpusResult {
+    pub path: PathBuf,
+    pub version: SpecVersion,
+    /// `Err` with a description if the validation did not match the expectation.
+    pub outcome: Result<(), String>,
+}
+
+/// The verdicts of all documents of a corpus.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CorpusReport {
+    pub results: Vec<CorpusResult>,
+}
+
+impl CorpusReport {
+    /// Returns all documents whose validation did not match the expectation.
+    pub fn failures(&self) -> impl Iterator<Item = &CorpusResult> {
+        self.results.iter().filter(|result| result.outcome.is_err())
+    }
+
+    /// Returns `true` if all documents matched their expectation.
+    pub fn is_success(&self) -> bool {
+        self.failures().next().is_none()
+    }
+}
+
+/// Validates all documents of the corpus in `root`, see the module documentation for the layout.
+pub fn run_corpus(root: &Path, options: &ValidationOptions) -> io::Result<CorpusReport> {
+    let mut report = CorpusReport::default();
+
+    for version_dir in sorted_entries(root)? {
+        let Some(version) = version_dir
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(SpecVersion::parse)
+        else {
+            continue;
+        };
+
+        for path in sorted_entries(&version_dir)? {
+            let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+                continue;
+            };
+            if !name.ends_with(".json") {
+                continue;
+            }
+
+            let outcome = if name.starts_with("invalid") {
+                let expected = read_expected(&path)?;
+                check_invalid(version, &fs::read(&path)?, &expected, options)
+            } else if name.starts_with("valid") {
+                check_valid(version, &fs::read(&path)?, options)
+            } else {
+                continue;
+            };
+
+            report.results.push(CorpusResult {
+                path,
+                version,
+                outcome,
+            });
+        }
+    }
+
+    Ok(report)
+}
+
+fn sorted_entries(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut entries = fs::read_dir(dir)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<io::Result<Vec<_>>>()?;
+    entries.sort();
+    Ok(entries)
+}
+
+fn read_expected(path: &Path) -> io::Result<Vec<String>> {
+    let mut expected_path = path.as_os_str().to_owned();
+    expected_path.push(".expected");
+
+    match fs::read_to_string(expected_path) {
+        Ok(content) => Ok(content
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(String::from)
+            .collect()),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(error) => Err(error),
+    }
+}
+
+fn check_valid(
+    version: SpecVersion,
+    input: &[u8],
+    options: &ValidationOptions,
+) -> Result<(), String> {
+    match validate_bytes(version, input, options) {
+        Err(errors) if errors.has_unsuppressed() => Err(format!(
+            "Expected a valid document, but got:\n{}",
+            errors.to_snapshot_string()
+        )),
+        _ => Ok(()),
+    }
+}
+
+fn check_invalid(
+    version: SpecVersion,
+    input: &[u8],
+    expected: &[String],
+    options: &ValidationOptions,
+) -> Result<(), String> {
+    let errors = match validate_bytes(version, input, options) {
+        Err(errors) if errors.has_unsuppressed() => errors,
+        _ => return Err(String::from("Expected an invalid document, but it passed")),
+    };
+
+    let missing = expected
+        .iter()
+        .filter(|code| !has_code(&errors, code))
+        .map(String::as_str)
+        .collect::<Vec<_>>();
+    if missing.is_empty() {
+        Ok(())
+    } else {
+        Err(format!(
+            "Expected findings [{}] are missing, got:\n{}",
+            missing.join(", "),
+            errors.to_snapshot_string()
+        ))
+    }
+}
+
+fn has_code(errors: &ValidationErrors, code: &str) -> bool {
+    errors
+        .errors()
+        .iter()
+        .any(|(_, error)| !error.suppressed && error.code.as_deref() == Some(code))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::PathBuf};
+
+    use crate::options::ValidationOptions;
+
+    use super::run_corpus;
+
+    fn corpus_dir(name: &str, files: &[(&str, &str)]) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("validation-tree-corpus-{}", name));
+        let _ = fs::remove_dir_all(&root);
+        for (path, content) in files {
+            let path = root.join(path);
+            fs::create_dir_all(path.parent().expect("Expected parent dir"))
+                .expect("Failed to create corpus dir");
+            fs::write(path, content).expect("Failed to write corpus file");
+        }
+        root
+    }
+
+    #[test]
+    fn corpus_matches_expectations() {
+        let root = corpus_dir(
+            "matches",
+            &[
+                ("1.5/valid-minimal.json", r#"{ "serialNumber": "1" }"#),
+                (
+                    "1.5/invalid-purl.json",
+                    r#"{ "components": [{ "type": "library", "name": "a", "purl": "a" }] }"#,
+                ),
+                ("1.5/invalid-purl.json.expected", "invalid-purl\n"),
+                (
+                    "1.3/invalid-vulnerabilities.json",
+                    r#"{ "vulnerabilities": [{}] }"#,
+                ),
+                ("README.md", "ignored"),
+            ],
+        );
+
+        let report = run_corpus(&root, &ValidationOptions::new()).expect("Failed to run corpus");
+        assert_eq!(report.results.len(), 3);
+        assert!(report.is_success(), "{:?}", report);
+    }
+
+    #[test]
+    fn corpus_reports_divergences() {
+        let root = corpus_dir(
+            "divergences",
+            &[
+                (
+                    "1.5/valid-purl.json",
+                    r#"{ "components": [{ "type": "library", "name": "a", "purl": "a" }] }"#,
+                ),
+                ("1.5/invalid-minimal.json", r#"{ "serialNumber": "1" }"#),
+            ],
+        );
+
+        let report = run_corpus(&root, &ValidationOptions::new()).expect("Failed to run corpus");
+        assert_eq!(report.failures().count(), 2);
+    }
+}
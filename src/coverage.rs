@@ -0,0 +1,170 @@
+//! Reports which fields of the model are checked by at least one validator in a spec version.
+//!
+//! A field counts as covered when a validator of the model (`#[validate(custom = "...")]` or
+//! a hand written [`Validate`](crate::validation::Validate) impl), its entry in the
+//! [`deprecations`](crate::deprecations) registry or one of the built-in [`rules`] reports
+//! findings at its path. Fields that only get traversed, e.g. with `#[validate(nested)]`, or
+//! that are only checked for the version they got introduced in are not covered.
+use crate::{deprecations, options::Profile, prelude::*, rules, validation::SpecVersion};
+
+/// A field of the model, generated from the model structs by the build script.
+struct ModelField {
+    path: &'static str,
+    model: &'static str,
+    since: Option<SpecVersion>,
+    checks: &'static [&'static str],
+}
+
+include!(concat!(env!("OUT_DIR"), "/coverage.rs"));
+
+/// A check reporting findings at a field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Validator {
+    /// A validator of the model, e.g. `validate_purl` or `Hash::validate`.
+    Model(&'static str),
+    /// The entry of the field in the deprecation registry.
+    Deprecation,
+    /// A built-in rule, by its id.
+    Rule(&'static str),
+}
+
+/// A covered field together with all validators checking it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldCoverage {
+    /// The path of the field, formatted like the constants of [`paths`](crate::paths).
+    pub path: &'static str,
+    pub validators: Vec<Validator>,
+}
+
+/// The fields of a spec version split into covered and unchecked ones, in model order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CoverageReport {
+    pub version: SpecVersion,
+    pub covered: Vec<FieldCoverage>,
+    pub unchecked: Vec<&'static str>,
+}
+
+impl CoverageReport {
+    /// Returns the share of covered fields, between `0.0` and `1.0`.
+    pub fn ratio(&self) -> f64 {
+        let total = self.covered.len() + self.unchecked.len();
+        if total == 0 {
+            return 1.0;
+        }
+        self.covered.len() as f64 / total as f64
+    }
+
+    /// Returns the validators checking the field at `path`, empty if it is unchecked.
+    pub fn validators(&self, path: &str) -> &[Validator] {
+        self.covered
+            .iter()
+            .find(|field| field.path == path)
+            .map(|field| field.validators.as_slice())
+            .unwrap_or_default()
+    }
+}
+
+/// Returns the coverage of all fields that exist in the given spec version, taking the
+/// built-in rules running with the given profile into account.
+pub fn coverage(version: SpecVersion, profile: Profile) -> CoverageReport {
+    let mut report = CoverageReport {
+        version,
+        covered: Vec::new(),
+        unchecked: Vec::new(),
+    };
+
+    for field in FIELDS
+        .iter()
+        .filter(|field| field.since.is_none_or(|since| version >= since))
+    {
+        let field_name = field.path.rsplit('.').next().unwrap_or(field.path);
+        let model = field.checks.iter().filter_map(|check| match *check {
+            "deprecated" => deprecations::find(field.model, field_name)
+                .filter(|deprecation| version >= deprecation.deprecated_in)
+                .map(|_| Validator::Deprecation),
+            check => Some(Validator::Model(check)),
+        });
+        let rules = rules::all()
+            .iter()
+            .filter(|rule| rule.applies_to(version, profile) && rule.paths.contains(&field.path))
+            .map(|rule| Validator::Rule(rule.id));
+
+        let validators = model.chain(rules).collect::<Vec<_>>();
+        if validators.is_empty() {
+            report.unchecked.push(field.path);
+        } else {
+            report.covered.push(FieldCoverage {
+                path: field.path,
+                validators,
+            });
+        }
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        options::Profile,
+        paths::{
+            COMPONENTS_AUTHOR, COMPONENTS_HASHES_CONTENT, COMPONENTS_MODIFIED, COMPONENTS_PURL,
+            COMPONENTS_SUPPLIER, FORMULATION,
+        },
+        validation::SpecVersion,
+    };
+
+    use super::{coverage, Validator};
+
+    #[test]
+    fn lists_validators_of_covered_fields() {
+        let report = coverage(SpecVersion::V1_6, Profile::Strict);
+
+        assert_eq!(
+            report.validators(COMPONENTS_PURL),
+            &[
+                Validator::Model("validate_purl"),
+                Validator::Rule("purl-presence")
+            ]
+        );
+        assert_eq!(
+            report.validators(COMPONENTS_HASHES_CONTENT),
+            &[
+                Validator::Model("Hash::validate"),
+                Validator::Rule("hash-consistency")
+            ]
+        );
+        assert_eq!(
+            report.validators(COMPONENTS_AUTHOR),
+            &[Validator::Deprecation, Validator::Rule("completeness")]
+        );
+    }
+
+    #[test]
+    fn depends_on_profile_and_version() {
+        let report = coverage(SpecVersion::V1_3, Profile::Default);
+
+        assert!(report.unchecked.contains(&COMPONENTS_AUTHOR));
+        assert!(report.validators(COMPONENTS_MODIFIED).is_empty());
+        assert_eq!(
+            report.validators(COMPONENTS_SUPPLIER),
+            &[
+                Validator::Rule("supplier-consistency"),
+                Validator::Rule("duplicate-contacts")
+            ]
+        );
+        assert!(!report.unchecked.contains(&FORMULATION));
+        assert!(coverage(SpecVersion::V1_5, Profile::Default)
+            .unchecked
+            .contains(&FORMULATION));
+    }
+
+    #[test]
+    fn splits_all_fields() {
+        let report = coverage(SpecVersion::V1_6, Profile::Strict);
+
+        assert!(!report.covered.is_empty());
+        assert!(!report.unchecked.is_empty());
+        assert!(report.ratio() > 0.0 && report.ratio() < 1.0);
+    }
+}
@@ -0,0 +1,160 @@
+//! The registry of deprecated fields per spec version.
+//!
+//! Every field that is deprecated by the CycloneDX specification is listed once in
+//! [`DEPRECATIONS`], together with the version it got deprecated in and, if known, the version
+//! it is removed in. Fields marked with `#[validate(deprecated)]` look up their entry here, so
+//! the warnings are consistent across all models and the [`upgrade`](crate::upgrade) report
+//! derives the same rename or remove actions from them.
+use crate::validation::{SpecVersion, ValidationError, VersionSupport};
+
+/// A field deprecated by the specification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Deprecation {
+    /// The name of the model, e.g. `Component`.
+    pub model: &'static str,
+    /// The name of the field, e.g. `author`.
+    pub field: &'static str,
+    /// The first spec version the field is deprecated in.
+    pub deprecated_in: SpecVersion,
+    /// The first spec version the field is no longer supported in.
+    pub removed_in: Option<SpecVersion>,
+    /// The field to use instead, if any.
+    pub replacement: Option<&'static str>,
+}
+
+impl Deprecation {
+    /// Returns the finding for a present field in the given spec version, if any.
+    pub fn check(&self, version: SpecVersion) -> Option<ValidationError> {
+        if let Some(removed_in) = self.removed_in.filter(|removed_in| version >= *removed_in) {
+            return Some(
+                ValidationError::new("field_removed")
+                    .with_param("field", self.field)
+                    .with_param("removed", removed_in)
+                    .with_code("field-not-supported")
+                    .with_version_support(VersionSupport::removed_in(removed_in)),
+            );
+        }
+        if version < self.deprecated_in {
+            return None;
+        }
+
+        let error = match self.replacement {
+            Some(replacement) => ValidationError::warning("field_deprecated_replaced")
+                .with_param("replacement", replacement),
+            None => ValidationError::warning("field_deprecated"),
+        };
+        Some(
+            error
+                .with_param("field", self.field)
+                .with_param("deprecated", self.deprecated_in)
+                .with_code("field-deprecated"),
+        )
+    }
+}
+
+/// All deprecated fields known to the validator.
+pub const DEPRECATIONS: &[Deprecation] = &[
+    Deprecation {
+        model: "Component",
+        field: "author",
+        deprecated_in: SpecVersion::V1_6,
+        removed_in: None,
+        replacement: Some("authors"),
+    },
+    Deprecation {
+        model: "Component",
+        field: "modified",
+        deprecated_in: SpecVersion::V1_4,
+        removed_in: None,
+        replacement: Some("pedigree"),
+    },
+];
+
+/// Returns the deprecation of the field of the given model, if it is deprecated at all.
+pub fn find(model: &str, field: &str) -> Option<&'static Deprecation> {
+    DEPRECATIONS
+        .iter()
+        .find(|deprecation| deprecation.model == model && deprecation.field == field)
+}
+
+/// Reports a present field according to its entry in [`DEPRECATIONS`].
+///
+/// Used by `#[validate(deprecated)]`, a field without an entry is never reported.
+pub fn validate_deprecated(
+    model: &str,
+    field: &str,
+    present: bool,
+    version: SpecVersion,
+) -> Option<Result<(), ValidationError>> {
+    if !present {
+        return None;
+    }
+    find(model, field)
+        .and_then(|deprecation| deprecation.check(version))
+        .map(Err)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::validation::{Severity, SpecVersion, VersionSupport};
+
+    use super::{find, validate_deprecated, Deprecation, DEPRECATIONS};
+
+    #[test]
+    fn lists_every_field_once() {
+        for (index, deprecation) in DEPRECATIONS.iter().enumerate() {
+            assert_eq!(
+                find(deprecation.model, deprecation.field),
+                Some(&DEPRECATIONS[index])
+            );
+            assert!(deprecation
+                .removed_in
+                .is_none_or(|removed_in| removed_in > deprecation.deprecated_in));
+        }
+    }
+
+    #[test]
+    fn warns_from_deprecated_version_on() {
+        assert!(validate_deprecated("Component", "modified", true, SpecVersion::V1_3).is_none());
+        assert!(validate_deprecated("Component", "modified", false, SpecVersion::V1_5).is_none());
+
+        let Some(Err(error)) =
+            validate_deprecated("Component", "modified", true, SpecVersion::V1_4)
+        else {
+            panic!("Expected a deprecation warning");
+        };
+        assert_eq!(error.severity, Severity::Warning);
+        assert_eq!(error.code.as_deref(), Some("field-deprecated"));
+        assert_eq!(
+            error.to_string(),
+            "Field 'modified' is deprecated since spec version 1.4, use 'pedigree' instead"
+        );
+    }
+
+    #[test]
+    fn fails_from_removed_version_on() {
+        let deprecation = Deprecation {
+            model: "Tool",
+            field: "vendor",
+            deprecated_in: SpecVersion::V1_5,
+            removed_in: Some(SpecVersion::V1_6),
+            replacement: None,
+        };
+
+        let warning = deprecation
+            .check(SpecVersion::V1_5)
+            .expect("Expected warning");
+        assert_eq!(warning.severity, Severity::Warning);
+        assert!(!warning.is_unsupported_feature());
+
+        let error = deprecation
+            .check(SpecVersion::V1_6)
+            .expect("Expected error");
+        assert_eq!(error.severity, Severity::Error);
+        assert_eq!(error.code.as_deref(), Some("field-not-supported"));
+        assert_eq!(
+            error.version_support,
+            Some(VersionSupport::removed_in(SpecVersion::V1_6))
+        );
+    }
+}
@@ -0,0 +1,261 @@
+use alloc::collections::BTreeSet;
+
+use serde::Serialize;
+
+use crate::{map::IndexMap, prelude::*, Bom, Component};
+
+/// Summary of a component that was added to or removed from a document.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComponentSummary {
+    pub identity: String,
+    pub name: String,
+    pub version: Option<String>,
+}
+
+/// A component that is present in both documents, but with a different version.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VersionChange {
+    pub identity: String,
+    pub old_version: Option<String>,
+    pub new_version: Option<String>,
+}
+
+/// A component that is present in both documents, but with different licenses.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LicenseChange {
+    pub identity: String,
+    pub old_licenses: Vec<String>,
+    pub new_licenses: Vec<String>,
+}
+
+/// A single edge of the dependency graph, `from` depends on `to`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub struct DependencyEdge {
+    pub from: String,
+    pub to: String,
+}
+
+/// The structured set of changes between two [`Bom`]s, see [`diff`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeSet {
+    pub components_added: Vec<ComponentSummary>,
+    pub components_removed: Vec<ComponentSummary>,
+    pub versions_changed: Vec<VersionChange>,
+    pub licenses_changed: Vec<LicenseChange>,
+    pub dependencies_added: Vec<DependencyEdge>,
+    pub dependencies_removed: Vec<DependencyEdge>,
+}
+
+impl ChangeSet {
+    /// Returns `true` if both documents are equal in all compared aspects.
+    pub fn is_empty(&self) -> bool {
+        self.components_added.is_empty()
+            && self.components_removed.is_empty()
+            && self.versions_changed.is_empty()
+            && self.licenses_changed.is_empty()
+            && self.dependencies_added.is_empty()
+            && self.dependencies_removed.is_empty()
+    }
+}
+
+/// Compares two [`Bom`]s and returns the [`ChangeSet`] to get from `old` to `new`.
+///
+/// Components are matched by their purl without version, qualifiers and subpath, or by
+/// their name if they have no valid purl. Changes are listed in document order.
+pub fn diff(old: &Bom, new: &Bom) -> ChangeSet {
+    let old_components = components_by_identity(old);
+    let new_components = components_by_identity(new);
+
+    let mut change_set = ChangeSet::default();
+    for (identity, component) in &new_components {
+        match old_components.get(identity) {
+            None => change_set
+                .components_added
+                .push(summary(identity, component)),
+            Some(old_component) => {
+                if old_component.version != component.version {
+                    change_set.versions_changed.push(VersionChange {
+                        identity: identity.clone(),
+                        old_version: old_component.version.clone(),
+                        new_version: component.version.clone(),
+                    });
+                }
+
+                let old_licenses = licenses(old_component);
+                let new_licenses = licenses(component);
+                if old_licenses != new_licenses {
+                    change_set.licenses_changed.push(LicenseChange {
+                        identity: identity.clone(),
+                        old_licenses,
+                        new_licenses,
+                    });
+                }
+            }
+        }
+    }
+    change_set.components_removed = old_components
+        .iter()
+        .filter(|(identity, _)| !new_components.contains_key(*identity))
+        .map(|(identity, component)| summary(identity, component))
+        .collect();
+
+    let old_edges = dependency_edges(old);
+    let new_edges = dependency_edges(new);
+    change_set.dependencies_added = new_edges.difference(&old_edges).cloned().collect();
+    change_set.dependencies_removed = old_edges.difference(&new_edges).cloned().collect();
+
+    change_set
+}
+
+/// Returns the identity used to match components between documents, without version.
+fn identity(component: &Component) -> String {
+    component.identity().without_version().to_string()
+}
+
+fn components_by_identity(bom: &Bom) -> IndexMap<String, &Component> {
+    let mut components = IndexMap::default();
+    for component in bom.components.iter().flatten() {
+        components.entry(identity(component)).or_insert(component);
+    }
+    components
+}
+
+fn summary(identity: &str, component: &Component) -> ComponentSummary {
+    ComponentSummary {
+        identity: identity.to_string(),
+        name: component.name.clone(),
+        version: component.version.clone(),
+    }
+}
+
+fn licenses(component: &Component) -> Vec<String> {
+    component
+        .licenses
+        .iter()
+        .flatten()
+        .map(|license| license.to_string())
+        .collect()
+}
+
+fn dependency_edges(bom: &Bom) -> BTreeSet<DependencyEdge> {
+    bom.dependencies
+        .iter()
+        .flatten()
+        .flat_map(|dependency| {
+            dependency
+                .depends_on
+                .iter()
+                .flatten()
+                .map(|to| DependencyEdge {
+                    from: dependency.dependency_ref.clone(),
+                    to: to.clone(),
+                })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Bom;
+
+    use super::{diff, ComponentSummary, DependencyEdge, LicenseChange, VersionChange};
+
+    const OLD: &str = r#"{
+        "components": [
+            { "type": "library", "name": "serde", "version": "1.0.100", "purl": "pkg:cargo/serde@1.0.100",
+              "licenses": [{ "license": { "id": "MIT" } }] },
+            { "type": "library", "name": "log", "version": "0.4.0", "purl": "pkg:cargo/log@0.4.0" }
+        ],
+        "dependencies": [
+            { "ref": "app", "dependsOn": ["serde", "log"] }
+        ]
+    }"#;
+
+    const NEW: &str = r#"{
+        "components": [
+            { "type": "library", "name": "serde", "version": "1.0.196", "purl": "pkg:cargo/serde@1.0.196",
+              "licenses": [{ "expression": "MIT OR Apache-2.0" }] },
+            { "type": "library", "name": "indexmap", "version": "2.2.2" }
+        ],
+        "dependencies": [
+            { "ref": "app", "dependsOn": ["serde", "indexmap"] }
+        ]
+    }"#;
+
+    #[test]
+    fn diff_equal_documents() {
+        let bom = Bom::parse_json(OLD).expect("Failed to parse BOM");
+
+        assert!(diff(&bom, &bom).is_empty());
+    }
+
+    #[test]
+    fn diff_changed_documents() {
+        let old = Bom::parse_json(OLD).expect("Failed to parse BOM");
+        let new = Bom::parse_json(NEW).expect("Failed to parse BOM");
+
+        let change_set = diff(&old, &new);
+
+        assert_eq!(
+            change_set.components_added,
+            vec![ComponentSummary {
+                identity: String::from("indexmap"),
+                name: String::from("indexmap"),
+                version: Some(String::from("2.2.2")),
+            }]
+        );
+        assert_eq!(
+            change_set.components_removed,
+            vec![ComponentSummary {
+                identity: String::from("pkg:cargo/log"),
+                name: String::from("log"),
+                version: Some(String::from("0.4.0")),
+            }]
+        );
+        assert_eq!(
+            change_set.versions_changed,
+            vec![VersionChange {
+                identity: String::from("pkg:cargo/serde"),
+                old_version: Some(String::from("1.0.100")),
+                new_version: Some(String::from("1.0.196")),
+            }]
+        );
+        assert_eq!(
+            change_set.licenses_changed,
+            vec![LicenseChange {
+                identity: String::from("pkg:cargo/serde"),
+                old_licenses: vec![String::from("MIT")],
+                new_licenses: vec![String::from("MIT OR Apache-2.0")],
+            }]
+        );
+        assert_eq!(
+            change_set.dependencies_added,
+            vec![DependencyEdge {
+                from: String::from("app"),
+                to: String::from("indexmap"),
+            }]
+        );
+        assert_eq!(
+            change_set.dependencies_removed,
+            vec![DependencyEdge {
+                from: String::from("app"),
+                to: String::from("log"),
+            }]
+        );
+    }
+
+    #[test]
+    fn serialize_change_set_as_json() {
+        let old = Bom::parse_json(OLD).expect("Failed to parse BOM");
+        let new = Bom::parse_json(NEW).expect("Failed to parse BOM");
+
+        let json = serde_json::to_value(diff(&old, &new)).expect("Failed to serialize");
+
+        assert_eq!(json["versionsChanged"][0]["newVersion"], "1.0.196");
+        assert_eq!(json["dependenciesRemoved"][0]["to"], "log");
+    }
+}
@@ -0,0 +1,205 @@
+//! Differential testing against the upstream [cyclonedx-cli](https://github.com/CycloneDX/cyclonedx-cli).
+//!
+//! Runs the same documents through `cyclonedx validate` and this crate and reports the
+//! documents where both verdicts diverge. The upstream tool is optional, if it is not found
+//! on `PATH` no comparison is made.
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+};
+
+use crate::{
+    options::ValidationOptions,
+    validate_bytes,
+    validation::{SpecVersion, ValidationErrors},
+};
+
+/// The verdicts of both validators for a single document.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub path: PathBuf,
+    pub version: SpecVersion,
+    /// The findings of this crate, `None` if the document is valid.
+    pub errors: Option<ValidationErrors>,
+    /// `true` if the upstream validator accepted the document.
+    pub upstream_valid: bool,
+}
+
+impl Comparison {
+    /// Returns `true` if this crate accepted the document.
+    pub fn valid(&self) -> bool {
+        self.errors.is_none()
+    }
+
+    /// Returns `true` if both validators disagree on the document.
+    pub fn diverges(&self) -> bool {
+        self.valid() != self.upstream_valid
+    }
+}
+
+/// Runs documents through the upstream validator and this crate.
+#[derive(Debug, Clone)]
+pub struct Differential {
+    program: String,
+}
+
+impl Default for Differential {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Differential {
+    /// Uses the `cyclonedx` binary found on `PATH`.
+    pub fn new() -> Self {
+        Self::with_program("cyclonedx")
+    }
+
+    /// Uses the given binary as upstream validator.
+    pub fn with_program(program: impl Into<String>) -> Self {
+        Self {
+            program: program.into(),
+        }
+    }
+
+    /// Returns `true` if the upstream validator can be run.
+    pub fn is_available(&self) -> bool {
+        Command::new(&self.program)
+            .arg("--version")
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .is_ok()
+    }
+
+    /// Validates the JSON document at `path` with both validators.
+    pub fn compare(
+        &self,
+        version: SpecVersion,
+        path: &Path,
+        options: &ValidationOptions,
+    ) -> io::Result<Comparison> {
+        let errors = validate_bytes(version, &fs::read(path)?, options)
+            .err()
+            .filter(ValidationErrors::has_unsuppressed);
+
+        let upstream_valid = Command::new(&self.program)
+            .arg("validate")
+            .arg("--input-file")
+            .arg(path)
+            .args(["--input-format", "json"])
+            .arg("--input-version")
+            .arg(format!("v{}", version.to_string().replace('.', "_")))
+            .arg("--fail-on-errors")
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()?
+            .success();
+
+        Ok(Comparison {
+            path: path.to_path_buf(),
+            version,
+            errors,
+            upstream_valid,
+        })
+    }
+
+    /// Compares all JSON documents in `dir`, returns only the divergent ones.
+    pub fn divergences(
+        &self,
+        version: SpecVersion,
+        dir: &Path,
+        options: &ValidationOptions,
+    ) -> io::Result<Vec<Comparison>> {
+        let mut paths = fs::read_dir(dir)?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<io::Result<Vec<_>>>()?;
+        paths.retain(|path| {
+            path.extension()
+                .is_some_and(|extension| extension == "json")
+        });
+        paths.sort();
+
+        let mut divergences = Vec::new();
+        for path in paths {
+            let comparison = self.compare(version, &path, options)?;
+            if comparison.diverges() {
+                divergences.push(comparison);
+            }
+        }
+        Ok(divergences)
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use std::{fs, path::PathBuf};
+
+    use crate::{options::ValidationOptions, validation::SpecVersion};
+
+    use super::Differential;
+
+    fn documents(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("validation-tree-differential-{}", name));
+        fs::create_dir_all(&dir).expect("Failed to create dir");
+        fs::write(dir.join("valid.json"), r#"{ "serialNumber": "1" }"#)
+            .expect("Failed to write document");
+        fs::write(
+            dir.join("invalid.json"),
+            r#"{ "components": [{ "type": "library", "name": "a", "purl": "a" }] }"#,
+        )
+        .expect("Failed to write document");
+        dir
+    }
+
+    #[test]
+    fn reports_divergent_verdicts() {
+        let dir = documents("accepting");
+
+        // `true` accepts every document
+        let divergences = Differential::with_program("true")
+            .divergences(SpecVersion::V1_5, &dir, &ValidationOptions::new())
+            .expect("Failed to compare");
+
+        assert_eq!(divergences.len(), 1);
+        assert!(divergences[0].path.ends_with("invalid.json"));
+        assert!(divergences[0].upstream_valid);
+    }
+
+    #[test]
+    fn skips_missing_upstream_validator() {
+        let differential = Differential::with_program("cyclonedx-validation-tree-missing");
+        assert!(!differential.is_available());
+    }
+
+    /// Documents of [`documents`] the upstream validator is known to judge differently, with
+    /// the reason.
+    const KNOWN_DIVERGENCES: &[(&str, &str)] = &[(
+        "valid.json",
+        "the upstream schema requires `bomFormat`, `specVersion` and a `urn:uuid` serial number",
+    )];
+
+    #[test]
+    fn compares_with_cyclonedx_cli() {
+        let differential = Differential::new();
+        if !differential.is_available() {
+            return;
+        }
+
+        let dir = documents("cyclonedx-cli");
+        let divergences = differential
+            .divergences(SpecVersion::V1_5, &dir, &ValidationOptions::new())
+            .expect("Failed to compare");
+        let names = divergences
+            .iter()
+            .filter_map(|comparison| comparison.path.file_name()?.to_str())
+            .collect::<Vec<_>>();
+        let known = KNOWN_DIVERGENCES
+            .iter()
+            .map(|(name, _)| *name)
+            .collect::<Vec<_>>();
+
+        assert_eq!(names, known, "Divergences: {:?}", divergences);
+    }
+}
@@ -0,0 +1,299 @@
+use std::{error::Error, future::Future};
+
+use futures::{
+    future::{join_all, BoxFuture},
+    FutureExt,
+};
+
+use crate::{
+    apply_options,
+    options::ValidationOptions,
+    rules::RuleContext,
+    suppress, validate_bom,
+    validation::{
+        SpecVersion, ValidationContext, ValidationError, ValidationErrors, ValidationResult,
+    },
+    Bom, ReleaseNotes,
+};
+
+/// A hook that consults an external service during validation, e.g. to check that a purl
+/// exists in its package registry or to fetch an updated SPDX license list.
+///
+/// Hooks only run when [`ValidationOptions::online`] is enabled, all registered hooks run
+/// concurrently and their errors are merged into the same error tree as the built-in validation.
+/// Like for rules, suppressions, severity overrides and the selected paths of the options apply.
+pub trait EnrichmentHook: Send + Sync {
+    fn enrich<'a>(&'a self, bom: &'a Bom, ctx: &'a RuleContext) -> BoxFuture<'a, ValidationResult>;
+}
+
+/// The async variant of [`Validate`](crate::validation::Validate) that also runs enrichment hooks.
+pub trait ValidateAsync {
+    fn validate_async(
+        &self,
+        version: SpecVersion,
+        options: &ValidationOptions,
+    ) -> impl Future<Output = ValidationResult>;
+}
+
+impl ValidateAsync for Bom {
+    async fn validate_async(
+        &self,
+        version: SpecVersion,
+        options: &ValidationOptions,
+    ) -> ValidationResult {
+        let result = validate_bom(version, self, options);
+        if !options.is_online() {
+            return result;
+        }
+
+        let context = RuleContext::new(version).with_profile(options.selected_profile());
+        let mut suppressions = suppress::collect(self);
+        suppressions.extend_from_slice(options.suppressions());
+        let hook_results = join_all(
+            options
+                .hooks()
+                .iter()
+                .map(|hook| hook.enrich(self, &context)),
+        )
+        .await;
+
+        hook_results
+            .into_iter()
+            .map(|hook_result| apply_options(hook_result, version, options, &suppressions))
+            .fold(result, ValidationErrors::merge_errors)
+    }
+}
+
+/// Resolves the content type of a url, e.g. with a `HEAD` request.
+pub trait ContentTypeSource: Send + Sync {
+    fn content_type<'a>(
+        &'a self,
+        url: &'a str,
+    ) -> BoxFuture<'a, Result<String, Box<dyn Error + Send + Sync>>>;
+}
+
+/// [`EnrichmentHook`] that warns about `featuredImage` and `socialImage` urls of release
+/// notes which do not resolve to an `image/*` content type.
+#[derive(Debug)]
+pub struct ReleaseNoteImagesHook<S> {
+    source: S,
+}
+
+impl<S: ContentTypeSource> ReleaseNoteImagesHook<S> {
+    pub fn new(source: S) -> Self {
+        Self { source }
+    }
+
+    async fn check_image(&self, url: Option<&str>) -> Option<Result<(), ValidationError>> {
+        let error = match self.source.content_type(url?).await {
+            Ok(content_type)
+                if content_type
+                    .trim()
+                    .to_ascii_lowercase()
+                    .starts_with("image/") =>
+            {
+                return None
+            }
+            Ok(content_type) => ValidationError::warning("image_content_type")
+                .with_param("content_type", &content_type)
+                .with_code("image-content-type")
+                .with_expected("image/*")
+                .with_actual(content_type),
+            Err(error) => ValidationError::warning("image_query_failed")
+                .with_param("error", error)
+                .with_code("image-query-failed"),
+        };
+        Some(Err(error))
+    }
+
+    async fn check_notes(&self, notes: Option<&ReleaseNotes>) -> ValidationResult {
+        let Some(notes) = notes else {
+            return Ok(());
+        };
+        let notes = ValidationContext::new()
+            .add_field(
+                "featured_image",
+                self.check_image(notes.featured_image.as_deref()).await,
+            )
+            .add_field(
+                "social_image",
+                self.check_image(notes.social_image.as_deref()).await,
+            )
+            .into();
+
+        ValidationContext::new()
+            .add_struct("release_notes", Some(notes))
+            .into()
+    }
+}
+
+impl<S: ContentTypeSource> EnrichmentHook for ReleaseNoteImagesHook<S> {
+    fn enrich<'a>(&'a self, bom: &'a Bom, ctx: &'a RuleContext) -> BoxFuture<'a, ValidationResult> {
+        async move {
+            if ctx.version < SpecVersion::V1_4 {
+                return Ok(());
+            }
+            let Some(components) = &bom.components else {
+                return Ok(());
+            };
+
+            let children = join_all(
+                components
+                    .iter()
+                    .map(|component| self.check_notes(component.release_notes.as_ref())),
+            )
+            .await;
+
+            ValidationContext::new()
+                .add_list("components", Some(children))
+                .into()
+        }
+        .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use futures::{executor::block_on, future::BoxFuture, FutureExt};
+
+    use crate::{
+        options::ValidationOptions,
+        rules::RuleContext,
+        suppress::Suppression,
+        testing::errors_at,
+        validation::{
+            Severity, SpecVersion, ValidationContext, ValidationError, ValidationErrors,
+            ValidationResult,
+        },
+        Bom, Metadata,
+    };
+
+    use super::{ContentTypeSource, EnrichmentHook, ReleaseNoteImagesHook, ValidateAsync};
+
+    /// Hook that pretends the serial number is unknown to an external service.
+    struct UnknownSerialNumber;
+
+    impl EnrichmentHook for UnknownSerialNumber {
+        fn enrich<'a>(
+            &'a self,
+            _bom: &'a Bom,
+            _ctx: &'a RuleContext,
+        ) -> BoxFuture<'a, ValidationResult> {
+            async {
+                ValidationContext::new()
+                    .add_field(
+                        "serial_number",
+                        Some(Err(ValidationError::new("serial number is unknown"))),
+                    )
+                    .into()
+            }
+            .boxed()
+        }
+    }
+
+    #[test]
+    fn hooks_do_not_run_offline() {
+        let bom = Bom::default();
+        let options = ValidationOptions::new().add_hook(UnknownSerialNumber);
+
+        let result = block_on(bom.validate_async(SpecVersion::V1_5, &options));
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn hooks_run_online() {
+        let bom = Bom {
+            meta_data: Some(Metadata {
+                timestamp: Some(String::from("2024-01-02")),
+                tools: None,
+                tool_components: None,
+                component: None,
+                authors: None,
+            }),
+            ..Default::default()
+        };
+        let options = ValidationOptions::new()
+            .add_hook(UnknownSerialNumber)
+            .online(true);
+
+        let result = block_on(bom.validate_async(SpecVersion::V1_4, &options));
+        assert!(ValidationErrors::has_error(&result, "serial_number"));
+        assert!(ValidationErrors::has_error(&result, "meta_data"));
+    }
+
+    /// Source that derives the content type from the file extension.
+    struct FakeContentTypes;
+
+    impl ContentTypeSource for FakeContentTypes {
+        fn content_type<'a>(
+            &'a self,
+            url: &'a str,
+        ) -> BoxFuture<'a, Result<String, Box<dyn Error + Send + Sync>>> {
+            async move {
+                match url.rsplit('.').next() {
+                    Some("png") => Ok(String::from("image/png")),
+                    Some("html") => Ok(String::from("text/html; charset=utf-8")),
+                    _ => Err("connection refused".into()),
+                }
+            }
+            .boxed()
+        }
+    }
+
+    #[test]
+    fn warns_about_release_note_images_that_are_no_images() {
+        let bom = Bom::parse_json(
+            r#"{ "components": [{ "type": "library", "name": "lib", "releaseNotes": {
+                "type": "minor",
+                "featuredImage": "https://example.com/banner.png",
+                "socialImage": "https://example.com/index.html"
+            } }] }"#,
+        )
+        .expect("Failed to parse BOM");
+        let options = ValidationOptions::new()
+            .add_hook(ReleaseNoteImagesHook::new(FakeContentTypes))
+            .online(true);
+
+        let result = block_on(bom.validate_async(SpecVersion::V1_5, &options));
+        assert!(errors_at(&result, "components[0].release_notes.featured_image").is_empty());
+        let errors = errors_at(&result, "components[0].release_notes.social_image");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code.as_deref(), Some("image-content-type"));
+    }
+
+    #[test]
+    fn options_apply_to_hook_findings() {
+        let bom = Bom::parse_json(
+            r#"{ "components": [{ "type": "library", "name": "lib", "releaseNotes": {
+                "type": "minor", "socialImage": "https://example.com/index.html"
+            } }] }"#,
+        )
+        .expect("Failed to parse BOM");
+        let options = || {
+            ValidationOptions::new()
+                .add_hook(ReleaseNoteImagesHook::new(FakeContentTypes))
+                .online(true)
+        };
+        let path = "components[0].release_notes.social_image";
+
+        let result = block_on(
+            bom.validate_async(
+                SpecVersion::V1_5,
+                &options()
+                    .severity("image-content-type", Severity::Info)
+                    .suppress(Suppression::new("image-content-type")),
+            ),
+        );
+        let errors = errors_at(&result, path);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].severity, Severity::Info);
+        assert_eq!(errors[0].version, Some(SpecVersion::V1_5));
+        assert!(errors[0].suppressed);
+
+        let excluded = options().exclude_paths(["components[*].releaseNotes"]);
+        let result = block_on(bom.validate_async(SpecVersion::V1_5, &excluded));
+        assert!(errors_at(&result, path).is_empty());
+    }
+}
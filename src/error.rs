@@ -0,0 +1,158 @@
+//! The crate-level [`Error`] covering all failure modes of validating a document, from
+//! reading it to the findings of the validation.
+//!
+//! Failures that prevent the validation, e.g. a document that cannot be parsed, can be folded
+//! into a [`ValidationErrors`] tree, where they are reported at the reserved key
+//! [`DOCUMENT_KEY`]. Consumers can then handle every failure with one reporting pipeline.
+use core::fmt;
+
+use crate::{
+    prelude::*,
+    validation::{
+        SpecVersion, ValidationContext, ValidationError, ValidationErrors, ValidationResult,
+    },
+};
+
+/// The key of the error tree failures concerning the whole document are reported at.
+pub const DOCUMENT_KEY: &str = "document";
+
+/// A failure to read, parse or validate a document.
+#[derive(Debug)]
+pub enum Error {
+    /// The document is not valid JSON or does not match the model.
+    Parse(serde_json::Error),
+    /// The spec version is not supported, e.g. `1.2`.
+    UnsupportedVersion(String),
+    /// The document does not declare its spec version.
+    MissingVersion,
+    /// The format of the document is not supported, e.g. `xml` without the `xml` feature.
+    UnsupportedFormat(String),
+    /// The document could not be read.
+    #[cfg(feature = "std")]
+    Io(std::io::Error),
+    /// The document was parsed, but the validation reported findings.
+    Validation(ValidationErrors),
+}
+
+impl Error {
+    /// Returns the error tree, failures other than [`Error::Validation`] are reported as a
+    /// single error at [`DOCUMENT_KEY`].
+    pub fn into_validation_errors(self) -> ValidationErrors {
+        let error = match self {
+            Error::Validation(errors) => return errors,
+            Error::Parse(error) => ValidationError::new("invalid_json")
+                .with_param("error", error)
+                .with_code("invalid-json"),
+            Error::UnsupportedVersion(version) => ValidationError::new("unsupported_version")
+                .with_param("version", &version)
+                .with_code("unsupported-version")
+                .with_expected("1.3, 1.4, 1.5 or 1.6")
+                .with_actual(version),
+            Error::MissingVersion => {
+                ValidationError::new("missing_spec_version").with_code("missing-spec-version")
+            }
+            Error::UnsupportedFormat(format) => ValidationError::new("unsupported_format")
+                .with_param("format", format)
+                .with_code("unsupported-format"),
+            #[cfg(feature = "std")]
+            Error::Io(error) => ValidationError::new("read_failed")
+                .with_param("error", error)
+                .with_code("read-failed"),
+        };
+
+        ValidationResult::from(ValidationContext::new().add_field(DOCUMENT_KEY, Some(Err(error))))
+            .err()
+            .unwrap_or_default()
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Parse(error) => write!(f, "Failed to parse document: {}", error),
+            Error::UnsupportedVersion(version) => {
+                write!(f, "Unsupported spec version '{}'", version)
+            }
+            Error::MissingVersion => write!(f, "Document does not declare its spec version"),
+            Error::UnsupportedFormat(format) => write!(f, "Unsupported format '{}'", format),
+            #[cfg(feature = "std")]
+            Error::Io(error) => write!(f, "Failed to read document: {}", error),
+            Error::Validation(errors) => {
+                write!(f, "Document is invalid: {} findings", errors.errors().len())
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Parse(error) => Some(error),
+            Error::Io(error) => Some(error),
+            Error::UnsupportedVersion(_)
+            | Error::MissingVersion
+            | Error::UnsupportedFormat(_)
+            | Error::Validation(_) => None,
+        }
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(error: serde_json::Error) -> Self {
+        Error::Parse(error)
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<std::io::Error> for Error {
+    fn from(error: std::io::Error) -> Self {
+        Error::Io(error)
+    }
+}
+
+impl From<ValidationErrors> for Error {
+    fn from(errors: ValidationErrors) -> Self {
+        Error::Validation(errors)
+    }
+}
+
+impl From<Error> for ValidationErrors {
+    fn from(error: Error) -> Self {
+        error.into_validation_errors()
+    }
+}
+
+impl core::str::FromStr for SpecVersion {
+    type Err = Error;
+
+    fn from_str(version: &str) -> Result<Self, Self::Err> {
+        SpecVersion::parse(version).ok_or_else(|| Error::UnsupportedVersion(version.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        validation::{SpecVersion, ValidationErrors},
+        Bom,
+    };
+
+    use super::{Error, DOCUMENT_KEY};
+
+    #[test]
+    fn folds_failures_into_document_key() {
+        let parse = Error::from(Bom::parse_json("{").expect_err("Expected parse error"));
+        let version = "1.2"
+            .parse::<SpecVersion>()
+            .expect_err("Expected unsupported version");
+        assert!(matches!(version, Error::UnsupportedVersion(_)));
+
+        for error in [parse, version] {
+            let errors = ValidationErrors::from(error);
+            assert_eq!(errors.errors().len(), 1);
+            assert!(errors.contains_key(DOCUMENT_KEY));
+        }
+        assert_eq!("1.5".parse::<SpecVersion>().ok(), Some(SpecVersion::V1_5));
+    }
+}
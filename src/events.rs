@@ -0,0 +1,224 @@
+//! Emits the findings of a [`ValidationReport`] as structured events, e.g. to collect them in
+//! a centralized logging when the validator runs inside a service.
+//!
+//! With the `tracing` feature every finding is emitted as `tracing` event, with the `log`
+//! feature as `log` record with key-values. Both use the target [`TARGET`] and the fields
+//! `rule`, `path`, `severity`, `code` and `suppressed`, the message is the English message
+//! of the finding.
+//!
+//! Findings describe the validated document, not a failure of the service. Errors are
+//! therefore emitted at `WARN` level, warnings at `INFO`, info and suppressed findings at `DEBUG`.
+use crate::{
+    report::{Finding, ValidationReport},
+    validation::Severity,
+};
+
+/// The target of all emitted events.
+pub const TARGET: &str = "validation_tree::findings";
+
+fn severity_name(finding: &Finding) -> &'static str {
+    match finding.severity {
+        Severity::Info => "info",
+        Severity::Warning => "warning",
+        Severity::Error => "error",
+    }
+}
+
+/// Emits one event per finding of the report.
+pub fn emit_findings(report: &ValidationReport) {
+    for finding in &report.findings {
+        #[cfg(feature = "tracing")]
+        emit_tracing(finding);
+        #[cfg(feature = "log")]
+        emit_log(finding);
+    }
+}
+
+#[cfg(feature = "tracing")]
+fn emit_tracing(finding: &Finding) {
+    macro_rules! emit {
+        ($level:ident) => {
+            tracing::$level!(
+                target: TARGET,
+                rule = %finding.rule,
+                path = %finding.path,
+                severity = severity_name(finding),
+                code = finding.code.as_deref(),
+                suppressed = finding.suppressed,
+                "{}",
+                finding.message
+            )
+        };
+    }
+
+    match (finding.suppressed, finding.severity) {
+        (true, _) => emit!(debug),
+        (false, Severity::Info) => emit!(debug),
+        (false, Severity::Warning) => emit!(info),
+        (false, Severity::Error) => emit!(warn),
+    }
+}
+
+#[cfg(feature = "log")]
+fn emit_log(finding: &Finding) {
+    let level = match (finding.suppressed, finding.severity) {
+        (true, _) => log::Level::Debug,
+        (false, Severity::Info) => log::Level::Debug,
+        (false, Severity::Warning) => log::Level::Info,
+        (false, Severity::Error) => log::Level::Warn,
+    };
+
+    log::log!(
+        target: TARGET,
+        level,
+        rule = finding.rule.as_str(),
+        path = finding.path.as_str(),
+        severity = severity_name(finding),
+        code = finding.code.as_deref().unwrap_or_default(),
+        suppressed = finding.suppressed;
+        "{}",
+        finding.message
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        options::ValidationOptions, report::validate_report, validation::SpecVersion, Bom,
+        Component, ComponentType,
+    };
+
+    fn report() -> crate::report::ValidationReport {
+        let bom = Bom {
+            components: Some(vec![Component {
+                purl: Some(String::from("serde")),
+                ..Component::new(ComponentType::Library, "serde")
+            }]),
+            ..Default::default()
+        };
+        validate_report(SpecVersion::V1_5, &bom, &ValidationOptions::new())
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn emits_tracing_events() {
+        use std::sync::{Arc, Mutex};
+
+        use tracing::{
+            field::{Field, Visit},
+            span, Event, Level, Metadata, Subscriber,
+        };
+
+        /// Records the level and fields of all events of the findings target.
+        #[derive(Default)]
+        struct Recorder {
+            events: Mutex<Vec<(Level, Vec<String>)>>,
+        }
+
+        struct Fields(Vec<String>);
+
+        impl Visit for Fields {
+            fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+                self.0.push(format!("{}={:?}", field.name(), value));
+            }
+
+            fn record_str(&mut self, field: &Field, value: &str) {
+                self.0.push(format!("{}={}", field.name(), value));
+            }
+        }
+
+        struct RecordingSubscriber(Arc<Recorder>);
+
+        impl Subscriber for RecordingSubscriber {
+            fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+                true
+            }
+
+            fn new_span(&self, _span: &span::Attributes<'_>) -> span::Id {
+                span::Id::from_u64(1)
+            }
+
+            fn record(&self, _span: &span::Id, _values: &span::Record<'_>) {}
+
+            fn record_follows_from(&self, _span: &span::Id, _follows: &span::Id) {}
+
+            fn event(&self, event: &Event<'_>) {
+                if event.metadata().target() == super::TARGET {
+                    let mut fields = Fields(Vec::new());
+                    event.record(&mut fields);
+                    let level = *event.metadata().level();
+                    self.0.events.lock().unwrap().push((level, fields.0));
+                }
+            }
+
+            fn enter(&self, _span: &span::Id) {}
+
+            fn exit(&self, _span: &span::Id) {}
+        }
+
+        let report = report();
+        let recorder = Arc::new(Recorder::default());
+        tracing::subscriber::with_default(RecordingSubscriber(recorder.clone()), || {
+            super::emit_findings(&report)
+        });
+
+        let events = recorder.events.lock().unwrap();
+        assert_eq!(events.len(), 1);
+        let (level, fields) = &events[0];
+        assert_eq!(*level, Level::WARN);
+        assert!(fields.contains(&String::from("rule=model")));
+        assert!(fields.contains(&String::from("path=components[0].purl")));
+        assert!(fields.contains(&String::from("severity=error")));
+    }
+
+    #[cfg(feature = "log")]
+    #[test]
+    fn emits_log_records() {
+        use std::sync::Mutex;
+
+        use log::{
+            kv::{Error, Key, Value, VisitSource},
+            Log, Metadata, Record,
+        };
+
+        static RECORDS: Mutex<Vec<(log::Level, Vec<String>)>> = Mutex::new(Vec::new());
+
+        struct Fields(Vec<String>);
+
+        impl<'kvs> VisitSource<'kvs> for Fields {
+            fn visit_pair(&mut self, key: Key<'kvs>, value: Value<'kvs>) -> Result<(), Error> {
+                self.0.push(format!("{}={}", key, value));
+                Ok(())
+            }
+        }
+
+        struct RecordingLogger;
+
+        impl Log for RecordingLogger {
+            fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+                true
+            }
+
+            fn log(&self, record: &Record<'_>) {
+                if record.target() == super::TARGET {
+                    let mut fields = Fields(Vec::new());
+                    let _ = record.key_values().visit(&mut fields);
+                    RECORDS.lock().unwrap().push((record.level(), fields.0));
+                }
+            }
+
+            fn flush(&self) {}
+        }
+
+        log::set_logger(&RecordingLogger).expect("Failed to set logger");
+        log::set_max_level(log::LevelFilter::Trace);
+        super::emit_findings(&report());
+
+        let records = RECORDS.lock().unwrap();
+        assert_eq!(records.len(), 1);
+        let (level, fields) = &records[0];
+        assert_eq!(*level, log::Level::Warn);
+        assert!(fields.contains(&String::from("rule=model")));
+        assert!(fields.contains(&String::from("code=invalid-purl")));
+    }
+}
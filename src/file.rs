@@ -0,0 +1,173 @@
+//! Validates a document read from a file, see [`validate_file`].
+//!
+//! The format and the spec version are detected from the file, so callers only pass a path,
+//! e.g. to validate every file of a directory. Every finding carries the path of the file it
+//! was reported for, so findings of many files can be reported together.
+use std::{fs, path::Path};
+
+use serde::Deserialize;
+
+use crate::{
+    error::Error,
+    options::ValidationOptions,
+    validate_bytes,
+    validation::{SpecVersion, ValidationResult},
+};
+
+/// The namespace of CycloneDX XML documents, followed by the spec version.
+const XML_NAMESPACE: &str = "http://cyclonedx.org/schema/bom/";
+
+/// The format of a document file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Json,
+    Xml,
+}
+
+impl Format {
+    /// Detects the format by the extension of the file, or by its content if the extension
+    /// is unknown. Documents starting with `<` are XML.
+    pub fn detect(path: &Path, bytes: &[u8]) -> Self {
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some(extension) if extension.eq_ignore_ascii_case("json") => Format::Json,
+            Some(extension) if extension.eq_ignore_ascii_case("xml") => Format::Xml,
+            _ => match bytes.iter().find(|byte| !byte.is_ascii_whitespace()) {
+                Some(b'<') => Format::Xml,
+                _ => Format::Json,
+            },
+        }
+    }
+}
+
+/// The fields of a JSON document needed before it is validated.
+#[derive(Deserialize)]
+struct Header {
+    #[serde(rename = "specVersion")]
+    spec_version: Option<String>,
+}
+
+/// Returns the spec version declared by the document.
+fn spec_version(format: Format, bytes: &[u8]) -> Result<SpecVersion, Error> {
+    let version = match format {
+        Format::Json => serde_json::from_slice::<Header>(bytes)?.spec_version,
+        Format::Xml => String::from_utf8_lossy(bytes)
+            .split_once(XML_NAMESPACE)
+            .and_then(|(_, rest)| rest.split(['"', '\'']).next())
+            .map(str::to_string),
+    };
+
+    version.ok_or(Error::MissingVersion)?.parse()
+}
+
+fn validate_content(path: &Path, options: &ValidationOptions) -> Result<(), Error> {
+    let bytes = fs::read(path)?;
+    let format = Format::detect(path, &bytes);
+    let version = spec_version(format, &bytes)?;
+
+    let result = match format {
+        Format::Json => validate_bytes(version, &bytes, options),
+        #[cfg(feature = "xml")]
+        Format::Xml => crate::xml::validate_xml(version, bytes.as_slice(), options),
+        #[cfg(not(feature = "xml"))]
+        Format::Xml => return Err(Error::UnsupportedFormat(String::from("xml"))),
+    };
+    result.map_err(Error::from)
+}
+
+/// Reads, parses and validates the document at `path`, against the spec version it declares.
+///
+/// Failures to read or parse the file are reported at [`DOCUMENT_KEY`](crate::error::DOCUMENT_KEY),
+/// every finding has its [`file`](crate::validation::ValidationError::file) set to `path`.
+pub fn validate_file<P: AsRef<Path>>(path: P, options: &ValidationOptions) -> ValidationResult {
+    let path = path.as_ref();
+    validate_content(path, options).map_err(|error| {
+        let file: Box<str> = path.display().to_string().into();
+        let mut errors = error.into_validation_errors();
+        errors.for_each_error_mut(|_, error| error.file = Some(file.clone()));
+        errors
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::PathBuf};
+
+    use crate::{error::DOCUMENT_KEY, options::ValidationOptions, testing::errors_at};
+
+    use super::{validate_file, Format};
+
+    fn write_file(name: &str, content: &str) -> PathBuf {
+        let root = std::env::temp_dir().join("validation-tree-file");
+        fs::create_dir_all(&root).expect("Failed to create temp dir");
+        let path = root.join(name);
+        fs::write(&path, content).expect("Failed to write document");
+        path
+    }
+
+    #[test]
+    fn detect_format() {
+        assert_eq!(
+            Format::detect(&PathBuf::from("bom.xml"), b"{}"),
+            Format::Xml
+        );
+        assert_eq!(
+            Format::detect(&PathBuf::from("bom.cdx"), b"  <bom/>"),
+            Format::Xml
+        );
+        assert_eq!(
+            Format::detect(&PathBuf::from("bom.cdx"), b"{}"),
+            Format::Json
+        );
+    }
+
+    #[test]
+    fn findings_carry_the_file() {
+        let path = write_file(
+            "invalid.json",
+            r#"{ "specVersion": "1.5", "components": [{ "type": "library", "name": "a", "purl": "cargo/a" }] }"#,
+        );
+
+        let result = validate_file(&path, &ValidationOptions::new());
+        let errors = errors_at(&result, "components[0].purl");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            errors[0].file.as_deref(),
+            Some(path.display().to_string().as_str())
+        );
+    }
+
+    #[test]
+    fn reports_format_aware_failures() {
+        let options = ValidationOptions::new();
+
+        let missing = write_file("missing-version.json", r#"{ "components": [] }"#);
+        let result = validate_file(&missing, &options);
+        let errors = errors_at(&result, DOCUMENT_KEY);
+        assert_eq!(errors[0].code.as_deref(), Some("missing-spec-version"));
+
+        let unsupported = write_file("unsupported.json", r#"{ "specVersion": "1.2" }"#);
+        let result = validate_file(&unsupported, &options);
+        let errors = errors_at(&result, DOCUMENT_KEY);
+        assert_eq!(errors[0].code.as_deref(), Some("unsupported-version"));
+
+        let unreadable = std::env::temp_dir().join("validation-tree-file/does-not-exist.json");
+        let result = validate_file(&unreadable, &options);
+        let errors = errors_at(&result, DOCUMENT_KEY);
+        assert_eq!(errors[0].code.as_deref(), Some("read-failed"));
+        assert!(errors[0].file.is_some());
+    }
+
+    #[cfg(feature = "xml")]
+    #[test]
+    fn validates_xml_files() {
+        let path = write_file(
+            "bom.xml",
+            r#"<bom xmlns="http://cyclonedx.org/schema/bom/1.5"><components>
+                <component type="library"><name>a</name><purl>cargo/a</purl></component>
+            </components></bom>"#,
+        );
+
+        let result = validate_file(&path, &ValidationOptions::new());
+        assert_eq!(errors_at(&result, "components[0].purl").len(), 1);
+    }
+}
@@ -0,0 +1,164 @@
+//! Validation of single elements of a document without a surrounding [`Bom`](crate::Bom),
+//! e.g. for tools that construct or patch fragments of a document.
+//!
+//! Each function runs the built-in validation of the element and the built-in rules that
+//! check the element on its own. Rules that need the whole document, e.g. the resolution of
+//! bom-refs, and custom rules are not run. Paths of the returned errors are relative to the
+//! element, e.g. `bom-ref` instead of `components[3].bom-ref`. Only suppressions of the options
+//! without a path are applied.
+use crate::{
+    apply_options,
+    options::ValidationOptions,
+    prelude::*,
+    rules::{self, RuleContext},
+    suppress::Suppression,
+    validation::{
+        validate_since, SpecVersion, Validate, ValidationContext, ValidationErrors,
+        ValidationResult,
+    },
+    Component, Metadata, Vulnerability,
+};
+
+/// Applies the options to the result of the element of the given model struct.
+fn finish(
+    model: &'static str,
+    result: ValidationResult,
+    version: SpecVersion,
+    options: &ValidationOptions,
+) -> ValidationResult {
+    let suppressions = options
+        .suppressions()
+        .iter()
+        .filter(|suppression| suppression.path.is_none())
+        .cloned()
+        .collect::<Vec<Suppression>>();
+    apply_options(result, version, options, &suppressions)
+        .map_err(|errors| errors.relative_to(model))
+}
+
+fn context(version: SpecVersion, options: &ValidationOptions) -> RuleContext {
+    RuleContext::new(version)
+        .with_profile(options.selected_profile())
+        .with_timestamp_tolerance(options.selected_timestamp_tolerance())
+        .with_allowed_hash_algorithms(options.allowed_hash_algorithms())
+}
+
+/// Validates a single component, e.g. one of `components`.
+pub fn validate_component(
+    version: SpecVersion,
+    component: &Component,
+    options: &ValidationOptions,
+) -> ValidationResult {
+    let result = ValidationErrors::merge_errors(
+        component.validate(version, options),
+        rules::check_component(component, &context(version, options)),
+    );
+    finish("Component", result, version, options)
+}
+
+/// Validates the metadata of a document, including its component and tools.
+pub fn validate_metadata(
+    version: SpecVersion,
+    metadata: &Metadata,
+    options: &ValidationOptions,
+) -> ValidationResult {
+    let result = ValidationErrors::merge_errors(
+        metadata.validate(version, options),
+        rules::check_metadata(metadata, &context(version, options)),
+    );
+    finish("Metadata", result, version, options)
+}
+
+/// Validates a single vulnerability, vulnerabilities are supported since spec version 1.4.
+pub fn validate_vulnerability(
+    version: SpecVersion,
+    vulnerability: &Vulnerability,
+    options: &ValidationOptions,
+) -> ValidationResult {
+    if version < SpecVersion::V1_4 {
+        return ValidationContext::new()
+            .add_field(
+                "vulnerability",
+                validate_since("vulnerability", true, version, SpecVersion::V1_4),
+            )
+            .into();
+    }
+
+    let result = rules::check_vulnerability(vulnerability, &context(version, options));
+    finish("Vulnerability", result, version, options)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        options::{Profile, ValidationOptions},
+        testing::errors_at,
+        validation::SpecVersion,
+        Bom, Component, ComponentType, Hash, HashAlgorithm, Metadata, Vulnerability,
+    };
+
+    use super::{validate_component, validate_metadata, validate_vulnerability};
+
+    #[test]
+    fn validates_component_with_relative_paths() {
+        let component = Component {
+            purl: Some(String::from("serde")),
+            hashes: Some(vec![Hash {
+                alg: HashAlgorithm::Md5,
+                content: String::from("d41d8cd98f00b204e9800998ecf8427e"),
+            }]),
+            ..Component::new(ComponentType::Library, "serde")
+        };
+
+        let result = validate_component(SpecVersion::V1_5, &component, &ValidationOptions::new());
+        assert_eq!(errors_at(&result, "purl").len(), 1);
+        assert!(errors_at(&result, "hashes[0].alg").is_empty());
+
+        let options = ValidationOptions::new().profile(Profile::Strict);
+        let result = validate_component(SpecVersion::V1_5, &component, &options);
+        assert_eq!(
+            errors_at(&result, "supplier")[0].code.as_deref(),
+            Some("missing-supplier")
+        );
+    }
+
+    #[test]
+    fn validates_metadata_and_its_component() {
+        let metadata = Metadata {
+            timestamp: Some(String::from("yesterday")),
+            component: Some(Component {
+                purl: Some(String::from("app")),
+                ..Component::new(ComponentType::Application, "app")
+            }),
+            ..Default::default()
+        };
+
+        let result = validate_metadata(SpecVersion::V1_5, &metadata, &ValidationOptions::new());
+        assert_eq!(errors_at(&result, "timestamp").len(), 1);
+        assert_eq!(errors_at(&result, "component.purl").len(), 1);
+    }
+
+    #[test]
+    fn validates_vulnerability() {
+        let bom = Bom::parse_json(
+            r#"{ "vulnerabilities": [{ "id": "CVE-2024-1234", "ratings": [
+                { "score": 11.0, "method": "CVSSv31" }
+            ] }] }"#,
+        )
+        .expect("Failed to parse BOM");
+        let vulnerability = &bom.vulnerabilities.expect("Expected vulnerabilities")[0];
+        let options = ValidationOptions::new();
+
+        let result = validate_vulnerability(SpecVersion::V1_5, vulnerability, &options);
+        assert_eq!(
+            errors_at(&result, "ratings[0].score")[0].code.as_deref(),
+            Some("rating-score-range")
+        );
+
+        let result = validate_vulnerability(SpecVersion::V1_3, &Vulnerability::default(), &options);
+        assert_eq!(
+            errors_at(&result, "vulnerability")[0].code.as_deref(),
+            Some("field-not-supported")
+        );
+    }
+}
@@ -0,0 +1,503 @@
+//! The graph of bom-ref relationships of a [`Bom`].
+//!
+//! Components and services with a bom-ref are the nodes, the entries of `dependencies` the
+//! edges. The graph is built once per validation and shared with all rules through
+//! [`RuleContext::graph`](crate::rules::RuleContext::graph), e.g. to find cycles or orphans.
+//! It can be exported as DOT or GraphML with the findings of a validation on its nodes.
+use alloc::collections::BTreeSet;
+use core::fmt::Write;
+
+use crate::{
+    map::IndexMap,
+    paths::list_index,
+    prelude::*,
+    validation::{Severity, ValidationError, ValidationErrors},
+    Bom,
+};
+
+/// The findings of a validation by bom-ref of the node they were found in, each with its path.
+pub type Findings<'e> = IndexMap<String, Vec<(String, &'e ValidationError)>>;
+
+/// What a node of the [`BomGraph`] represents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeKind {
+    Component,
+    Service,
+    /// Only referenced by `dependencies`, without a component or service declaring it.
+    Undeclared,
+}
+
+/// A single node of the [`BomGraph`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Node {
+    pub bom_ref: String,
+    pub name: Option<String>,
+    pub kind: NodeKind,
+}
+
+/// The graph of components, services and their dependencies.
+#[derive(Debug, Clone, Default)]
+pub struct BomGraph {
+    nodes: IndexMap<String, Node>,
+    /// The outgoing edges, by index of the node.
+    edges: Vec<Vec<usize>>,
+}
+
+impl BomGraph {
+    /// Builds the graph of the document, the first declaration of a bom-ref wins.
+    pub fn new(bom: &Bom) -> Self {
+        let mut graph = Self {
+            nodes: IndexMap::default(),
+            edges: Vec::new(),
+        };
+
+        let components = bom
+            .meta_data
+            .iter()
+            .filter_map(|metadata| metadata.component.as_ref())
+            .chain(bom.components.iter().flatten());
+        for component in components {
+            if let Some(bom_ref) = &component.bom_ref {
+                graph.insert(bom_ref, Some(&component.name), NodeKind::Component);
+            }
+        }
+        for service in bom.services.iter().flatten() {
+            if let Some(bom_ref) = &service.bom_ref {
+                graph.insert(bom_ref, Some(&service.name), NodeKind::Service);
+            }
+        }
+
+        for dependency in bom.dependencies.iter().flatten() {
+            let from = graph.insert(&dependency.dependency_ref, None, NodeKind::Undeclared);
+            for depends_on in dependency.depends_on.iter().flatten() {
+                let to = graph.insert(depends_on, None, NodeKind::Undeclared);
+                if !graph.edges[from].contains(&to) {
+                    graph.edges[from].push(to);
+                }
+            }
+        }
+
+        graph
+    }
+
+    /// Returns the index of the node, inserting it if it does not exist yet.
+    fn insert(&mut self, bom_ref: &str, name: Option<&String>, kind: NodeKind) -> usize {
+        if let Some(index) = self.nodes.get_index_of(bom_ref) {
+            return index;
+        }
+        self.nodes.insert(
+            bom_ref.to_string(),
+            Node {
+                bom_ref: bom_ref.to_string(),
+                name: name.cloned(),
+                kind,
+            },
+        );
+        self.edges.push(Vec::new());
+        self.nodes.len() - 1
+    }
+
+    fn bom_ref(&self, index: usize) -> &str {
+        self.nodes[index].bom_ref.as_str()
+    }
+
+    /// Returns all nodes in the order of their first appearance in the document.
+    pub fn nodes(&self) -> impl Iterator<Item = &Node> {
+        self.nodes.values()
+    }
+
+    pub fn node(&self, bom_ref: &str) -> Option<&Node> {
+        self.nodes.get(bom_ref)
+    }
+
+    /// Returns all edges as `(from, to)`, where `from` depends on `to`.
+    pub fn edges(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.edges
+            .iter()
+            .enumerate()
+            .flat_map(move |(from, targets)| {
+                targets
+                    .iter()
+                    .map(move |&to| (self.bom_ref(from), self.bom_ref(to)))
+            })
+    }
+
+    /// Returns the bom-refs the node directly depends on.
+    pub fn dependencies_of(&self, bom_ref: &str) -> Vec<&str> {
+        self.nodes
+            .get_index_of(bom_ref)
+            .map(|index| {
+                self.edges[index]
+                    .iter()
+                    .map(|&to| self.bom_ref(to))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Returns the bom-refs that directly depend on the node.
+    pub fn dependents_of(&self, bom_ref: &str) -> Vec<&str> {
+        self.edges()
+            .filter(|&(_, to)| to == bom_ref)
+            .map(|(from, _)| from)
+            .collect()
+    }
+
+    /// Returns the nodes without any incoming or outgoing edge.
+    pub fn orphans(&self) -> Vec<&Node> {
+        let connected = self
+            .edges()
+            .flat_map(|(from, to)| [from, to])
+            .collect::<BTreeSet<_>>();
+
+        self.nodes()
+            .filter(|node| !connected.contains(node.bom_ref.as_str()))
+            .collect()
+    }
+
+    /// Returns all bom-refs reachable from the node, excluding the node unless it is part
+    /// of a cycle.
+    pub fn reachable_from(&self, bom_ref: &str) -> BTreeSet<&str> {
+        let mut reachable = BTreeSet::new();
+        let Some(start) = self.nodes.get_index_of(bom_ref) else {
+            return reachable;
+        };
+
+        let mut pending = self.edges[start].clone();
+        while let Some(index) = pending.pop() {
+            if reachable.insert(self.bom_ref(index)) {
+                pending.extend_from_slice(&self.edges[index]);
+            }
+        }
+        reachable
+    }
+
+    /// Returns every cycle of the dependency graph as the bom-refs taking part in it.
+    ///
+    /// Each cycle is a strongly connected component with more than one node or a node that
+    /// depends on itself.
+    pub fn cycles(&self) -> Vec<Vec<&str>> {
+        let len = self.nodes.len();
+        let mut order: Vec<Option<usize>> = vec![None; len];
+        let mut low = vec![0; len];
+        let mut on_stack = vec![false; len];
+        let mut stack = Vec::new();
+        let mut next = 0;
+        let mut cycles = Vec::new();
+
+        // iterative Tarjan, each entry of `work` is a node and the position of its next edge
+        for start in 0..len {
+            if order[start].is_some() {
+                continue;
+            }
+            let mut work = vec![(start, 0)];
+            while let Some(&(node, edge)) = work.last() {
+                if order[node].is_none() {
+                    order[node] = Some(next);
+                    low[node] = next;
+                    next += 1;
+                    stack.push(node);
+                    on_stack[node] = true;
+                }
+
+                if let Some(&target) = self.edges[node].get(edge) {
+                    if let Some(last) = work.last_mut() {
+                        last.1 += 1;
+                    }
+                    match order[target] {
+                        None => work.push((target, 0)),
+                        Some(target_order) if on_stack[target] => {
+                            low[node] = low[node].min(target_order)
+                        }
+                        Some(_) => {}
+                    }
+                    continue;
+                }
+
+                work.pop();
+                if let Some(&(parent, _)) = work.last() {
+                    low[parent] = low[parent].min(low[node]);
+                }
+                if Some(low[node]) == order[node] {
+                    let mut component = Vec::new();
+                    while let Some(member) = stack.pop() {
+                        on_stack[member] = false;
+                        component.push(member);
+                        if member == node {
+                            break;
+                        }
+                    }
+                    if component.len() > 1 || self.edges[node].contains(&node) {
+                        component.sort_unstable();
+                        cycles.push(
+                            component
+                                .into_iter()
+                                .map(|index| self.bom_ref(index))
+                                .collect(),
+                        );
+                    }
+                }
+            }
+        }
+        cycles
+    }
+
+    /// Assigns the findings of a validation of `bom` to the nodes they were found in.
+    ///
+    /// Findings of `components[i]`, `services[i]`, `meta_data.component` and `dependencies[i]`
+    /// are assigned to the node of the element's bom-ref, all others are left out.
+    pub fn findings<'e>(&self, bom: &Bom, errors: &'e ValidationErrors) -> Findings<'e> {
+        let mut findings = Findings::default();
+        for (path, error) in errors.model_errors() {
+            let bom_ref = if path.starts_with("meta_data.component.") {
+                bom.meta_data
+                    .as_ref()
+                    .and_then(|metadata| metadata.component.as_ref())
+                    .and_then(|component| component.bom_ref.as_ref())
+            } else if let Some(index) = list_index(&path, "components") {
+                bom.components
+                    .as_ref()
+                    .and_then(|components| components.get(index))
+                    .and_then(|component| component.bom_ref.as_ref())
+            } else if let Some(index) = list_index(&path, "services") {
+                bom.services
+                    .as_ref()
+                    .and_then(|services| services.get(index))
+                    .and_then(|service| service.bom_ref.as_ref())
+            } else if let Some(index) = list_index(&path, "dependencies") {
+                bom.dependencies
+                    .as_ref()
+                    .and_then(|dependencies| dependencies.get(index))
+                    .map(|dependency| &dependency.dependency_ref)
+            } else {
+                None
+            };
+
+            if let Some(bom_ref) = bom_ref.filter(|bom_ref| self.nodes.contains_key(*bom_ref)) {
+                findings
+                    .entry(bom_ref.clone())
+                    .or_default()
+                    .push((path, error));
+            }
+        }
+        findings
+    }
+
+    /// Renders the graph in the DOT format of Graphviz, e.g. for debugging.
+    pub fn to_dot(&self) -> String {
+        self.to_dot_with_findings(&Findings::default())
+    }
+
+    /// Renders the graph in the DOT format, nodes with findings are colored by their most
+    /// severe finding and list the findings in their tooltip.
+    pub fn to_dot_with_findings(&self, findings: &Findings) -> String {
+        let mut dot = String::from("digraph bom {\n");
+        for node in self.nodes() {
+            let shape = match node.kind {
+                NodeKind::Component => "box",
+                NodeKind::Service => "ellipse",
+                NodeKind::Undeclared => "plaintext",
+            };
+            let mut label = node.name.clone().unwrap_or_else(|| node.bom_ref.clone());
+            let mut attributes = String::new();
+            if let Some(node_findings) = findings.get(&node.bom_ref) {
+                let _ = write!(label, "\n({} findings)", node_findings.len());
+                let _ = write!(
+                    attributes,
+                    ", color={}, tooltip=\"{}\"",
+                    color(node_findings),
+                    escape(&describe(node_findings, "\n"))
+                );
+            }
+            let _ = writeln!(
+                dot,
+                "    \"{}\" [label=\"{}\", shape={}{}];",
+                escape(&node.bom_ref),
+                escape(&label),
+                shape,
+                attributes
+            );
+        }
+        for (from, to) in self.edges() {
+            let _ = writeln!(dot, "    \"{}\" -> \"{}\";", escape(from), escape(to));
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Renders the graph in the GraphML format, with the number of findings and their
+    /// descriptions as node data.
+    pub fn to_graphml(&self, findings: &Findings) -> String {
+        let mut xml = String::from(concat!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+            "<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n",
+            "  <key id=\"name\" for=\"node\" attr.name=\"name\" attr.type=\"string\"/>\n",
+            "  <key id=\"kind\" for=\"node\" attr.name=\"kind\" attr.type=\"string\"/>\n",
+            "  <key id=\"findings\" for=\"node\" attr.name=\"findings\" attr.type=\"int\"/>\n",
+            "  <key id=\"details\" for=\"node\" attr.name=\"details\" attr.type=\"string\"/>\n",
+            "  <graph id=\"bom\" edgedefault=\"directed\">\n",
+        ));
+        for node in self.nodes() {
+            let _ = writeln!(xml, "    <node id=\"{}\">", escape_xml(&node.bom_ref));
+            if let Some(name) = &node.name {
+                let _ = writeln!(xml, "      <data key=\"name\">{}</data>", escape_xml(name));
+            }
+            let kind = match node.kind {
+                NodeKind::Component => "component",
+                NodeKind::Service => "service",
+                NodeKind::Undeclared => "undeclared",
+            };
+            let _ = writeln!(xml, "      <data key=\"kind\">{}</data>", kind);
+            if let Some(node_findings) = findings.get(&node.bom_ref) {
+                let _ = writeln!(
+                    xml,
+                    "      <data key=\"findings\">{}</data>",
+                    node_findings.len()
+                );
+                let _ = writeln!(
+                    xml,
+                    "      <data key=\"details\">{}</data>",
+                    escape_xml(&describe(node_findings, "; "))
+                );
+            }
+            xml.push_str("    </node>\n");
+        }
+        for (from, to) in self.edges() {
+            let _ = writeln!(
+                xml,
+                "    <edge source=\"{}\" target=\"{}\"/>",
+                escape_xml(from),
+                escape_xml(to)
+            );
+        }
+        xml.push_str("  </graph>\n</graphml>\n");
+        xml
+    }
+}
+
+/// Returns the DOT color of the most severe finding.
+fn color(findings: &[(String, &ValidationError)]) -> &'static str {
+    match findings.iter().map(|(_, error)| error.severity).max() {
+        Some(Severity::Error) => "red",
+        _ => "orange",
+    }
+}
+
+/// Lists every finding as `path: message`.
+fn describe(findings: &[(String, &ValidationError)], separator: &str) -> String {
+    findings
+        .iter()
+        .map(|(path, error)| format!("{}: {}", path, error))
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+/// Escapes a string for a quoted DOT id.
+fn escape(input: &str) -> String {
+    input
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// Escapes a string for XML text and attribute values.
+fn escape_xml(input: &str) -> String {
+    input
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{options::ValidationOptions, validate_bom, validation::SpecVersion, Bom};
+
+    use super::{BomGraph, NodeKind};
+
+    fn graph() -> BomGraph {
+        let bom = Bom::parse_json(
+            r#"{
+                "metadata": { "component": { "type": "application", "name": "app", "bom-ref": "app" } },
+                "components": [
+                    { "type": "library", "name": "a", "bom-ref": "a" },
+                    { "type": "library", "name": "b", "bom-ref": "b" },
+                    { "type": "library", "name": "lonely", "bom-ref": "lonely" }
+                ],
+                "services": [{ "name": "api", "bom-ref": "api" }],
+                "dependencies": [
+                    { "ref": "app", "dependsOn": ["a", "api"] },
+                    { "ref": "a", "dependsOn": ["b"] },
+                    { "ref": "b", "dependsOn": ["a", "missing"] }
+                ]
+            }"#,
+        )
+        .expect("Failed to parse BOM");
+        BomGraph::new(&bom)
+    }
+
+    #[test]
+    fn builds_nodes_and_edges() {
+        let graph = graph();
+
+        assert_eq!(graph.nodes().count(), 6);
+        assert_eq!(
+            graph.node("api").map(|node| node.kind),
+            Some(NodeKind::Service)
+        );
+        assert_eq!(
+            graph.node("missing").map(|node| node.kind),
+            Some(NodeKind::Undeclared)
+        );
+        assert_eq!(graph.dependencies_of("app"), vec!["a", "api"]);
+        assert_eq!(graph.dependents_of("a"), vec!["app", "b"]);
+    }
+
+    #[test]
+    fn finds_orphans_cycles_and_reachable_nodes() {
+        let graph = graph();
+
+        let orphans = graph
+            .orphans()
+            .into_iter()
+            .map(|node| node.bom_ref.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(orphans, vec!["lonely"]);
+        assert_eq!(graph.cycles(), vec![vec!["a", "b"]]);
+        assert_eq!(
+            graph.reachable_from("app").into_iter().collect::<Vec<_>>(),
+            vec!["a", "api", "b", "missing"]
+        );
+    }
+
+    #[test]
+    fn renders_dot() {
+        let dot = graph().to_dot();
+
+        assert!(dot.starts_with("digraph bom {\n"));
+        assert!(dot.contains("    \"api\" [label=\"api\", shape=ellipse];\n"));
+        assert!(dot.contains("    \"app\" -> \"a\";\n"));
+    }
+
+    #[test]
+    fn renders_findings_on_nodes() {
+        let bom = Bom::parse_json(
+            r#"{ "components": [
+                { "type": "library", "name": "a", "bom-ref": "a" },
+                { "type": "library", "name": "b", "bom-ref": "b", "purl": "invalid" }
+            ] }"#,
+        )
+        .expect("Failed to parse BOM");
+        let errors = validate_bom(SpecVersion::V1_5, &bom, &ValidationOptions::new())
+            .expect_err("Expected invalid purl");
+        let graph = BomGraph::new(&bom);
+        let findings = graph.findings(&bom, &errors);
+
+        assert_eq!(findings.keys().collect::<Vec<_>>(), vec!["b"]);
+        let dot = graph.to_dot_with_findings(&findings);
+        assert!(dot.contains("    \"b\" [label=\"b\\n(1 findings)\", shape=box, color=red"));
+        let graphml = graph.to_graphml(&findings);
+        assert!(graphml.contains("<data key=\"findings\">1</data>"));
+        assert!(graphml.contains("<node id=\"a\">"));
+    }
+}
@@ -0,0 +1,205 @@
+//! A standalone HTML page of a [`ValidationReport`], with the `report-html` feature.
+//!
+//! The page needs no external resources. It groups the findings per component, shows them as
+//! collapsible tree of their paths and has a filter by severity.
+use core::fmt::Write;
+
+use crate::{
+    map::IndexMap,
+    paths::list_index,
+    prelude::*,
+    report::{Finding, ValidationReport},
+    validation::Severity,
+    Bom,
+};
+
+const STYLE: &str = "\
+body { font-family: sans-serif; margin: 2em; }
+details { margin-left: 1em; }
+summary { cursor: pointer; }
+ul { margin: 0.25em 0; }
+.error { color: #b00020; }
+.warning { color: #a06000; }
+.suppressed { opacity: 0.5; }
+.code { font-family: monospace; }
+body.hide-error li.error, body.hide-warning li.warning { display: none; }";
+
+const SCRIPT: &str = "\
+for (const filter of document.querySelectorAll('input[data-severity]')) {
+  filter.addEventListener('change', () =>
+    document.body.classList.toggle('hide-' + filter.dataset.severity, !filter.checked));
+}";
+
+/// A node of the tree of finding paths, e.g. `hashes[0]` below `components[3]`.
+#[derive(Default)]
+struct Tree<'a> {
+    children: IndexMap<&'a str, Tree<'a>>,
+    findings: Vec<&'a Finding>,
+}
+
+impl<'a> Tree<'a> {
+    fn insert(&mut self, segments: &[&'a str], finding: &'a Finding) {
+        match segments.split_first() {
+            Some((segment, rest)) => self
+                .children
+                .entry(segment)
+                .or_default()
+                .insert(rest, finding),
+            None => self.findings.push(finding),
+        }
+    }
+
+    fn count(&self) -> usize {
+        self.findings.len() + self.children.values().map(Tree::count).sum::<usize>()
+    }
+
+    fn render(&self, html: &mut String) {
+        if !self.findings.is_empty() {
+            html.push_str("<ul>\n");
+            for finding in &self.findings {
+                render_finding(html, finding);
+            }
+            html.push_str("</ul>\n");
+        }
+        for (segment, child) in &self.children {
+            let _ = writeln!(
+                html,
+                "<details open><summary>{} ({})</summary>",
+                escape(segment),
+                child.count()
+            );
+            child.render(html);
+            html.push_str("</details>\n");
+        }
+    }
+}
+
+fn render_finding(html: &mut String, finding: &Finding) {
+    let severity = match finding.severity {
+        Severity::Info => "info",
+        Severity::Warning => "warning",
+        Severity::Error => "error",
+    };
+    let suppressed = if finding.suppressed {
+        " suppressed"
+    } else {
+        ""
+    };
+    let _ = write!(html, "<li class=\"{}{}\">", severity, suppressed);
+    if let Some(code) = &finding.code {
+        let _ = write!(html, "<span class=\"code\">{}</span> ", escape(code));
+    }
+    let _ = writeln!(
+        html,
+        "{} <small>({})</small></li>",
+        escape(&finding.message),
+        escape(&finding.rule)
+    );
+}
+
+/// Returns the heading of the group a finding belongs to, the component it was found in or
+/// the document itself.
+fn group(bom: &Bom, path: &str) -> String {
+    let component = if path.starts_with("metadata.component.") {
+        bom.meta_data
+            .as_ref()
+            .and_then(|metadata| metadata.component.as_ref())
+    } else {
+        list_index(path, "components").and_then(|index| bom.components.as_ref()?.get(index))
+    };
+
+    match component {
+        Some(component) => match (&component.purl, &component.version) {
+            (Some(purl), _) => format!("{} ({})", component.name, purl),
+            (None, Some(version)) => format!("{}@{}", component.name, version),
+            (None, None) => component.name.clone(),
+        },
+        None => String::from("Document"),
+    }
+}
+
+/// Renders the report of `bom` as standalone HTML page.
+pub fn render_html(report: &ValidationReport, bom: &Bom) -> String {
+    let mut groups: IndexMap<String, Tree> = IndexMap::default();
+    for finding in &report.findings {
+        let segments = finding.path.split('.').collect::<Vec<_>>();
+        groups
+            .entry(group(bom, &finding.path))
+            .or_default()
+            .insert(&segments, finding);
+    }
+
+    let mut html = String::new();
+    let _ = writeln!(
+        html,
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <title>Validation report</title>\n<style>\n{}\n</style>\n</head>\n<body>",
+        STYLE
+    );
+    let _ = writeln!(
+        html,
+        "<h1>Validation report</h1>\n<p>Spec version {}: {} passed, {} failed, {} skipped \
+         checks, {} findings.</p>",
+        report.version,
+        report.passed.len(),
+        report.failed.len(),
+        report.skipped.len(),
+        report.findings.len()
+    );
+    html.push_str(
+        "<p><label><input type=\"checkbox\" data-severity=\"error\" checked> Errors</label> \
+         <label><input type=\"checkbox\" data-severity=\"warning\" checked> Warnings</label></p>\n",
+    );
+
+    for (heading, tree) in &groups {
+        let _ = writeln!(
+            html,
+            "<details open><summary><strong>{}</strong> ({})</summary>",
+            escape(heading),
+            tree.count()
+        );
+        tree.render(&mut html);
+        html.push_str("</details>\n");
+    }
+
+    let _ = writeln!(html, "<script>\n{}\n</script>\n</body>\n</html>", SCRIPT);
+    html
+}
+
+/// Escapes text for HTML content.
+fn escape(input: &str) -> String {
+    input
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        options::ValidationOptions, report::validate_report, validation::SpecVersion, Bom,
+    };
+
+    use super::render_html;
+
+    #[test]
+    fn renders_findings_grouped_by_component() {
+        let bom = Bom::parse_json(
+            r#"{ "components": [
+                { "type": "library", "name": "serde", "version": "1.0.0",
+                  "purl": "cargo/<serde>", "hashes": [{ "alg": "MD5", "content": "xyz" }] }
+            ] }"#,
+        )
+        .expect("Failed to parse BOM");
+        let report = validate_report(SpecVersion::V1_5, &bom, &ValidationOptions::new());
+        let html = render_html(&report, &bom);
+
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(
+            html.contains("<summary><strong>serde (cargo/&lt;serde&gt;)</strong> (2)</summary>")
+        );
+        assert!(html.contains("<details open><summary>hashes[0] (1)</summary>"));
+        assert!(html.contains("<li class=\"error\"><span class=\"code\">invalid-purl</span>"));
+    }
+}
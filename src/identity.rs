@@ -0,0 +1,150 @@
+//! Best-effort stable identity of a [`Component`], shared by diff, merge and the grouping of
+//! findings so downstream tools can agree with the validator on which components are equal.
+//!
+//! The identity is taken from the strongest identifier a component has, in order: purl, cpe,
+//! swid tag id, and finally group, name and version. Purls are compared in their canonical
+//! form, e.g. with sorted qualifiers.
+use core::fmt;
+
+use crate::{prelude::*, purl::Purl, Component};
+
+/// The identity of a [`Component`], see the [module](self) documentation.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ComponentIdentity {
+    /// The package URL, in canonical form if it is valid.
+    Purl(String),
+    /// The CPE as given.
+    Cpe(String),
+    /// The tag id of the SWID tag.
+    Swid(String),
+    /// The coordinates of a component without any identifier.
+    Coordinates {
+        group: Option<String>,
+        name: String,
+        version: Option<String>,
+    },
+}
+
+impl ComponentIdentity {
+    /// Returns the identity of the component.
+    pub fn of(component: &Component) -> Self {
+        if let Some(purl) = &component.purl {
+            let purl = Purl::parse(purl)
+                .map(|purl| purl.to_string())
+                .unwrap_or_else(|_| purl.clone());
+            return ComponentIdentity::Purl(purl);
+        }
+        if let Some(cpe) = &component.cpe {
+            return ComponentIdentity::Cpe(cpe.clone());
+        }
+        if let Some(swid) = &component.swid {
+            return ComponentIdentity::Swid(swid.tag_id.clone());
+        }
+        ComponentIdentity::Coordinates {
+            group: component.group.clone(),
+            name: component.name.clone(),
+            version: component.version.clone(),
+        }
+    }
+
+    /// Returns the identity without version, e.g. to match the same package across two
+    /// releases of a document. Qualifiers and subpath of a purl are dropped as well.
+    ///
+    /// CPEs and SWID tag ids are kept as they are, their version is not reliably separable.
+    pub fn without_version(&self) -> Self {
+        match self {
+            ComponentIdentity::Purl(purl) => match Purl::parse(purl) {
+                Ok(purl) => ComponentIdentity::Purl(
+                    Purl {
+                        version: None,
+                        qualifiers: Default::default(),
+                        subpath: None,
+                        ..purl
+                    }
+                    .to_string(),
+                ),
+                Err(_) => self.clone(),
+            },
+            ComponentIdentity::Coordinates { group, name, .. } => ComponentIdentity::Coordinates {
+                group: group.clone(),
+                name: name.clone(),
+                version: None,
+            },
+            identity => identity.clone(),
+        }
+    }
+}
+
+impl fmt::Display for ComponentIdentity {
+    /// Formats the identity, e.g. `pkg:cargo/serde@1.0.0`, `swid:<tag id>` or
+    /// `group/name@version`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ComponentIdentity::Purl(purl) => write!(f, "{}", purl),
+            ComponentIdentity::Cpe(cpe) => write!(f, "{}", cpe),
+            ComponentIdentity::Swid(tag_id) => write!(f, "swid:{}", tag_id),
+            ComponentIdentity::Coordinates {
+                group,
+                name,
+                version,
+            } => {
+                if let Some(group) = group {
+                    write!(f, "{}/", group)?;
+                }
+                write!(f, "{}", name)?;
+                if let Some(version) = version {
+                    write!(f, "@{}", version)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Component, ComponentType, Swid};
+
+    use super::ComponentIdentity;
+
+    #[test]
+    fn identity_prefers_strongest_identifier() {
+        let mut component = Component {
+            group: Some(String::from("org.example")),
+            version: Some(String::from("1.0.0")),
+            ..Component::new(ComponentType::Library, "lib")
+        };
+        assert_eq!(component.identity().to_string(), "org.example/lib@1.0.0");
+        assert_eq!(
+            component.identity().without_version().to_string(),
+            "org.example/lib"
+        );
+
+        component.swid = Some(Swid {
+            tag_id: String::from("example.com-lib-1.0.0"),
+            name: String::from("lib"),
+            version: None,
+        });
+        assert_eq!(
+            component.identity().to_string(),
+            "swid:example.com-lib-1.0.0"
+        );
+
+        component.cpe = Some(String::from("cpe:2.3:a:example:lib:1.0.0:*:*:*:*:*:*:*"));
+        assert!(matches!(component.identity(), ComponentIdentity::Cpe(_)));
+
+        component.purl = Some(String::from(
+            "pkg:maven/org.example/lib@1.0.0?type=jar&classifier=x",
+        ));
+        assert_eq!(
+            component.identity(),
+            ComponentIdentity::Purl(String::from(
+                "pkg:maven/org.example/lib@1.0.0?classifier=x&type=jar"
+            ))
+        );
+        assert_eq!(
+            component.identity().without_version().to_string(),
+            "pkg:maven/org.example/lib"
+        );
+    }
+}
@@ -0,0 +1,701 @@
+use std::{
+    fs,
+    path::{self, Path, PathBuf},
+};
+
+use md5::Md5;
+use sha1::Sha1;
+use sha2::{Digest, Sha256, Sha384, Sha512};
+
+use crate::{
+    error::DOCUMENT_KEY,
+    rules::{check_components, Rule, RuleContext},
+    validation::{ValidationContext, ValidationError, ValidationResult},
+    Bom, Component, ComponentType, ExternalReference, Hash, HashAlgorithm,
+};
+
+/// Computes the hex encoded digest of the given content.
+///
+/// Returns `None` when the algorithm is not supported.
+pub fn compute_digest(algorithm: &HashAlgorithm, content: &[u8]) -> Option<String> {
+    let digest = match algorithm {
+        HashAlgorithm::Md5 => Md5::digest(content).to_vec(),
+        HashAlgorithm::Sha1 => Sha1::digest(content).to_vec(),
+        HashAlgorithm::Sha256 => Sha256::digest(content).to_vec(),
+        HashAlgorithm::Sha384 => Sha384::digest(content).to_vec(),
+        HashAlgorithm::Sha512 => Sha512::digest(content).to_vec(),
+        _ => return None,
+    };
+
+    Some(digest.iter().map(|byte| format!("{:02x}", byte)).collect())
+}
+
+/// Resolves the local artifact of a [`Component`], e.g. the file on disk.
+pub trait ArtifactResolver: Send + Sync {
+    /// Returns the path of the artifact or `None` if the component cannot be resolved.
+    fn resolve(&self, component: &Component) -> Option<PathBuf>;
+}
+
+impl<F> ArtifactResolver for F
+where
+    F: Fn(&Component) -> Option<PathBuf> + Send + Sync,
+{
+    fn resolve(&self, component: &Component) -> Option<PathBuf> {
+        self(component)
+    }
+}
+
+/// Resolves artifacts by their component name relative to a root directory.
+///
+/// Names that could leave the root, e.g. absolute paths, names containing `..` or symbolic
+/// links pointing outside of the root, are not resolved, the document may come from an
+/// untrusted source.
+#[derive(Debug, Clone)]
+pub struct RootDirectory {
+    root: PathBuf,
+}
+
+impl RootDirectory {
+    pub fn new<P: AsRef<Path>>(root: P) -> Self {
+        Self {
+            root: root.as_ref().to_path_buf(),
+        }
+    }
+
+    /// Returns the canonical path of the file if it is located within the root.
+    fn contain(&self, path: &Path) -> Option<PathBuf> {
+        let root = self.root.canonicalize().ok()?;
+        let path = path.canonicalize().ok()?;
+        (path.starts_with(&root) && path.is_file()).then_some(path)
+    }
+}
+
+impl ArtifactResolver for RootDirectory {
+    fn resolve(&self, component: &Component) -> Option<PathBuf> {
+        let name = Path::new(&component.name);
+        if !name
+            .components()
+            .all(|part| matches!(part, path::Component::Normal(_)))
+        {
+            return None;
+        }
+
+        self.contain(&self.root.join(name))
+    }
+}
+
+/// Opt-in [`Rule`] that recomputes the digests of all components of type `file`
+/// and compares them with the declared hashes.
+///
+/// Components that cannot be resolved to a local artifact or use an unsupported
+/// hash algorithm are skipped.
+#[derive(Debug)]
+pub struct HashVerificationRule<R> {
+    resolver: R,
+}
+
+impl<R: ArtifactResolver> HashVerificationRule<R> {
+    pub fn new(resolver: R) -> Self {
+        Self { resolver }
+    }
+
+    fn verify_component(&self, component: &Component) -> ValidationResult {
+        if component.component_type != ComponentType::File {
+            return Ok(());
+        }
+        let (Some(hashes), Some(path)) = (&component.hashes, self.resolver.resolve(component))
+        else {
+            return Ok(());
+        };
+
+        match read_file(&path) {
+            Ok(content) => {
+                let children = hashes
+                    .iter()
+                    .map(|hash| verify_hash(hash, &content))
+                    .collect::<Vec<_>>();
+
+                ValidationContext::new()
+                    .add_list("hashes", Some(children))
+                    .into()
+            }
+            Err(error) => ValidationContext::new()
+                .add_field(
+                    "hashes",
+                    Some(Err(ValidationError::new("artifact_unreadable")
+                        .with_param("path", path.display())
+                        .with_param("error", error)
+                        .with_code("artifact-unreadable"))),
+                )
+                .into(),
+        }
+    }
+}
+
+impl HashVerificationRule<RootDirectory> {
+    /// Creates the rule resolving artifacts relative to the given root directory.
+    pub fn from_root<P: AsRef<Path>>(root: P) -> Self {
+        Self::new(RootDirectory::new(root))
+    }
+}
+
+impl<R: ArtifactResolver> Rule for HashVerificationRule<R> {
+    fn id(&self) -> &str {
+        "hash-verification"
+    }
+
+    fn check(&self, bom: &Bom, _ctx: &RuleContext) -> ValidationResult {
+        let children = check_components(bom.components.as_deref(), &|component| {
+            self.verify_component(component)
+        });
+
+        ValidationContext::new()
+            .add_list("components", children)
+            .into()
+    }
+}
+
+/// Opt-in [`Rule`] that verifies the declared hashes of external references.
+///
+/// References pointing to a local `file:` URL are only verified when
+/// [`ExternalReferenceHashRule::verify_local`] is set and the file is located within the given
+/// root, like the artifacts resolved by [`RootDirectory`]. Remote URLs are only downloaded and
+/// verified when the `net` feature is enabled and [`ExternalReferenceHashRule::verify_remote`]
+/// is set. Artifacts larger than [`MAX_DOWNLOAD_LEN`] are reported as unreadable.
+#[derive(Debug, Default)]
+pub struct ExternalReferenceHashRule {
+    local_root: Option<RootDirectory>,
+    #[cfg(feature = "net")]
+    verify_remote: bool,
+}
+
+impl ExternalReferenceHashRule {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enables reading local `file:` references within the given root to verify their hashes.
+    pub fn verify_local<P: AsRef<Path>>(mut self, root: P) -> Self {
+        self.local_root = Some(RootDirectory::new(root));
+        self
+    }
+
+    /// Enables downloading remote references to verify their hashes.
+    #[cfg(feature = "net")]
+    pub fn verify_remote(mut self, verify_remote: bool) -> Self {
+        self.verify_remote = verify_remote;
+        self
+    }
+
+    /// Returns the content the reference points to or `None` if it cannot be verified.
+    fn fetch(&self, url: &str) -> Option<Result<Vec<u8>, String>> {
+        if url.starts_with("file:") {
+            let root = self.local_root.as_ref()?;
+            let Some(path) = file_url_path(url) else {
+                return Some(Err(String::from("only local file URLs are supported")));
+            };
+            let Some(path) = root.contain(&root.root.join(path)) else {
+                return Some(Err(String::from("file is not located within the root")));
+            };
+            return Some(
+                read_file(&path).map_err(|_| String::from("file cannot be read within the limit")),
+            );
+        }
+
+        #[cfg(feature = "net")]
+        if self.verify_remote && (url.starts_with("https://") || url.starts_with("http://")) {
+            return Some(download(url));
+        }
+
+        None
+    }
+
+    fn verify_reference(&self, reference: &ExternalReference) -> ValidationResult {
+        let Some(hashes) = &reference.hashes else {
+            return Ok(());
+        };
+
+        match self.fetch(&reference.url) {
+            Some(Ok(content)) => {
+                let children = hashes
+                    .iter()
+                    .map(|hash| verify_hash(hash, &content))
+                    .collect::<Vec<_>>();
+
+                ValidationContext::new()
+                    .add_list("hashes", Some(children))
+                    .into()
+            }
+            Some(Err(error)) => ValidationContext::new()
+                .add_field(
+                    "url",
+                    Some(Err(ValidationError::new("fetch_failed")
+                        .with_param("url", &reference.url)
+                        .with_param("error", error)
+                        .with_code("artifact-unreadable"))),
+                )
+                .into(),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Rule for ExternalReferenceHashRule {
+    fn id(&self) -> &str {
+        "external-reference-hashes"
+    }
+
+    fn check(&self, bom: &Bom, _ctx: &RuleContext) -> ValidationResult {
+        let children = check_components(bom.components.as_deref(), &|component| {
+            let references = component.external_references.as_ref().map(|references| {
+                references
+                    .iter()
+                    .map(|reference| self.verify_reference(reference))
+                    .collect::<Vec<_>>()
+            });
+
+            ValidationContext::new()
+                .add_list("external_references", references)
+                .into()
+        });
+
+        ValidationContext::new()
+            .add_list("components", children)
+            .into()
+    }
+}
+
+/// Opt-in [`Rule`] that compares the [`Bom::digest`] with an externally supplied digest,
+/// e.g. the one recorded in an attestation.
+#[derive(Debug, Clone)]
+pub struct ExpectedDigestRule {
+    algorithm: HashAlgorithm,
+    expected: String,
+}
+
+impl ExpectedDigestRule {
+    pub fn new<T: ToString>(algorithm: HashAlgorithm, expected: T) -> Self {
+        Self {
+            algorithm,
+            expected: expected.to_string(),
+        }
+    }
+}
+
+impl Rule for ExpectedDigestRule {
+    fn check(&self, bom: &Bom, _ctx: &RuleContext) -> ValidationResult {
+        let error = match bom.digest(&self.algorithm) {
+            Some(digest) if digest.eq_ignore_ascii_case(&self.expected) => None,
+            Some(digest) => Some(Err(ValidationError::new("digest_mismatch")
+                .with_param("expected", &self.expected)
+                .with_param("computed", &digest)
+                .with_code("digest-mismatch")
+                .with_expected(&self.expected)
+                .with_actual(digest))),
+            None => Some(Err(ValidationError::new("unsupported_digest_algorithm")
+                .with_param("alg", format!("{:?}", self.algorithm))
+                .with_code("unsupported-digest-algorithm"))),
+        };
+
+        ValidationContext::new()
+            .add_field(DOCUMENT_KEY, error)
+            .into()
+    }
+
+    fn id(&self) -> &str {
+        "expected-digest"
+    }
+}
+
+/// Returns the local path of a `file:` URL, e.g. `file:///tmp/artifact.tar.gz`.
+///
+/// URLs with a host other than `localhost` are not local and return `None`.
+fn file_url_path(url: &str) -> Option<PathBuf> {
+    let path = url.strip_prefix("file:")?;
+    let path = match path.strip_prefix("//") {
+        Some(authority_and_path) => {
+            let (host, path) = authority_and_path.split_at(authority_and_path.find('/')?);
+            if !matches!(host, "" | "localhost") {
+                return None;
+            }
+            path
+        }
+        None => path,
+    };
+
+    Some(PathBuf::from(path))
+}
+
+/// The maximum size of an artifact read to verify its hashes, e.g. a local file or a remote
+/// artifact downloaded by [`ExternalReferenceHashRule`].
+pub const MAX_DOWNLOAD_LEN: u64 = 256 * 1024 * 1024;
+
+/// Reads the file, failing if it is larger than [`MAX_DOWNLOAD_LEN`].
+fn read_file(path: &Path) -> Result<Vec<u8>, String> {
+    let file = fs::File::open(path).map_err(|error| error.to_string())?;
+    read_limited(file, MAX_DOWNLOAD_LEN)
+}
+
+/// Reads the content, failing if it is larger than `limit` bytes.
+fn read_limited(reader: impl std::io::Read, limit: u64) -> Result<Vec<u8>, String> {
+    use std::io::Read;
+
+    let mut content = Vec::new();
+    reader
+        .take(limit + 1)
+        .read_to_end(&mut content)
+        .map_err(|error| error.to_string())?;
+
+    if content.len() as u64 > limit {
+        return Err(format!("content exceeds the limit of {} bytes", limit));
+    }
+    Ok(content)
+}
+
+#[cfg(feature = "net")]
+fn download(url: &str) -> Result<Vec<u8>, String> {
+    let response = ureq::get(url).call().map_err(|error| error.to_string())?;
+    read_limited(response.into_reader(), MAX_DOWNLOAD_LEN)
+}
+
+fn verify_hash(hash: &Hash, content: &[u8]) -> ValidationResult {
+    let error = compute_digest(&hash.alg, content)
+        .filter(|digest| !digest.eq_ignore_ascii_case(&hash.content))
+        .map(|digest| {
+            Err(ValidationError::new("hash_mismatch")
+                .with_param("declared", &hash.content)
+                .with_param("computed", &digest)
+                .with_code("hash-mismatch")
+                .with_expected(&hash.content)
+                .with_actual(digest))
+        });
+
+    ValidationContext::new().add_field("content", error).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::PathBuf};
+
+    use crate::{
+        rules::{opt_in, Rule, RuleContext},
+        testing::errors_at,
+        validation::{SpecVersion, ValidationErrors},
+        Bom, Component, ComponentType, ExternalReference, ExternalReferenceType, Hash,
+        HashAlgorithm,
+    };
+
+    use super::{
+        compute_digest, file_url_path, read_limited, ExpectedDigestRule, ExternalReferenceHashRule,
+        HashVerificationRule,
+    };
+
+    const HELLO_SHA256: &str = "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824";
+
+    fn file_component(name: &str, content: &str) -> Component {
+        Component {
+            hashes: Some(vec![Hash {
+                alg: HashAlgorithm::Sha256,
+                content: content.to_string(),
+            }]),
+            ..Component::new(ComponentType::File, name)
+        }
+    }
+
+    fn artifact_dir(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("validation-tree-{}", name));
+        fs::create_dir_all(&root).expect("Failed to create temp dir");
+        fs::write(root.join("hello.txt"), "hello").expect("Failed to write artifact");
+        root
+    }
+
+    #[test]
+    fn compute_known_digests() {
+        assert_eq!(
+            compute_digest(&HashAlgorithm::Sha256, b"hello").as_deref(),
+            Some(HELLO_SHA256)
+        );
+        assert_eq!(
+            compute_digest(&HashAlgorithm::Md5, b"hello").as_deref(),
+            Some("5d41402abc4b2a76b9719d911017c592")
+        );
+        assert_eq!(compute_digest(&HashAlgorithm::Blake3, b"hello"), None);
+    }
+
+    #[test]
+    fn digest_ignores_absent_fields_and_key_order() {
+        let bom = Bom::parse_json(r#"{ "version": 1, "serialNumber": null, "components": [] }"#)
+            .expect("Failed to parse BOM");
+        let reordered =
+            Bom::parse_json(r#"{ "components": [], "version": 1 }"#).expect("Failed to parse BOM");
+
+        assert_eq!(
+            bom.to_value().to_string(),
+            r#"{"components":[],"version":1}"#
+        );
+        assert_eq!(
+            bom.digest(&HashAlgorithm::Sha256),
+            reordered.digest(&HashAlgorithm::Sha256)
+        );
+        assert_eq!(
+            bom.digest(&HashAlgorithm::Sha256),
+            compute_digest(&HashAlgorithm::Sha256, br#"{"components":[],"version":1}"#)
+        );
+    }
+
+    #[test]
+    fn verify_expected_digest() {
+        let bom = Bom {
+            components: Some(vec![file_component("hello.txt", HELLO_SHA256)]),
+            ..Default::default()
+        };
+        let digest = bom
+            .digest(&HashAlgorithm::Sha256)
+            .expect("Expected supported algorithm");
+        let ctx = RuleContext::new(SpecVersion::V1_5);
+
+        let rule = ExpectedDigestRule::new(HashAlgorithm::Sha256, digest.to_uppercase());
+        assert!(rule.check(&bom, &ctx).is_ok());
+
+        let result =
+            ExpectedDigestRule::new(HashAlgorithm::Sha256, "0".repeat(64)).check(&bom, &ctx);
+        assert!(ValidationErrors::has_error(&result, "document"));
+
+        let result = ExpectedDigestRule::new(HashAlgorithm::Blake3, digest).check(&bom, &ctx);
+        assert!(ValidationErrors::has_error(&result, "document"));
+    }
+
+    #[test]
+    fn verify_matching_hash() {
+        let root = artifact_dir("verify-matching");
+        let bom = Bom {
+            components: Some(vec![file_component("hello.txt", HELLO_SHA256)]),
+            ..Default::default()
+        };
+
+        let rule = HashVerificationRule::from_root(&root);
+        assert!(rule
+            .check(&bom, &RuleContext::new(SpecVersion::V1_5))
+            .is_ok());
+    }
+
+    #[test]
+    fn verify_mismatching_hash() {
+        let root = artifact_dir("verify-mismatching");
+        let bom = Bom {
+            components: Some(vec![file_component("hello.txt", &"0".repeat(64))]),
+            ..Default::default()
+        };
+
+        let rule = HashVerificationRule::from_root(&root);
+        let result = rule.check(&bom, &RuleContext::new(SpecVersion::V1_5));
+        assert!(ValidationErrors::has_error(&result, "components"));
+    }
+
+    #[test]
+    fn verify_nested_components() {
+        let root = artifact_dir("verify-nested");
+        let bom = Bom {
+            components: Some(vec![Component {
+                components: Some(vec![file_component("hello.txt", &"0".repeat(64))]),
+                ..Component::new(ComponentType::Library, "assembly")
+            }]),
+            ..Default::default()
+        };
+
+        let rule = HashVerificationRule::from_root(&root);
+        let result = rule.check(&bom, &RuleContext::new(SpecVersion::V1_5));
+        assert_eq!(
+            errors_at(&result, "components[0].components[0].hashes[0].content").len(),
+            1
+        );
+    }
+
+    #[test]
+    fn verify_with_resolver_callback() {
+        let root = artifact_dir("verify-callback");
+        let bom = Bom {
+            components: Some(vec![file_component("unknown", &"0".repeat(64))]),
+            ..Default::default()
+        };
+
+        let rule = HashVerificationRule::new(|_component: &Component| Some(root.join("hello.txt")));
+        let result = rule.check(&bom, &RuleContext::new(SpecVersion::V1_5));
+        assert!(ValidationErrors::has_error(&result, "components"));
+    }
+
+    #[test]
+    fn skip_names_outside_of_root() {
+        let outside = artifact_dir("verify-outside");
+        let root = outside.join("root");
+        fs::create_dir_all(&root).expect("Failed to create root dir");
+        let bom = Bom {
+            components: Some(vec![
+                file_component("../hello.txt", &"0".repeat(64)),
+                file_component(
+                    &outside.join("hello.txt").display().to_string(),
+                    &"0".repeat(64),
+                ),
+            ]),
+            ..Default::default()
+        };
+
+        let rule = HashVerificationRule::from_root(&root);
+        assert!(rule
+            .check(&bom, &RuleContext::new(SpecVersion::V1_5))
+            .is_ok());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn skip_symlinks_outside_of_root() {
+        let outside = artifact_dir("verify-symlink");
+        let root = outside.join("root");
+        fs::create_dir_all(&root).expect("Failed to create root dir");
+        let link = root.join("link.txt");
+        if !link.exists() {
+            std::os::unix::fs::symlink(outside.join("hello.txt"), &link)
+                .expect("Failed to create symlink");
+        }
+        let bom = Bom {
+            components: Some(vec![file_component("link.txt", &"0".repeat(64))]),
+            ..Default::default()
+        };
+
+        let rule = HashVerificationRule::from_root(&root);
+        assert!(rule
+            .check(&bom, &RuleContext::new(SpecVersion::V1_5))
+            .is_ok());
+    }
+
+    #[test]
+    fn rules_are_registered() {
+        let rules: [&dyn Rule; 3] = [
+            &HashVerificationRule::from_root("."),
+            &ExternalReferenceHashRule::new(),
+            &ExpectedDigestRule::new(HashAlgorithm::Sha256, ""),
+        ];
+        for rule in rules {
+            assert!(
+                opt_in().iter().any(|info| info.id == rule.id()),
+                "{} is not registered",
+                rule.id()
+            );
+        }
+    }
+
+    #[test]
+    fn skip_unresolved_and_non_file_components() {
+        let root = artifact_dir("verify-skip");
+        let mut library = file_component("hello.txt", &"0".repeat(64));
+        library.component_type = ComponentType::Library;
+        let bom = Bom {
+            components: Some(vec![
+                library,
+                file_component("missing.txt", &"0".repeat(64)),
+            ]),
+            ..Default::default()
+        };
+
+        let rule = HashVerificationRule::from_root(&root);
+        assert!(rule
+            .check(&bom, &RuleContext::new(SpecVersion::V1_5))
+            .is_ok());
+    }
+
+    fn bom_with_reference(url: String, content: &str) -> Bom {
+        Bom {
+            components: Some(vec![Component {
+                external_references: Some(vec![ExternalReference {
+                    reference_type: ExternalReferenceType::Distribution,
+                    url,
+                    hashes: Some(vec![Hash {
+                        alg: HashAlgorithm::Sha256,
+                        content: content.to_string(),
+                    }]),
+                }]),
+                ..Component::new(ComponentType::Library, "lib")
+            }]),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn parse_file_urls() {
+        assert_eq!(
+            file_url_path("file:///tmp/hello.txt"),
+            Some(PathBuf::from("/tmp/hello.txt"))
+        );
+        assert_eq!(
+            file_url_path("file://localhost/tmp/hello.txt"),
+            Some(PathBuf::from("/tmp/hello.txt"))
+        );
+        assert_eq!(
+            file_url_path("file:hello.txt"),
+            Some(PathBuf::from("hello.txt"))
+        );
+        assert_eq!(file_url_path("file://example.com/tmp/hello.txt"), None);
+        assert_eq!(file_url_path("https://example.com/hello.txt"), None);
+    }
+
+    #[test]
+    fn verify_external_reference_hashes() {
+        let root = artifact_dir("verify-reference");
+        let url = format!("file://{}", root.join("hello.txt").display());
+        let context = RuleContext::new(SpecVersion::V1_5);
+        let rule = ExternalReferenceHashRule::new().verify_local(&root);
+
+        let bom = bom_with_reference(url.clone(), HELLO_SHA256);
+        assert!(rule.check(&bom, &context).is_ok());
+
+        let bom = bom_with_reference(String::from("file:hello.txt"), HELLO_SHA256);
+        assert!(rule.check(&bom, &context).is_ok());
+
+        let bom = bom_with_reference(url.clone(), &"0".repeat(64));
+        let result = rule.check(&bom, &context);
+        assert!(ValidationErrors::has_error(&result, "components"));
+
+        let result = ExternalReferenceHashRule::new().check(&bom, &context);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn reject_local_references_outside_of_root() {
+        let outside = artifact_dir("verify-reference-outside");
+        let root = outside.join("root");
+        fs::create_dir_all(&root).expect("Failed to create root dir");
+        let context = RuleContext::new(SpecVersion::V1_5);
+        let rule = ExternalReferenceHashRule::new().verify_local(&root);
+
+        for url in [
+            format!("file://{}", outside.join("hello.txt").display()),
+            String::from("file:../hello.txt"),
+            format!("file://example.com{}", root.join("hello.txt").display()),
+        ] {
+            let result = rule.check(&bom_with_reference(url.clone(), HELLO_SHA256), &context);
+            let errors = errors_at(&result, "components[0].external_references[0].url");
+            assert_eq!(errors.len(), 1, "{}", url);
+            assert!(!errors[0]
+                .message
+                .param("error")
+                .unwrap_or_default()
+                .contains(&outside.display().to_string()));
+        }
+    }
+
+    #[test]
+    fn limit_read_content() {
+        assert_eq!(read_limited(&b"hello"[..], 5), Ok(b"hello".to_vec()));
+        assert!(read_limited(&b"hello!"[..], 5).is_err());
+    }
+
+    #[test]
+    fn skip_remote_external_references() {
+        let bom = bom_with_reference(String::from("https://example.com/hello.txt"), HELLO_SHA256);
+
+        let rule = ExternalReferenceHashRule::new();
+        assert!(rule
+            .check(&bom, &RuleContext::new(SpecVersion::V1_5))
+            .is_ok());
+    }
+}
@@ -1,139 +1,1415 @@
-mod validation;
+//! Validation of CycloneDX SBOMs into a tree of errors.
+//!
+//! The core, i.e. the model, the [`validation`] types and the rules, is `no_std` compatible and
+//! only requires `alloc`. Everything touching the file system, processes or randomness, e.g.
+//! [`config`], [`integrity`] or [`normalize`], requires the default `std` feature.
+#![cfg_attr(not(feature = "std"), no_std)]
 
-use validation::{SpecVersion, Validate, ValidationContext, ValidationError, ValidationErrors};
+extern crate alloc;
+extern crate self as validation_tree;
 
-fn validate_timestamp(input: &str) -> Result<(), validation::ValidationError> {
-    if input.contains("a") {
-        return Err(ValidationError::new("timestamp contains char 'a'"));
-    }
+#[cfg(feature = "std")]
+pub mod batch;
+pub mod builder;
+pub mod canonical;
+#[cfg(feature = "std")]
+pub mod config;
+#[cfg(feature = "std")]
+pub mod corpus;
+pub mod coverage;
+pub mod deprecations;
+pub mod diff;
+#[cfg(feature = "differential")]
+pub mod differential;
+#[cfg(feature = "async")]
+pub mod enrichment;
+pub mod error;
+#[cfg(any(feature = "tracing", feature = "log"))]
+pub mod events;
+#[cfg(feature = "std")]
+pub mod file;
+pub mod fragment;
+pub mod graph;
+#[cfg(feature = "report-html")]
+pub mod html;
+pub mod identity;
+#[cfg(feature = "std")]
+pub mod integrity;
+pub mod licenses;
+pub mod lineage;
+pub mod lint;
+pub mod map;
+#[cfg(feature = "std")]
+pub mod merge;
+pub mod message;
+#[cfg(feature = "metrics")]
+pub mod monitoring;
+#[cfg(feature = "std")]
+pub mod normalize;
+pub mod options;
+#[cfg(feature = "net")]
+pub mod osv;
+pub mod patch;
+pub mod paths;
+#[cfg(feature = "plugins-wasm")]
+pub mod plugin;
+pub mod purl;
+pub mod redact;
+pub mod report;
+#[cfg(feature = "std")]
+pub mod rule_pack;
+pub mod rules;
+#[cfg(feature = "arbitrary")]
+pub mod strategies;
+pub mod suppress;
+pub mod testing;
+pub mod upgrade;
+pub mod validation;
+pub mod validator;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+#[cfg(feature = "xml")]
+pub mod xml;
 
-    Ok(())
+/// The items of the std prelude provided by `alloc`, imported by the `no_std` compatible modules.
+mod prelude {
+    pub use alloc::{
+        boxed::Box,
+        format,
+        string::{String, ToString},
+        vec,
+        vec::Vec,
+    };
 }
 
+#[cfg(feature = "std")]
+pub use file::validate_file;
+pub use fragment::{validate_component, validate_metadata, validate_vulnerability};
+use graph::BomGraph;
+pub use lineage::validate_lineage;
+use options::ValidationOptions;
+use prelude::*;
+use purl::Purl;
+use rules::RuleContext;
+use serde::{Deserialize, Serialize};
+use validation::{
+    SpecVersion, Validate, ValidationContext, ValidationError, ValidationErrors, ValidationResult,
+};
+
 fn validate_string(input: &str) -> Result<(), validation::ValidationError> {
     if input.len() > 4 {
-        return Err(ValidationError::new("String is too large"));
+        return Err(ValidationError::new("too_long")
+            .with_param("max", 4)
+            .with_param("actual", input.len())
+            .with_code("string-too-long")
+            .with_expected("at most 4 characters")
+            .with_actual(input));
     }
 
     Ok(())
 }
 
+fn validate_metadata_timestamp(
+    timestamp: &str,
+    version: SpecVersion,
+    options: &ValidationOptions,
+) -> Result<(), validation::ValidationError> {
+    match version {
+        SpecVersion::V1_4 => validate_string(timestamp),
+        _ => rules::validate_timestamp(timestamp, options.selected_timestamp_tolerance()),
+    }
+}
+
+/// Reads `tools.components` of the object form, ignoring the legacy array of tools.
+fn deserialize_tool_components<'de, D>(deserializer: D) -> Result<Option<Vec<Component>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Tools {
+        Object { components: Option<Vec<Component>> },
+        Legacy(serde::de::IgnoredAny),
+    }
+
+    Ok(match Tools::deserialize(deserializer)? {
+        Tools::Object { components } => components,
+        Tools::Legacy(_) => None,
+    })
+}
+
+/// Writes the tool components in the object form, i.e. `{ "components": [...] }`.
+fn serialize_tool_components<S>(
+    components: &Option<Vec<Component>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    #[derive(Serialize)]
+    struct Tools<'a> {
+        components: &'a Option<Vec<Component>>,
+    }
+
+    match components {
+        Some(_) => Tools { components }.serialize(serializer),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// Reads a single object or a list of objects, e.g. `evidence.identity` which became a list
+/// in spec version 1.6.
+fn deserialize_one_or_many<'de, D, T>(deserializer: D) -> Result<Option<Vec<T>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany<T> {
+        Many(Vec<T>),
+        One(T),
+    }
+
+    Ok(match Option::<OneOrMany<T>>::deserialize(deserializer)? {
+        Some(OneOrMany::Many(values)) => Some(values),
+        Some(OneOrMany::One(value)) => Some(vec![value]),
+        None => None,
+    })
+}
+
 fn validate_vendor(_input: &str) -> Result<(), validation::ValidationError> {
     Ok(())
 }
 
 fn validate_toolkind(kind: &ToolKind) -> Result<(), validation::ValidationError> {
     if matches!(kind, ToolKind::Hammer) {
-        return Err(ValidationError::new("Tool must not be a hammer"));
+        return Err(ValidationError::new("tool_kind")
+            .with_param("kind", "hammer")
+            .with_code("tool-kind")
+            .with_actual(format!("{:?}", kind)));
+    }
+    Ok(())
+}
+
+fn validate_hash_content(algorithm: &HashAlgorithm, content: &str) -> Result<(), ValidationError> {
+    if !content.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(ValidationError::new("hash_not_hex")
+            .with_code("invalid-hash")
+            .with_expected("hex string")
+            .with_actual(content));
+    }
+    if content.len() != algorithm.hex_len() {
+        return Err(ValidationError::new("hash_length")
+            .with_param("alg", format!("{:?}", algorithm))
+            .with_param("len", algorithm.hex_len())
+            .with_code("invalid-hash")
+            .with_expected(format!("{} hex digits", algorithm.hex_len()))
+            .with_actual(content));
+    }
+
+    Ok(())
+}
+
+/// Checks that the input is an absolute URI, i.e. has a scheme and contains no whitespace.
+pub(crate) fn validate_uri(uri: &str) -> Result<(), ValidationError> {
+    let valid_scheme = uri.split_once(':').is_some_and(|(scheme, _)| {
+        scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+            && scheme
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+    });
+
+    if !valid_scheme || uri.chars().any(|c| c.is_whitespace() || c.is_control()) {
+        return Err(ValidationError::new("invalid_uri")
+            .with_code("invalid-uri")
+            .with_expected("URI")
+            .with_actual(uri));
+    }
+
+    Ok(())
+}
+
+/// The valid values of [`DataFlow::flow`].
+const DATA_FLOWS: &[&str] = &["inbound", "outbound", "bi-directional", "unknown"];
+
+fn validate_confidence(confidence: &f64) -> Result<(), ValidationError> {
+    if !(0.0..=1.0).contains(confidence) {
+        return Err(ValidationError::new("confidence_range")
+            .with_param("confidence", confidence)
+            .with_code("confidence-range")
+            .with_expected("0 to 1")
+            .with_actual(confidence.to_string()));
+    }
+    Ok(())
+}
+
+/// The maximum length of a copyright statement, longer ones are usually whole license texts.
+pub const MAX_COPYRIGHT_LEN: usize = 4096;
+
+fn validate_copyright(copyright: &str) -> Result<(), ValidationError> {
+    if let Some(c) = copyright
+        .chars()
+        .find(|c| c.is_control() && !matches!(c, '\t' | '\n' | '\r'))
+    {
+        return Err(ValidationError::new("copyright_control_character")
+            .with_param("character", format!("U+{:04X}", c as u32))
+            .with_code("control-character")
+            .with_actual(copyright.escape_default()));
+    }
+    if copyright.trim().is_empty() {
+        return Err(ValidationError::warning("copyright_blank").with_code("blank-copyright"));
+    }
+    if copyright.len() > MAX_COPYRIGHT_LEN {
+        return Err(ValidationError::warning("copyright_too_long")
+            .with_param("max", MAX_COPYRIGHT_LEN)
+            .with_param("actual", copyright.len())
+            .with_code("copyright-too-long")
+            .with_expected(format!("at most {} bytes", MAX_COPYRIGHT_LEN)));
+    }
+    Ok(())
+}
+
+fn validate_license_acknowledgement(acknowledgement: &str) -> Result<(), ValidationError> {
+    if !matches!(acknowledgement, "declared" | "concluded") {
+        return Err(ValidationError::new("invalid_license_acknowledgement")
+            .with_param("acknowledgement", acknowledgement)
+            .with_code("invalid-license-acknowledgement")
+            .with_expected("declared, concluded")
+            .with_actual(acknowledgement));
+    }
+
+    Ok(())
+}
+
+fn validate_data_flow(flow: &str) -> Result<(), ValidationError> {
+    if !DATA_FLOWS.contains(&flow) {
+        return Err(ValidationError::new("invalid_data_flow")
+            .with_param("flow", flow)
+            .with_code("invalid-data-flow")
+            .with_expected(DATA_FLOWS.join(", "))
+            .with_actual(flow));
     }
+
     Ok(())
 }
 
+/// Checks that the input is a BOM-Link, e.g. `urn:cdx:<serial number>/1#<bom-ref>`.
+fn validate_bom_link(link: &str) -> Result<(), ValidationError> {
+    let valid = link.strip_prefix("urn:cdx:").is_some_and(|rest| {
+        let (document, bom_ref) = rest.split_once('#').unwrap_or((rest, ""));
+        let valid_ref = !rest.contains('#') || !bom_ref.is_empty();
+        document.split_once('/').is_some_and(|(uuid, version)| {
+            let valid_uuid = uuid.len() == 36
+                && uuid.char_indices().all(|(index, c)| match index {
+                    8 | 13 | 18 | 23 => c == '-',
+                    _ => c.is_ascii_hexdigit(),
+                });
+            let valid_version = version.parse::<u32>().is_ok_and(|version| version > 0);
+            valid_uuid && valid_version && valid_ref
+        })
+    });
+
+    if !valid {
+        return Err(ValidationError::new("invalid_bom_link")
+            .with_code("invalid-bom-link")
+            .with_expected("urn:cdx:<serial number>/<version>[#<bom-ref>]")
+            .with_actual(link));
+    }
+
+    Ok(())
+}
+
+/// Checks a list of references, which are either BOM-Links or URIs.
+fn validate_bom_links(links: &[String]) -> Result<(), ValidationError> {
+    for (index, link) in links.iter().enumerate() {
+        let result = if link.starts_with("urn:cdx:") {
+            validate_bom_link(link)
+        } else {
+            validate_uri(link)
+        };
+        result.map_err(|error| error.with_index(index))?;
+    }
+
+    Ok(())
+}
+
+fn validate_purl(purl: &str) -> Result<(), ValidationError> {
+    let parsed = Purl::parse(purl).map_err(|error| {
+        error
+            .with_code("invalid-purl")
+            .with_expected("package URL")
+            .with_actual(purl)
+    })?;
+
+    match parsed.canonical_form(purl) {
+        Some(canonical) => Err(ValidationError::warning("purl_not_canonical")
+            .with_code("non-canonical-purl")
+            .with_expected(&canonical)
+            .with_actual(purl)
+            .with_suggestion(canonical, true)),
+        None => Ok(()),
+    }
+}
+
 #[derive(Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum ToolKind {
     Hammer,
     ScrewDriver,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Tool {
+    #[validate(custom = "validate_vendor")]
     pub vendor: Option<String>,
+    #[validate(custom = "validate_string")]
     pub name: Option<String>,
+    #[validate(custom = "validate_toolkind", enum)]
     pub kind: ToolKind,
 }
 
-impl Validate for Tool {
-    fn validate(&self, _version: validation::SpecVersion) -> Result<(), ValidationErrors> {
+#[derive(Debug, Default, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct Metadata {
+    #[validate(custom = "validate_metadata_timestamp", version, options)]
+    pub timestamp: Option<String>,
+    #[serde(skip)]
+    #[validate(each)]
+    pub tools: Option<Vec<Tool>>,
+    /// The `components` of the `tools` object, since spec version 1.5.
+    #[serde(
+        default,
+        rename = "tools",
+        deserialize_with = "deserialize_tool_components",
+        serialize_with = "serialize_tool_components"
+    )]
+    #[validate(each)]
+    pub tool_components: Option<Vec<Component>>,
+    #[validate(nested)]
+    pub component: Option<Component>,
+    /// The persons who created the BOM.
+    pub authors: Option<Vec<OrganizationalContact>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum HashAlgorithm {
+    #[serde(rename = "MD5")]
+    Md5,
+    #[serde(rename = "SHA-1")]
+    Sha1,
+    #[serde(rename = "SHA-256")]
+    Sha256,
+    #[serde(rename = "SHA-384")]
+    Sha384,
+    #[serde(rename = "SHA-512")]
+    Sha512,
+    #[serde(rename = "SHA3-256")]
+    Sha3_256,
+    #[serde(rename = "SHA3-384")]
+    Sha3_384,
+    #[serde(rename = "SHA3-512")]
+    Sha3_512,
+    #[serde(rename = "BLAKE2b-256")]
+    Blake2b256,
+    #[serde(rename = "BLAKE2b-384")]
+    Blake2b384,
+    #[serde(rename = "BLAKE2b-512")]
+    Blake2b512,
+    #[serde(rename = "BLAKE3")]
+    Blake3,
+}
+
+impl HashAlgorithm {
+    /// Returns `true` for algorithms that are considered broken, i.e. MD5 and SHA-1.
+    pub fn is_weak(&self) -> bool {
+        matches!(self, HashAlgorithm::Md5 | HashAlgorithm::Sha1)
+    }
+
+    /// Returns the number of hex digits a digest of this algorithm has.
+    pub fn hex_len(&self) -> usize {
+        match self {
+            HashAlgorithm::Md5 => 32,
+            HashAlgorithm::Sha1 => 40,
+            HashAlgorithm::Sha256
+            | HashAlgorithm::Sha3_256
+            | HashAlgorithm::Blake2b256
+            | HashAlgorithm::Blake3 => 64,
+            HashAlgorithm::Sha384 | HashAlgorithm::Sha3_384 | HashAlgorithm::Blake2b384 => 96,
+            HashAlgorithm::Sha512 | HashAlgorithm::Sha3_512 | HashAlgorithm::Blake2b512 => 128,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct Hash {
+    pub alg: HashAlgorithm,
+    pub content: String,
+}
+
+impl Validate for Hash {
+    fn validate(
+        &self,
+        _version: SpecVersion,
+        _options: &ValidationOptions,
+    ) -> Result<(), ValidationErrors> {
         ValidationContext::new()
-            .add_field(
-                "vendor",
-                self.vendor.as_ref().map(|vendor| validate_vendor(&vendor)),
-            )
-            .add_field(
-                "name",
-                self.name.as_ref().map(|name| validate_string(&name)),
-            )
-            .add_enum("kind", Some(validate_toolkind(&self.kind)))
+            .field(field!(self.content), |content| {
+                validate_hash_content(&self.alg, content)
+            })
             .into()
     }
 }
 
-#[derive(Debug)]
-pub struct Metadata {
-    pub timestamp: Option<String>,
-    pub tools: Option<Vec<Tool>>,
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum ComponentType {
+    Application,
+    Framework,
+    Library,
+    Container,
+    OperatingSystem,
+    Device,
+    Firmware,
+    File,
 }
 
-impl Validate for Metadata {
-    fn validate(&self, version: SpecVersion) -> Result<(), ValidationErrors> {
-        let children = self.tools.as_ref().map(|tools| {
-            tools
-                .iter()
-                .map(|tool| tool.validate(version))
-                .collect::<Vec<_>>()
-        });
+impl ComponentType {
+    /// Returns `true` for devices and firmware, which are identified without a purl.
+    pub fn is_hardware(&self) -> bool {
+        matches!(self, ComponentType::Device | ComponentType::Firmware)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum ExternalReferenceType {
+    Vcs,
+    IssueTracker,
+    Website,
+    Advisories,
+    Bom,
+    MailingList,
+    Social,
+    Chat,
+    Documentation,
+    Support,
+    Distribution,
+    License,
+    BuildMeta,
+    BuildSystem,
+    ReleaseNotes,
+    Other,
+}
 
-        let mut builder = ValidationContext::new().add_list("tools", children);
+#[derive(Debug, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct ExternalReference {
+    #[serde(rename = "type")]
+    pub reference_type: ExternalReferenceType,
+    pub url: String,
+    #[validate(each)]
+    pub hashes: Option<Vec<Hash>>,
+}
 
-        match version {
-            SpecVersion::V1_4 => {
-                builder = builder.add_field(
-                    "timestamp",
-                    self.timestamp.as_ref().map(|t| validate_string(t)),
-                );
-            }
-            _ => {
-                builder = builder.add_field(
-                    "timestamp",
-                    self.timestamp.as_ref().map(|t| validate_timestamp(t)),
-                );
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct OrganizationalEntity {
+    pub name: Option<String>,
+    pub contact: Option<Vec<OrganizationalContact>>,
+}
+
+/// A person, e.g. one of the authors of a [`Component`].
+#[derive(Debug, Default, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct OrganizationalContact {
+    pub name: Option<String>,
+    pub email: Option<String>,
+    pub phone: Option<String>,
+}
+
+#[derive(Debug, Default, PartialEq, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct License {
+    pub id: Option<String>,
+    pub name: Option<String>,
+    /// The full license text, if embedded in the document.
+    pub text: Option<AttachedText>,
+    /// Whether the license was `declared` or `concluded`, since spec version 1.6.
+    #[validate(since = "1.6", custom = "validate_license_acknowledgement")]
+    pub acknowledgement: Option<String>,
+}
+
+/// Text embedded in the document, e.g. the full text of a [`License`].
+#[derive(Debug, Default, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct AttachedText {
+    pub content_type: Option<String>,
+    /// The encoding of the content, e.g. `base64`, plain text if not set.
+    pub encoding: Option<String>,
+    pub content: String,
+}
+
+/// An SPDX license expression, e.g. `MIT OR Apache-2.0`.
+#[derive(Debug, Default, PartialEq, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct LicenseExpression {
+    pub expression: String,
+    /// Whether the expression was `declared` or `concluded`, since spec version 1.6.
+    #[validate(since = "1.6", custom = "validate_license_acknowledgement")]
+    pub acknowledgement: Option<String>,
+}
+
+/// Either a single license or an SPDX license expression.
+#[derive(Debug, PartialEq, Deserialize)]
+#[serde(from = "RawLicenseChoice")]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum LicenseChoice {
+    License(License),
+    Expression(LicenseExpression),
+}
+
+/// The license choice as it appears in the document, an expression has its fields next to
+/// the `expression` key, e.g. `{ "expression": "MIT", "acknowledgement": "declared" }`.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawLicenseChoice {
+    License { license: License },
+    Expression(LicenseExpression),
+}
+
+impl From<RawLicenseChoice> for LicenseChoice {
+    fn from(choice: RawLicenseChoice) -> Self {
+        match choice {
+            RawLicenseChoice::License { license } => LicenseChoice::License(license),
+            RawLicenseChoice::Expression(expression) => LicenseChoice::Expression(expression),
+        }
+    }
+}
+
+impl Serialize for LicenseChoice {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        struct Wrapped<'a> {
+            license: &'a License,
+        }
+
+        match self {
+            LicenseChoice::License(license) => Wrapped { license }.serialize(serializer),
+            LicenseChoice::Expression(expression) => expression.serialize(serializer),
+        }
+    }
+}
+
+impl Validate for LicenseChoice {
+    fn validate(&self, version: SpecVersion, options: &ValidationOptions) -> ValidationResult {
+        self.validate_at(version, options, "")
+    }
+
+    fn validate_at(
+        &self,
+        version: SpecVersion,
+        options: &ValidationOptions,
+        path: &str,
+    ) -> ValidationResult {
+        match self {
+            LicenseChoice::License(license) => license.validate_at(version, options, path),
+            LicenseChoice::Expression(expression) => expression.validate_at(version, options, path),
+        }
+    }
+}
+
+impl core::fmt::Display for LicenseChoice {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            LicenseChoice::License(license) => {
+                let label = license.id.as_ref().or(license.name.as_ref());
+                write!(f, "{}", label.map(String::as_str).unwrap_or_default())
             }
+            LicenseChoice::Expression(expression) => write!(f, "{}", expression.expression),
         }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct Property {
+    pub name: String,
+    pub value: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Validate)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct Component {
+    #[serde(rename = "type")]
+    pub component_type: ComponentType,
+    #[serde(rename = "bom-ref")]
+    pub bom_ref: Option<String>,
+    pub group: Option<String>,
+    pub name: String,
+    pub version: Option<String>,
+    pub supplier: Option<OrganizationalEntity>,
+    /// Deprecated in spec version 1.6 in favor of `authors`.
+    #[validate(deprecated)]
+    pub author: Option<String>,
+    #[validate(since = "1.6")]
+    pub authors: Option<Vec<OrganizationalContact>>,
+    pub publisher: Option<String>,
+    #[validate(each)]
+    pub licenses: Option<Vec<LicenseChoice>>,
+    pub cpe: Option<String>,
+    #[validate(custom = "validate_purl")]
+    pub purl: Option<String>,
+    pub swid: Option<Swid>,
+    /// Deprecated in spec version 1.4 in favor of `pedigree`.
+    #[validate(deprecated)]
+    pub modified: Option<bool>,
+    #[validate(each)]
+    pub hashes: Option<Vec<Hash>>,
+    #[validate(each)]
+    pub external_references: Option<Vec<ExternalReference>>,
+    pub properties: Option<Vec<Property>>,
+    #[validate(custom = "validate_copyright")]
+    pub copyright: Option<String>,
+    #[validate(nested)]
+    pub evidence: Option<ComponentEvidence>,
+    #[validate(since = "1.4")]
+    pub release_notes: Option<ReleaseNotes>,
+    /// The components this component is assembled from.
+    #[validate(each)]
+    pub components: Option<Vec<Component>>,
+}
+
+/// The release notes of a [`Component`], e.g. of a major or patch release.
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct ReleaseNotes {
+    #[serde(rename = "type")]
+    pub release_type: String,
+    pub title: Option<String>,
+    /// The url of an image to feature with the release notes.
+    pub featured_image: Option<String>,
+    /// The url of an image to show when the release notes are shared on social media.
+    pub social_image: Option<String>,
+    pub description: Option<String>,
+    pub timestamp: Option<String>,
+    /// Other names of the release, e.g. a code name.
+    pub aliases: Option<Vec<String>>,
+    pub tags: Option<Vec<String>>,
+}
+
+/// The evidence collected for a [`Component`].
+#[derive(Debug, Default, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct ComponentEvidence {
+    /// A single object in spec version 1.5, a list since 1.6.
+    #[serde(default, deserialize_with = "deserialize_one_or_many")]
+    #[validate(each)]
+    pub identity: Option<Vec<Identity>>,
+    #[validate(each)]
+    pub copyright: Option<Vec<Copyright>>,
+}
+
+/// A copyright statement found as evidence, e.g. in a source file header.
+#[derive(Debug, Default, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct Copyright {
+    #[validate(custom = "validate_copyright")]
+    pub text: String,
+}
+
+/// The evidence of the identity of a component, e.g. its `purl`.
+#[derive(Debug, Default, Deserialize, Serialize, Validate)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct Identity {
+    pub field: Option<String>,
+    /// The overall confidence of all methods, from `0` to `1`.
+    #[validate(custom = "validate_confidence")]
+    pub confidence: Option<f64>,
+    pub concluded_value: Option<String>,
+    #[validate(each)]
+    pub methods: Option<Vec<IdentityMethod>>,
+}
+
+/// A technique used to determine the identity of a component.
+#[derive(Debug, Default, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct IdentityMethod {
+    pub technique: String,
+    #[validate(custom = "validate_confidence")]
+    pub confidence: f64,
+    pub value: Option<String>,
+}
+
+/// The ISO/IEC 19770-2 software identification tag of a [`Component`].
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct Swid {
+    pub tag_id: String,
+    pub name: String,
+    pub version: Option<String>,
+}
 
-        builder.into()
+impl Component {
+    /// Returns the identity of the component, see [`ComponentIdentity`](identity::ComponentIdentity).
+    pub fn identity(&self) -> identity::ComponentIdentity {
+        identity::ComponentIdentity::of(self)
+    }
+
+    /// Creates a new [`Component`] with only the required fields set.
+    pub fn new<T: ToString>(component_type: ComponentType, name: T) -> Self {
+        Self {
+            component_type,
+            bom_ref: None,
+            group: None,
+            name: name.to_string(),
+            version: None,
+            supplier: None,
+            author: None,
+            authors: None,
+            publisher: None,
+            licenses: None,
+            cpe: None,
+            purl: None,
+            swid: None,
+            modified: None,
+            hashes: None,
+            external_references: None,
+            properties: None,
+            copyright: None,
+            evidence: None,
+            release_notes: None,
+            components: None,
+        }
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct Dependency {
+    #[serde(rename = "ref")]
+    pub dependency_ref: String,
+    pub depends_on: Option<Vec<String>>,
+}
+
+/// Describes how complete the inventory of a set of assemblies or dependencies is.
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct Composition {
+    /// The completeness, e.g. `complete`, `incomplete` or `unknown`.
+    pub aggregate: String,
+    /// The bom-refs of the components and services the composition applies to.
+    pub assemblies: Option<Vec<String>>,
+    /// The bom-refs of the components and services whose dependencies are described.
+    pub dependencies: Option<Vec<String>>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct Vulnerability {
+    pub id: Option<String>,
+    pub ratings: Option<Vec<Rating>>,
+    pub affects: Option<Vec<Affect>>,
+    pub created: Option<String>,
+    pub published: Option<String>,
+    pub updated: Option<String>,
+    /// The timestamp the vulnerability was rejected at, since spec version 1.5.
+    pub rejected: Option<String>,
+    pub analysis: Option<VulnerabilityAnalysis>,
+}
+
+/// The impact analysis of a [`Vulnerability`].
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct VulnerabilityAnalysis {
+    /// The state of the analysis, e.g. `not_affected` or `false_positive`.
+    pub state: Option<String>,
+    /// The justification if the state is `not_affected`.
+    pub justification: Option<String>,
+    pub detail: Option<String>,
+}
+
+/// A component affected by a vulnerability.
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct Affect {
+    #[serde(rename = "ref")]
+    pub affect_ref: String,
+    pub versions: Option<Vec<AffectedVersion>>,
+}
+
+/// A single version or a range of versions of an affected component.
+///
+/// Exactly one of `version` or `range` is required. The `status` is kept as text, so an
+/// unknown status is reported at its path instead of failing to parse the whole document.
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct AffectedVersion {
+    pub version: Option<String>,
+    pub range: Option<String>,
+    pub status: Option<String>,
+}
+
+/// Describes how a set of components or services was manufactured or deployed.
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct Formula {
+    #[serde(rename = "bom-ref")]
+    pub bom_ref: Option<String>,
+    pub components: Option<Vec<Component>>,
+    pub services: Option<Vec<Service>>,
+    pub workflows: Option<Vec<Workflow>>,
+}
+
+/// A reference to a resource, either by its bom-ref or as external reference.
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct ResourceReference {
+    #[serde(rename = "ref")]
+    pub resource_ref: Option<String>,
+    pub external_reference: Option<ExternalReference>,
+}
+
+/// A workflow of a [`Formula`], e.g. a CI pipeline.
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct Workflow {
+    #[serde(rename = "bom-ref")]
+    pub bom_ref: Option<String>,
+    pub uid: Option<String>,
+    pub name: Option<String>,
+    pub task_types: Option<Vec<String>>,
+    pub trigger: Option<Trigger>,
+    pub tasks: Option<Vec<Task>>,
+    pub steps: Option<Vec<Step>>,
+    pub resource_references: Option<Vec<ResourceReference>>,
+}
+
+/// The event starting a [`Workflow`].
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct Trigger {
+    #[serde(rename = "bom-ref")]
+    pub bom_ref: Option<String>,
+    pub uid: Option<String>,
+    /// One of `manual`, `api`, `webhook` or `scheduled`.
+    #[serde(rename = "type")]
+    pub trigger_type: String,
+    pub resource_references: Option<Vec<ResourceReference>>,
+}
+
+/// A task of a [`Workflow`].
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct Task {
+    #[serde(rename = "bom-ref")]
+    pub bom_ref: Option<String>,
+    pub uid: Option<String>,
+    pub name: Option<String>,
+    pub task_types: Vec<String>,
+    pub steps: Option<Vec<Step>>,
+    pub resource_references: Option<Vec<ResourceReference>>,
+}
+
+/// A step of a [`Workflow`] or [`Task`].
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct Step {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub resource_references: Option<Vec<ResourceReference>>,
+}
+
+/// A service, e.g. a web API, the described software depends on.
+#[derive(Debug, Default, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct Service {
+    #[serde(rename = "bom-ref")]
+    pub bom_ref: Option<String>,
+    pub name: String,
+    pub endpoints: Option<Vec<String>>,
+    /// Whether the service requires authentication.
+    pub authenticated: Option<bool>,
+    /// Whether calling the service crosses a trust zone or boundary.
+    #[serde(rename = "x-trust-boundary")]
+    pub trust_boundary: Option<bool>,
+    #[validate(each)]
+    pub data: Option<Vec<DataFlow>>,
+}
+
+/// The classification of data exchanged with a [`Service`] and the direction it flows in.
+#[derive(Debug, Default, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct DataFlow {
+    #[validate(custom = "validate_data_flow")]
+    pub flow: String,
+    pub classification: Option<String>,
+    #[validate(since = "1.5")]
+    pub governance: Option<DataGovernance>,
+    #[validate(since = "1.5", custom = "validate_bom_links")]
+    pub source: Option<Vec<String>>,
+    #[validate(since = "1.5", custom = "validate_bom_links")]
+    pub destination: Option<Vec<String>>,
+}
+
+/// The parties responsible for the data of a [`DataFlow`].
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct DataGovernance {
+    pub custodians: Option<Vec<DataGovernanceParty>>,
+    pub stewards: Option<Vec<DataGovernanceParty>>,
+    pub owners: Option<Vec<DataGovernanceParty>>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct DataGovernanceParty {
+    pub organization: Option<OrganizationalEntity>,
+}
+
+/// The source of a vulnerability or rating, e.g. NVD.
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct VulnerabilitySource {
+    pub name: Option<String>,
+    pub url: Option<String>,
+}
+
+/// The severity of a vulnerability [`Rating`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum RatingSeverity {
+    Critical,
+    High,
+    Medium,
+    Low,
+    Info,
+    None,
+    Unknown,
+}
+
+/// The method the score of a vulnerability [`Rating`] was calculated with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum ScoreMethod {
+    #[serde(rename = "CVSSv2")]
+    CvssV2,
+    #[serde(rename = "CVSSv3")]
+    CvssV3,
+    #[serde(rename = "CVSSv31")]
+    CvssV31,
+    #[serde(rename = "CVSSv4")]
+    CvssV4,
+    #[serde(rename = "OWASP")]
+    Owasp,
+    #[serde(rename = "SSVC")]
+    Ssvc,
+    #[serde(rename = "other")]
+    Other,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct Rating {
+    pub source: Option<VulnerabilitySource>,
+    pub score: Option<f64>,
+    pub severity: Option<RatingSeverity>,
+    pub method: Option<ScoreMethod>,
+    pub vector: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize, Validate)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Bom {
+    #[validate(custom = "validate_string")]
     pub serial_number: Option<String>,
+    pub version: Option<u32>,
+    #[serde(rename = "metadata")]
+    #[validate(nested)]
     pub meta_data: Option<Metadata>,
+    #[validate(each)]
+    pub components: Option<Vec<Component>>,
+    #[validate(each)]
+    pub services: Option<Vec<Service>>,
+    pub dependencies: Option<Vec<Dependency>>,
+    pub compositions: Option<Vec<Composition>>,
+    #[validate(since = "1.4")]
+    pub vulnerabilities: Option<Vec<Vulnerability>>,
+    #[validate(since = "1.5")]
+    pub formulation: Option<Vec<Formula>>,
+    pub properties: Option<Vec<Property>>,
 }
 
-/// The implementation should be easy to digest
-impl Validate for Bom {
-    fn validate(&self, version: validation::SpecVersion) -> Result<(), ValidationErrors> {
-        ValidationContext::new()
-            .add_field("serial_number", self.serial_number.as_ref().map(|sn| validate_string(sn)))
-            .add_struct("meta_data", self.meta_data.as_ref().map(|metadata| metadata.validate(version)))
-            .into()
+/// What a document describes, which selects the rules that apply to it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DocumentKind {
+    /// A software bill of materials listing components or services.
+    #[default]
+    Sbom,
+    /// A hardware bill of materials, only listing devices and their firmware, which are not
+    /// expected to have purls.
+    Hbom,
+    /// A bill of materials of a SaaS offering, listing services but no components.
+    Saasbom,
+    /// A standalone VEX document, only listing vulnerabilities of products described elsewhere.
+    Vex,
+}
+
+impl DocumentKind {
+    /// Returns `true` if documents of this kind are about their components, the rules
+    /// checking components only apply to these.
+    pub fn describes_components(self) -> bool {
+        matches!(self, DocumentKind::Sbom | DocumentKind::Hbom)
+    }
+}
+
+impl Bom {
+    /// Detects the [`DocumentKind`] by the sections of the document.
+    ///
+    /// * components that are all devices or firmware make a hardware BOM
+    /// * services without components make a SaaSBOM
+    /// * vulnerabilities without components and services make a standalone VEX document
+    pub fn kind(&self) -> DocumentKind {
+        let components = self.components.as_deref().unwrap_or_default();
+        let has_services = self
+            .services
+            .as_ref()
+            .is_some_and(|services| !services.is_empty());
+        let has_vulnerabilities = self
+            .vulnerabilities
+            .as_ref()
+            .is_some_and(|vulnerabilities| !vulnerabilities.is_empty());
+
+        if !components.is_empty() {
+            let is_hardware = components
+                .iter()
+                .all(|component| component.component_type.is_hardware());
+            match is_hardware {
+                true => DocumentKind::Hbom,
+                false => DocumentKind::Sbom,
+            }
+        } else if has_services {
+            DocumentKind::Saasbom
+        } else if has_vulnerabilities {
+            DocumentKind::Vex
+        } else {
+            DocumentKind::Sbom
+        }
+    }
+
+    /// Parses a BOM from its JSON representation.
+    pub fn parse_json(input: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(input)
+    }
+
+    /// Returns the JSON representation of the modeled fields, absent fields are omitted.
+    ///
+    /// Fields unknown to the model are not part of it, use [`patch::Document`] to keep the
+    /// document as is.
+    pub fn to_value(&self) -> serde_json::Value {
+        fn strip_nulls(value: &mut serde_json::Value) {
+            match value {
+                serde_json::Value::Object(map) => {
+                    map.retain(|_, value| !value.is_null());
+                    map.values_mut().for_each(strip_nulls);
+                }
+                serde_json::Value::Array(values) => values.iter_mut().for_each(strip_nulls),
+                _ => {}
+            }
+        }
+
+        let mut value = serde_json::to_value(self).unwrap_or_default();
+        strip_nulls(&mut value);
+        value
+    }
+
+    /// Computes the hex encoded digest of the [`canonical`] serialization of
+    /// [`Bom::to_value`], e.g. to compare it with an attestation or to use it as cache key.
+    ///
+    /// Returns `None` when the algorithm is not supported.
+    #[cfg(feature = "std")]
+    pub fn digest(&self, algorithm: &HashAlgorithm) -> Option<String> {
+        let canonical = canonical::canonicalize(&self.to_value());
+        integrity::compute_digest(algorithm, canonical.as_bytes())
     }
 }
 
 /// Validates the bom according to a given [`SpecVersion`].
-pub fn validate_bom(version: SpecVersion, bom: Bom) -> Result<(), ValidationErrors> {
-    bom.validate(version)
+///
+/// Runs the built-in validation followed by the built-in rules and all custom rules
+/// registered in the [`ValidationOptions`].
+pub fn validate_bom(
+    version: SpecVersion,
+    bom: &Bom,
+    options: &ValidationOptions,
+) -> Result<(), ValidationErrors> {
+    #[cfg(feature = "tracing")]
+    let _span = tracing::info_span!("validate_bom", version = %version).entered();
+    #[cfg(feature = "metrics")]
+    let start = std::time::Instant::now();
+
+    let result = run_checks(version, bom, options)
+        .into_iter()
+        .filter_map(|(_, result)| result)
+        .fold(Ok(()), ValidationErrors::merge_errors);
+
+    #[cfg(feature = "metrics")]
+    monitoring::record_validation(&result, start.elapsed());
+
+    result
+}
+
+/// The maximum size of a document accepted by [`validate_bytes`] and `xml::validate_xml`.
+pub const MAX_INPUT_LEN: usize = 16 * 1024 * 1024;
+
+/// Parses and validates an untrusted JSON document.
+///
+/// Designed as entry point for fuzzing: it never panics and bounds memory by rejecting
+/// documents larger than [`MAX_INPUT_LEN`], nesting is bounded by the JSON parser.
+/// Documents that cannot be parsed are reported as error at [`error::DOCUMENT_KEY`].
+pub fn validate_bytes(
+    version: SpecVersion,
+    input: &[u8],
+    options: &ValidationOptions,
+) -> ValidationResult {
+    #[cfg(feature = "metrics")]
+    monitoring::record_document_size(input.len());
+
+    if input.len() > MAX_INPUT_LEN {
+        let error = ValidationError::new("input_too_large")
+            .with_param("max", MAX_INPUT_LEN)
+            .with_param("actual", input.len())
+            .with_code("input-too-large");
+        return ValidationContext::new()
+            .add_field(error::DOCUMENT_KEY, Some(Err(error)))
+            .into();
+    }
+
+    match serde_json::from_slice::<Bom>(input) {
+        Ok(bom) => validate_bom(version, &bom, options),
+        Err(error) => Err(error::Error::from(error).into()),
+    }
+}
+
+/// Sets the help uri of a rule on all findings of the rule without one.
+fn with_help_uri(result: ValidationResult, help_uri: Option<&str>) -> ValidationResult {
+    let Some(help_uri) = help_uri else {
+        return result;
+    };
+    result.map_err(|mut errors| {
+        errors.for_each_error_mut(|_, error| {
+            error.help_uri.get_or_insert_with(|| help_uri.into());
+        });
+        errors
+    })
+}
+
+/// Runs the built-in validation, identified as `model`, followed by all rules.
+///
+/// Returns the id of every check with its result, or `None` if the check was skipped.
+pub(crate) fn run_checks(
+    version: SpecVersion,
+    bom: &Bom,
+    options: &ValidationOptions,
+) -> Vec<(String, Option<ValidationResult>)> {
+    let model = {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("check", id = "model").entered();
+        bom.validate(version, options)
+    };
+    let model = apply_options(
+        model,
+        version,
+        options,
+        &document_suppressions(bom, options),
+    );
+    #[cfg(feature = "tracing")]
+    if let Err(errors) = &model {
+        trace_findings("model", errors);
+    }
+
+    core::iter::once((String::from("model"), Some(model)))
+        .chain(run_rules(version, bom, options))
+        .collect()
+}
+
+/// Runs all built-in and custom rules on the document, without the built-in validation.
+///
+/// Returns the id of every rule with its result, or `None` if the rule was skipped.
+pub(crate) fn run_rules(
+    version: SpecVersion,
+    bom: &Bom,
+    options: &ValidationOptions,
+) -> Vec<(String, Option<ValidationResult>)> {
+    let context = RuleContext::new(version)
+        .with_profile(options.selected_profile())
+        .with_timestamp_tolerance(options.selected_timestamp_tolerance())
+        .with_allowed_hash_algorithms(options.allowed_hash_algorithms())
+        .with_kind(
+            options
+                .selected_document_kind()
+                .unwrap_or_else(|| bom.kind()),
+        )
+        .with_graph(BomGraph::new(bom));
+    let suppressions = document_suppressions(bom, options);
+
+    rules::builtin()
+        .iter()
+        .chain(options.rules())
+        .map(|rule| {
+            #[cfg(feature = "tracing")]
+            let _span = tracing::debug_span!("check", id = rule.id()).entered();
+            let result = (!options.is_rule_disabled(rule.id()) && rule.applies(bom, &context))
+                .then(|| with_help_uri(rule.check(bom, &context), rule.help_uri()))
+                .map(|result| apply_options(result, version, options, &suppressions));
+            #[cfg(feature = "tracing")]
+            if let Some(Err(errors)) = &result {
+                trace_findings(rule.id(), errors);
+            }
+            (rule.id().to_string(), result)
+        })
+        .collect()
+}
+
+/// Returns the suppressions declared in the document followed by those of the options.
+pub(crate) fn document_suppressions(
+    bom: &Bom,
+    options: &ValidationOptions,
+) -> Vec<suppress::Suppression> {
+    let mut suppressions = suppress::collect(bom);
+    suppressions.extend_from_slice(options.suppressions());
+    suppressions
+}
+
+/// Emits an event for every finding of a check.
+#[cfg(feature = "tracing")]
+fn trace_findings(check: &str, errors: &ValidationErrors) {
+    for (path, error) in errors.errors() {
+        tracing::debug!(
+            check,
+            path = %path,
+            code = error.code.as_deref(),
+            severity = ?error.severity,
+            suppressed = error.suppressed,
+            "{}",
+            error
+        );
+    }
+}
+
+/// Applies the severity overrides and suppressions to the result of a check, and records
+/// the spec version on every finding.
+pub(crate) fn apply_options(
+    result: ValidationResult,
+    version: SpecVersion,
+    options: &ValidationOptions,
+    suppressions: &[suppress::Suppression],
+) -> ValidationResult {
+    let result = match result {
+        Err(mut errors) if options.selects_paths() => {
+            errors.retain(|path, _| options.is_path_selected(path));
+            match errors.is_empty() {
+                true => Ok(()),
+                false => Err(errors),
+            }
+        }
+        result => result,
+    };
+
+    result.map_err(|mut errors| {
+        errors.for_each_error_mut(|_, error| {
+            error.version.get_or_insert(version);
+            if let Some(severity) = error
+                .code
+                .as_deref()
+                .and_then(|code| options.severity_override(code))
+            {
+                error.severity = severity;
+            }
+        });
+
+        suppress::apply(&mut errors, suppressions);
+        errors
+    })
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{validate_bom, validation::SpecVersion, Bom, Metadata, Tool, ToolKind};
+    use crate::{
+        options::{Profile, TimestampTolerance, ValidationOptions},
+        paths::{
+            index, COMPONENTS_AUTHOR, COMPONENTS_PUBLISHER, COMPONENTS_SUPPLIER, VULNERABILITIES,
+        },
+        rules::{Rule, RuleContext},
+        suppress::{Suppression, SUPPRESS_PROPERTY},
+        testing::errors_at,
+        validate_bom, validate_bytes,
+        validation::{
+            validate_dyn, Severity, SpecVersion, Validate, ValidationContext, ValidationError,
+            ValidationErrors, ValidationResult,
+        },
+        Bom, Component, ComponentType, Hash, HashAlgorithm, Metadata, Property, Tool, ToolKind,
+        Vulnerability, MAX_COPYRIGHT_LEN, MAX_INPUT_LEN,
+    };
+
+    /// Custom rule that requires a serial number to be present.
+    struct RequireSerialNumber;
+
+    impl Rule for RequireSerialNumber {
+        fn check(&self, bom: &Bom, _ctx: &RuleContext) -> ValidationResult {
+            let error = bom
+                .serial_number
+                .is_none()
+                .then(|| Err(ValidationError::new("serial number is required")));
+
+            ValidationContext::new()
+                .add_field("serial_number", error)
+                .into()
+        }
+    }
 
     #[test]
     fn validate_succeeds() {
         let bom = Bom {
             serial_number: Some("1234".to_string()),
             meta_data: Some(Metadata {
-                timestamp: Some(String::from("2024-01-02")),
+                timestamp: Some(String::from("2024-01-02T00:00:00Z")),
                 tools: Some(vec![Tool {
                     vendor: Some(String::from("Vendor")),
                     name: Some(String::from("dig")),
                     kind: ToolKind::ScrewDriver,
                 }]),
+                tool_components: None,
+                component: None,
+                authors: None,
             }),
+            ..Default::default()
         };
 
-        assert!(dbg!(validate_bom(SpecVersion::V1_3, bom)).is_ok());
+        assert!(dbg!(validate_bom(
+            SpecVersion::V1_3,
+            &bom,
+            &ValidationOptions::new()
+        ))
+        .is_ok());
     }
 
     #[test]
@@ -154,9 +1430,510 @@ mod tests {
                         kind: ToolKind::Hammer,
                     },
                 ]),
+                tool_components: None,
+                component: None,
+                authors: None,
             }),
+            ..Default::default()
+        };
+
+        assert!(dbg!(validate_bom(
+            SpecVersion::V1_4,
+            &bom,
+            &ValidationOptions::new()
+        ))
+        .is_err());
+    }
+
+    #[test]
+    fn validate_runs_custom_rules() {
+        let bom = Bom {
+            serial_number: None,
+            ..Default::default()
+        };
+        let options = ValidationOptions::new().add_rule(RequireSerialNumber);
+
+        let result = validate_bom(SpecVersion::V1_5, &bom, &options);
+        assert!(ValidationErrors::has_error(&result, "serial_number"));
+    }
+
+    #[test]
+    fn validate_merges_custom_rule_errors() {
+        let bom = Bom {
+            serial_number: None,
+            meta_data: Some(Metadata {
+                timestamp: Some(String::from("2024-01-02")),
+                tools: None,
+                tool_components: None,
+                component: None,
+                authors: None,
+            }),
+            ..Default::default()
+        };
+        let options = ValidationOptions::new().add_rule(RequireSerialNumber);
+
+        let result = validate_bom(SpecVersion::V1_4, &bom, &options);
+        assert!(ValidationErrors::has_error(&result, "serial_number"));
+        assert!(ValidationErrors::has_error(&result, "meta_data"));
+    }
+
+    #[test]
+    fn validate_metadata_timestamp_with_tolerance() {
+        let bom = |timestamp: &str| Bom {
+            meta_data: Some(Metadata {
+                timestamp: Some(String::from(timestamp)),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let strict = ValidationOptions::new();
+        let lenient = ValidationOptions::new().timestamp_tolerance(TimestampTolerance::Lenient);
+
+        let result = validate_bom(SpecVersion::V1_5, &bom("2024-01-02"), &strict);
+        let errors = errors_at(&result, "metadata.timestamp");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code.as_deref(), Some("invalid-timestamp"));
+        let result = validate_bom(SpecVersion::V1_5, &bom("2024-01-02 10:00:00"), &strict);
+        assert_eq!(errors_at(&result, "metadata.timestamp").len(), 1);
+        let result = validate_bom(SpecVersion::V1_5, &bom("2024-01-02 10:00:00"), &lenient);
+        assert!(errors_at(&result, "metadata.timestamp").is_empty());
+        let result = validate_bom(SpecVersion::V1_5, &bom("2024-01-02T10:00:00Z"), &strict);
+        assert!(errors_at(&result, "metadata.timestamp").is_empty());
+    }
+
+    #[test]
+    fn validate_fails_with_invalid_hash() {
+        let bom = Bom {
+            components: Some(vec![Component {
+                hashes: Some(vec![Hash {
+                    alg: HashAlgorithm::Sha1,
+                    content: String::from("abcd"),
+                }]),
+                ..Component::new(ComponentType::Library, "lib")
+            }]),
+            ..Default::default()
+        };
+
+        let result = validate_bom(SpecVersion::V1_5, &bom, &ValidationOptions::new());
+        assert!(ValidationErrors::has_error(&result, "components"));
+    }
+
+    #[test]
+    fn validate_reports_suppressed_findings() {
+        let bom = Bom {
+            components: Some(vec![
+                Component {
+                    properties: Some(vec![Property {
+                        name: String::from(SUPPRESS_PROPERTY),
+                        value: Some(String::from("missing-supplier")),
+                    }]),
+                    ..Component::new(ComponentType::Library, "serde")
+                },
+                Component::new(ComponentType::Library, "zlib"),
+            ]),
+            ..Default::default()
+        };
+        let options = ValidationOptions::new()
+            .profile(Profile::Strict)
+            .suppress(Suppression::parse("missing-author at components[1]"));
+
+        let errors =
+            validate_bom(SpecVersion::V1_5, &bom, &options).expect_err("Expected findings");
+        let suppressed = errors
+            .errors()
+            .into_iter()
+            .filter(|(_, error)| error.suppressed)
+            .map(|(path, _)| path)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            suppressed,
+            vec!["components[0].supplier", "components[1].author"]
+        );
+        assert!(errors.has_unsuppressed());
+    }
+
+    #[test]
+    fn validate_applies_severity_overrides() {
+        let bom = Bom {
+            components: Some(vec![Component {
+                purl: Some(String::from("pkg:cargo/serde@1.0.196")),
+                ..Component::new(ComponentType::Library, "serde")
+            }]),
+            ..Default::default()
+        };
+        let options = ValidationOptions::new()
+            .profile(Profile::Strict)
+            .severity("missing-supplier", Severity::Error);
+
+        let errors =
+            validate_bom(SpecVersion::V1_5, &bom, &options).expect_err("Expected findings");
+        let severities = errors
+            .errors()
+            .into_iter()
+            .map(|(path, error)| (path, error.severity))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            severities,
+            vec![
+                (index(COMPONENTS_SUPPLIER, &[0]), Severity::Error),
+                (index(COMPONENTS_AUTHOR, &[0]), Severity::Warning),
+                (index(COMPONENTS_PUBLISHER, &[0]), Severity::Warning),
+            ]
+        );
+    }
+
+    #[test]
+    fn validate_fails_with_field_before_its_spec_version() {
+        let bom = Bom {
+            vulnerabilities: Some(vec![Vulnerability::default()]),
+            ..Default::default()
+        };
+
+        let result = validate_bom(SpecVersion::V1_3, &bom, &ValidationOptions::new());
+        assert!(ValidationErrors::has_error(&result, VULNERABILITIES));
+    }
+
+    #[test]
+    fn validate_warns_about_deprecated_field() {
+        #[derive(Validate)]
+        struct Legacy {
+            #[validate(until = "1.3")]
+            modified: Option<bool>,
+        }
+        let legacy = Legacy {
+            modified: Some(true),
+        };
+
+        let options = ValidationOptions::new();
+        assert!(legacy.validate(SpecVersion::V1_3, &options).is_ok());
+        let errors = legacy
+            .validate(SpecVersion::V1_5, &options)
+            .expect_err("Expected deprecation warning");
+        assert_eq!(errors.errors()[0].1.severity, Severity::Warning);
+    }
+
+    #[test]
+    fn validate_migrates_component_author_to_authors() {
+        let bom = Bom::parse_json(
+            r#"{ "components": [
+                { "type": "library", "name": "a", "author": "Jane Doe" },
+                { "type": "library", "name": "b", "authors": [{ "name": "Jane Doe" }] }
+            ] }"#,
+        )
+        .expect("Failed to parse BOM");
+        let options = ValidationOptions::new();
+
+        let result = validate_bom(SpecVersion::V1_5, &bom, &options);
+        assert!(errors_at(&result, "components[0].author").is_empty());
+        assert_eq!(
+            errors_at(&result, "components[1].authors")[0]
+                .code
+                .as_deref(),
+            Some("field-not-supported")
+        );
+
+        let result = validate_bom(SpecVersion::V1_6, &bom, &options);
+        let deprecated = errors_at(&result, "components[0].author");
+        assert_eq!(deprecated[0].code.as_deref(), Some("field-deprecated"));
+        assert_eq!(deprecated[0].severity, Severity::Warning);
+        assert!(errors_at(&result, "components[1].authors").is_empty());
+    }
+
+    #[test]
+    fn unsupported_features_are_distinct_from_format_errors() {
+        let bom = Bom::parse_json(
+            r#"{ "metadata": { "timestamp": "yesterday" },
+                "components": [{ "type": "library", "name": "a", "authors": [{ "name": "Jane" }] }],
+                "vulnerabilities": [] }"#,
+        )
+        .expect("Failed to parse BOM");
+
+        let result = validate_bom(SpecVersion::V1_3, &bom, &ValidationOptions::new());
+        let unsupported = result
+            .as_ref()
+            .expect_err("Expected errors")
+            .unsupported_features()
+            .into_iter()
+            .map(|(path, error)| (path, error.version_support.and_then(|s| s.since)))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            unsupported,
+            vec![
+                (
+                    String::from("components[0].authors"),
+                    Some(SpecVersion::V1_6)
+                ),
+                (String::from("vulnerabilities"), Some(SpecVersion::V1_4)),
+            ]
+        );
+        let timestamp = errors_at(&result, "meta_data.timestamp");
+        assert!(!timestamp.is_empty());
+        assert!(timestamp
+            .iter()
+            .all(|error| !error.is_unsupported_feature()));
+    }
+
+    #[test]
+    fn validate_checks_copyright_hygiene() {
+        let long = "Copyright Acme Inc. ".repeat(MAX_COPYRIGHT_LEN / 10);
+        let bom = Bom::parse_json(&format!(
+            r#"{{ "components": [
+                {{ "type": "library", "name": "a", "copyright": "Copyright 2024 Acme\u0000" }},
+                {{ "type": "library", "name": "b", "copyright": "  " }},
+                {{ "type": "library", "name": "c", "evidence": {{ "copyright": [
+                    {{ "text": "Copyright 2024 Acme Inc." }}, {{ "text": "{}" }}
+                ] }} }}
+            ] }}"#,
+            long
+        ))
+        .expect("Failed to parse BOM");
+
+        let result = validate_bom(SpecVersion::V1_5, &bom, &ValidationOptions::new());
+        let errors = errors_at(&result, "components[0].copyright");
+        assert_eq!(errors[0].code.as_deref(), Some("control-character"));
+        assert_eq!(errors[0].severity, Severity::Error);
+        let errors = errors_at(&result, "components[1].copyright");
+        assert_eq!(errors[0].code.as_deref(), Some("blank-copyright"));
+        assert!(errors_at(&result, "components[2].evidence.copyright[0].text").is_empty());
+        let errors = errors_at(&result, "components[2].evidence.copyright[1].text");
+        assert_eq!(errors[0].code.as_deref(), Some("copyright-too-long"));
+    }
+
+    #[test]
+    fn validate_checks_license_acknowledgement() {
+        let bom = Bom::parse_json(
+            r#"{ "components": [{ "type": "library", "name": "a", "licenses": [
+                { "license": { "id": "MIT", "acknowledgement": "declared" } },
+                { "license": { "id": "MIT", "acknowledgement": "guessed" } }
+            ] }, { "type": "library", "name": "b", "licenses": [
+                { "expression": "MIT OR Apache-2.0", "acknowledgement": "concluded" }
+            ] }] }"#,
+        )
+        .expect("Failed to parse BOM");
+        let options = ValidationOptions::new();
+        let codes = |result: &ValidationResult, path: &str| {
+            errors_at(result, path)
+                .into_iter()
+                .filter_map(|error| error.code.clone())
+                .collect::<Vec<_>>()
+        };
+
+        let result = validate_bom(SpecVersion::V1_6, &bom, &options);
+        assert!(codes(&result, "components[0].licenses[0].acknowledgement").is_empty());
+        assert_eq!(
+            codes(&result, "components[0].licenses[1].acknowledgement"),
+            vec!["invalid-license-acknowledgement"]
+        );
+        assert!(codes(&result, "components[1].licenses[0].acknowledgement").is_empty());
+
+        let result = validate_bom(SpecVersion::V1_5, &bom, &options);
+        assert_eq!(
+            codes(&result, "components[1].licenses[0].acknowledgement"),
+            vec!["field-not-supported"]
+        );
+    }
+
+    #[test]
+    fn validate_checks_service_data_flows() {
+        let bom = Bom::parse_json(
+            r#"{ "services": [{ "name": "api", "data": [
+                { "flow": "inbound", "classification": "PII" },
+                { "flow": "sideways", "classification": "PII" },
+                { "flow": "outbound", "classification": "PII", "source": [
+                    "https://example.com/data",
+                    "urn:cdx:3e671687-395b-41f5-a30f-a58921a69b79/1#api"
+                ], "destination": ["urn:cdx:not-a-serial/1"] }
+            ] }] }"#,
+        )
+        .expect("Failed to parse BOM");
+        let options = ValidationOptions::new();
+
+        let result = validate_bom(SpecVersion::V1_5, &bom, &options);
+        assert!(errors_at(&result, "services[0].data[0].flow").is_empty());
+        assert_eq!(errors_at(&result, "services[0].data[1].flow").len(), 1);
+        assert!(errors_at(&result, "services[0].data[2].source").is_empty());
+        assert_eq!(
+            errors_at(&result, "services[0].data[2].destination")[0]
+                .code
+                .as_deref(),
+            Some("invalid-bom-link")
+        );
+
+        let result = validate_bom(SpecVersion::V1_4, &bom, &options);
+        let codes = errors_at(&result, "services[0].data[2].source")
+            .into_iter()
+            .filter_map(|error| error.code.as_deref())
+            .collect::<Vec<_>>();
+        assert_eq!(codes, vec!["field-not-supported"]);
+    }
+
+    #[test]
+    fn validate_elements_through_trait_objects() {
+        let hash = Hash {
+            alg: HashAlgorithm::Md5,
+            content: String::from("xyz"),
         };
+        let component = Component::new(ComponentType::Library, "lib");
+        let elements: Vec<&dyn Validate> = vec![&hash, &component];
+
+        let options = ValidationOptions::new();
+        let results = elements
+            .into_iter()
+            .map(|element| validate_dyn(element, SpecVersion::V1_5, &options))
+            .collect::<Vec<_>>();
+
+        assert!(ValidationErrors::has_error(&results[0], "content"));
+        assert!(results[1].is_ok());
+    }
+
+    #[test]
+    fn validate_selected_paths_only() {
+        let bom = Bom::parse_json(
+            r#"{ "serialNumber": "too long", "metadata": { "component": {
+                "type": "application", "name": "app", "purl": "pkg:cargo/other@1.0.0"
+            } }, "components": [
+                { "type": "library", "name": "a", "purl": "a", "hashes": [{ "alg": "MD5", "content": "xyz" }] },
+                { "type": "library", "name": "b", "purl": "b" }
+            ] }"#,
+        )
+        .expect("Failed to parse BOM");
+        let paths =
+            |options: &ValidationOptions| match validate_bom(SpecVersion::V1_5, &bom, options) {
+                Ok(()) => Vec::new(),
+                Err(errors) => errors
+                    .errors()
+                    .into_iter()
+                    .map(|(path, _)| path)
+                    .collect::<Vec<_>>(),
+            };
+
+        let options = ValidationOptions::new().include_paths(["components[*].purl"]);
+        assert_eq!(
+            paths(&options),
+            vec!["components[0].purl", "components[1].purl"]
+        );
+
+        let options = ValidationOptions::new()
+            .include_paths(["components"])
+            .exclude_paths(["components[0]"]);
+        assert_eq!(paths(&options), vec!["components[1].purl"]);
+
+        let options = ValidationOptions::new().exclude_paths(["components", "serial_number"]);
+        let remaining = paths(&options);
+        assert!(!remaining.is_empty());
+        assert!(remaining.iter().all(|path| path.starts_with("metadata.")));
+    }
+
+    #[test]
+    fn findings_record_spec_version() {
+        let bom = Bom {
+            serial_number: Some(String::from("too long")),
+            ..Default::default()
+        };
+        let errors = validate_bom(SpecVersion::V1_4, &bom, &ValidationOptions::new())
+            .expect_err("Expected invalid serial number");
+
+        assert!(errors
+            .errors()
+            .iter()
+            .all(|(_, error)| error.version == Some(SpecVersion::V1_4)));
+        let json = serde_json::to_value(&errors).expect("Failed to serialize errors");
+        assert_eq!(json["serial_number"]["field"][0]["version"], "1.4");
+    }
+
+    #[test]
+    fn validate_bytes_reports_invalid_input() {
+        let options = ValidationOptions::new();
+
+        let result = validate_bytes(SpecVersion::V1_5, b"{\"components\": [", &options);
+        assert!(ValidationErrors::has_error(&result, "document"));
+
+        let result = validate_bytes(SpecVersion::V1_5, &vec![b' '; MAX_INPUT_LEN + 1], &options);
+        assert!(ValidationErrors::has_error(&result, "document"));
+
+        let result = validate_bytes(SpecVersion::V1_5, b"{\"serialNumber\": \"1\"}", &options);
+        assert!(result.is_ok());
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn tracing_emits_spans_per_element_and_events_per_finding() {
+        use std::sync::{Arc, Mutex};
+
+        use tracing::{
+            field::{Field, Visit},
+            span, Event, Metadata, Subscriber,
+        };
+
+        /// Records the `element` of all spans and the number of events.
+        #[derive(Default)]
+        struct Recorder {
+            elements: Mutex<Vec<String>>,
+            events: Mutex<usize>,
+        }
+
+        impl Visit for &Recorder {
+            fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+                if field.name() == "element" {
+                    self.elements.lock().unwrap().push(format!("{:?}", value));
+                }
+            }
+
+            fn record_str(&mut self, field: &Field, value: &str) {
+                if field.name() == "element" {
+                    self.elements.lock().unwrap().push(value.to_string());
+                }
+            }
+        }
+
+        struct RecordingSubscriber(Arc<Recorder>);
+
+        impl Subscriber for RecordingSubscriber {
+            fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+                true
+            }
+
+            fn new_span(&self, span: &span::Attributes<'_>) -> span::Id {
+                span.record(&mut &*self.0);
+                span::Id::from_u64(1)
+            }
+
+            fn record(&self, _span: &span::Id, _values: &span::Record<'_>) {}
+
+            fn record_follows_from(&self, _span: &span::Id, _follows: &span::Id) {}
+
+            fn event(&self, _event: &Event<'_>) {
+                *self.0.events.lock().unwrap() += 1;
+            }
+
+            fn enter(&self, _span: &span::Id) {}
+
+            fn exit(&self, _span: &span::Id) {}
+        }
+
+        let bom = Bom {
+            components: Some(vec![
+                Component::new(ComponentType::Library, "a"),
+                Component {
+                    purl: Some(String::from("a")),
+                    ..Component::new(ComponentType::Library, "b")
+                },
+            ]),
+            ..Default::default()
+        };
+
+        let recorder = Arc::new(Recorder::default());
+        let subscriber = RecordingSubscriber(recorder.clone());
+        let result = tracing::subscriber::with_default(subscriber, || {
+            validate_bom(SpecVersion::V1_5, &bom, &ValidationOptions::new())
+        });
 
-        assert!(dbg!(validate_bom(SpecVersion::V1_4, bom)).is_err());
+        assert!(result.is_err());
+        let elements = recorder.elements.lock().unwrap();
+        assert!(elements.contains(&String::from("components[0]")));
+        assert!(elements.contains(&String::from("components[1]")));
+        assert_eq!(*recorder.events.lock().unwrap(), 1);
     }
 }
@@ -0,0 +1,306 @@
+//! Validation of SPDX license ids and expressions against a list of known licenses.
+//!
+//! No license list is compiled in, as it goes stale quickly. The list is loaded at runtime from
+//! the JSON files of an [SPDX license list release](https://github.com/spdx/license-list-data),
+//! see [`KnownLicenses::load`].
+use alloc::collections::BTreeSet;
+#[cfg(feature = "std")]
+use std::{fmt, fs, io, path::Path};
+
+use serde::Deserialize;
+
+use crate::{
+    prelude::*,
+    rules::{Rule, RuleContext},
+    validation::{ValidationContext, ValidationError, ValidationResult},
+    Bom, Component, LicenseChoice,
+};
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LicenseList {
+    licenses: Vec<LicenseEntry>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LicenseEntry {
+    license_id: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ExceptionList {
+    exceptions: Vec<ExceptionEntry>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ExceptionEntry {
+    license_exception_id: String,
+}
+
+/// Error returned when the license list cannot be loaded.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub enum LicensesError {
+    Io(io::Error),
+    Parse(serde_json::Error),
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for LicensesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LicensesError::Io(error) => write!(f, "Failed to read license list: {}", error),
+            LicensesError::Parse(error) => write!(f, "Failed to parse license list: {}", error),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LicensesError {}
+
+/// The known SPDX license and exception ids, compared case-insensitively as in the SPDX spec.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct KnownLicenses {
+    licenses: BTreeSet<String>,
+    exceptions: BTreeSet<String>,
+}
+
+impl KnownLicenses {
+    pub fn new<L, E, S>(licenses: L, exceptions: E) -> Self
+    where
+        L: IntoIterator<Item = S>,
+        E: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        Self {
+            licenses: licenses
+                .into_iter()
+                .map(|id| id.as_ref().to_ascii_lowercase())
+                .collect(),
+            exceptions: exceptions
+                .into_iter()
+                .map(|id| id.as_ref().to_ascii_lowercase())
+                .collect(),
+        }
+    }
+
+    /// Parses the contents of the `licenses.json` and `exceptions.json` files of a release.
+    pub fn from_spdx_json(licenses: &str, exceptions: &str) -> Result<Self, serde_json::Error> {
+        let licenses: LicenseList = serde_json::from_str(licenses)?;
+        let exceptions: ExceptionList = serde_json::from_str(exceptions)?;
+
+        Ok(Self::new(
+            licenses.licenses.iter().map(|entry| &entry.license_id),
+            exceptions
+                .exceptions
+                .iter()
+                .map(|entry| &entry.license_exception_id),
+        ))
+    }
+
+    /// Loads `licenses.json` and `exceptions.json` from the `json` directory of a release.
+    #[cfg(feature = "std")]
+    pub fn load<P: AsRef<Path>>(dir: P) -> Result<Self, LicensesError> {
+        let dir = dir.as_ref();
+        let licenses = fs::read_to_string(dir.join("licenses.json")).map_err(LicensesError::Io)?;
+        let exceptions =
+            fs::read_to_string(dir.join("exceptions.json")).map_err(LicensesError::Io)?;
+
+        Self::from_spdx_json(&licenses, &exceptions).map_err(LicensesError::Parse)
+    }
+
+    /// Returns `true` if the id is a known license id.
+    pub fn is_license(&self, id: &str) -> bool {
+        self.licenses.contains(&id.to_ascii_lowercase())
+    }
+
+    /// Returns `true` if the id is a known license exception id.
+    pub fn is_exception(&self, id: &str) -> bool {
+        self.exceptions.contains(&id.to_ascii_lowercase())
+    }
+
+    /// Checks all ids of an SPDX license expression, e.g. `MIT OR Apache-2.0 WITH LLVM-exception`.
+    ///
+    /// Custom ids, e.g. `LicenseRef-internal`, are accepted, the structure of the expression
+    /// is not validated.
+    pub fn validate_expression(&self, expression: &str) -> Result<(), ValidationError> {
+        let spaced = expression.replace('(', " ( ").replace(')', " ) ");
+        let mut after_with = false;
+
+        for token in spaced.split_whitespace() {
+            match token {
+                "(" | ")" | "AND" | "OR" => after_with = false,
+                "WITH" => after_with = true,
+                exception if after_with => {
+                    after_with = false;
+                    if !self.is_exception(exception) {
+                        return Err(unknown_exception(exception));
+                    }
+                }
+                license => {
+                    let id = license.strip_suffix('+').unwrap_or(license);
+                    if !is_custom_ref(id) && !self.is_license(id) {
+                        return Err(unknown_license(id));
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn validate_id(&self, id: &str) -> Result<(), ValidationError> {
+        if is_custom_ref(id) || self.is_license(id) {
+            Ok(())
+        } else {
+            Err(unknown_license(id))
+        }
+    }
+}
+
+fn is_custom_ref(id: &str) -> bool {
+    id.starts_with("LicenseRef-") || id.starts_with("DocumentRef-")
+}
+
+fn unknown_license(id: &str) -> ValidationError {
+    ValidationError::new("unknown_license")
+        .with_param("id", id)
+        .with_code("unknown-license")
+        .with_expected("SPDX license id")
+        .with_actual(id)
+}
+
+fn unknown_exception(id: &str) -> ValidationError {
+    ValidationError::new("unknown_license_exception")
+        .with_param("id", id)
+        .with_code("unknown-license-exception")
+        .with_expected("SPDX license exception id")
+        .with_actual(id)
+}
+
+/// Opt-in [`Rule`] that checks the license ids and expressions of all components against
+/// the [`KnownLicenses`], e.g. a recent SPDX release loaded with [`KnownLicenses::load`].
+#[derive(Debug)]
+pub struct KnownLicensesRule {
+    licenses: KnownLicenses,
+}
+
+impl KnownLicensesRule {
+    pub fn new(licenses: KnownLicenses) -> Self {
+        Self { licenses }
+    }
+
+    fn validate_license(&self, license: &LicenseChoice) -> ValidationResult {
+        match license {
+            LicenseChoice::License(license) => ValidationContext::new()
+                .add_field(
+                    "id",
+                    license
+                        .id
+                        .as_deref()
+                        .map(|id| self.licenses.validate_id(id)),
+                )
+                .into(),
+            LicenseChoice::Expression(expression) => ValidationContext::new()
+                .add_field(
+                    "expression",
+                    Some(self.licenses.validate_expression(&expression.expression)),
+                )
+                .into(),
+        }
+    }
+
+    fn validate_component(&self, component: &Component) -> ValidationResult {
+        let children = component.licenses.as_ref().map(|licenses| {
+            licenses
+                .iter()
+                .map(|license| self.validate_license(license))
+                .collect::<Vec<_>>()
+        });
+
+        ValidationContext::new()
+            .add_list("licenses", children)
+            .into()
+    }
+}
+
+impl Rule for KnownLicensesRule {
+    fn id(&self) -> &str {
+        "known-licenses"
+    }
+
+    fn check(&self, bom: &Bom, _ctx: &RuleContext) -> ValidationResult {
+        let children = bom.components.as_ref().map(|components| {
+            components
+                .iter()
+                .map(|component| self.validate_component(component))
+                .collect::<Vec<_>>()
+        });
+
+        ValidationContext::new()
+            .add_list("components", children)
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        options::ValidationOptions, testing::errors_at, validate_bom, validation::SpecVersion, Bom,
+    };
+
+    use super::{KnownLicenses, KnownLicensesRule};
+
+    const LICENSES: &str = r#"{
+        "licenseListVersion": "3.23",
+        "licenses": [{ "licenseId": "MIT" }, { "licenseId": "Apache-2.0" }]
+    }"#;
+
+    const EXCEPTIONS: &str = r#"{
+        "licenseListVersion": "3.23",
+        "exceptions": [{ "licenseExceptionId": "LLVM-exception" }]
+    }"#;
+
+    fn known_licenses() -> KnownLicenses {
+        KnownLicenses::from_spdx_json(LICENSES, EXCEPTIONS).expect("Failed to parse licenses")
+    }
+
+    #[test]
+    fn validates_expressions() {
+        let licenses = known_licenses();
+
+        assert!(licenses.validate_expression("mit").is_ok());
+        assert!(licenses
+            .validate_expression("(MIT OR Apache-2.0 WITH LLVM-exception) AND LicenseRef-x")
+            .is_ok());
+        assert!(licenses.validate_expression("Apache-2.0+").is_ok());
+        assert!(licenses.validate_expression("MIT OR GPL-4.0").is_err());
+        assert!(licenses
+            .validate_expression("MIT WITH Unknown-exception")
+            .is_err());
+    }
+
+    #[test]
+    fn rule_reports_unknown_licenses() {
+        let bom: Bom = serde_json::from_str(
+            r#"{ "components": [{ "type": "library", "name": "a", "licenses": [
+                { "license": { "id": "MIT" } },
+                { "license": { "id": "Made-Up" } },
+                { "expression": "Apache-2.0 OR Made-Up" }
+            ] }] }"#,
+        )
+        .expect("Failed to parse BOM");
+        let options = ValidationOptions::new().add_rule(KnownLicensesRule::new(known_licenses()));
+
+        let result = validate_bom(SpecVersion::V1_5, &bom, &options);
+        assert!(errors_at(&result, "components[0].licenses[0].id").is_empty());
+        assert_eq!(errors_at(&result, "components[0].licenses[1].id").len(), 1);
+        assert_eq!(
+            errors_at(&result, "components[0].licenses[2].expression").len(),
+            1
+        );
+    }
+}
@@ -0,0 +1,123 @@
+use crate::{
+    prelude::*,
+    validation::{ValidationContext, ValidationError, ValidationResult},
+    Bom,
+};
+
+/// Checks that `next` is a valid revision of the document `prev`.
+///
+/// Both revisions must carry the same serial number and the `version` of `next` must be
+/// greater than the one of `prev`, a missing version is treated as `1` as in the specification.
+/// All violations are reported as [`ValidationErrorsKind::Lineage`](crate::validation::ValidationErrorsKind::Lineage).
+pub fn validate_lineage(prev: &Bom, next: &Bom) -> ValidationResult {
+    ValidationContext::new()
+        .add_lineage(
+            "serial_number",
+            Some(validate_serial_number(
+                prev.serial_number.as_deref(),
+                next.serial_number.as_deref(),
+            )),
+        )
+        .add_lineage(
+            "version",
+            Some(validate_version(
+                prev.version.unwrap_or(1),
+                next.version.unwrap_or(1),
+            )),
+        )
+        .into()
+}
+
+fn validate_serial_number(prev: Option<&str>, next: Option<&str>) -> Result<(), ValidationError> {
+    match (prev, next) {
+        (Some(prev), Some(next)) if prev != next => {
+            Err(ValidationError::new("serial_number_changed")
+                .with_param("prev", prev)
+                .with_param("next", next)
+                .with_code("serial-number-changed")
+                .with_expected(prev)
+                .with_actual(next))
+        }
+        (Some(_), Some(_)) => Ok(()),
+        _ => Err(ValidationError::new("serial_number_missing").with_code("serial-number-missing")),
+    }
+}
+
+fn validate_version(prev: u32, next: u32) -> Result<(), ValidationError> {
+    if next <= prev {
+        return Err(ValidationError::new("version_not_increased")
+            .with_param("prev", prev)
+            .with_param("next", next)
+            .with_code("version-not-increased")
+            .with_expected(format!("version greater than {}", prev))
+            .with_actual(next));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        validation::{ValidationError, ValidationErrorsKind},
+        Bom,
+    };
+
+    use super::validate_lineage;
+
+    fn revision(serial_number: Option<&str>, version: Option<u32>) -> Bom {
+        Bom {
+            serial_number: serial_number.map(String::from),
+            version,
+            ..Default::default()
+        }
+    }
+
+    const SERIAL: &str = "urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79";
+
+    #[test]
+    fn accepts_next_revision() {
+        let prev = revision(Some(SERIAL), None);
+        let next = revision(Some(SERIAL), Some(2));
+
+        assert!(validate_lineage(&prev, &next).is_ok());
+    }
+
+    #[test]
+    fn fails_when_version_does_not_increase() {
+        let prev = revision(Some(SERIAL), Some(3));
+        let next = revision(Some(SERIAL), Some(3));
+
+        let errors = validate_lineage(&prev, &next).expect_err("Expected lineage errors");
+        assert!(!errors.contains_key("serial_number"));
+        assert_eq!(
+            errors.get("version"),
+            Some(&ValidationErrorsKind::Lineage(vec![ValidationError::new(
+                "version_not_increased"
+            )
+            .with_param("prev", 3)
+            .with_param("next", 3)
+            .with_code("version-not-increased")
+            .with_expected("version greater than 3")
+            .with_actual(3)]))
+        );
+    }
+
+    #[test]
+    fn fails_when_serial_number_changes() {
+        let prev = revision(Some(SERIAL), Some(1));
+        let next = revision(Some("urn:uuid:other"), Some(2));
+
+        let errors = validate_lineage(&prev, &next).expect_err("Expected lineage errors");
+        assert!(errors.contains_key("serial_number"));
+    }
+
+    #[test]
+    fn fails_when_serial_number_is_missing() {
+        let prev = revision(None, Some(1));
+        let next = revision(Some(SERIAL), Some(2));
+
+        let errors = validate_lineage(&prev, &next).expect_err("Expected lineage errors");
+        assert!(errors.contains_key("serial_number"));
+    }
+}
@@ -0,0 +1,617 @@
+//! Advisory checks for recommended practices, separate from the validation of a document.
+//!
+//! Lints never make a document invalid, all findings have severity [`Severity::Info`], e.g.
+//! to prefer a single license expression over a list of licenses. Run them with [`lint`], the
+//! findings can be suppressed and overridden like the findings of the validation.
+use alloc::collections::{BTreeMap, BTreeSet};
+
+use crate::{
+    apply_options,
+    options::ValidationOptions,
+    prelude::*,
+    rules::{Rule, RuleContext},
+    suppress,
+    validation::{
+        Severity, SpecVersion, ValidationContext, ValidationError, ValidationErrors,
+        ValidationResult,
+    },
+    Bom, Component, LicenseChoice, Property, MAX_INPUT_LEN,
+};
+
+/// Recommends a single SPDX license expression instead of a list of licenses, the relation
+/// between multiple licenses, e.g. `AND` or `OR`, is only expressed by an expression.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LicenseExpressionLint;
+
+impl Rule for LicenseExpressionLint {
+    fn id(&self) -> &str {
+        "license-expression"
+    }
+
+    fn check(&self, bom: &Bom, _ctx: &RuleContext) -> ValidationResult {
+        let children = check_components(bom, |component| {
+            let count = component.licenses.as_ref().map_or(0, Vec::len);
+            ValidationContext::new()
+                .add_field(
+                    "licenses",
+                    (count > 1).then(|| {
+                        Err(ValidationError::new("prefer_license_expression")
+                            .with_severity(Severity::Info)
+                            .with_param("count", count)
+                            .with_code("prefer-license-expression"))
+                    }),
+                )
+                .into()
+        });
+
+        ValidationContext::new()
+            .add_list("components", children)
+            .into()
+    }
+}
+
+/// Recommends a purl for components only identified by a cpe, open source packages are
+/// identified by their purl. Hardware components are not checked.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PurlOverCpeLint;
+
+impl Rule for PurlOverCpeLint {
+    fn id(&self) -> &str {
+        "purl-over-cpe"
+    }
+
+    fn check(&self, bom: &Bom, _ctx: &RuleContext) -> ValidationResult {
+        let children = check_components(bom, |component| {
+            let cpe_only = component.cpe.is_some()
+                && component.purl.is_none()
+                && !component.component_type.is_hardware();
+            ValidationContext::new()
+                .add_field(
+                    "cpe",
+                    cpe_only.then(|| {
+                        Err(ValidationError::new("prefer_purl")
+                            .with_severity(Severity::Info)
+                            .with_code("prefer-purl"))
+                    }),
+                )
+                .into()
+        });
+
+        ValidationContext::new()
+            .add_list("components", children)
+            .into()
+    }
+}
+
+/// Recommends to list properties of the same namespace next to each other, e.g. all
+/// `cdx:npm:*` properties. The namespace is the name up to the last `:`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GroupedPropertiesLint;
+
+impl GroupedPropertiesLint {
+    fn check_properties(properties: Option<&Vec<Property>>) -> Option<Result<(), ValidationError>> {
+        let mut seen: Vec<&str> = Vec::new();
+        for (index, property) in properties?.iter().enumerate() {
+            let Some((namespace, _)) = property.name.rsplit_once(':') else {
+                continue;
+            };
+            if seen.last() == Some(&namespace) {
+                continue;
+            }
+            if seen.contains(&namespace) {
+                return Some(Err(ValidationError::new("ungrouped_properties")
+                    .with_severity(Severity::Info)
+                    .with_param("namespace", namespace)
+                    .with_index(index)
+                    .with_code("ungrouped-properties")));
+            }
+            seen.push(namespace);
+        }
+        None
+    }
+}
+
+impl Rule for GroupedPropertiesLint {
+    fn id(&self) -> &str {
+        "grouped-properties"
+    }
+
+    fn check(&self, bom: &Bom, _ctx: &RuleContext) -> ValidationResult {
+        let children = check_components(bom, |component| {
+            ValidationContext::new()
+                .add_field(
+                    "properties",
+                    Self::check_properties(component.properties.as_ref()),
+                )
+                .into()
+        });
+
+        ValidationContext::new()
+            .add_field(
+                "properties",
+                Self::check_properties(bom.properties.as_ref()),
+            )
+            .add_list("components", children)
+            .into()
+    }
+}
+
+/// Recommends to reference a license by its SPDX id or url instead of embedding the same
+/// large license text verbatim in many components.
+///
+/// Every copy after the first one is reported, together with the bytes all copies add to the
+/// document, which count towards the [`MAX_INPUT_LEN`] of [`validate_bytes`](crate::validate_bytes).
+#[derive(Debug, Clone, Copy)]
+pub struct DuplicateLicenseTextLint {
+    /// Shorter texts are not reported.
+    pub min_len: usize,
+    /// Texts embedded less often are not reported.
+    pub min_count: usize,
+}
+
+impl Default for DuplicateLicenseTextLint {
+    fn default() -> Self {
+        Self {
+            min_len: 1024,
+            min_count: 3,
+        }
+    }
+}
+
+/// Returns the embedded license texts of the component with the index of their license.
+fn license_texts(component: &Component) -> impl Iterator<Item = (usize, &str)> {
+    component
+        .licenses
+        .iter()
+        .flatten()
+        .enumerate()
+        .filter_map(|(index, license)| match license {
+            LicenseChoice::License(license) => {
+                Some((index, license.text.as_ref()?.content.as_str()))
+            }
+            LicenseChoice::Expression(_) => None,
+        })
+}
+
+impl Rule for DuplicateLicenseTextLint {
+    fn id(&self) -> &str {
+        "duplicate-license-text"
+    }
+
+    fn check(&self, bom: &Bom, _ctx: &RuleContext) -> ValidationResult {
+        let mut counts = BTreeMap::<&str, usize>::new();
+        for component in bom.components.iter().flatten() {
+            for (_, text) in license_texts(component) {
+                *counts.entry(text).or_default() += 1;
+            }
+        }
+
+        let mut seen = BTreeSet::new();
+        let children = check_components(bom, |component| {
+            license_texts(component)
+                .filter(|(_, text)| text.len() >= self.min_len && counts[text] >= self.min_count)
+                .filter(|(_, text)| !seen.insert(*text))
+                .fold(ValidationContext::new(), |context, (index, text)| {
+                    context.add_field(
+                        "licenses",
+                        Some(Err(ValidationError::new("duplicate_license_text")
+                            .with_severity(Severity::Info)
+                            .with_param("count", counts[text])
+                            .with_param("bytes", text.len() * counts[text])
+                            .with_param("max", MAX_INPUT_LEN)
+                            .with_index(index)
+                            .with_code("duplicate-license-text"))),
+                    )
+                })
+                .into()
+        });
+
+        ValidationContext::new()
+            .add_list("components", children)
+            .into()
+    }
+}
+
+/// Flags copyright statements that still contain the placeholders of a template, e.g.
+/// `Copyright (c) YEAR AUTHOR` or `Copyright <year> <name of author>`.
+///
+/// Placeholders are upper case words like `YEAR` or `AUTHOR` and bracketed terms mentioning a
+/// year, name, author, owner or holder.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CopyrightPlaceholderLint;
+
+/// The upper case words of copyright templates.
+const PLACEHOLDER_WORDS: &[&str] = &["YEAR", "YYYY", "AUTHOR", "AUTHORS", "OWNER", "FULLNAME"];
+
+/// The terms of bracketed placeholders, e.g. `[fullname]`.
+const PLACEHOLDER_TERMS: &[&str] = &["year", "name", "author", "owner", "holder"];
+
+/// Returns the first placeholder of the copyright statement, if any.
+fn copyright_placeholder(copyright: &str) -> Option<&str> {
+    let bracketed = [('<', '>'), ('[', ']'), ('{', '}')]
+        .iter()
+        .find_map(|(open, close)| {
+            let start = copyright.find(*open)?;
+            let end = start + copyright[start..].find(*close)?;
+            let term = copyright[start + 1..end].to_lowercase();
+            PLACEHOLDER_TERMS
+                .iter()
+                .any(|placeholder| term.contains(placeholder))
+                .then(|| &copyright[start..=end])
+        });
+
+    bracketed.or_else(|| {
+        copyright
+            .split(|c: char| !c.is_alphanumeric())
+            .find(|word| PLACEHOLDER_WORDS.contains(word))
+    })
+}
+
+impl CopyrightPlaceholderLint {
+    fn check_copyright(copyright: Option<&str>) -> Option<Result<(), ValidationError>> {
+        let placeholder = copyright_placeholder(copyright?)?;
+        Some(Err(ValidationError::new("copyright_placeholder")
+            .with_severity(Severity::Info)
+            .with_param("placeholder", placeholder)
+            .with_code("copyright-placeholder")))
+    }
+}
+
+impl Rule for CopyrightPlaceholderLint {
+    fn id(&self) -> &str {
+        "copyright-placeholder"
+    }
+
+    fn check(&self, bom: &Bom, _ctx: &RuleContext) -> ValidationResult {
+        let children = check_components(bom, |component| {
+            let evidence = component.evidence.as_ref().map(|evidence| {
+                let copyrights = evidence.copyright.as_ref().map(|copyrights| {
+                    copyrights
+                        .iter()
+                        .map(|copyright| {
+                            ValidationContext::new()
+                                .add_field("text", Self::check_copyright(Some(&copyright.text)))
+                                .into()
+                        })
+                        .collect::<Vec<_>>()
+                });
+                ValidationContext::new()
+                    .add_list("copyright", copyrights)
+                    .into()
+            });
+
+            ValidationContext::new()
+                .add_field(
+                    "copyright",
+                    Self::check_copyright(component.copyright.as_deref()),
+                )
+                .add_struct("evidence", evidence)
+                .into()
+        });
+
+        ValidationContext::new()
+            .add_list("components", children)
+            .into()
+    }
+}
+
+/// Warns about component names and groups mixing scripts with confusable characters, e.g. a
+/// Cyrillic `а` in `pаckage`, which defeat matching the names against allow lists.
+///
+/// Only words mixing Latin letters with Cyrillic or Greek letters that look like a Latin
+/// letter are reported, names written entirely in another script are not. The name with all
+/// confusable characters replaced by their Latin look-alike is reported as expected value.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ConfusableNameLint;
+
+/// Cyrillic and Greek letters with the Latin letter they are confused with.
+const CONFUSABLES: &[(char, char)] = &[
+    ('\u{0430}', 'a'),
+    ('\u{0435}', 'e'),
+    ('\u{043E}', 'o'),
+    ('\u{0440}', 'p'),
+    ('\u{0441}', 'c'),
+    ('\u{0443}', 'y'),
+    ('\u{0445}', 'x'),
+    ('\u{0456}', 'i'),
+    ('\u{0458}', 'j'),
+    ('\u{0455}', 's'),
+    ('\u{0501}', 'd'),
+    ('\u{051B}', 'q'),
+    ('\u{051D}', 'w'),
+    ('\u{0410}', 'A'),
+    ('\u{0412}', 'B'),
+    ('\u{0415}', 'E'),
+    ('\u{041A}', 'K'),
+    ('\u{041C}', 'M'),
+    ('\u{041D}', 'H'),
+    ('\u{041E}', 'O'),
+    ('\u{0420}', 'P'),
+    ('\u{0421}', 'C'),
+    ('\u{0422}', 'T'),
+    ('\u{0425}', 'X'),
+    ('\u{03B1}', 'a'),
+    ('\u{03BF}', 'o'),
+    ('\u{03BD}', 'v'),
+    ('\u{03C1}', 'p'),
+    ('\u{0391}', 'A'),
+    ('\u{0392}', 'B'),
+    ('\u{0395}', 'E'),
+    ('\u{0396}', 'Z'),
+    ('\u{0397}', 'H'),
+    ('\u{0399}', 'I'),
+    ('\u{039A}', 'K'),
+    ('\u{039C}', 'M'),
+    ('\u{039D}', 'N'),
+    ('\u{039F}', 'O'),
+    ('\u{03A1}', 'P'),
+    ('\u{03A4}', 'T'),
+    ('\u{03A5}', 'Y'),
+    ('\u{03A7}', 'X'),
+];
+
+fn is_latin(c: char) -> bool {
+    c.is_ascii_alphabetic() || (matches!(c, '\u{00C0}'..='\u{024F}') && c.is_alphabetic())
+}
+
+fn look_alike(c: char) -> Option<char> {
+    CONFUSABLES
+        .iter()
+        .find(|(confusable, _)| *confusable == c)
+        .map(|(_, latin)| *latin)
+}
+
+impl ConfusableNameLint {
+    fn check_name(value: Option<&str>) -> Option<Result<(), ValidationError>> {
+        let value = value?;
+        let confusable = value
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|word| word.chars().any(is_latin))
+            .flat_map(str::chars)
+            .find(|c| look_alike(*c).is_some())?;
+        let skeleton = value
+            .chars()
+            .map(|c| look_alike(c).unwrap_or(c))
+            .collect::<String>();
+
+        Some(Err(ValidationError::new("confusable_name")
+            .with_severity(Severity::Info)
+            .with_param("character", format!("U+{:04X}", confusable as u32))
+            .with_param("latin", look_alike(confusable).unwrap_or(confusable))
+            .with_code("confusable-name")
+            .with_expected(skeleton)
+            .with_actual(value)))
+    }
+}
+
+impl Rule for ConfusableNameLint {
+    fn id(&self) -> &str {
+        "confusable-names"
+    }
+
+    fn check(&self, bom: &Bom, _ctx: &RuleContext) -> ValidationResult {
+        let children = check_components(bom, |component| {
+            ValidationContext::new()
+                .add_field("name", Self::check_name(Some(&component.name)))
+                .add_field("group", Self::check_name(component.group.as_deref()))
+                .into()
+        });
+
+        ValidationContext::new()
+            .add_list("components", children)
+            .into()
+    }
+}
+
+/// Checks every component of `components`.
+fn check_components<'a, F>(bom: &'a Bom, check: F) -> Option<Vec<ValidationResult>>
+where
+    F: FnMut(&'a Component) -> ValidationResult,
+{
+    bom.components
+        .as_ref()
+        .map(|components| components.iter().map(check).collect())
+}
+
+/// Returns all lints, in the order they are run.
+pub fn lints() -> Vec<Box<dyn Rule>> {
+    vec![
+        Box::new(LicenseExpressionLint),
+        Box::new(PurlOverCpeLint),
+        Box::new(GroupedPropertiesLint),
+        Box::new(DuplicateLicenseTextLint::default()),
+        Box::new(CopyrightPlaceholderLint),
+    ]
+}
+
+/// Returns the lints that only run if enabled with
+/// [`ValidationOptions::enable_rule`], e.g. security checks.
+pub fn opt_in_lints() -> Vec<Box<dyn Rule>> {
+    vec![Box::new(ConfusableNameLint)]
+}
+
+/// Runs all lints on the document and returns their findings as one error tree.
+///
+/// Profile, severity overrides, suppressions and the selected paths of the options apply,
+/// custom rules of the options are not run, [`opt_in_lints`] only if they are enabled.
+pub fn lint(version: SpecVersion, bom: &Bom, options: &ValidationOptions) -> ValidationResult {
+    let context = RuleContext::new(version).with_profile(options.selected_profile());
+    let mut suppressions = suppress::collect(bom);
+    suppressions.extend_from_slice(options.suppressions());
+
+    let opt_in = opt_in_lints()
+        .into_iter()
+        .filter(|lint| options.is_rule_enabled(lint.id()));
+
+    lints()
+        .into_iter()
+        .chain(opt_in)
+        .filter(|lint| !options.is_rule_disabled(lint.id()) && lint.applies(bom, &context))
+        .map(|lint| apply_options(lint.check(bom, &context), version, options, &suppressions))
+        .filter_map(Result::err)
+        .reduce(ValidationErrors::merge)
+        .map_or(Ok(()), Err)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        options::ValidationOptions,
+        testing::errors_at,
+        validation::{Severity, SpecVersion},
+        AttachedText, Bom, Component, ComponentType, License, LicenseChoice, LicenseExpression,
+        Property,
+    };
+
+    use super::lint;
+
+    fn license_text(content: &str) -> LicenseChoice {
+        LicenseChoice::License(License {
+            id: Some(String::from("MIT")),
+            text: Some(AttachedText {
+                content: content.to_string(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        })
+    }
+
+    fn property(name: &str) -> Property {
+        Property {
+            name: name.to_string(),
+            value: None,
+        }
+    }
+
+    #[test]
+    fn lints_recommended_practices() {
+        let license = || {
+            LicenseChoice::Expression(LicenseExpression {
+                expression: String::from("MIT"),
+                ..Default::default()
+            })
+        };
+        let bom = Bom {
+            components: Some(vec![Component {
+                licenses: Some(vec![license(), license()]),
+                cpe: Some(String::from("cpe:2.3:a:serde:serde:1.0.0:*:*:*:*:*:*:*")),
+                properties: Some(vec![
+                    property("cdx:npm:package:path"),
+                    property("internal:team"),
+                    property("cdx:npm:package:bundled"),
+                ]),
+                ..Component::new(ComponentType::Library, "serde")
+            }]),
+            ..Default::default()
+        };
+
+        let result = lint(SpecVersion::V1_5, &bom, &ValidationOptions::new());
+        let errors = result.as_ref().expect_err("Expected lint findings");
+        assert!(errors
+            .errors()
+            .iter()
+            .all(|(_, error)| error.severity == Severity::Info));
+        assert_eq!(errors_at(&result, "components[0].licenses").len(), 1);
+        assert_eq!(errors_at(&result, "components[0].cpe").len(), 1);
+        let properties = errors_at(&result, "components[0].properties");
+        assert_eq!(properties.len(), 1);
+        assert_eq!(properties[0].context.index, Some(2));
+    }
+
+    #[test]
+    fn accepts_recommended_practices() {
+        let bom = Bom {
+            components: Some(vec![Component {
+                purl: Some(String::from("pkg:cargo/serde@1.0.0")),
+                cpe: Some(String::from("cpe:2.3:a:serde:serde:1.0.0:*:*:*:*:*:*:*")),
+                properties: Some(vec![
+                    property("cdx:a"),
+                    property("cdx:b"),
+                    property("other"),
+                ]),
+                ..Component::new(ComponentType::Library, "serde")
+            }]),
+            ..Default::default()
+        };
+
+        assert!(lint(SpecVersion::V1_5, &bom, &ValidationOptions::new()).is_ok());
+    }
+
+    #[test]
+    fn lints_duplicated_license_texts() {
+        let text = "Permission is hereby granted, free of charge, ".repeat(40);
+        let component = |name: &str| Component {
+            licenses: Some(vec![license_text(&text)]),
+            ..Component::new(ComponentType::Library, name)
+        };
+        let bom = Bom {
+            components: Some(vec![component("a"), component("b"), component("c")]),
+            ..Default::default()
+        };
+
+        let result = lint(SpecVersion::V1_5, &bom, &ValidationOptions::new());
+        assert!(errors_at(&result, "components[0].licenses").is_empty());
+        let errors = errors_at(&result, "components[1].licenses");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code.as_deref(), Some("duplicate-license-text"));
+        assert_eq!(errors[0].severity, Severity::Info);
+        assert_eq!(errors_at(&result, "components[2].licenses").len(), 1);
+
+        let bom = Bom {
+            components: Some(vec![component("a"), component("b")]),
+            ..Default::default()
+        };
+        assert!(lint(SpecVersion::V1_5, &bom, &ValidationOptions::new()).is_ok());
+    }
+
+    #[test]
+    fn lints_templated_copyrights() {
+        let bom = Bom::parse_json(
+            r#"{ "components": [
+                { "type": "library", "name": "a", "copyright": "Copyright (c) YEAR AUTHOR" },
+                { "type": "library", "name": "b", "evidence": { "copyright": [
+                    { "text": "Copyright 2024 Acme Inc." },
+                    { "text": "Copyright (C) <year> <name of author>" }
+                ] } },
+                { "type": "library", "name": "c", "copyright": "Copyright 2019 The Authors" }
+            ] }"#,
+        )
+        .expect("Failed to parse BOM");
+
+        let result = lint(SpecVersion::V1_5, &bom, &ValidationOptions::new());
+        let errors = errors_at(&result, "components[0].copyright");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].message.param("placeholder"), Some("YEAR"));
+        assert!(errors_at(&result, "components[1].evidence.copyright[0].text").is_empty());
+        let errors = errors_at(&result, "components[1].evidence.copyright[1].text");
+        assert_eq!(errors[0].message.param("placeholder"), Some("<year>"));
+        assert!(errors_at(&result, "components[2].copyright").is_empty());
+    }
+
+    #[test]
+    fn lints_confusable_names_if_enabled() {
+        let bom = Bom {
+            components: Some(vec![
+                Component {
+                    group: Some(String::from("com.\u{0430}cme")),
+                    ..Component::new(ComponentType::Library, "p\u{0430}ckage")
+                },
+                Component::new(
+                    ComponentType::Library,
+                    "\u{043F}\u{0430}\u{043A}\u{0435}\u{0442}",
+                ),
+            ]),
+            ..Default::default()
+        };
+        assert!(lint(SpecVersion::V1_5, &bom, &ValidationOptions::new()).is_ok());
+
+        let options = ValidationOptions::new().enable_rule("confusable-names");
+        let result = lint(SpecVersion::V1_5, &bom, &options);
+        let errors = errors_at(&result, "components[0].name");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code.as_deref(), Some("confusable-name"));
+        assert_eq!(errors[0].context.expected.as_deref(), Some("package"));
+        assert_eq!(errors_at(&result, "components[0].group").len(), 1);
+        assert!(errors_at(&result, "components[1].name").is_empty());
+    }
+}
@@ -0,0 +1,37 @@
+//! The insertion ordered map used throughout the crate.
+//!
+//! With the `std` feature this is [`indexmap::IndexMap`] with its default hasher. Without `std`
+//! there is no source of randomness, a fixed FNV-1a hasher is used instead.
+#[cfg(not(feature = "std"))]
+use core::hash::{BuildHasherDefault, Hasher};
+
+#[cfg(feature = "std")]
+pub type IndexMap<K, V> = indexmap::IndexMap<K, V>;
+
+#[cfg(not(feature = "std"))]
+pub type IndexMap<K, V> = indexmap::IndexMap<K, V, BuildHasherDefault<FnvHasher>>;
+
+/// A FNV-1a hasher, used as the hasher of [`IndexMap`] without `std`.
+#[cfg(not(feature = "std"))]
+#[derive(Debug, Clone, Copy)]
+pub struct FnvHasher(u64);
+
+#[cfg(not(feature = "std"))]
+impl Default for FnvHasher {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl Hasher for FnvHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 = (self.0 ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+}
@@ -0,0 +1,312 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    identity::ComponentIdentity,
+    map::IndexMap,
+    validation::{ValidationContext, ValidationError, ValidationResult},
+    Bom, Component, Composition, Dependency,
+};
+
+/// Combines multiple [`Bom`]s, e.g. the per-language SBOMs of one application, into one document.
+///
+/// * the metadata, serial number and version of the first document are kept, the metadata
+///   components of all other documents are treated as the same root component
+/// * components are deduplicated by their [`ComponentIdentity`], the first occurrence is kept
+/// * bom-refs of components and services are rewritten to stay unique, references in
+///   dependencies and compositions are updated accordingly
+/// * dependencies of the same bom-ref are combined, services, compositions, vulnerabilities
+///   and formulation are concatenated, properties are concatenated without exact duplicates
+///
+/// Merge conflicts, e.g. components with the same identity but different hashes, are reported
+/// in the returned result at the path of the dropped duplicate, e.g. `boms[1].components[3]`.
+pub fn merge(boms: Vec<Bom>) -> (Bom, ValidationResult) {
+    let mut merged = Bom::default();
+    let mut components: Vec<Component> = Vec::new();
+    let mut by_identity: HashMap<ComponentIdentity, usize> = HashMap::new();
+    let mut used_refs: HashSet<String> = HashSet::new();
+    let mut dependencies: IndexMap<String, Vec<String>> = IndexMap::default();
+    let mut root_ref: Option<String> = None;
+    let mut results = Vec::new();
+
+    for (index, bom) in boms.into_iter().enumerate() {
+        // maps the bom-refs of this document to the bom-refs in the merged document
+        let mut refs: HashMap<String, String> = HashMap::new();
+
+        if index == 0 {
+            merged.serial_number = bom.serial_number;
+            merged.version = bom.version;
+        }
+
+        if let Some(meta_data) = bom.meta_data {
+            let bom_ref = meta_data
+                .component
+                .as_ref()
+                .and_then(|component| component.bom_ref.clone());
+
+            if index == 0 {
+                if let Some(bom_ref) = &bom_ref {
+                    used_refs.insert(bom_ref.clone());
+                }
+                root_ref = bom_ref;
+                merged.meta_data = Some(meta_data);
+            } else if let (Some(bom_ref), Some(root_ref)) = (bom_ref, &root_ref) {
+                refs.insert(bom_ref, root_ref.clone());
+            }
+        }
+
+        let mut component_results = Vec::new();
+        for mut component in bom.components.into_iter().flatten() {
+            let identity = component.identity();
+            let duplicate = by_identity.get(&identity).copied();
+
+            match duplicate {
+                Some(position) => {
+                    let kept = &components[position];
+                    component_results.push(check_conflict(kept, &component));
+                    if let (Some(bom_ref), Some(kept_ref)) = (component.bom_ref, &kept.bom_ref) {
+                        refs.insert(bom_ref, kept_ref.clone());
+                    }
+                }
+                None => {
+                    component_results.push(Ok(()));
+                    if let Some(bom_ref) = component.bom_ref.take() {
+                        let unique_ref = unique_ref(&bom_ref, &used_refs);
+                        used_refs.insert(unique_ref.clone());
+                        refs.insert(bom_ref, unique_ref.clone());
+                        component.bom_ref = Some(unique_ref);
+                    }
+                    by_identity.insert(identity, components.len());
+                    components.push(component);
+                }
+            }
+        }
+
+        for mut service in bom.services.into_iter().flatten() {
+            if let Some(bom_ref) = service.bom_ref.take() {
+                let unique_ref = unique_ref(&bom_ref, &used_refs);
+                used_refs.insert(unique_ref.clone());
+                refs.insert(bom_ref, unique_ref.clone());
+                service.bom_ref = Some(unique_ref);
+            }
+            merged.services.get_or_insert_with(Vec::new).push(service);
+        }
+
+        let resolve = |bom_ref: &String| refs.get(bom_ref).unwrap_or(bom_ref).clone();
+        let resolve_all = |refs: Option<Vec<String>>| {
+            refs.map(|refs| refs.iter().map(resolve).collect::<Vec<_>>())
+        };
+        for dependency in bom.dependencies.into_iter().flatten() {
+            let depends_on = dependencies
+                .entry(resolve(&dependency.dependency_ref))
+                .or_default();
+            for bom_ref in dependency.depends_on.iter().flatten() {
+                let bom_ref = resolve(bom_ref);
+                if !depends_on.contains(&bom_ref) {
+                    depends_on.push(bom_ref);
+                }
+            }
+        }
+
+        for composition in bom.compositions.into_iter().flatten() {
+            merged
+                .compositions
+                .get_or_insert_with(Vec::new)
+                .push(Composition {
+                    aggregate: composition.aggregate,
+                    assemblies: resolve_all(composition.assemblies),
+                    dependencies: resolve_all(composition.dependencies),
+                });
+        }
+
+        if let Some(vulnerabilities) = bom.vulnerabilities {
+            merged
+                .vulnerabilities
+                .get_or_insert_with(Vec::new)
+                .extend(vulnerabilities);
+        }
+        if let Some(formulation) = bom.formulation {
+            merged
+                .formulation
+                .get_or_insert_with(Vec::new)
+                .extend(formulation);
+        }
+        for property in bom.properties.into_iter().flatten() {
+            let properties = merged.properties.get_or_insert_with(Vec::new);
+            if !properties.contains(&property) {
+                properties.push(property);
+            }
+        }
+
+        results.push(
+            ValidationContext::new()
+                .add_list("components", Some(component_results))
+                .into(),
+        );
+    }
+
+    merged.components = (!components.is_empty()).then_some(components);
+    merged.dependencies = (!dependencies.is_empty()).then(|| {
+        dependencies
+            .into_iter()
+            .map(|(dependency_ref, depends_on)| Dependency {
+                dependency_ref,
+                depends_on: (!depends_on.is_empty()).then_some(depends_on),
+            })
+            .collect()
+    });
+
+    let result = ValidationContext::new()
+        .add_list("boms", Some(results))
+        .into();
+
+    (merged, result)
+}
+
+/// Returns a bom-ref based on the given one that is not used yet.
+fn unique_ref(bom_ref: &str, used_refs: &HashSet<String>) -> String {
+    let mut unique_ref = bom_ref.to_string();
+    let mut counter = 1;
+    while used_refs.contains(&unique_ref) {
+        counter += 1;
+        unique_ref = format!("{}-{}", bom_ref, counter);
+    }
+    unique_ref
+}
+
+/// Checks that a duplicate component does not declare a different digest than the kept one.
+fn check_conflict(kept: &Component, duplicate: &Component) -> ValidationResult {
+    let conflict = duplicate
+        .hashes
+        .iter()
+        .flatten()
+        .find(|hash| {
+            kept.hashes.iter().flatten().any(|kept_hash| {
+                kept_hash.alg == hash.alg && !kept_hash.content.eq_ignore_ascii_case(&hash.content)
+            })
+        })
+        .map(|hash| {
+            Err(ValidationError::new("merge_conflict")
+                .with_param("alg", format!("{:?}", hash.alg))
+                .with_param("purl", duplicate.purl.as_deref().unwrap_or_default())
+                .with_code("merge-conflict"))
+        });
+
+    ValidationContext::new()
+        .add_field("hashes", conflict)
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{validation::ValidationErrors, Bom};
+
+    use super::merge;
+
+    const RUST: &str = r#"{
+        "metadata": { "component": { "type": "application", "bom-ref": "app", "name": "app" } },
+        "components": [
+            { "type": "library", "bom-ref": "lib", "name": "serde", "purl": "pkg:cargo/serde@1.0.196" },
+            { "type": "library", "bom-ref": "zlib", "name": "zlib", "purl": "pkg:generic/zlib@1.3",
+              "hashes": [{ "alg": "SHA-1", "content": "0000000000000000000000000000000000000000" }] }
+        ],
+        "dependencies": [{ "ref": "app", "dependsOn": ["lib", "zlib"] }]
+    }"#;
+
+    const NPM: &str = r#"{
+        "metadata": { "component": { "type": "application", "bom-ref": "frontend", "name": "app" } },
+        "components": [
+            { "type": "library", "bom-ref": "lib", "name": "react", "purl": "pkg:npm/react@18.2.0" },
+            { "type": "library", "bom-ref": "libz", "name": "zlib", "purl": "pkg:generic/zlib@1.3",
+              "hashes": [{ "alg": "SHA-1", "content": "0000000000000000000000000000000000000000" }] }
+        ],
+        "dependencies": [{ "ref": "frontend", "dependsOn": ["lib", "libz"] }]
+    }"#;
+
+    fn parse(input: &str) -> Bom {
+        Bom::parse_json(input).expect("Failed to parse BOM")
+    }
+
+    #[test]
+    fn merge_deduplicates_and_rewrites_refs() {
+        let (bom, result) = merge(vec![parse(RUST), parse(NPM)]);
+        assert!(result.is_ok());
+
+        let components = bom.components.expect("Expected components");
+        let refs = components
+            .iter()
+            .map(|component| component.bom_ref.as_deref().unwrap_or_default())
+            .collect::<Vec<_>>();
+        assert_eq!(refs, vec!["lib", "zlib", "lib-2"]);
+
+        let dependencies = bom.dependencies.expect("Expected dependencies");
+        assert_eq!(dependencies.len(), 1);
+        assert_eq!(dependencies[0].dependency_ref, "app");
+        assert_eq!(
+            dependencies[0].depends_on,
+            Some(vec![
+                String::from("lib"),
+                String::from("zlib"),
+                String::from("lib-2")
+            ])
+        );
+    }
+
+    #[test]
+    fn merge_keeps_document_level_fields() {
+        let first = parse(
+            r#"{
+                "serialNumber": "urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79", "version": 2,
+                "services": [{ "bom-ref": "api", "name": "api" }],
+                "compositions": [{ "aggregate": "complete", "assemblies": ["api"] }],
+                "properties": [{ "name": "team", "value": "core" }]
+            }"#,
+        );
+        let second = parse(
+            r#"{
+                "serialNumber": "urn:uuid:00000000-0000-4000-8000-000000000000", "version": 7,
+                "services": [{ "bom-ref": "api", "name": "auth" }],
+                "compositions": [{ "aggregate": "incomplete", "assemblies": ["api"] }],
+                "formulation": [{ "bom-ref": "build" }],
+                "properties": [{ "name": "team", "value": "core" }, { "name": "lang", "value": "js" }]
+            }"#,
+        );
+
+        let (bom, result) = merge(vec![first, second]);
+        assert!(result.is_ok());
+        assert_eq!(
+            bom.serial_number.as_deref(),
+            Some("urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79")
+        );
+        assert_eq!(bom.version, Some(2));
+
+        let services = bom.services.expect("Expected services");
+        let refs = services
+            .iter()
+            .map(|service| service.bom_ref.as_deref().unwrap_or_default())
+            .collect::<Vec<_>>();
+        assert_eq!(refs, vec!["api", "api-2"]);
+
+        let compositions = bom.compositions.expect("Expected compositions");
+        assert_eq!(
+            compositions[1].assemblies,
+            Some(vec![String::from("api-2")])
+        );
+        assert_eq!(
+            bom.formulation.map(|formulation| formulation.len()),
+            Some(1)
+        );
+        assert_eq!(bom.properties.map(|properties| properties.len()), Some(2));
+    }
+
+    #[test]
+    fn merge_reports_conflicting_hashes() {
+        let npm = NPM.replace(
+            "0000000000000000000000000000000000000000",
+            "1111111111111111111111111111111111111111",
+        );
+
+        let (bom, result) = merge(vec![parse(RUST), parse(&npm)]);
+        assert_eq!(bom.components.map(|components| components.len()), Some(3));
+        assert!(ValidationErrors::has_error(&result, "boms"));
+    }
+}
@@ -0,0 +1,777 @@
+use core::fmt;
+
+use serde::Serialize;
+
+use crate::prelude::*;
+
+/// The language validation messages are rendered in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Locale {
+    /// English, all messages are available in English.
+    #[default]
+    En,
+    /// German, falls back to English for messages without translation.
+    De,
+}
+
+impl Locale {
+    /// Returns the locale for the given language tag, e.g. `de` or `de-AT`.
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        let language = tag.split(['-', '_']).next().unwrap_or_default();
+        match language.to_ascii_lowercase().as_str() {
+            "en" => Some(Locale::En),
+            "de" => Some(Locale::De),
+            _ => None,
+        }
+    }
+
+    fn templates(&self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Locale::En => EN,
+            Locale::De => DE,
+        }
+    }
+}
+
+/// The English templates of all messages of the built-in validators, keyed by template key.
+///
+/// Parameters are referenced by name in braces, e.g. `{max}`.
+const EN: &[(&str, &str)] = &[
+    ("context_expected_actual", ": expected {expected}, got {actual}"),
+    ("context_expected", ": expected {expected}"),
+    ("context_actual", ": got {actual}"),
+    (
+        "input_too_large",
+        "Document is too large, at most {max} bytes are allowed but got {actual}",
+    ),
+    ("invalid_json", "Document is not a valid JSON BOM: {error}"),
+    ("read_failed", "Document could not be read: {error}"),
+    ("unsupported_version", "Spec version '{version}' is not supported"),
+    ("missing_spec_version", "Document does not declare its spec version"),
+    (
+        "unsupported_format",
+        "Documents in {format} format are not supported by this build",
+    ),
+    (
+        "reference_url_mismatch",
+        "Url does not look like a {type} reference: {reason}",
+    ),
+    ("invalid_xml", "Document is not a well-formed XML BOM: {error}"),
+    ("purl_not_canonical", "Purl is not in its canonical form"),
+    ("invalid_element", "Element cannot be read: {error}"),
+    (
+        "field_since",
+        "Field '{field}' is not allowed before spec version {since}",
+    ),
+    (
+        "field_until",
+        "Field '{field}' is deprecated after spec version {until}",
+    ),
+    (
+        "field_replaced",
+        "Field '{field}' is deprecated after spec version {until}, use '{replacement}' instead",
+    ),
+    (
+        "field_deprecated",
+        "Field '{field}' is deprecated since spec version {deprecated}",
+    ),
+    (
+        "field_deprecated_replaced",
+        "Field '{field}' is deprecated since spec version {deprecated}, use '{replacement}' instead",
+    ),
+    (
+        "field_removed",
+        "Field '{field}' is not supported since spec version {removed}",
+    ),
+    (
+        "too_long",
+        "String is too long, at most {max} characters are allowed but got {actual}",
+    ),
+    ("tool_kind", "Tool must not be a {kind}"),
+    ("hash_not_hex", "Hash content must be a hex string"),
+    (
+        "hash_length",
+        "Hash content of algorithm {alg} must have {len} hex digits",
+    ),
+    ("purl_scheme", "Purl must start with 'pkg:'"),
+    ("purl_type_and_name", "Purl must contain a type and a name"),
+    ("purl_invalid_type", "Purl type '{type}' is invalid"),
+    ("purl_name", "Purl must contain a name"),
+    ("purl_qualifier_value", "Purl qualifier '{qualifier}' has no value"),
+    (
+        "purl_percent_encoding",
+        "Purl component '{component}' contains an invalid percent-encoding",
+    ),
+    ("purl_utf8", "Purl component '{component}' is not valid UTF-8"),
+    (
+        "serial_number_changed",
+        "Serial number changed from '{prev}' to '{next}' between revisions",
+    ),
+    (
+        "serial_number_missing",
+        "Serial number is required in both revisions to track the lineage",
+    ),
+    (
+        "version_not_increased",
+        "Version must increase between revisions, but went from {prev} to {next}",
+    ),
+    ("artifact_unreadable", "Failed to read artifact '{path}': {error}"),
+    ("fetch_failed", "Failed to fetch '{url}': {error}"),
+    (
+        "hash_mismatch",
+        "Integrity error: declared digest '{declared}' does not match computed digest '{computed}'",
+    ),
+    (
+        "merge_conflict",
+        "Merge conflict: {alg} hash differs from the already merged component with purl '{purl}'",
+    ),
+    (
+        "missing_advisory",
+        "Known advisory '{id}' is missing from vulnerabilities",
+    ),
+    ("advisory_query_failed", "Failed to query advisories: {error}"),
+    ("unknown_license", "Unknown SPDX license id '{id}'"),
+    (
+        "unknown_license_exception",
+        "Unknown SPDX license exception id '{id}'",
+    ),
+    (
+        "hash_conflict",
+        "{alg} digest differs from the digest declared in hashes[{index}]",
+    ),
+    (
+        "weak_hashes",
+        "{alg} is a weak hash algorithm and the component declares no stronger digest",
+    ),
+    ("invalid_uri", "Value is not a valid URI"),
+    (
+        "rating_score_range",
+        "Score {score} is outside of the range {min} to {max} of method {method}",
+    ),
+    (
+        "rating_severity_mismatch",
+        "Severity '{severity}' does not match the {method} score {score}, expected '{expected}'",
+    ),
+    (
+        "affected_version_or_range",
+        "Exactly one of 'version' or 'range' is required",
+    ),
+    (
+        "invalid_affected_status",
+        "Status '{status}' is invalid, must be one of affected, unaffected or unknown",
+    ),
+    (
+        "invalid_data_flow",
+        "Data flow '{flow}' is invalid, must be one of inbound, outbound, bi-directional or unknown",
+    ),
+    ("invalid_bom_link", "Value is not a valid BOM-Link"),
+    ("endpoint_scheme", "Endpoint uses the scheme '{scheme}' which is not allowed"),
+    ("blank_entry", "Entry is blank"),
+    ("plugin_failed", "Plugin '{plugin}' failed: {error}"),
+    ("pack_required_field", "Field is required by rule '{rule}'"),
+    (
+        "pack_pattern_mismatch",
+        "Value does not match the pattern of rule '{rule}'",
+    ),
+    ("pack_value_not_allowed", "Value is not allowed by rule '{rule}'"),
+    (
+        "timestamp_after_bom",
+        "Timestamp is after the BOM timestamp '{timestamp}'",
+    ),
+    (
+        "copyright_control_character",
+        "Copyright contains the control character {character}",
+    ),
+    ("copyright_blank", "Copyright is blank"),
+    (
+        "copyright_too_long",
+        "Copyright has {actual} bytes, at most {max} are expected",
+    ),
+    (
+        "copyright_placeholder",
+        "Copyright contains the template placeholder '{placeholder}'",
+    ),
+    (
+        "disallowed_hashes",
+        "Hash algorithm {alg} is not allowed and the component declares no digest of the allowed algorithms {allowed}",
+    ),
+    (
+        "confusable_name",
+        "Name mixes scripts, {character} looks like the Latin '{latin}'",
+    ),
+    (
+        "bidi_character",
+        "Value contains the bidirectional formatting character {character} at {position}",
+    ),
+    (
+        "control_character",
+        "Value contains the control character {character} at {position}",
+    ),
+    (
+        "replacement_character",
+        "Value contains a replacement character at {position}, likely a broken surrogate",
+    ),
+    (
+        "duplicate_contact",
+        "Contact '{email}' is listed more than once, at {indices}",
+    ),
+    ("duplicate_entry", "Entry '{value}' is listed more than once"),
+    (
+        "image_content_type",
+        "Url does not resolve to an image but to '{content_type}'",
+    ),
+    (
+        "image_query_failed",
+        "Failed to resolve the content type of the url: {error}",
+    ),
+    (
+        "assembly_self_reference",
+        "Component '{component}' contains itself, see '{path}'",
+    ),
+    (
+        "assembly_duplicate",
+        "Component '{component}' is listed again at '{path}'",
+    ),
+    (
+        "unauthenticated_trust_boundary",
+        "Service crosses a trust boundary without authentication",
+    ),
+    (
+        "missing_trust_flags",
+        "Service declares neither authenticated nor x-trust-boundary",
+    ),
+    (
+        "group_convention",
+        "Group does not follow the {type} convention '{pattern}'",
+    ),
+    (
+        "purl_repository_url_missing",
+        "Purl of the internal namespace '{namespace}' has no repository_url qualifier",
+    ),
+    (
+        "purl_qualifier_missing",
+        "Purl of type '{type}' has no '{qualifier}' qualifier",
+    ),
+    (
+        "purl_checksum_mismatch",
+        "Purl checksum qualifier contradicts the declared {alg} hash",
+    ),
+    (
+        "composition_missing_dependency",
+        "Composition claims complete dependencies, but '{ref}' has no dependency entry",
+    ),
+    ("invalid_timestamp", "Value is not a valid RFC 3339 timestamp"),
+    (
+        "timestamp_order",
+        "Timestamp '{field}' must not be before '{other}'",
+    ),
+    (
+        "rejected_missing_analysis",
+        "Rejected vulnerability requires the analysis field '{field}'",
+    ),
+    (
+        "tool_component_type",
+        "Tool component must be of type application or library",
+    ),
+    ("missing_tool_version", "Tool '{name}' has no version"),
+    ("confidence_range", "Confidence {confidence} is not within 0 and 1"),
+    (
+        "identity_confidence",
+        "Confidence {confidence} exceeds {expected}, the combined confidence of the methods",
+    ),
+    (
+        "invalid_license_acknowledgement",
+        "Acknowledgement '{acknowledgement}' must be declared or concluded",
+    ),
+    (
+        "unresolved_resource_reference",
+        "Resource reference '{ref}' does not match a bom-ref of the formula",
+    ),
+    ("invalid_trigger_type", "Unknown trigger type '{type}'"),
+    ("invalid_task_type", "Unknown task type '{type}'"),
+    (
+        "digest_mismatch",
+        "Document digest '{computed}' does not match expected digest '{expected}'",
+    ),
+    (
+        "unsupported_digest_algorithm",
+        "Hash algorithm {alg} is not supported to compute the document digest",
+    ),
+    ("missing_vulnerabilities", "VEX document has no vulnerabilities"),
+    (
+        "supplier_mismatch",
+        "Supplier '{supplier}' differs from supplier '{expected}' of the metadata component",
+    ),
+    (
+        "prefer_license_expression",
+        "Component lists {count} licenses, prefer a single license expression",
+    ),
+    ("prefer_purl", "Component is only identified by a cpe, prefer a purl"),
+    (
+        "identifier_name_mismatch",
+        "Product name '{name}' of the {identifier} differs from '{expected}' of the {reference}",
+    ),
+    (
+        "identifier_version_mismatch",
+        "Version '{version}' of the {identifier} differs from '{expected}' of the {reference}",
+    ),
+    (
+        "duplicate_license_text",
+        "License text is embedded in {count} components, adding {bytes} of at most {max} bytes, reference it by SPDX id or url instead",
+    ),
+    (
+        "ungrouped_properties",
+        "Properties of namespace '{namespace}' are not listed next to each other",
+    ),
+    ("missing_supplier", "Component has no supplier"),
+    ("missing_author", "Component has no author"),
+    ("missing_publisher", "Component has no publisher"),
+    ("missing_purl", "Component has no purl"),
+    (
+        "metadata_component_not_root",
+        "Metadata component must be the root of the dependency graph, but '{dependency}' depends on it",
+    ),
+    (
+        "metadata_component_duplicated",
+        "Metadata component is duplicated in components[{index}]",
+    ),
+    (
+        "name_mismatch",
+        "Name '{name}' does not match the name '{purl_name}' of the purl",
+    ),
+    (
+        "version_mismatch",
+        "Version '{version}' does not match the version '{purl_version}' of the purl",
+    ),
+];
+
+/// The German templates, keyed by template key.
+const DE: &[(&str, &str)] = &[
+    ("context_expected_actual", ": erwartet {expected}, erhalten {actual}"),
+    ("context_expected", ": erwartet {expected}"),
+    ("context_actual", ": erhalten {actual}"),
+    (
+        "input_too_large",
+        "Dokument ist zu groß, höchstens {max} Bytes sind erlaubt, aber es sind {actual}",
+    ),
+    ("invalid_json", "Dokument ist keine gültige JSON-BOM: {error}"),
+    ("read_failed", "Dokument konnte nicht gelesen werden: {error}"),
+    ("unsupported_version", "Spezifikationsversion '{version}' wird nicht unterstützt"),
+    ("missing_spec_version", "Dokument gibt keine Spezifikationsversion an"),
+    (
+        "unsupported_format",
+        "Dokumente im Format {format} werden von diesem Build nicht unterstützt",
+    ),
+    (
+        "reference_url_mismatch",
+        "Url sieht nicht nach einer Referenz vom Typ {type} aus: {reason}",
+    ),
+    ("invalid_xml", "Dokument ist keine wohlgeformte XML-BOM: {error}"),
+    ("purl_not_canonical", "Purl ist nicht in kanonischer Form"),
+    ("invalid_element", "Element kann nicht gelesen werden: {error}"),
+    (
+        "field_since",
+        "Feld '{field}' ist vor Spezifikationsversion {since} nicht erlaubt",
+    ),
+    (
+        "field_until",
+        "Feld '{field}' ist nach Spezifikationsversion {until} veraltet",
+    ),
+    (
+        "field_replaced",
+        "Feld '{field}' ist nach Spezifikationsversion {until} veraltet, stattdessen '{replacement}' verwenden",
+    ),
+    (
+        "field_deprecated",
+        "Feld '{field}' ist seit Spezifikationsversion {deprecated} veraltet",
+    ),
+    (
+        "field_deprecated_replaced",
+        "Feld '{field}' ist seit Spezifikationsversion {deprecated} veraltet, stattdessen '{replacement}' verwenden",
+    ),
+    (
+        "field_removed",
+        "Feld '{field}' wird seit Spezifikationsversion {removed} nicht mehr unterstützt",
+    ),
+    (
+        "too_long",
+        "Zeichenkette ist zu lang, höchstens {max} Zeichen sind erlaubt, aber es sind {actual}",
+    ),
+    ("tool_kind", "Werkzeug darf kein {kind} sein"),
+    ("hash_not_hex", "Hash-Inhalt muss eine Hex-Zeichenkette sein"),
+    (
+        "hash_length",
+        "Hash-Inhalt des Algorithmus {alg} muss {len} Hex-Ziffern haben",
+    ),
+    ("purl_scheme", "Purl muss mit 'pkg:' beginnen"),
+    ("purl_type_and_name", "Purl muss einen Typ und einen Namen enthalten"),
+    ("purl_invalid_type", "Purl-Typ '{type}' ist ungültig"),
+    ("purl_name", "Purl muss einen Namen enthalten"),
+    ("purl_qualifier_value", "Purl-Qualifier '{qualifier}' hat keinen Wert"),
+    (
+        "purl_percent_encoding",
+        "Purl-Bestandteil '{component}' enthält eine ungültige Prozent-Kodierung",
+    ),
+    ("purl_utf8", "Purl-Bestandteil '{component}' ist kein gültiges UTF-8"),
+    (
+        "serial_number_changed",
+        "Seriennummer hat sich zwischen den Revisionen von '{prev}' zu '{next}' geändert",
+    ),
+    (
+        "serial_number_missing",
+        "Seriennummer wird in beiden Revisionen benötigt, um die Abstammung nachzuverfolgen",
+    ),
+    (
+        "version_not_increased",
+        "Version muss zwischen Revisionen steigen, ging aber von {prev} zu {next}",
+    ),
+    (
+        "artifact_unreadable",
+        "Artefakt '{path}' konnte nicht gelesen werden: {error}",
+    ),
+    ("fetch_failed", "'{url}' konnte nicht abgerufen werden: {error}"),
+    (
+        "hash_mismatch",
+        "Integritätsfehler: angegebener Digest '{declared}' stimmt nicht mit berechnetem Digest '{computed}' überein",
+    ),
+    (
+        "merge_conflict",
+        "Merge-Konflikt: {alg}-Hash unterscheidet sich von der bereits zusammengeführten Komponente mit Purl '{purl}'",
+    ),
+    (
+        "missing_advisory",
+        "Bekanntes Advisory '{id}' fehlt in den Schwachstellen",
+    ),
+    (
+        "advisory_query_failed",
+        "Abfrage der Advisories fehlgeschlagen: {error}",
+    ),
+    ("unknown_license", "Unbekannte SPDX-Lizenz-ID '{id}'"),
+    (
+        "unknown_license_exception",
+        "Unbekannte SPDX-Lizenzausnahme-ID '{id}'",
+    ),
+    (
+        "hash_conflict",
+        "{alg}-Digest unterscheidet sich vom Digest in hashes[{index}]",
+    ),
+    (
+        "weak_hashes",
+        "{alg} ist ein schwacher Hash-Algorithmus und die Komponente gibt keinen stärkeren Digest an",
+    ),
+    ("invalid_uri", "Wert ist keine gültige URI"),
+    (
+        "rating_score_range",
+        "Bewertung {score} liegt außerhalb des Bereichs {min} bis {max} der Methode {method}",
+    ),
+    (
+        "rating_severity_mismatch",
+        "Schweregrad '{severity}' passt nicht zur {method}-Bewertung {score}, erwartet '{expected}'",
+    ),
+    (
+        "affected_version_or_range",
+        "Genau eines von 'version' oder 'range' ist erforderlich",
+    ),
+    (
+        "invalid_affected_status",
+        "Status '{status}' ist ungültig, erlaubt sind affected, unaffected oder unknown",
+    ),
+    (
+        "invalid_data_flow",
+        "Datenfluss '{flow}' ist ungültig, erlaubt sind inbound, outbound, bi-directional oder unknown",
+    ),
+    ("invalid_bom_link", "Wert ist kein gültiger BOM-Link"),
+    (
+        "endpoint_scheme",
+        "Endpunkt verwendet das nicht erlaubte Schema '{scheme}'",
+    ),
+    ("blank_entry", "Eintrag ist leer"),
+    ("plugin_failed", "Plugin '{plugin}' ist fehlgeschlagen: {error}"),
+    ("pack_required_field", "Feld ist laut Regel '{rule}' erforderlich"),
+    (
+        "pack_pattern_mismatch",
+        "Wert entspricht nicht dem Muster der Regel '{rule}'",
+    ),
+    ("pack_value_not_allowed", "Wert ist laut Regel '{rule}' nicht erlaubt"),
+    (
+        "timestamp_after_bom",
+        "Zeitstempel liegt nach dem BOM-Zeitstempel '{timestamp}'",
+    ),
+    (
+        "copyright_control_character",
+        "Copyright enthält das Steuerzeichen {character}",
+    ),
+    ("copyright_blank", "Copyright ist leer"),
+    (
+        "copyright_too_long",
+        "Copyright hat {actual} Bytes, erwartet sind höchstens {max}",
+    ),
+    (
+        "copyright_placeholder",
+        "Copyright enthält den Platzhalter '{placeholder}' einer Vorlage",
+    ),
+    (
+        "disallowed_hashes",
+        "Hash-Algorithmus {alg} ist nicht erlaubt und die Komponente gibt keinen Hashwert der erlaubten Algorithmen {allowed} an",
+    ),
+    (
+        "confusable_name",
+        "Name mischt Schriften, {character} sieht aus wie das lateinische '{latin}'",
+    ),
+    (
+        "bidi_character",
+        "Wert enthält das bidirektionale Steuerzeichen {character} an Position {position}",
+    ),
+    (
+        "control_character",
+        "Wert enthält das Steuerzeichen {character} an Position {position}",
+    ),
+    (
+        "replacement_character",
+        "Wert enthält ein Ersatzzeichen an Position {position}, vermutlich ein defektes Surrogat",
+    ),
+    (
+        "duplicate_contact",
+        "Kontakt '{email}' ist mehrfach aufgeführt, an {indices}",
+    ),
+    ("duplicate_entry", "Eintrag '{value}' ist mehrfach aufgeführt"),
+    (
+        "image_content_type",
+        "Url verweist nicht auf ein Bild, sondern auf '{content_type}'",
+    ),
+    (
+        "image_query_failed",
+        "Inhaltstyp der Url konnte nicht ermittelt werden: {error}",
+    ),
+    (
+        "assembly_self_reference",
+        "Komponente '{component}' enthält sich selbst, siehe '{path}'",
+    ),
+    (
+        "assembly_duplicate",
+        "Komponente '{component}' ist zusätzlich unter '{path}' aufgeführt",
+    ),
+    (
+        "unauthenticated_trust_boundary",
+        "Dienst überschreitet eine Vertrauensgrenze ohne Authentifizierung",
+    ),
+    (
+        "missing_trust_flags",
+        "Dienst gibt weder authenticated noch x-trust-boundary an",
+    ),
+    (
+        "group_convention",
+        "Gruppe folgt nicht der Konvention '{pattern}' für {type}",
+    ),
+    (
+        "purl_repository_url_missing",
+        "Purl des internen Namensraums '{namespace}' hat keinen repository_url-Qualifier",
+    ),
+    (
+        "purl_qualifier_missing",
+        "Purl vom Typ '{type}' hat keinen Qualifier '{qualifier}'",
+    ),
+    (
+        "purl_checksum_mismatch",
+        "Checksum-Qualifier der Purl widerspricht dem angegebenen {alg}-Hash",
+    ),
+    (
+        "composition_missing_dependency",
+        "Komposition gibt vollständige Abhängigkeiten an, aber '{ref}' hat keinen Abhängigkeitseintrag",
+    ),
+    ("invalid_timestamp", "Wert ist kein gültiger RFC-3339-Zeitstempel"),
+    (
+        "timestamp_order",
+        "Zeitstempel '{field}' darf nicht vor '{other}' liegen",
+    ),
+    (
+        "rejected_missing_analysis",
+        "Zurückgewiesene Schwachstelle benötigt das Analysefeld '{field}'",
+    ),
+    (
+        "tool_component_type",
+        "Werkzeugkomponente muss vom Typ application oder library sein",
+    ),
+    ("missing_tool_version", "Werkzeug '{name}' hat keine Version"),
+    ("confidence_range", "Konfidenz {confidence} liegt nicht zwischen 0 und 1"),
+    (
+        "identity_confidence",
+        "Konfidenz {confidence} übersteigt {expected}, die kombinierte Konfidenz der Methoden",
+    ),
+    (
+        "invalid_license_acknowledgement",
+        "Anerkennung '{acknowledgement}' muss declared oder concluded sein",
+    ),
+    (
+        "unresolved_resource_reference",
+        "Ressourcenreferenz '{ref}' verweist auf keine bom-ref der Formel",
+    ),
+    ("invalid_trigger_type", "Unbekannter Auslösertyp '{type}'"),
+    ("invalid_task_type", "Unbekannter Aufgabentyp '{type}'"),
+    (
+        "digest_mismatch",
+        "Dokument-Digest '{computed}' stimmt nicht mit erwartetem Digest '{expected}' überein",
+    ),
+    (
+        "unsupported_digest_algorithm",
+        "Hash-Algorithmus {alg} wird für den Dokument-Digest nicht unterstützt",
+    ),
+    ("missing_vulnerabilities", "VEX-Dokument hat keine Schwachstellen"),
+    (
+        "supplier_mismatch",
+        "Lieferant '{supplier}' weicht vom Lieferanten '{expected}' der Metadaten-Komponente ab",
+    ),
+    (
+        "prefer_license_expression",
+        "Komponente listet {count} Lizenzen, ein einzelner Lizenzausdruck ist vorzuziehen",
+    ),
+    (
+        "prefer_purl",
+        "Komponente ist nur durch eine CPE identifiziert, eine Purl ist vorzuziehen",
+    ),
+    (
+        "identifier_name_mismatch",
+        "Produktname '{name}' der {identifier} weicht von '{expected}' der {reference} ab",
+    ),
+    (
+        "identifier_version_mismatch",
+        "Version '{version}' der {identifier} weicht von '{expected}' der {reference} ab",
+    ),
+    (
+        "duplicate_license_text",
+        "Lizenztext ist in {count} Komponenten eingebettet und belegt {bytes} von höchstens {max} Bytes, stattdessen per SPDX-ID oder Url referenzieren",
+    ),
+    (
+        "ungrouped_properties",
+        "Eigenschaften des Namensraums '{namespace}' stehen nicht beieinander",
+    ),
+    ("missing_supplier", "Komponente hat keinen Lieferanten"),
+    ("missing_author", "Komponente hat keinen Autor"),
+    ("missing_publisher", "Komponente hat keinen Herausgeber"),
+    ("missing_purl", "Komponente hat keine Purl"),
+    (
+        "metadata_component_not_root",
+        "Metadaten-Komponente muss die Wurzel des Abhängigkeitsgraphen sein, aber '{dependency}' hängt von ihr ab",
+    ),
+    (
+        "metadata_component_duplicated",
+        "Metadaten-Komponente ist in components[{index}] doppelt vorhanden",
+    ),
+    (
+        "name_mismatch",
+        "Name '{name}' stimmt nicht mit dem Namen '{purl_name}' der Purl überein",
+    ),
+    (
+        "version_mismatch",
+        "Version '{version}' stimmt nicht mit der Version '{purl_version}' der Purl überein",
+    ),
+];
+
+/// Returns the template for the given key in the given locale, falling back to English.
+pub fn template(locale: Locale, key: &str) -> Option<&'static str> {
+    let find = |templates: &'static [(&'static str, &'static str)]| {
+        templates
+            .iter()
+            .find(|(template_key, _)| *template_key == key)
+            .map(|(_, template)| *template)
+    };
+
+    find(locale.templates()).or_else(|| find(EN))
+}
+
+/// A message stored as template key plus parameters, e.g. `too_long { max: 4, actual: 12 }`.
+///
+/// The message is only rendered when displayed. Keys without a registered template, e.g. the
+/// free text messages of custom rules, are rendered as they are.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Message {
+    pub key: String,
+    /// The parameters referenced by the template, in order of insertion.
+    pub params: Vec<(String, String)>,
+}
+
+impl Message {
+    /// Creates a message for the given template key without parameters.
+    pub fn new<T: ToString>(key: T) -> Self {
+        Self {
+            key: key.to_string(),
+            params: Vec::new(),
+        }
+    }
+
+    /// Adds a parameter referenced by the template.
+    pub fn with_param<N: ToString, V: ToString>(mut self, name: N, value: V) -> Self {
+        self.params.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    /// Returns the value of the given parameter.
+    pub fn param(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(param, _)| param == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Renders the given template, replacing all `{name}` placeholders with the parameters.
+    pub fn render(&self, template: &str) -> String {
+        self.params
+            .iter()
+            .fold(template.to_string(), |rendered, (name, value)| {
+                rendered.replace(&format!("{{{}}}", name), value)
+            })
+    }
+
+    /// Renders the message in the given locale.
+    pub fn localize(&self, locale: Locale) -> String {
+        match template(locale, &self.key) {
+            Some(template) => self.render(template),
+            None => self.key.clone(),
+        }
+    }
+}
+
+impl fmt::Display for Message {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.localize(Locale::En))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Locale, Message};
+
+    #[test]
+    fn render_template_with_params() {
+        let message = Message::new("too_long")
+            .with_param("max", 256)
+            .with_param("actual", 1053);
+
+        assert_eq!(
+            message.to_string(),
+            "String is too long, at most 256 characters are allowed but got 1053"
+        );
+        assert_eq!(message.param("max"), Some("256"));
+    }
+
+    #[test]
+    fn render_free_text() {
+        assert_eq!(
+            Message::new("serial number is required").to_string(),
+            "serial number is required"
+        );
+    }
+
+    #[test]
+    fn localize_message() {
+        let message = Message::new("missing_supplier");
+
+        assert_eq!(
+            message.localize(Locale::De),
+            "Komponente hat keinen Lieferanten"
+        );
+        assert_eq!(message.localize(Locale::En), "Component has no supplier");
+        assert_eq!(Locale::from_tag("de-AT"), Some(Locale::De));
+        assert_eq!(Locale::from_tag("fr"), None);
+    }
+}
@@ -0,0 +1,192 @@
+//! Metrics about the validated documents, recorded through the [`metrics`] facade.
+//!
+//! Services sharing a validator install a recorder, e.g. a Prometheus exporter, and call
+//! [`describe`] once. Without an installed recorder recording is a no-op.
+use std::time::Duration;
+
+use metrics::{counter, describe_counter, describe_histogram, histogram, Unit};
+
+use crate::validation::{Severity, ValidationResult};
+
+/// Counts validated documents, labeled with the `result`, either `valid` or `invalid`.
+pub const DOCUMENTS_VALIDATED: &str = "cyclonedx_validation_documents_total";
+/// Counts findings, labeled with the `severity`, suppressed findings are not counted.
+pub const FINDINGS: &str = "cyclonedx_validation_findings_total";
+/// The duration of a single validation.
+pub const VALIDATION_DURATION: &str = "cyclonedx_validation_duration_seconds";
+/// The size of a validated raw document.
+pub const DOCUMENT_SIZE: &str = "cyclonedx_validation_document_size_bytes";
+
+/// Registers units and descriptions of all metrics with the installed recorder.
+pub fn describe() {
+    describe_counter!(
+        DOCUMENTS_VALIDATED,
+        Unit::Count,
+        "Number of validated documents"
+    );
+    describe_counter!(FINDINGS, Unit::Count, "Number of findings by severity");
+    describe_histogram!(
+        VALIDATION_DURATION,
+        Unit::Seconds,
+        "Duration of the validation of a document"
+    );
+    describe_histogram!(
+        DOCUMENT_SIZE,
+        Unit::Bytes,
+        "Size of the validated raw documents"
+    );
+}
+
+/// Records the outcome of the validation of a single document.
+pub(crate) fn record_validation(result: &ValidationResult, duration: Duration) {
+    histogram!(VALIDATION_DURATION).record(duration.as_secs_f64());
+
+    let errors = match result {
+        Ok(()) => {
+            counter!(DOCUMENTS_VALIDATED, "result" => "valid").increment(1);
+            return;
+        }
+        Err(errors) => errors,
+    };
+
+    let label = if errors.has_unsuppressed() {
+        "invalid"
+    } else {
+        "valid"
+    };
+    counter!(DOCUMENTS_VALIDATED, "result" => label).increment(1);
+
+    for (_, error) in errors.errors() {
+        if error.suppressed {
+            continue;
+        }
+        let severity = match error.severity {
+            Severity::Info => "info",
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        };
+        counter!(FINDINGS, "severity" => severity).increment(1);
+    }
+}
+
+/// Records the size of a raw document.
+pub(crate) fn record_document_size(len: usize) {
+    histogram!(DOCUMENT_SIZE).record(len as f64);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    };
+
+    use metrics::{
+        Counter, CounterFn, Gauge, Histogram, HistogramFn, Key, KeyName, Metadata, Recorder,
+        SharedString, Unit,
+    };
+
+    use crate::{
+        options::ValidationOptions, validate_bytes, validation::SpecVersion, Bom, Component,
+        ComponentType,
+    };
+
+    use super::{DOCUMENTS_VALIDATED, DOCUMENT_SIZE, FINDINGS, VALIDATION_DURATION};
+
+    struct Value(AtomicU64);
+
+    impl CounterFn for Value {
+        fn increment(&self, value: u64) {
+            self.0.fetch_add(value, Ordering::Relaxed);
+        }
+
+        fn absolute(&self, value: u64) {
+            self.0.store(value, Ordering::Relaxed);
+        }
+    }
+
+    impl HistogramFn for Value {
+        fn record(&self, _value: f64) {
+            self.0.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Keeps the value of every registered metric, histograms count their samples.
+    #[derive(Default)]
+    struct TestRecorder {
+        values: Mutex<Vec<(Key, Arc<Value>)>>,
+    }
+
+    impl TestRecorder {
+        fn value(&self, name: &str, label: Option<(&str, &str)>) -> u64 {
+            self.values
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|(key, _)| key.name() == name)
+                .filter(|(key, _)| {
+                    label.is_none_or(|(label, value)| {
+                        key.labels()
+                            .any(|other| other.key() == label && other.value() == value)
+                    })
+                })
+                .map(|(_, value)| value.0.load(Ordering::Relaxed))
+                .sum()
+        }
+
+        fn register(&self, key: &Key) -> Arc<Value> {
+            let mut values = self.values.lock().unwrap();
+            if let Some((_, value)) = values.iter().find(|(other, _)| other == key) {
+                return value.clone();
+            }
+            let value = Arc::new(Value(AtomicU64::new(0)));
+            values.push((key.clone(), value.clone()));
+            value
+        }
+    }
+
+    impl Recorder for TestRecorder {
+        fn describe_counter(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+        fn describe_gauge(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+        fn describe_histogram(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+        fn register_counter(&self, key: &Key, _: &Metadata<'_>) -> Counter {
+            Counter::from_arc(self.register(key))
+        }
+
+        fn register_gauge(&self, _: &Key, _: &Metadata<'_>) -> Gauge {
+            Gauge::noop()
+        }
+
+        fn register_histogram(&self, key: &Key, _: &Metadata<'_>) -> Histogram {
+            Histogram::from_arc(self.register(key))
+        }
+    }
+
+    #[test]
+    fn records_documents_and_findings() {
+        let recorder = TestRecorder::default();
+        let options = ValidationOptions::new();
+        let invalid = r#"{ "components": [{ "type": "library", "name": "a", "purl": "a" }] }"#;
+
+        metrics::with_local_recorder(&recorder, || {
+            assert!(validate_bytes(SpecVersion::V1_5, invalid.as_bytes(), &options).is_err());
+            let bom = Bom {
+                components: Some(vec![Component::new(ComponentType::Library, "a")]),
+                ..Default::default()
+            };
+            assert!(crate::validate_bom(SpecVersion::V1_5, &bom, &options).is_ok());
+        });
+
+        assert_eq!(recorder.value(DOCUMENTS_VALIDATED, None), 2);
+        assert_eq!(
+            recorder.value(DOCUMENTS_VALIDATED, Some(("result", "invalid"))),
+            1
+        );
+        assert_eq!(recorder.value(FINDINGS, Some(("severity", "error"))), 1);
+        assert_eq!(recorder.value(VALIDATION_DURATION, None), 2);
+        assert_eq!(recorder.value(DOCUMENT_SIZE, None), 1);
+    }
+}
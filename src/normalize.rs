@@ -0,0 +1,266 @@
+use uuid::Uuid;
+
+use crate::{purl::Purl, Bom, Component, Property};
+
+/// A single change made by [`normalize`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fix {
+    /// The path of the changed field, e.g. `components[2].purl`.
+    pub path: String,
+    /// Describes the change, e.g. the value before and after.
+    pub message: String,
+}
+
+/// Lists every change made by [`normalize`], in the order they were applied.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FixReport {
+    pub fixes: Vec<Fix>,
+}
+
+impl FixReport {
+    /// Returns `true` if the document did not need any fixes.
+    pub fn is_empty(&self) -> bool {
+        self.fixes.is_empty()
+    }
+
+    fn add<P: ToString, M: ToString>(&mut self, path: P, message: M) {
+        self.fixes.push(Fix {
+            path: path.to_string(),
+            message: message.to_string(),
+        });
+    }
+}
+
+/// Repairs mechanically fixable issues of the document in place.
+///
+/// * trims surrounding whitespace of names, versions, purls and URLs
+/// * lowercases the hex content of hashes
+/// * canonicalizes purls, e.g. sorts qualifiers and normalizes the percent-encoding
+/// * generates a random `urn:uuid` serial number if missing
+/// * sorts properties by name
+///
+/// Components are fixed wherever they appear, i.e. also nested components and the components
+/// of `metadata.tools`. Every change is listed in the returned [`FixReport`], so callers can
+/// decide whether to write the fixed document back.
+pub fn normalize(bom: &mut Bom) -> FixReport {
+    let mut report = FixReport::default();
+
+    match &mut bom.serial_number {
+        Some(serial_number) => trim(&mut report, "serial_number", serial_number),
+        None => {
+            let serial_number = format!("urn:uuid:{}", Uuid::new_v4());
+            report.add(
+                "serial_number",
+                format!("Generated serial number '{}'", serial_number),
+            );
+            bom.serial_number = Some(serial_number);
+        }
+    }
+
+    if let Some(meta_data) = &mut bom.meta_data {
+        for (index, component) in meta_data.tool_components.iter_mut().flatten().enumerate() {
+            normalize_component(
+                &mut report,
+                &format!("meta_data.tool_components[{}]", index),
+                component,
+            );
+        }
+        if let Some(component) = &mut meta_data.component {
+            normalize_component(&mut report, "meta_data.component", component);
+        }
+    }
+    for (index, component) in bom.components.iter_mut().flatten().enumerate() {
+        normalize_component(&mut report, &format!("components[{}]", index), component);
+    }
+    if let Some(properties) = &mut bom.properties {
+        sort_properties(&mut report, "properties", properties);
+    }
+
+    report
+}
+
+fn normalize_component(report: &mut FixReport, path: &str, component: &mut Component) {
+    trim(report, format!("{}.name", path), &mut component.name);
+    if let Some(version) = &mut component.version {
+        trim(report, format!("{}.version", path), version);
+    }
+    if let Some(author) = &mut component.author {
+        trim(report, format!("{}.author", path), author);
+    }
+    if let Some(publisher) = &mut component.publisher {
+        trim(report, format!("{}.publisher", path), publisher);
+    }
+    if let Some(purl) = &mut component.purl {
+        let purl_path = format!("{}.purl", path);
+        trim(report, &purl_path, purl);
+        if let Ok(canonical) = Purl::parse(purl).map(|parsed| parsed.to_string()) {
+            if canonical != *purl {
+                report.add(
+                    purl_path,
+                    format!("Canonicalized purl '{}' to '{}'", purl, canonical),
+                );
+                *purl = canonical;
+            }
+        }
+    }
+    for (index, hash) in component.hashes.iter_mut().flatten().enumerate() {
+        let content = hash.content.trim().to_ascii_lowercase();
+        if content != hash.content {
+            report.add(
+                format!("{}.hashes[{}].content", path, index),
+                format!(
+                    "Normalized hash content '{}' to '{}'",
+                    hash.content, content
+                ),
+            );
+            hash.content = content;
+        }
+    }
+    for (index, reference) in component
+        .external_references
+        .iter_mut()
+        .flatten()
+        .enumerate()
+    {
+        trim(
+            report,
+            format!("{}.external_references[{}].url", path, index),
+            &mut reference.url,
+        );
+    }
+    if let Some(properties) = &mut component.properties {
+        sort_properties(report, format!("{}.properties", path), properties);
+    }
+    for (index, child) in component.components.iter_mut().flatten().enumerate() {
+        normalize_component(report, &format!("{}.components[{}]", path, index), child);
+    }
+}
+
+fn trim<P: ToString>(report: &mut FixReport, path: P, value: &mut String) {
+    let trimmed = value.trim();
+    if trimmed.len() != value.len() {
+        report.add(path, format!("Trimmed whitespace of '{}'", value));
+        *value = trimmed.to_string();
+    }
+}
+
+fn sort_properties<P: ToString>(report: &mut FixReport, path: P, properties: &mut [Property]) {
+    if !properties
+        .windows(2)
+        .all(|pair| pair[0].name <= pair[1].name)
+    {
+        properties.sort_by(|lhs, rhs| lhs.name.cmp(&rhs.name));
+        report.add(path, "Sorted properties by name");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Bom;
+
+    use super::normalize;
+
+    const INPUT: &str = r#"{
+        "serialNumber": "urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79",
+        "components": [
+            {
+                "type": "library",
+                "name": " serde ",
+                "purl": "PKG:cargo/serde@1.0.196?b=2&a=1",
+                "hashes": [{ "alg": "SHA-1", "content": "ABCDEF0000000000000000000000000000000000" }],
+                "properties": [{ "name": "z" }, { "name": "a" }]
+            }
+        ]
+    }"#;
+
+    #[test]
+    fn normalize_fixes_and_reports() {
+        let mut bom = Bom::parse_json(INPUT).expect("Failed to parse BOM");
+
+        let report = normalize(&mut bom);
+
+        let paths = report
+            .fixes
+            .iter()
+            .map(|fix| fix.path.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            paths,
+            vec![
+                "components[0].name",
+                "components[0].purl",
+                "components[0].hashes[0].content",
+                "components[0].properties",
+            ]
+        );
+
+        let component = &bom.components.expect("Expected components")[0];
+        assert_eq!(component.name, "serde");
+        assert_eq!(
+            component.purl.as_deref(),
+            Some("pkg:cargo/serde@1.0.196?a=1&b=2")
+        );
+        assert_eq!(
+            component.hashes.as_ref().expect("Expected hashes")[0].content,
+            "abcdef0000000000000000000000000000000000"
+        );
+    }
+
+    #[test]
+    fn normalize_generates_serial_number() {
+        let mut bom = Bom::default();
+
+        let report = normalize(&mut bom);
+
+        assert_eq!(report.fixes.len(), 1);
+        assert!(bom
+            .serial_number
+            .is_some_and(|serial_number| serial_number.starts_with("urn:uuid:")));
+    }
+
+    #[test]
+    fn normalize_nested_and_tool_components() {
+        let mut bom = Bom::parse_json(
+            r#"{
+                "serialNumber": "urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79",
+                "metadata": { "tools": { "components": [
+                    { "type": "application", "name": "cargo-cyclonedx ", "version": " 0.5.0" }
+                ] } },
+                "components": [{ "type": "library", "name": "app", "components": [
+                    { "type": "library", "name": "zlib", "purl": "PKG:generic/zlib@1.3" }
+                ] }]
+            }"#,
+        )
+        .expect("Failed to parse BOM");
+
+        let report = normalize(&mut bom);
+
+        let paths = report
+            .fixes
+            .iter()
+            .map(|fix| fix.path.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            paths,
+            vec![
+                "meta_data.tool_components[0].name",
+                "meta_data.tool_components[0].version",
+                "components[0].components[0].purl",
+            ]
+        );
+        let components = bom.components.expect("Expected components");
+        let nested = &components[0]
+            .components
+            .as_ref()
+            .expect("Expected components")[0];
+        assert_eq!(nested.purl.as_deref(), Some("pkg:generic/zlib@1.3"));
+    }
+
+    #[test]
+    fn normalize_is_idempotent() {
+        let mut bom = Bom::parse_json(INPUT).expect("Failed to parse BOM");
+
+        normalize(&mut bom);
+        assert!(normalize(&mut bom).is_empty());
+    }
+}
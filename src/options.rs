@@ -0,0 +1,349 @@
+use core::fmt;
+
+#[cfg(feature = "std")]
+use crate::config::Config;
+#[cfg(feature = "async")]
+use crate::enrichment::EnrichmentHook;
+use crate::{
+    map::IndexMap, message::Locale, paths::PathPattern, prelude::*, rules::Rule,
+    suppress::Suppression, validation::Severity, DocumentKind, HashAlgorithm,
+};
+
+/// Selects which optional, policy driven checks are run in addition to the specification rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Profile {
+    /// Only checks what the CycloneDX specification requires.
+    #[default]
+    Default,
+    /// Additionally warns about fields most procurement policies require, e.g. component suppliers.
+    Strict,
+}
+
+/// Selects how strictly timestamps are parsed, e.g. `metadata.timestamp` or the `created`
+/// timestamp of a vulnerability.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimestampTolerance {
+    /// Only accepts RFC 3339 timestamps, as required by the specification.
+    #[default]
+    Strict,
+    /// Additionally accepts ISO 8601 timestamps some generators emit, e.g. without timezone,
+    /// which is then assumed to be UTC, with a space instead of `T` or an offset without `:`.
+    Lenient,
+}
+
+/// Options to configure how a [`Bom`](crate::Bom) gets validated.
+#[derive(Default)]
+pub struct ValidationOptions {
+    /// The profile selecting the optional built-in rules.
+    profile: Profile,
+    /// How strictly timestamps are parsed.
+    timestamp_tolerance: TimestampTolerance,
+    /// The hash algorithms components must declare at least one digest of, if set.
+    allowed_hash_algorithms: Option<Vec<HashAlgorithm>>,
+    /// The kind of the validated documents, detected per document if not set.
+    document_kind: Option<DocumentKind>,
+    /// Only the subtrees matched by one of these patterns are validated, if any are set.
+    include_paths: Vec<PathPattern>,
+    /// The subtrees matched by one of these patterns are not validated.
+    exclude_paths: Vec<PathPattern>,
+    /// The ids of the rules that are not run.
+    disabled_rules: Vec<String>,
+    /// The ids of the opt-in lints that are run.
+    enabled_rules: Vec<String>,
+    /// Whether the report records why checks or parts of the document were not checked.
+    record_skips: bool,
+    /// List of custom rules run after the built-in validation.
+    rules: Vec<Box<dyn Rule>>,
+    /// List of findings that are reported as suppressed.
+    suppressions: Vec<Suppression>,
+    /// Maps the code of a finding to the severity it is reported with.
+    severities: IndexMap<String, Severity>,
+    /// The language validation messages are rendered in.
+    locale: Locale,
+    /// List of enrichment hooks that may consult external services.
+    #[cfg(feature = "async")]
+    hooks: Vec<Box<dyn EnrichmentHook>>,
+    /// Whether enrichment hooks are allowed to run, validation is strictly offline by default.
+    #[cfg(feature = "async")]
+    online: bool,
+}
+
+impl ValidationOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Selects the [`Profile`] used for the built-in rules.
+    pub fn profile(mut self, profile: Profile) -> Self {
+        self.profile = profile;
+        self
+    }
+
+    /// Returns the selected [`Profile`].
+    pub fn selected_profile(&self) -> Profile {
+        self.profile
+    }
+
+    /// Selects the [`TimestampTolerance`] used to parse timestamps.
+    pub fn timestamp_tolerance(mut self, tolerance: TimestampTolerance) -> Self {
+        self.timestamp_tolerance = tolerance;
+        self
+    }
+
+    /// Returns the selected [`TimestampTolerance`].
+    pub fn selected_timestamp_tolerance(&self) -> TimestampTolerance {
+        self.timestamp_tolerance
+    }
+
+    /// Requires components declaring digests to declare at least one of the given algorithms,
+    /// e.g. `[Sha256, Sha384, Sha512]`, components only declaring others are rejected.
+    pub fn allow_hash_algorithms<I>(mut self, algorithms: I) -> Self
+    where
+        I: IntoIterator<Item = HashAlgorithm>,
+    {
+        self.allowed_hash_algorithms = Some(algorithms.into_iter().collect());
+        self
+    }
+
+    /// Returns the allowed hash algorithms, `None` if all algorithms are allowed.
+    pub fn allowed_hash_algorithms(&self) -> Option<&[HashAlgorithm]> {
+        self.allowed_hash_algorithms.as_deref()
+    }
+
+    /// Validates all documents as the given [`DocumentKind`] instead of detecting it, e.g. to
+    /// validate a VEX document that also lists the affected components.
+    pub fn document_kind(mut self, kind: DocumentKind) -> Self {
+        self.document_kind = Some(kind);
+        self
+    }
+
+    /// Returns the selected [`DocumentKind`], if any.
+    pub fn selected_document_kind(&self) -> Option<DocumentKind> {
+        self.document_kind
+    }
+
+    /// Only validates the subtrees matched by the given patterns, e.g. `components[*].licenses`,
+    /// see [`PathPattern`]. Elements outside of them are not traversed, findings of rules
+    /// outside of them are dropped.
+    pub fn include_paths<I>(mut self, patterns: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        self.include_paths.extend(
+            patterns
+                .into_iter()
+                .map(|pattern| PathPattern::parse(pattern.as_ref())),
+        );
+        self
+    }
+
+    /// Skips the subtrees matched by the given patterns, e.g. `components[*].evidence`.
+    pub fn exclude_paths<I>(mut self, patterns: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        self.exclude_paths.extend(
+            patterns
+                .into_iter()
+                .map(|pattern| PathPattern::parse(pattern.as_ref())),
+        );
+        self
+    }
+
+    /// Returns the patterns of the included paths.
+    pub fn included_paths(&self) -> &[PathPattern] {
+        &self.include_paths
+    }
+
+    /// Returns the patterns of the excluded paths.
+    pub fn excluded_paths(&self) -> &[PathPattern] {
+        &self.exclude_paths
+    }
+
+    /// Returns `true` if only parts of the documents are validated.
+    pub fn selects_paths(&self) -> bool {
+        !self.include_paths.is_empty() || !self.exclude_paths.is_empty()
+    }
+
+    /// Returns `true` if findings at the given path are reported.
+    pub fn is_path_selected(&self, path: &str) -> bool {
+        !self.is_path_excluded(path)
+            && (self.include_paths.is_empty()
+                || self
+                    .include_paths
+                    .iter()
+                    .any(|pattern| pattern.contains(path)))
+    }
+
+    /// Returns `true` if the element at the given path is validated, either because it is
+    /// selected or because a selected subtree lies below it.
+    pub fn is_path_traversed(&self, path: &str) -> bool {
+        !self.is_path_excluded(path)
+            && (self.include_paths.is_empty()
+                || self
+                    .include_paths
+                    .iter()
+                    .any(|pattern| pattern.contains(path) || pattern.is_below(path)))
+    }
+
+    fn is_path_excluded(&self, path: &str) -> bool {
+        self.exclude_paths
+            .iter()
+            .any(|pattern| pattern.contains(path))
+    }
+
+    /// Disables the rule with the given id, e.g. `weak-hashes`, it is reported as skipped.
+    pub fn disable_rule<T: ToString>(mut self, id: T) -> Self {
+        self.disabled_rules.push(id.to_string());
+        self
+    }
+
+    /// Returns `true` if the rule with the given id is disabled.
+    pub fn is_rule_disabled(&self, id: &str) -> bool {
+        self.disabled_rules.iter().any(|disabled| disabled == id)
+    }
+
+    /// Enables the opt-in lint with the given id, e.g. `confusable-names`, see
+    /// [`opt_in_lints`](crate::lint::opt_in_lints).
+    pub fn enable_rule<T: ToString>(mut self, id: T) -> Self {
+        self.enabled_rules.push(id.to_string());
+        self
+    }
+
+    /// Returns `true` if the opt-in lint with the given id is enabled.
+    pub fn is_rule_enabled(&self, id: &str) -> bool {
+        self.enabled_rules.iter().any(|enabled| enabled == id)
+    }
+
+    /// Records in the [`ValidationReport`](crate::report::ValidationReport) why checks or
+    /// parts of the document were not checked, so auditors can tell them from passed checks.
+    pub fn record_skips(mut self, record_skips: bool) -> Self {
+        self.record_skips = record_skips;
+        self
+    }
+
+    /// Returns `true` if skips are recorded in the report.
+    pub fn records_skips(&self) -> bool {
+        self.record_skips
+    }
+
+    /// Registers a custom [`Rule`] that is run for every validated document.
+    pub fn add_rule<R: Rule + 'static>(mut self, rule: R) -> Self {
+        self.rules.push(Box::new(rule));
+        self
+    }
+
+    /// Returns all registered custom rules, in order of registration.
+    pub fn rules(&self) -> &[Box<dyn Rule>] {
+        &self.rules
+    }
+
+    /// Suppresses the findings matched by the given [`Suppression`].
+    pub fn suppress(mut self, suppression: Suppression) -> Self {
+        self.suppressions.push(suppression);
+        self
+    }
+
+    /// Returns all configured suppressions.
+    pub fn suppressions(&self) -> &[Suppression] {
+        &self.suppressions
+    }
+
+    /// Reports all findings with the given code with the given [`Severity`], e.g. to treat
+    /// `missing-supplier` as an error. Overrides are applied after all rules have run.
+    pub fn severity<T: ToString>(mut self, code: T, severity: Severity) -> Self {
+        self.severities.insert(code.to_string(), severity);
+        self
+    }
+
+    /// Returns the overridden severity for the given code, if any.
+    pub fn severity_override(&self, code: &str) -> Option<Severity> {
+        self.severities.get(code).copied()
+    }
+
+    /// Selects the [`Locale`] validation messages are rendered in.
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Returns the selected [`Locale`].
+    pub fn selected_locale(&self) -> Locale {
+        self.locale
+    }
+
+    /// Applies the settings of a [`Config`], e.g. loaded from a file.
+    #[cfg(feature = "std")]
+    pub fn with_config(mut self, config: &Config) -> Self {
+        self.suppressions.extend(config.suppressions());
+        self.severities.extend(
+            config
+                .severity
+                .iter()
+                .map(|(code, severity)| (code.clone(), *severity)),
+        );
+        if let Some(rule) = config.group_rule() {
+            self = self.add_rule(rule);
+        }
+        if let Some(hashes) = &config.hashes {
+            self = self.allow_hash_algorithms(hashes.allowed.iter().copied());
+        }
+        match &config.purl {
+            Some(purl) => self.add_rule(purl.rule()),
+            None => self,
+        }
+    }
+
+    /// Registers an [`EnrichmentHook`] that is run by the async validation when online.
+    #[cfg(feature = "async")]
+    pub fn add_hook<H: EnrichmentHook + 'static>(mut self, hook: H) -> Self {
+        self.hooks.push(Box::new(hook));
+        self
+    }
+
+    /// Returns all registered enrichment hooks, in order of registration.
+    #[cfg(feature = "async")]
+    pub fn hooks(&self) -> &[Box<dyn EnrichmentHook>] {
+        &self.hooks
+    }
+
+    /// Allows enrichment hooks to consult external services.
+    #[cfg(feature = "async")]
+    pub fn online(mut self, online: bool) -> Self {
+        self.online = online;
+        self
+    }
+
+    /// Returns `true` if enrichment hooks are allowed to run.
+    #[cfg(feature = "async")]
+    pub fn is_online(&self) -> bool {
+        self.online
+    }
+}
+
+impl fmt::Debug for ValidationOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct("ValidationOptions");
+        debug
+            .field("profile", &self.profile)
+            .field("timestamp_tolerance", &self.timestamp_tolerance)
+            .field("allowed_hash_algorithms", &self.allowed_hash_algorithms)
+            .field("document_kind", &self.document_kind)
+            .field("include_paths", &self.include_paths)
+            .field("exclude_paths", &self.exclude_paths)
+            .field("disabled_rules", &self.disabled_rules)
+            .field("enabled_rules", &self.enabled_rules)
+            .field("record_skips", &self.record_skips)
+            .field("rules", &self.rules.len())
+            .field("suppressions", &self.suppressions)
+            .field("severities", &self.severities)
+            .field("locale", &self.locale);
+        #[cfg(feature = "async")]
+        debug
+            .field("hooks", &self.hooks.len())
+            .field("online", &self.online);
+        debug.finish()
+    }
+}
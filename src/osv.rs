@@ -0,0 +1,225 @@
+use std::{collections::HashSet, error::Error};
+
+use serde::Deserialize;
+
+use crate::{
+    rules::{Rule, RuleContext},
+    validation::{ValidationContext, ValidationError, ValidationResult},
+    Bom, Component,
+};
+
+/// A known advisory for a package, e.g. as reported by OSV.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Advisory {
+    pub id: String,
+    #[serde(default)]
+    pub aliases: Vec<String>,
+}
+
+/// Source of known advisories for a package identified by its purl.
+pub trait AdvisorySource: Send + Sync {
+    fn advisories(&self, purl: &str) -> Result<Vec<Advisory>, Box<dyn Error>>;
+}
+
+#[derive(Debug, Deserialize)]
+struct QueryResponse {
+    #[serde(default)]
+    vulns: Vec<Advisory>,
+}
+
+/// Queries the [OSV](https://osv.dev) API for known advisories.
+#[derive(Debug)]
+pub struct OsvClient {
+    endpoint: String,
+    agent: ureq::Agent,
+}
+
+impl OsvClient {
+    pub const DEFAULT_ENDPOINT: &'static str = "https://api.osv.dev/v1/query";
+
+    pub fn new() -> Self {
+        Self::with_endpoint(Self::DEFAULT_ENDPOINT)
+    }
+
+    /// Creates a client that queries a different endpoint, e.g. a mirror of the OSV API.
+    pub fn with_endpoint<T: ToString>(endpoint: T) -> Self {
+        Self {
+            endpoint: endpoint.to_string(),
+            agent: ureq::Agent::new(),
+        }
+    }
+}
+
+impl Default for OsvClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AdvisorySource for OsvClient {
+    fn advisories(&self, purl: &str) -> Result<Vec<Advisory>, Box<dyn Error>> {
+        let response: QueryResponse = self
+            .agent
+            .post(&self.endpoint)
+            .send_json(serde_json::json!({ "package": { "purl": purl } }))?
+            .into_json()?;
+
+        Ok(response.vulns)
+    }
+}
+
+/// [`Rule`] that looks up the known advisories of every component purl and warns when
+/// the `vulnerabilities` section of the BOM does not list them, e.g. due to stale VEX data.
+///
+/// An advisory counts as listed when either its id or one of its aliases matches the id
+/// of a vulnerability in the BOM.
+#[derive(Debug)]
+pub struct MissingAdvisoriesRule<S> {
+    source: S,
+}
+
+impl<S: AdvisorySource> MissingAdvisoriesRule<S> {
+    pub fn new(source: S) -> Self {
+        Self { source }
+    }
+
+    fn check_component(
+        &self,
+        component: &Component,
+        known_ids: &HashSet<&str>,
+    ) -> ValidationResult {
+        let Some(purl) = &component.purl else {
+            return Ok(());
+        };
+
+        match self.source.advisories(purl) {
+            Ok(advisories) => advisories
+                .iter()
+                .filter(|advisory| {
+                    !std::iter::once(&advisory.id)
+                        .chain(advisory.aliases.iter())
+                        .any(|id| known_ids.contains(id.as_str()))
+                })
+                .fold(ValidationContext::new(), |context, advisory| {
+                    context.add_field(
+                        "purl",
+                        Some(Err(ValidationError::warning("missing_advisory")
+                            .with_param("id", &advisory.id)
+                            .with_code("missing-advisory"))),
+                    )
+                })
+                .into(),
+            Err(error) => ValidationContext::new()
+                .add_field(
+                    "purl",
+                    Some(Err(ValidationError::warning("advisory_query_failed")
+                        .with_param("error", error)
+                        .with_code("advisory-query-failed"))),
+                )
+                .into(),
+        }
+    }
+}
+
+impl MissingAdvisoriesRule<OsvClient> {
+    /// Creates the rule querying the public OSV API.
+    pub fn osv() -> Self {
+        Self::new(OsvClient::new())
+    }
+}
+
+impl<S: AdvisorySource> Rule for MissingAdvisoriesRule<S> {
+    fn check(&self, bom: &Bom, _ctx: &RuleContext) -> ValidationResult {
+        let known_ids = bom
+            .vulnerabilities
+            .iter()
+            .flatten()
+            .filter_map(|vulnerability| vulnerability.id.as_deref())
+            .collect::<HashSet<_>>();
+
+        let children = bom.components.as_ref().map(|components| {
+            components
+                .iter()
+                .map(|component| self.check_component(component, &known_ids))
+                .collect::<Vec<_>>()
+        });
+
+        ValidationContext::new()
+            .add_list("components", children)
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use crate::{
+        rules::{Rule, RuleContext},
+        validation::{SpecVersion, ValidationErrors},
+        Bom, Component, ComponentType, Vulnerability,
+    };
+
+    use super::{Advisory, AdvisorySource, MissingAdvisoriesRule};
+
+    struct FakeSource;
+
+    impl AdvisorySource for FakeSource {
+        fn advisories(&self, purl: &str) -> Result<Vec<Advisory>, Box<dyn Error>> {
+            match purl {
+                "pkg:cargo/vulnerable@1.0.0" => Ok(vec![Advisory {
+                    id: String::from("GHSA-1234"),
+                    aliases: vec![String::from("CVE-2024-1234")],
+                }]),
+                "pkg:cargo/offline@1.0.0" => Err("connection refused".into()),
+                _ => Ok(vec![]),
+            }
+        }
+    }
+
+    fn bom_with_purl(purl: &str, vulnerabilities: Option<Vec<Vulnerability>>) -> Bom {
+        Bom {
+            components: Some(vec![Component {
+                purl: Some(purl.to_string()),
+                ..Component::new(ComponentType::Library, "lib")
+            }]),
+            vulnerabilities,
+            ..Default::default()
+        }
+    }
+
+    fn check(bom: &Bom) -> Result<(), ValidationErrors> {
+        MissingAdvisoriesRule::new(FakeSource).check(bom, &RuleContext::new(SpecVersion::V1_5))
+    }
+
+    #[test]
+    fn warns_about_missing_advisory() {
+        let result = check(&bom_with_purl("pkg:cargo/vulnerable@1.0.0", None));
+        assert!(ValidationErrors::has_error(&result, "components"));
+    }
+
+    #[test]
+    fn accepts_advisory_listed_by_alias() {
+        let vulnerabilities = vec![Vulnerability {
+            id: Some(String::from("CVE-2024-1234")),
+            ..Default::default()
+        }];
+
+        let result = check(&bom_with_purl(
+            "pkg:cargo/vulnerable@1.0.0",
+            Some(vulnerabilities),
+        ));
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn accepts_package_without_advisories() {
+        assert!(check(&bom_with_purl("pkg:cargo/safe@1.0.0", None)).is_ok());
+    }
+
+    #[test]
+    fn warns_when_query_fails() {
+        let result = check(&bom_with_purl("pkg:cargo/offline@1.0.0", None));
+        assert!(ValidationErrors::has_error(&result, "components"));
+    }
+}
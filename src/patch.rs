@@ -0,0 +1,614 @@
+//! Applies a JSON Patch ([RFC 6902](https://www.rfc-editor.org/rfc/rfc6902)) to a document and
+//! revalidates only the elements it touched, e.g. for SBOM editing UIs.
+//!
+//! A patch that only changes fields inside of components, vulnerabilities or the metadata
+//! revalidates the model of these elements and runs all rules, including custom ones, on the
+//! whole document, as a changed element may affect findings elsewhere, e.g. a changed bom-ref
+//! breaks the dependencies referring to it. Any other operation, e.g. adding or removing a whole
+//! component, revalidates the whole document.
+//!
+//! Findings with a [`Suggestion`](crate::validation::Suggestion) are turned into a patch with
+//! [`suggested_fixes`], e.g. to fix the canonical form of all purls at once.
+use core::fmt;
+
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::{
+    apply_options,
+    canonical::canonicalize,
+    document_suppressions,
+    options::ValidationOptions,
+    paths::to_wire,
+    prelude::*,
+    run_rules, validate_bom,
+    validation::{
+        SpecVersion, Validate, ValidationContext, ValidationError, ValidationErrors,
+        ValidationResult,
+    },
+    Bom,
+};
+
+/// A single operation of a JSON Patch, paths are JSON Pointers, e.g. `/components/0/purl`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(tag = "op", rename_all = "lowercase")]
+pub enum PatchOperation {
+    Add { path: String, value: Value },
+    Remove { path: String },
+    Replace { path: String, value: Value },
+    Move { from: String, path: String },
+    Copy { from: String, path: String },
+    Test { path: String, value: Value },
+}
+
+#[derive(Debug)]
+pub enum PatchError {
+    /// The JSON Pointer is malformed, e.g. does not start with `/`.
+    InvalidPointer(String),
+    /// The JSON Pointer does not point to an existing location.
+    NotFound(String),
+    /// A `test` operation failed.
+    TestFailed(String),
+    /// The patched document is not a BOM anymore.
+    Parse(serde_json::Error),
+}
+
+impl fmt::Display for PatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PatchError::InvalidPointer(path) => write!(f, "Invalid JSON pointer '{}'", path),
+            PatchError::NotFound(path) => write!(f, "Path '{}' does not exist", path),
+            PatchError::TestFailed(path) => write!(f, "Test of path '{}' failed", path),
+            PatchError::Parse(error) => write!(f, "Patched document is invalid: {}", error),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PatchError {}
+
+/// A parsed BOM together with its JSON representation, which patches are applied to.
+#[derive(Debug)]
+pub struct Document {
+    value: Value,
+    bom: Bom,
+}
+
+impl Document {
+    pub fn parse(input: &str) -> Result<Self, serde_json::Error> {
+        Self::from_value(serde_json::from_str(input)?)
+    }
+
+    pub fn from_value(value: Value) -> Result<Self, serde_json::Error> {
+        let bom = serde_json::from_value(value.clone())?;
+        Ok(Self { value, bom })
+    }
+
+    pub fn bom(&self) -> &Bom {
+        &self.bom
+    }
+
+    pub fn value(&self) -> &Value {
+        &self.value
+    }
+
+    pub fn into_value(self) -> Value {
+        self.value
+    }
+
+    /// Returns the canonical serialization of the document, see [`canonicalize`].
+    pub fn canonicalize(&self) -> String {
+        canonicalize(&self.value)
+    }
+}
+
+/// The patched document and the findings that changed by the patch, each with its path.
+///
+/// Findings are compared by path, the findings of an element that moved to another index
+/// are reported as removed and added.
+#[derive(Debug)]
+pub struct PatchOutcome {
+    pub document: Document,
+    /// Findings of the patched document that were not reported before.
+    pub added: Vec<(String, ValidationError)>,
+    /// Findings of the original document that are gone.
+    pub removed: Vec<(String, ValidationError)>,
+}
+
+/// The part of the document an operation touches.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Scope {
+    Component(usize),
+    Metadata,
+    Vulnerability(usize),
+    Document,
+}
+
+/// Splits a JSON Pointer into its unescaped reference tokens.
+fn parse_pointer(path: &str) -> Result<Vec<String>, PatchError> {
+    if path.is_empty() {
+        return Ok(Vec::new());
+    }
+    let Some(tokens) = path.strip_prefix('/') else {
+        return Err(PatchError::InvalidPointer(path.to_string()));
+    };
+    Ok(tokens
+        .split('/')
+        .map(|token| token.replace("~1", "/").replace("~0", "~"))
+        .collect())
+}
+
+fn scope(tokens: &[String]) -> Scope {
+    let index = |token: &String| token.parse::<usize>().ok();
+    match tokens {
+        [list, element, _, ..] if list == "components" => {
+            index(element).map_or(Scope::Document, Scope::Component)
+        }
+        [list, element, _, ..] if list == "vulnerabilities" => {
+            index(element).map_or(Scope::Document, Scope::Vulnerability)
+        }
+        [metadata, ..] if metadata == "metadata" => Scope::Metadata,
+        _ => Scope::Document,
+    }
+}
+
+fn array_index(array: &[Value], token: &str, path: &str) -> Result<usize, PatchError> {
+    token
+        .parse::<usize>()
+        .ok()
+        .filter(|&index| index < array.len())
+        .ok_or_else(|| PatchError::NotFound(path.to_string()))
+}
+
+fn get<'v>(value: &'v Value, tokens: &[String], path: &str) -> Result<&'v Value, PatchError> {
+    tokens.iter().try_fold(value, |value, token| match value {
+        Value::Object(object) => object
+            .get(token)
+            .ok_or_else(|| PatchError::NotFound(path.to_string())),
+        Value::Array(array) => Ok(&array[array_index(array, token, path)?]),
+        _ => Err(PatchError::NotFound(path.to_string())),
+    })
+}
+
+fn get_mut<'v>(
+    value: &'v mut Value,
+    tokens: &[String],
+    path: &str,
+) -> Result<&'v mut Value, PatchError> {
+    tokens.iter().try_fold(value, |value, token| match value {
+        Value::Object(object) => object
+            .get_mut(token)
+            .ok_or_else(|| PatchError::NotFound(path.to_string())),
+        Value::Array(array) => {
+            let index = array_index(array, token, path)?;
+            Ok(&mut array[index])
+        }
+        _ => Err(PatchError::NotFound(path.to_string())),
+    })
+}
+
+fn add(document: &mut Value, path: &str, value: Value) -> Result<(), PatchError> {
+    let tokens = parse_pointer(path)?;
+    let Some((last, parent)) = tokens.split_last() else {
+        *document = value;
+        return Ok(());
+    };
+
+    match get_mut(document, parent, path)? {
+        Value::Object(object) => {
+            object.insert(last.clone(), value);
+        }
+        Value::Array(array) if last == "-" => array.push(value),
+        Value::Array(array) => match last.parse::<usize>() {
+            Ok(index) if index <= array.len() => array.insert(index, value),
+            _ => return Err(PatchError::NotFound(path.to_string())),
+        },
+        _ => return Err(PatchError::NotFound(path.to_string())),
+    }
+    Ok(())
+}
+
+fn remove(document: &mut Value, path: &str) -> Result<Value, PatchError> {
+    let tokens = parse_pointer(path)?;
+    let Some((last, parent)) = tokens.split_last() else {
+        return Err(PatchError::InvalidPointer(path.to_string()));
+    };
+
+    match get_mut(document, parent, path)? {
+        Value::Object(object) => object
+            .remove(last)
+            .ok_or_else(|| PatchError::NotFound(path.to_string())),
+        Value::Array(array) => {
+            let index = array_index(array, last, path)?;
+            Ok(array.remove(index))
+        }
+        _ => Err(PatchError::NotFound(path.to_string())),
+    }
+}
+
+/// Applies a single operation to the JSON document.
+fn apply_operation(document: &mut Value, operation: &PatchOperation) -> Result<(), PatchError> {
+    match operation {
+        PatchOperation::Add { path, value } => add(document, path, value.clone()),
+        PatchOperation::Remove { path } => remove(document, path).map(|_| ()),
+        PatchOperation::Replace { path, value } => {
+            *get_mut(document, &parse_pointer(path)?, path)? = value.clone();
+            Ok(())
+        }
+        PatchOperation::Move { from, path } => {
+            let value = remove(document, from)?;
+            add(document, path, value)
+        }
+        PatchOperation::Copy { from, path } => {
+            let value = get(document, &parse_pointer(from)?, from)?.clone();
+            add(document, path, value)
+        }
+        PatchOperation::Test { path, value } => {
+            if get(document, &parse_pointer(path)?, path)? == value {
+                Ok(())
+            } else {
+                Err(PatchError::TestFailed(path.clone()))
+            }
+        }
+    }
+}
+
+/// Returns all findings of the result with their path.
+fn findings(result: ValidationResult) -> Vec<(String, ValidationError)> {
+    let Err(errors) = result else {
+        return Vec::new();
+    };
+    errors
+        .errors()
+        .into_iter()
+        .map(|(path, error)| (path, error.clone()))
+        .collect()
+}
+
+/// Validates the model of the element in the scope, the paths of the result are relative to
+/// the document.
+fn validate_element(
+    scope: Scope,
+    bom: &Bom,
+    version: SpecVersion,
+    options: &ValidationOptions,
+) -> ValidationResult {
+    match scope {
+        Scope::Component(index) => {
+            let Some(component) = bom
+                .components
+                .as_ref()
+                .and_then(|components| components.get(index))
+            else {
+                return Ok(());
+            };
+            let path = format!("components[{}]", index);
+            let mut children = vec![Ok(()); index];
+            children.push(component.validate_at(version, options, &path));
+            ValidationContext::new()
+                .add_list("components", Some(children))
+                .into()
+        }
+        Scope::Metadata => ValidationContext::new()
+            .add_struct(
+                "meta_data",
+                bom.meta_data
+                    .as_ref()
+                    .map(|metadata| metadata.validate_at(version, options, "meta_data")),
+            )
+            .into(),
+        // vulnerabilities are only checked by rules
+        Scope::Vulnerability(_) => Ok(()),
+        Scope::Document => validate_bom(version, bom, options),
+    }
+}
+
+/// Validates the parts of the document in the scopes, followed by all rules if the scopes are
+/// elements of the document.
+fn validate_scopes(
+    scopes: &[Scope],
+    bom: &Bom,
+    version: SpecVersion,
+    options: &ValidationOptions,
+) -> ValidationResult {
+    if scopes.contains(&Scope::Document) {
+        return validate_bom(version, bom, options);
+    }
+
+    let suppressions = document_suppressions(bom, options);
+    scopes
+        .iter()
+        .map(|scope| {
+            let result = validate_element(*scope, bom, version, options);
+            apply_options(result, version, options, &suppressions)
+        })
+        .chain(
+            run_rules(version, bom, options)
+                .into_iter()
+                .filter_map(|(_, result)| result),
+        )
+        .fold(Ok(()), ValidationErrors::merge_errors)
+}
+
+/// Converts the path of a finding into a JSON Pointer into the serialized document, e.g.
+/// `meta_data.component.bom_ref` into `/metadata/component/bom-ref`.
+pub fn pointer(path: &str) -> String {
+    to_wire(path)
+        .split('.')
+        .flat_map(|segment| segment.split('['))
+        .map(|token| {
+            token
+                .trim_end_matches(']')
+                .replace('~', "~0")
+                .replace('/', "~1")
+        })
+        .fold(String::new(), |mut pointer, token| {
+            pointer.push('/');
+            pointer.push_str(&token);
+            pointer
+        })
+}
+
+/// Returns a patch replacing every offending value with its suggested fix, only the safe ones
+/// if `safe_only` is set. Suppressed findings are not fixed.
+pub fn suggested_fixes(errors: &ValidationErrors, safe_only: bool) -> Vec<PatchOperation> {
+    errors
+        .model_errors()
+        .into_iter()
+        .filter(|(_, error)| !error.suppressed)
+        .filter_map(|(path, error)| {
+            let suggestion = error.context.suggestion.as_ref()?;
+            (suggestion.safe || !safe_only).then(|| PatchOperation::Replace {
+                path: pointer(&path),
+                value: suggestion.value.clone(),
+            })
+        })
+        .collect()
+}
+
+/// Applies the patch to the document and revalidates the touched elements, see the
+/// [module documentation](self).
+///
+/// The patch is applied atomically, on error the document is left unchanged.
+pub fn apply_patch(
+    document: &Document,
+    patch: &[PatchOperation],
+    version: SpecVersion,
+    options: &ValidationOptions,
+) -> Result<PatchOutcome, PatchError> {
+    let mut value = document.value.clone();
+    let mut scopes = Vec::new();
+    for operation in patch {
+        apply_operation(&mut value, operation)?;
+
+        let paths = match operation {
+            PatchOperation::Move { from, path } => vec![from, path],
+            PatchOperation::Test { .. } => vec![],
+            PatchOperation::Add { path, .. }
+            | PatchOperation::Remove { path }
+            | PatchOperation::Replace { path, .. }
+            | PatchOperation::Copy { path, .. } => vec![path],
+        };
+        for path in paths {
+            let scope = scope(&parse_pointer(path)?);
+            if !scopes.contains(&scope) {
+                scopes.push(scope);
+            }
+        }
+    }
+
+    let patched = Document::from_value(value).map_err(PatchError::Parse)?;
+    let mut removed = findings(validate_scopes(&scopes, &document.bom, version, options));
+    let mut added = findings(validate_scopes(&scopes, &patched.bom, version, options));
+
+    // findings reported before and after the patch are unchanged
+    added.retain(
+        |finding| match removed.iter().position(|other| other == finding) {
+            Some(position) => {
+                removed.remove(position);
+                false
+            }
+            None => true,
+        },
+    );
+
+    Ok(PatchOutcome {
+        document: patched,
+        added,
+        removed,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{options::ValidationOptions, validate_bom, validation::SpecVersion};
+
+    use super::{apply_patch, pointer, suggested_fixes, Document, PatchError, PatchOperation};
+
+    const DOCUMENT: &str = r#"{
+        "components": [
+            { "type": "library", "name": "serde", "purl": "serde" },
+            { "type": "library", "name": "log", "purl": "log" }
+        ]
+    }"#;
+
+    fn patch(document: &Document, patch: &str) -> Result<super::PatchOutcome, PatchError> {
+        let patch: Vec<PatchOperation> = serde_json::from_str(patch).expect("Invalid patch");
+        apply_patch(
+            document,
+            &patch,
+            SpecVersion::V1_5,
+            &ValidationOptions::new(),
+        )
+    }
+
+    #[test]
+    fn revalidates_patched_component() {
+        let document = Document::parse(DOCUMENT).expect("Failed to parse document");
+        let outcome = patch(
+            &document,
+            r#"[
+                { "op": "test", "path": "/components/0/name", "value": "serde" },
+                { "op": "replace", "path": "/components/0/purl", "value": "pkg:cargo/serde@1.0.0" },
+                { "op": "add", "path": "/components/0/hashes", "value": [
+                    { "alg": "SHA-256", "content": "not-hex" }
+                ] }
+            ]"#,
+        )
+        .expect("Failed to apply patch");
+
+        let paths = |findings: &[(String, _)]| {
+            findings
+                .iter()
+                .map(|(path, _)| path.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(paths(&outcome.removed), vec!["components[0].purl"]);
+        assert_eq!(
+            paths(&outcome.added),
+            vec!["components[0].hashes[0].content"]
+        );
+        assert_eq!(
+            outcome.document.bom().components.as_ref().unwrap()[0]
+                .purl
+                .as_deref(),
+            Some("pkg:cargo/serde@1.0.0")
+        );
+    }
+
+    #[test]
+    fn runs_document_rules_when_patching_an_element() {
+        let document = Document::parse(
+            r#"{
+                "properties": [{ "name": "cdx:validator:suppress", "value": "invalid-purl" }],
+                "components": [
+                    { "type": "library", "name": "app", "bom-ref": "app", "components": [
+                        { "type": "library", "name": "serde", "bom-ref": "serde" }
+                    ] },
+                    { "type": "library", "name": "log", "bom-ref": "log" }
+                ]
+            }"#,
+        )
+        .expect("Failed to parse document");
+        let outcome = patch(
+            &document,
+            r#"[
+                { "op": "replace", "path": "/components/1/bom-ref", "value": "serde" },
+                { "op": "add", "path": "/components/1/purl", "value": "log" }
+            ]"#,
+        )
+        .expect("Failed to apply patch");
+
+        let codes = outcome
+            .added
+            .iter()
+            .map(|(path, error)| (path.as_str(), error.code.as_deref(), error.suppressed))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            codes,
+            vec![
+                (
+                    "components[0].components[0].bom-ref",
+                    Some("assembly-duplicate"),
+                    false
+                ),
+                ("components[1].purl", Some("invalid-purl"), true),
+                ("components[1].bom-ref", Some("assembly-duplicate"), false),
+            ]
+        );
+        assert!(outcome.removed.is_empty());
+    }
+
+    #[test]
+    fn revalidates_whole_document_when_removing_an_element() {
+        let document = Document::parse(DOCUMENT).expect("Failed to parse document");
+        let outcome = patch(
+            &document,
+            r#"[{ "op": "remove", "path": "/components/0" }]"#,
+        )
+        .expect("Failed to apply patch");
+
+        // findings are compared by path, the finding of `log` moved from index 1 to 0
+        let paths = |findings: &[(String, _)]| {
+            findings
+                .iter()
+                .map(|(path, _)| path.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            paths(&outcome.removed),
+            vec!["components[0].purl", "components[1].purl"]
+        );
+        assert_eq!(paths(&outcome.added), vec!["components[0].purl"]);
+    }
+
+    #[test]
+    fn fails_on_failed_test_and_missing_path() {
+        let document = Document::parse(DOCUMENT).expect("Failed to parse document");
+
+        assert!(matches!(
+            patch(
+                &document,
+                r#"[{ "op": "test", "path": "/components/0/name", "value": "log" }]"#
+            ),
+            Err(PatchError::TestFailed(_))
+        ));
+        assert!(matches!(
+            patch(
+                &document,
+                r#"[{ "op": "remove", "path": "/components/5" }]"#
+            ),
+            Err(PatchError::NotFound(_))
+        ));
+        assert!(matches!(
+            patch(
+                &document,
+                r#"[{ "op": "replace", "path": "/components/0/type", "value": "rocket" }]"#
+            ),
+            Err(PatchError::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn convert_paths_to_pointers() {
+        assert_eq!(
+            pointer("meta_data.component.bom_ref"),
+            "/metadata/component/bom-ref"
+        );
+        assert_eq!(
+            pointer("components[3].hashes[0].content"),
+            "/components/3/hashes/0/content"
+        );
+        assert_eq!(
+            pointer("meta_data.tool_components[0].version"),
+            "/metadata/tools/components/0/version"
+        );
+    }
+
+    #[test]
+    fn apply_suggested_fixes() {
+        let document = Document::parse(
+            r#"{ "components": [{ "type": "library", "name": "serde", "purl": "pkg:Cargo/serde@1.0.0" }],
+                "vulnerabilities": [{ "id": "CVE-1", "created": "2024-01-01 12:00:00" }] }"#,
+        )
+        .expect("Failed to parse document");
+        let options = ValidationOptions::new();
+        let errors = validate_bom(SpecVersion::V1_5, document.bom(), &options)
+            .expect_err("Expected findings");
+
+        let safe = suggested_fixes(&errors, true);
+        assert_eq!(
+            safe,
+            vec![PatchOperation::Replace {
+                path: String::from("/components/0/purl"),
+                value: serde_json::json!("pkg:cargo/serde@1.0.0"),
+            }]
+        );
+        assert_eq!(suggested_fixes(&errors, false).len(), 2);
+
+        let outcome = apply_patch(&document, &safe, SpecVersion::V1_5, &options)
+            .expect("Failed to apply fixes");
+        assert_eq!(outcome.removed.len(), 1);
+        assert!(outcome.added.is_empty());
+    }
+}
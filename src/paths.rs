@@ -0,0 +1,340 @@
+//! Path constants for every field of the model, e.g. [`META_DATA_TIMESTAMP`], generated from
+//! the model structs by the build script.
+//!
+//! Paths are formatted with the field names of the model, [`to_wire`] translates them into the
+//! names of the serialized document used by the paths returned by
+//! [`ValidationErrors::errors`](crate::validation::ValidationErrors::errors). Elements of lists
+//! are marked with `[]` and get resolved to a concrete element with [`index`], e.g.
+//! `index(COMPONENTS_PURL, &[3])` returns `components[3].purl`.
+
+use crate::prelude::*;
+
+include!(concat!(env!("OUT_DIR"), "/paths.rs"));
+
+/// Replaces the `[]` markers of the path in order with the given indices.
+pub fn index(path: &str, indices: &[usize]) -> String {
+    let mut indices = indices.iter();
+    let mut segments = path.split("[]");
+    let mut resolved = segments.next().unwrap_or_default().to_string();
+    for segment in segments {
+        match indices.next() {
+            Some(index) => resolved.push_str(&format!("[{}]", index)),
+            None => resolved.push_str("[]"),
+        }
+        resolved.push_str(segment);
+    }
+    resolved
+}
+
+/// A field of a model struct, with the model struct of its value if nested.
+struct WireField {
+    model: &'static str,
+    name: &'static str,
+    wire: &'static str,
+    nested: Option<&'static str>,
+}
+
+/// Translates a path formatted with the field names of the model into the names used in the
+/// serialized document, e.g. `meta_data.component.bom_ref` into `metadata.component.bom-ref`.
+///
+/// Names are taken from the `#[serde(rename)]` and `#[serde(rename_all)]` attributes of the
+/// model, nested structs at any depth are translated, e.g. the components of components. Keys
+/// below the model, e.g. added by rules, are kept as they are.
+pub fn to_wire(path: &str) -> String {
+    to_wire_at(ROOT_MODEL, path)
+}
+
+/// Translates a path relative to the given model struct like [`to_wire`], e.g. the path
+/// `bom_ref` relative to a `Component`.
+pub(crate) fn to_wire_at(model: &'static str, path: &str) -> String {
+    translate(model, path, |field| (field.name, field.wire))
+}
+
+/// Translates a path formatted with the names of the serialized document into the field names
+/// of the model, the inverse of [`to_wire`].
+pub fn from_wire(path: &str) -> String {
+    translate(ROOT_MODEL, path, |field| (field.wire, field.name))
+}
+
+/// Translates the path field by field starting at the given model, `select` returns the names
+/// of a field as `(from, to)`.
+///
+/// List elements, including the `[*]` and `[]` of patterns, are kept as they are. A name may
+/// span several segments, e.g. the tool components serialized at `tools.components`.
+fn translate(
+    model: &'static str,
+    path: &str,
+    select: fn(&WireField) -> (&'static str, &'static str),
+) -> String {
+    let segments = path.split('.').collect::<Vec<_>>();
+    let mut translated = Vec::with_capacity(segments.len());
+    let mut model = Some(model);
+    let mut rest = &segments[..];
+
+    while let Some(segment) = rest.first() {
+        let field = model.and_then(|model| {
+            WIRE_FIELDS
+                .iter()
+                .filter(|field| field.model == model)
+                .filter_map(|field| {
+                    let (from, to) = select(field);
+                    let len = matching_segments(from, rest)?;
+                    Some((len, to, field.nested))
+                })
+                .max_by_key(|(len, ..)| *len)
+        });
+
+        match field {
+            Some((len, to, nested)) => {
+                let last = rest[len - 1];
+                translated.push(format!(
+                    "{}{}",
+                    to,
+                    &last[last.find('[').unwrap_or(last.len())..]
+                ));
+                model = nested;
+                rest = &rest[len..];
+            }
+            None => {
+                translated.push(segment.to_string());
+                model = None;
+                rest = &rest[1..];
+            }
+        }
+    }
+    translated.join(".")
+}
+
+/// Returns the number of segments the dotted `name` matches at the start of `segments`, only
+/// the last one may select list elements.
+fn matching_segments(name: &str, segments: &[&str]) -> Option<usize> {
+    let mut len = 0;
+    for part in name.split('.') {
+        if len > 0 && segments[len - 1].contains('[') {
+            return None;
+        }
+        let segment = segments.get(len)?;
+        if segment.split('[').next() != Some(part) {
+            return None;
+        }
+        len += 1;
+    }
+    Some(len)
+}
+
+/// Returns the index of the list element the path starts with, e.g. `3` for
+/// `list_index("components[3].purl", "components")`.
+pub fn list_index(path: &str, list: &str) -> Option<usize> {
+    let rest = path.strip_prefix(list)?.strip_prefix('[')?;
+    rest[..rest.find(']')?].parse().ok()
+}
+
+/// A glob-like pattern selecting a subtree of the document, e.g. `components[*].licenses`.
+///
+/// Patterns are formatted like paths, `[*]` or `[]` match any element of a list and `*` any
+/// field, so the path constants can be used as patterns as well. Fields are named either like
+/// the model or like the serialized document, e.g. `serialNumber` matches `serial_number`, so
+/// paths copied from a [`ValidationReport`](crate::report::ValidationReport) can be used.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PathPattern {
+    pattern: String,
+    segments: Vec<Segment>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    Key(String),
+    AnyKey,
+    Index(usize),
+    AnyIndex,
+}
+
+impl Segment {
+    fn matches(&self, actual: &Segment) -> bool {
+        match (self, actual) {
+            (Segment::AnyKey, Segment::Key(_)) | (Segment::AnyIndex, Segment::Index(_)) => true,
+            (pattern, actual) => pattern == actual,
+        }
+    }
+}
+
+fn segments(path: &str) -> Vec<Segment> {
+    let mut segments = Vec::new();
+    for part in path.split('.').filter(|part| !part.is_empty()) {
+        let mut brackets = part.split('[');
+        match brackets.next() {
+            Some("*") => segments.push(Segment::AnyKey),
+            Some("") | None => {}
+            Some(key) => segments.push(Segment::Key(key.to_string())),
+        }
+        for index in brackets {
+            match index.trim_end_matches(']') {
+                "" | "*" => segments.push(Segment::AnyIndex),
+                index => segments.push(
+                    index
+                        .parse()
+                        .map_or_else(|_| Segment::Key(index.to_string()), Segment::Index),
+                ),
+            }
+        }
+    }
+    segments
+}
+
+impl PathPattern {
+    pub fn parse(pattern: &str) -> Self {
+        Self {
+            pattern: pattern.to_string(),
+            segments: segments(&from_wire(pattern)),
+        }
+    }
+
+    /// Returns the pattern as it was parsed.
+    pub fn as_str(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Returns `true` if the path is the selected subtree or lies within it.
+    pub fn contains(&self, path: &str) -> bool {
+        let path = segments(path);
+        path.len() >= self.segments.len()
+            && self
+                .segments
+                .iter()
+                .zip(&path)
+                .all(|(pattern, actual)| pattern.matches(actual))
+    }
+
+    /// Returns `true` if the path is exactly the selected element, not one within it.
+    pub fn matches(&self, path: &str) -> bool {
+        let path = segments(path);
+        path.len() == self.segments.len()
+            && self
+                .segments
+                .iter()
+                .zip(&path)
+                .all(|(pattern, actual)| pattern.matches(actual))
+    }
+
+    /// Returns `true` if the selected subtree lies below the path, i.e. the element at the
+    /// path has to be traversed to reach it.
+    pub fn is_below(&self, path: &str) -> bool {
+        let path = segments(path);
+        path.len() < self.segments.len()
+            && path
+                .iter()
+                .zip(&self.segments)
+                .all(|(actual, pattern)| pattern.matches(actual))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        from_wire, index, list_index, to_wire, PathPattern, COMPONENTS_HASHES_CONTENT,
+        META_DATA_COMPONENT_PURL, META_DATA_TIMESTAMP,
+    };
+
+    #[test]
+    fn generated_paths() {
+        assert_eq!(META_DATA_TIMESTAMP, "meta_data.timestamp");
+        assert_eq!(META_DATA_COMPONENT_PURL, "meta_data.component.purl");
+        assert_eq!(COMPONENTS_HASHES_CONTENT, "components[].hashes[].content");
+    }
+
+    #[test]
+    fn index_list_elements() {
+        assert_eq!(
+            index(COMPONENTS_HASHES_CONTENT, &[3, 0]),
+            "components[3].hashes[0].content"
+        );
+        assert_eq!(index(META_DATA_TIMESTAMP, &[]), "meta_data.timestamp");
+    }
+
+    #[test]
+    fn translate_to_wire_names() {
+        assert_eq!(
+            to_wire("meta_data.component.bom_ref"),
+            "metadata.component.bom-ref"
+        );
+        assert_eq!(
+            to_wire("components[3].external_references[0].reference_type"),
+            "components[3].externalReferences[0].type"
+        );
+        assert_eq!(to_wire("components[3].purl"), "components[3].purl");
+        assert_eq!(
+            to_wire("components[0].components[1].components[2].bom_ref"),
+            "components[0].components[1].components[2].bom-ref"
+        );
+        assert_eq!(
+            from_wire("metadata.component.components[1].externalReferences[0].type"),
+            "meta_data.component.components[1].external_references[0].reference_type"
+        );
+        assert_eq!(to_wire("document"), "document");
+        assert_eq!(
+            from_wire("metadata.component.bom-ref"),
+            "meta_data.component.bom_ref"
+        );
+        assert_eq!(
+            from_wire("components[3].externalReferences[0].type"),
+            "components[3].external_references[0].reference_type"
+        );
+    }
+
+    #[test]
+    fn translate_legacy_tools_and_tool_components() {
+        assert_eq!(
+            to_wire("meta_data.tool_components[0].version"),
+            "metadata.tools.components[0].version"
+        );
+        assert_eq!(to_wire("meta_data.tools[1].name"), "metadata.tools[1].name");
+        assert_eq!(
+            from_wire("metadata.tools.components[0].version"),
+            "meta_data.tool_components[0].version"
+        );
+        assert_eq!(
+            from_wire("metadata.tools[1].name"),
+            "meta_data.tools[1].name"
+        );
+    }
+
+    #[test]
+    fn translate_patterns() {
+        assert_eq!(
+            from_wire("components[*].externalReferences[].type"),
+            "components[*].external_references[].reference_type"
+        );
+        assert_eq!(from_wire("serialNumber"), "serial_number");
+        assert_eq!(from_wire("serial_number"), "serial_number");
+        assert!(PathPattern::parse("components[*].bom-ref").contains("components[2].bom_ref"));
+    }
+
+    #[test]
+    fn match_path_patterns() {
+        let pattern = PathPattern::parse("components[*].licenses");
+
+        assert!(pattern.contains("components[3].licenses"));
+        assert!(pattern.contains("components[3].licenses[0].license.id"));
+        assert!(!pattern.contains("components[3].purl"));
+        assert!(!pattern.contains("components[3]"));
+        assert!(pattern.matches("components[3].licenses"));
+        assert!(!pattern.matches("components[3].licenses[0].license.id"));
+        assert!(pattern.is_below("components"));
+        assert!(pattern.is_below("components[3]"));
+        assert!(!pattern.is_below("components[3].licenses"));
+        assert!(!pattern.is_below("services"));
+
+        assert!(PathPattern::parse(COMPONENTS_HASHES_CONTENT)
+            .contains("components[1].hashes[2].content"));
+        assert!(PathPattern::parse("*.component").contains("meta_data.component.purl"));
+        assert!(PathPattern::parse("components[2]").contains("components[2].purl"));
+        assert!(!PathPattern::parse("components[2]").contains("components[12].purl"));
+    }
+
+    #[test]
+    fn list_index_of_path() {
+        assert_eq!(list_index("components[3].purl", "components"), Some(3));
+        assert_eq!(list_index("components[3].purl", "services"), None);
+        assert_eq!(list_index("meta_data.component.purl", "components"), None);
+    }
+}
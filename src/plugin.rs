@@ -0,0 +1,455 @@
+//! Custom rules compiled to WebAssembly, run sandboxed by an interpreter inside the validator.
+//!
+//! A plugin is a module exporting
+//!
+//! * `memory`, its linear memory
+//! * `alloc(len: i32) -> i32`, returning the offset of `len` bytes the host writes the input to
+//! * `check(ptr: i32, len: i32)`, checking the input at the given offset
+//!
+//! The input is the JSON object `{ "specVersion": "1.5", "document": { ... } }`, the document
+//! serialized with the names of the CycloneDX format. The plugin reports each finding by
+//! calling the imported function `cyclonedx.report(ptr: i32, len: i32)` with a JSON object
+//!
+//! ```json
+//! { "path": "components[0].bom-ref", "message": "...", "code": "acme-ref", "severity": "warning" }
+//! ```
+//!
+//! The path uses the names of the serialized document as well, `code` and `severity` are
+//! optional, the severity defaults to `error`.
+//!
+//! A plugin can only access its own memory and runs with a limited amount of fuel, i.e. executed
+//! instructions, and memory. It may report at most [`WasmRule::MAX_FINDINGS`] findings of
+//! [`WasmRule::MAX_REPORTED_LEN`] bytes in total per document. A plugin that fails, e.g. by
+//! running out of fuel or reporting an invalid finding or a path to a list element not present
+//! in the document, is reported as `plugin-failed` at [`DOCUMENT_KEY`].
+use std::{fmt, fs, io, path::Path};
+
+use serde::Deserialize;
+use wasmi::{
+    Caller, Config, Engine, Extern, Linker, Module, Store, StoreLimits, StoreLimitsBuilder,
+};
+
+use crate::{
+    error::DOCUMENT_KEY,
+    paths,
+    rules::{Rule, RuleContext},
+    validation::{
+        Severity, ValidationContext, ValidationError, ValidationErrors, ValidationResult,
+    },
+    Bom,
+};
+
+/// The error returned when a plugin cannot be loaded or run.
+#[derive(Debug)]
+pub enum PluginError {
+    Io(io::Error),
+    Wasm(wasmi::Error),
+    /// The plugin does not export the function or memory of the given name.
+    MissingExport(&'static str),
+    /// The plugin reported a finding that is not valid JSON or lacks a field.
+    InvalidFinding(serde_json::Error),
+    /// The plugin reported a finding at a list element not present in the document.
+    InvalidPath(String),
+}
+
+impl fmt::Display for PluginError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PluginError::Io(error) => write!(f, "Failed to read plugin: {}", error),
+            PluginError::Wasm(error) => write!(f, "Failed to run plugin: {}", error),
+            PluginError::MissingExport(name) => write!(f, "Plugin does not export '{}'", name),
+            PluginError::InvalidFinding(error) => write!(f, "Invalid finding: {}", error),
+            PluginError::InvalidPath(path) => {
+                write!(f, "Finding at '{}' is not in the document", path)
+            }
+        }
+    }
+}
+
+impl std::error::Error for PluginError {}
+
+impl<E: Into<wasmi::Error>> From<E> for PluginError {
+    fn from(error: E) -> Self {
+        PluginError::Wasm(error.into())
+    }
+}
+
+/// A finding reported by a plugin.
+#[derive(Debug, Deserialize)]
+struct Finding {
+    path: String,
+    message: String,
+    code: Option<String>,
+    #[serde(default)]
+    severity: Severity,
+}
+
+struct HostState {
+    limits: StoreLimits,
+    findings: Vec<Finding>,
+    invalid: Option<serde_json::Error>,
+    /// The number of bytes reported so far.
+    reported: usize,
+}
+
+/// The `cyclonedx.report` function imported by plugins.
+fn report(mut caller: Caller<'_, HostState>, ptr: i32, len: i32) -> Result<(), wasmi::Error> {
+    let memory = caller
+        .get_export("memory")
+        .and_then(Extern::into_memory)
+        .ok_or_else(|| wasmi::Error::new("plugin does not export 'memory'"))?;
+    let state = caller.data();
+    let len = len as u32 as usize;
+    if state.findings.len() >= WasmRule::MAX_FINDINGS {
+        return Err(wasmi::Error::new("plugin reported too many findings"));
+    }
+    if state.reported + len > WasmRule::MAX_REPORTED_LEN {
+        return Err(wasmi::Error::new("plugin reported too many bytes"));
+    }
+
+    let start = ptr as u32 as usize;
+    let finding = memory
+        .data(&caller)
+        .get(start..start.saturating_add(len))
+        .ok_or_else(|| wasmi::Error::new("finding exceeds the memory of the plugin"))
+        .map(serde_json::from_slice::<Finding>)?;
+
+    let state = caller.data_mut();
+    state.reported += len;
+    match finding {
+        Ok(finding) => state.findings.push(finding),
+        Err(error) => {
+            state.invalid.get_or_insert(error);
+        }
+    }
+    Ok(())
+}
+
+/// Returns `true` if every list element of the path, e.g. `components[0].purl`, is present in the
+/// serialized document.
+fn in_document(document: &serde_json::Value, path: &str) -> bool {
+    let mut value = document;
+    for segment in path.split('.') {
+        let (name, index) = match segment.split_once('[') {
+            Some((name, index)) => (name, Some(index.trim_end_matches(']'))),
+            None => (segment, None),
+        };
+        value = &value[name];
+        if let Some(index) = index {
+            match index
+                .parse::<usize>()
+                .ok()
+                .and_then(|index| value.get(index))
+            {
+                Some(element) => value = element,
+                None => return false,
+            }
+        }
+    }
+    true
+}
+
+/// Returns a tree with the error at the path, e.g. `components[0].purl`.
+fn error_at(path: &str, error: ValidationError) -> ValidationErrors {
+    let mut segments = path.split('.').rev();
+    let leaf = segments.next().unwrap_or(path);
+    let leaf = match leaf.split_once('[') {
+        Some((name, index)) => ValidationContext::new().add_field(
+            name,
+            Some(Err(match index.trim_end_matches(']').parse() {
+                Ok(index) => error.with_index(index),
+                Err(_) => error,
+            })),
+        ),
+        None => ValidationContext::new().add_field(leaf, Some(Err(error))),
+    };
+
+    let result = segments.fold(ValidationResult::from(leaf), |child, segment| {
+        let nested = match segment.split_once('[') {
+            Some((name, index)) => {
+                let index = index.trim_end_matches(']').parse().unwrap_or(0);
+                let mut children = vec![Ok(()); index];
+                children.push(child);
+                ValidationContext::new().add_list(name, Some(children))
+            }
+            None => ValidationContext::new().add_struct(segment, Some(child)),
+        };
+        nested.into()
+    });
+    result.err().unwrap_or_default()
+}
+
+/// [`Rule`] running a plugin, see the [module documentation](self).
+#[derive(Debug)]
+pub struct WasmRule {
+    id: String,
+    engine: Engine,
+    module: Module,
+    fuel: u64,
+    max_memory: usize,
+}
+
+impl WasmRule {
+    /// The fuel a plugin may consume per document, roughly the number of executed instructions.
+    pub const DEFAULT_FUEL: u64 = 1_000_000_000;
+    /// The size in bytes the memory of a plugin may grow to.
+    pub const DEFAULT_MAX_MEMORY: usize = 64 * 1024 * 1024;
+    /// The number of findings a plugin may report per document.
+    pub const MAX_FINDINGS: usize = 10_000;
+    /// The size in bytes of all findings a plugin may report per document.
+    pub const MAX_REPORTED_LEN: usize = 16 * 1024 * 1024;
+
+    /// Compiles the plugin from its binary or text format, `id` identifies the rule in reports.
+    pub fn new<T: ToString>(id: T, wasm: &[u8]) -> Result<Self, PluginError> {
+        let mut config = Config::default();
+        config.consume_fuel(true);
+        let engine = Engine::new(&config);
+        let module = Module::new(&engine, wasm)?;
+
+        Ok(Self {
+            id: id.to_string(),
+            engine,
+            module,
+            fuel: Self::DEFAULT_FUEL,
+            max_memory: Self::DEFAULT_MAX_MEMORY,
+        })
+    }
+
+    /// Reads and compiles the plugin from a file.
+    pub fn load<T: ToString, P: AsRef<Path>>(id: T, path: P) -> Result<Self, PluginError> {
+        let wasm = fs::read(path).map_err(PluginError::Io)?;
+        Self::new(id, &wasm)
+    }
+
+    /// Limits the fuel the plugin may consume per document.
+    pub fn fuel(mut self, fuel: u64) -> Self {
+        self.fuel = fuel;
+        self
+    }
+
+    /// Limits the size in bytes the memory of the plugin may grow to.
+    pub fn max_memory(mut self, max_memory: usize) -> Self {
+        self.max_memory = max_memory;
+        self
+    }
+
+    /// Runs the plugin on the input in a fresh instance and returns its findings.
+    fn run(&self, input: &[u8]) -> Result<Vec<Finding>, PluginError> {
+        let state = HostState {
+            limits: StoreLimitsBuilder::new()
+                .memory_size(self.max_memory)
+                .build(),
+            findings: Vec::new(),
+            invalid: None,
+            reported: 0,
+        };
+        let mut store = Store::new(&self.engine, state);
+        store.limiter(|state| &mut state.limits);
+        store.set_fuel(self.fuel)?;
+
+        let mut linker = Linker::new(&self.engine);
+        linker.func_wrap("cyclonedx", "report", report)?;
+        let instance = linker
+            .instantiate(&mut store, &self.module)?
+            .start(&mut store)?;
+
+        let memory = instance
+            .get_memory(&store, "memory")
+            .ok_or(PluginError::MissingExport("memory"))?;
+        let alloc = instance
+            .get_typed_func::<i32, i32>(&store, "alloc")
+            .map_err(|_| PluginError::MissingExport("alloc"))?;
+        let check = instance
+            .get_typed_func::<(i32, i32), ()>(&store, "check")
+            .map_err(|_| PluginError::MissingExport("check"))?;
+
+        let len = i32::try_from(input.len())
+            .map_err(|_| wasmi::Error::new("input exceeds the 32 bit address space"))?;
+        let ptr = alloc.call(&mut store, len)?;
+        memory.write(&mut store, ptr as u32 as usize, input)?;
+        check.call(&mut store, (ptr, len))?;
+
+        let state = store.into_data();
+        match state.invalid {
+            Some(error) => Err(PluginError::InvalidFinding(error)),
+            None => Ok(state.findings),
+        }
+    }
+}
+
+impl Rule for WasmRule {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn check(&self, bom: &Bom, ctx: &RuleContext) -> ValidationResult {
+        let input = serde_json::json!({
+            "specVersion": ctx.version.to_string(),
+            "document": bom.to_value(),
+        });
+        let findings = serde_json::to_vec(&input)
+            .map_err(PluginError::InvalidFinding)
+            .and_then(|serialized| self.run(&serialized))
+            .and_then(|findings| {
+                match findings
+                    .iter()
+                    .find(|finding| !in_document(&input["document"], &finding.path))
+                {
+                    Some(finding) => Err(PluginError::InvalidPath(finding.path.clone())),
+                    None => Ok(findings),
+                }
+            });
+
+        match findings {
+            Ok(findings) => {
+                let errors =
+                    findings
+                        .into_iter()
+                        .fold(ValidationErrors::new(), |errors, finding| {
+                            let mut error = ValidationError::new(finding.message)
+                                .with_severity(finding.severity)
+                                .with_param("plugin", &self.id);
+                            if let Some(code) = finding.code {
+                                error = error.with_code(code);
+                            }
+                            errors.merge(error_at(&paths::from_wire(&finding.path), error))
+                        });
+                if errors.is_empty() {
+                    Ok(())
+                } else {
+                    Err(errors)
+                }
+            }
+            Err(error) => ValidationContext::new()
+                .add_field(
+                    DOCUMENT_KEY,
+                    Some(Err(ValidationError::new("plugin_failed")
+                        .with_param("plugin", &self.id)
+                        .with_param("error", error)
+                        .with_code("plugin-failed"))),
+                )
+                .into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::DOCUMENT_KEY,
+        options::ValidationOptions,
+        testing::errors_at,
+        validate_bom,
+        validation::{Severity, SpecVersion, ValidationResult},
+        Bom,
+    };
+
+    use super::WasmRule;
+
+    /// Returns a plugin reporting the given JSON once for every document.
+    fn plugin(finding: &str) -> Vec<u8> {
+        let wat = format!(
+            r#"(module
+                (import "cyclonedx" "report" (func $report (param i32 i32)))
+                (memory (export "memory") 1)
+                (data (i32.const 0) "{}")
+                (func (export "alloc") (param i32) (result i32) i32.const 1024)
+                (func (export "check") (param i32 i32)
+                    (call $report (i32.const 0) (i32.const {}))))"#,
+            finding.replace('"', "\\\""),
+            finding.len()
+        );
+        wat::parse_str(wat).expect("Failed to assemble plugin")
+    }
+
+    fn validate(rule: WasmRule) -> ValidationResult {
+        let bom = Bom::parse_json(
+            r#"{ "components": [{ "type": "library", "name": "lib", "bom-ref": "lib" }] }"#,
+        )
+        .expect("Failed to parse BOM");
+        validate_bom(
+            SpecVersion::V1_5,
+            &bom,
+            &ValidationOptions::new().add_rule(rule),
+        )
+    }
+
+    #[test]
+    fn reports_findings_of_plugin() {
+        let finding = r#"{"path":"components[0].bom-ref","message":"Reference is not namespaced","code":"acme-ref","severity":"warning"}"#;
+        let rule = WasmRule::new("acme", &plugin(finding)).expect("Failed to compile plugin");
+        let result = validate(rule);
+
+        let errors = errors_at(&result, "components[0].bom_ref");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code.as_deref(), Some("acme-ref"));
+        assert_eq!(errors[0].severity, Severity::Warning);
+        assert_eq!(errors[0].message.param("plugin"), Some("acme"));
+    }
+
+    #[test]
+    fn reports_invalid_findings() {
+        let rule =
+            WasmRule::new("acme", &plugin(r#"{"path":"name"}"#)).expect("Failed to compile plugin");
+        let result = validate(rule);
+
+        let errors = errors_at(&result, DOCUMENT_KEY);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code.as_deref(), Some("plugin-failed"));
+    }
+
+    #[test]
+    fn reports_findings_outside_of_document() {
+        let finding = r#"{"path":"components[99999999999].name","message":"Name is missing"}"#;
+        let rule = WasmRule::new("acme", &plugin(finding)).expect("Failed to compile plugin");
+        let result = validate(rule);
+
+        let errors = errors_at(&result, DOCUMENT_KEY);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code.as_deref(), Some("plugin-failed"));
+        assert!(errors_at(&result, "components").is_empty());
+    }
+
+    #[test]
+    fn reports_findings_beyond_memory() {
+        let wasm = wat::parse_str(
+            r#"(module
+                (import "cyclonedx" "report" (func $report (param i32 i32)))
+                (memory (export "memory") 1)
+                (func (export "alloc") (param i32) (result i32) i32.const 1024)
+                (func (export "check") (param i32 i32)
+                    (call $report (i32.const 65000) (i32.const 1000))))"#,
+        )
+        .expect("Failed to assemble plugin");
+        let rule = WasmRule::new("acme", &wasm).expect("Failed to compile plugin");
+        let result = validate(rule);
+
+        let errors = errors_at(&result, DOCUMENT_KEY);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code.as_deref(), Some("plugin-failed"));
+    }
+
+    #[test]
+    fn stops_plugin_out_of_fuel() {
+        let wasm = wat::parse_str(
+            r#"(module
+                (memory (export "memory") 1)
+                (func (export "alloc") (param i32) (result i32) i32.const 0)
+                (func (export "check") (param i32 i32) (loop $spin (br $spin))))"#,
+        )
+        .expect("Failed to assemble plugin");
+        let rule = WasmRule::new("spin", &wasm)
+            .expect("Failed to compile plugin")
+            .fuel(10_000);
+        let result = validate(rule);
+
+        let errors = errors_at(&result, DOCUMENT_KEY);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].message.param("plugin"), Some("spin"));
+    }
+
+    #[test]
+    fn rejects_invalid_modules() {
+        assert!(WasmRule::new("broken", b"not wasm").is_err());
+    }
+}
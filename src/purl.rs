@@ -0,0 +1,284 @@
+use alloc::borrow::Cow;
+use core::fmt;
+
+use crate::validation::ValidationError;
+use crate::{map::IndexMap, prelude::*};
+
+/// A parsed [package URL](https://github.com/package-url/purl-spec), e.g.
+/// `pkg:maven/org.apache.commons/commons-lang3@3.12.0?type=jar`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Purl {
+    pub purl_type: String,
+    pub namespace: Option<String>,
+    pub name: String,
+    pub version: Option<String>,
+    pub qualifiers: IndexMap<String, String>,
+    pub subpath: Option<String>,
+}
+
+impl Purl {
+    /// Parses a package URL, all components are percent-decoded.
+    pub fn parse(input: &str) -> Result<Self, ValidationError> {
+        let remainder = match input.split_once(':') {
+            Some((scheme, remainder)) if scheme.eq_ignore_ascii_case("pkg") => {
+                remainder.trim_start_matches('/')
+            }
+            _ => return Err(ValidationError::new("purl_scheme")),
+        };
+
+        let (remainder, subpath) = match remainder.split_once('#') {
+            Some((remainder, subpath)) => (remainder, Some(subpath)),
+            None => (remainder, None),
+        };
+        let (remainder, qualifiers) = match remainder.split_once('?') {
+            Some((remainder, qualifiers)) => (remainder, Some(qualifiers)),
+            None => (remainder, None),
+        };
+        let (remainder, version) = match remainder.rsplit_once('@') {
+            Some((remainder, version)) if !remainder.ends_with('/') => (remainder, Some(version)),
+            _ => (remainder, None),
+        };
+
+        let Some((purl_type, path)) = remainder.split_once('/') else {
+            return Err(ValidationError::new("purl_type_and_name"));
+        };
+        if purl_type.is_empty()
+            || !purl_type
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '+' | '-'))
+        {
+            return Err(ValidationError::new("purl_invalid_type").with_param("type", purl_type));
+        }
+
+        let path = path.trim_matches('/');
+        let (namespace, name) = match path.rsplit_once('/') {
+            Some((namespace, name)) => (Some(namespace), name),
+            None => (None, path),
+        };
+        if name.is_empty() {
+            return Err(ValidationError::new("purl_name"));
+        }
+
+        let qualifiers = qualifiers
+            .into_iter()
+            .flat_map(|qualifiers| qualifiers.split('&'))
+            .filter(|qualifier| !qualifier.is_empty())
+            .map(|qualifier| match qualifier.split_once('=') {
+                Some((key, value)) => Ok((key.to_ascii_lowercase(), decode(value)?.into_owned())),
+                None => {
+                    Err(ValidationError::new("purl_qualifier_value")
+                        .with_param("qualifier", qualifier))
+                }
+            })
+            .collect::<Result<IndexMap<_, _>, _>>()?;
+
+        Ok(Self {
+            purl_type: purl_type.to_ascii_lowercase(),
+            namespace: namespace.map(decode_segments).transpose()?,
+            name: decode(name)?.into_owned(),
+            version: version
+                .map(|version| decode(version).map(Cow::into_owned))
+                .transpose()?,
+            qualifiers,
+            subpath: subpath
+                .map(|subpath| subpath.trim_matches('/'))
+                .filter(|subpath| !subpath.is_empty())
+                .map(decode_segments)
+                .transpose()?,
+        })
+    }
+}
+
+impl Purl {
+    /// Returns the canonical form of the purl if it differs from `input`, the text it was
+    /// parsed from, by more than the percent-encoding, see [`canonical_form`].
+    pub fn canonical_form(&self, input: &str) -> Option<String> {
+        let canonical = self.to_string();
+        if canonical == input {
+            return None;
+        }
+        (decode(input).ok()? != decode(&canonical).ok()?).then_some(canonical)
+    }
+}
+
+impl fmt::Display for Purl {
+    /// Formats the package URL in its canonical form, with qualifiers sorted by key.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "pkg:{}/", self.purl_type)?;
+        if let Some(namespace) = &self.namespace {
+            write!(f, "{}/", EncodedSegments(namespace))?;
+        }
+        write!(f, "{}", Encoded(&self.name))?;
+        if let Some(version) = &self.version {
+            write!(f, "@{}", Encoded(version))?;
+        }
+
+        let mut qualifiers = self
+            .qualifiers
+            .iter()
+            .filter(|(_, value)| !value.is_empty())
+            .collect::<Vec<_>>();
+        qualifiers.sort_by_key(|(key, _)| *key);
+        for (index, (key, value)) in qualifiers.into_iter().enumerate() {
+            let separator = if index == 0 { '?' } else { '&' };
+            write!(f, "{}{}={}", separator, key, Encoded(value))?;
+        }
+
+        if let Some(subpath) = &self.subpath {
+            write!(f, "#{}", EncodedSegments(subpath))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the canonical form of the purl if it differs from `input` by more than the
+/// percent-encoding, e.g. by the case of the type or the order of the qualifiers.
+pub fn canonical_form(input: &str) -> Option<String> {
+    Purl::parse(input).ok()?.canonical_form(input)
+}
+
+/// Percent-decodes a single purl component, borrowing the input if it contains no escapes.
+fn decode(input: &str) -> Result<Cow<'_, str>, ValidationError> {
+    if !input.contains('%') {
+        return Ok(Cow::Borrowed(input));
+    }
+
+    let mut bytes = Vec::with_capacity(input.len());
+    let mut iter = input.bytes();
+    while let Some(byte) = iter.next() {
+        if byte == b'%' {
+            let hex = [iter.next(), iter.next()];
+            let decoded = match hex {
+                [Some(high), Some(low)] => core::str::from_utf8(&[high, low])
+                    .ok()
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok()),
+                _ => None,
+            };
+            match decoded {
+                Some(decoded) => bytes.push(decoded),
+                None => {
+                    return Err(ValidationError::new("purl_percent_encoding")
+                        .with_param("component", input))
+                }
+            }
+        } else {
+            bytes.push(byte);
+        }
+    }
+
+    String::from_utf8(bytes)
+        .map(Cow::Owned)
+        .map_err(|_| ValidationError::new("purl_utf8").with_param("component", input))
+}
+
+fn decode_segments(input: &str) -> Result<String, ValidationError> {
+    let mut decoded = String::with_capacity(input.len());
+    for segment in input.split('/').filter(|segment| !segment.is_empty()) {
+        if !decoded.is_empty() {
+            decoded.push('/');
+        }
+        decoded.push_str(&decode(segment)?);
+    }
+    Ok(decoded)
+}
+
+/// Formats a single purl component percent-encoded, without allocating.
+struct Encoded<'a>(&'a str);
+
+impl fmt::Display for Encoded<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let unreserved =
+            |byte: u8| byte.is_ascii_alphanumeric() || matches!(byte, b'.' | b'-' | b'_' | b'~');
+
+        let mut rest = self.0;
+        while let Some(position) = rest.bytes().position(|byte| !unreserved(byte)) {
+            f.write_str(&rest[..position])?;
+            let end = rest[position..]
+                .bytes()
+                .position(unreserved)
+                .map_or(rest.len(), |end| position + end);
+            for byte in rest[position..end].bytes() {
+                write!(f, "%{:02X}", byte)?;
+            }
+            rest = &rest[end..];
+        }
+        f.write_str(rest)
+    }
+}
+
+/// Formats a purl component of `/` separated segments, each percent-encoded.
+struct EncodedSegments<'a>(&'a str);
+
+impl fmt::Display for EncodedSegments<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, segment) in self.0.split('/').enumerate() {
+            if index > 0 {
+                f.write_str("/")?;
+            }
+            write!(f, "{}", Encoded(segment))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{canonical_form, Purl};
+
+    #[test]
+    fn parse_full_purl() {
+        let purl = Purl::parse("pkg:maven/org.apache.commons/commons-lang3@3.12.0?type=jar#src")
+            .expect("Failed to parse purl");
+
+        assert_eq!(purl.purl_type, "maven");
+        assert_eq!(purl.namespace.as_deref(), Some("org.apache.commons"));
+        assert_eq!(purl.name, "commons-lang3");
+        assert_eq!(purl.version.as_deref(), Some("3.12.0"));
+        assert_eq!(purl.qualifiers["type"], "jar");
+        assert_eq!(purl.subpath.as_deref(), Some("src"));
+    }
+
+    #[test]
+    fn parse_percent_encoded_purl() {
+        let purl = Purl::parse("pkg:npm/%40angular/core@16.0.0").expect("Failed to parse purl");
+
+        assert_eq!(purl.namespace.as_deref(), Some("@angular"));
+        assert_eq!(purl.name, "core");
+    }
+
+    #[test]
+    fn parse_invalid_purls() {
+        assert!(Purl::parse("maven/org.apache/commons").is_err());
+        assert!(Purl::parse("pkg:cargo").is_err());
+        assert!(Purl::parse("pkg:cargo/").is_err());
+        assert!(Purl::parse("pkg:cargo/serde%2").is_err());
+    }
+
+    #[test]
+    fn display_canonical_form() {
+        let purl = Purl::parse("PKG:NPM/%40angular/core@16.0.0?b=2&a=1").expect("Failed to parse");
+
+        assert_eq!(purl.to_string(), "pkg:npm/%40angular/core@16.0.0?a=1&b=2");
+    }
+
+    #[test]
+    fn display_encodes_reserved_characters() {
+        let purl = Purl::parse("pkg:generic/a%20b/c%2Bd@1.0%2Fbeta?tag=x%26y#sub/a%20b")
+            .expect("Failed to parse");
+
+        assert_eq!(
+            purl.to_string(),
+            "pkg:generic/a%20b/c%2Bd@1.0%2Fbeta?tag=x%26y#sub/a%20b"
+        );
+    }
+
+    #[test]
+    fn canonical_form_ignores_percent_encoding() {
+        assert_eq!(canonical_form("pkg:npm/@angular/core@16.0.0"), None);
+        assert_eq!(
+            canonical_form("pkg:Cargo/serde@1.0.0?b=2&a=1").as_deref(),
+            Some("pkg:cargo/serde@1.0.0?a=1&b=2")
+        );
+    }
+}
@@ -0,0 +1,258 @@
+//! Redaction of sensitive fields before a BOM is shared externally, e.g. internal hostnames
+//! of service endpoints or the emails of authors.
+//!
+//! Redactions address fields by their JSON keys, elements of lists are marked with `[]`, e.g.
+//! `services[].endpoints[]` or `components[].authors[].email`. Every redacted field is
+//! reported with its concrete path, e.g. `services[0].endpoints[1]`.
+//!
+//! A validated [`Bom`](crate::Bom) is exported with [`Bom::to_value`](crate::Bom::to_value)
+//! and redacted afterwards.
+use serde_json::Value;
+
+use crate::prelude::*;
+
+/// The value masked fields are replaced with.
+pub const MASK: &str = "[REDACTED]";
+
+/// What happens to a redacted field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RedactionAction {
+    /// Removes the field, or the element of a list.
+    Remove,
+    /// Replaces the value with [`MASK`].
+    Mask,
+}
+
+/// Redacts all fields at the given path, optionally only string values containing a pattern.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Redaction {
+    /// The path of the fields, e.g. `services[].endpoints[]`.
+    pub path: String,
+    pub action: RedactionAction,
+    /// Only redacts string values containing it, e.g. `.internal`.
+    pub containing: Option<String>,
+}
+
+impl Redaction {
+    /// Creates a redaction removing all fields at the given path.
+    pub fn remove<T: ToString>(path: T) -> Self {
+        Self {
+            path: path.to_string(),
+            action: RedactionAction::Remove,
+            containing: None,
+        }
+    }
+
+    /// Creates a redaction masking all fields at the given path.
+    pub fn mask<T: ToString>(path: T) -> Self {
+        Self {
+            path: path.to_string(),
+            action: RedactionAction::Mask,
+            containing: None,
+        }
+    }
+
+    /// Restricts the redaction to string values containing the given pattern.
+    pub fn containing<T: ToString>(mut self, pattern: T) -> Self {
+        self.containing = Some(pattern.to_string());
+        self
+    }
+
+    fn matches(&self, value: &Value) -> bool {
+        match &self.containing {
+            None => true,
+            Some(pattern) => value
+                .as_str()
+                .is_some_and(|value| value.contains(pattern.as_str())),
+        }
+    }
+}
+
+/// A field that was redacted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RedactedField {
+    /// The concrete path of the field in the original document, e.g. `services[0].endpoints[1]`.
+    pub path: String,
+    pub action: RedactionAction,
+}
+
+enum Segment<'a> {
+    Key(&'a str),
+    Each,
+}
+
+fn segments(path: &str) -> Vec<Segment<'_>> {
+    path.split('.')
+        .filter(|part| !part.is_empty())
+        .flat_map(|part| {
+            let key = part.trim_end_matches("[]");
+            let lists = (part.len() - key.len()) / 2;
+            core::iter::once(Segment::Key(key)).chain((0..lists).map(|_| Segment::Each))
+        })
+        .collect()
+}
+
+fn join(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", path, key)
+    }
+}
+
+/// Applies the redactions to the JSON document in place and returns the redacted fields.
+///
+/// Redactions are applied in order, a field removed by one redaction is not reported again.
+pub fn redact(document: &mut Value, redactions: &[Redaction]) -> Vec<RedactedField> {
+    let mut redacted = Vec::new();
+    for redaction in redactions {
+        redact_value(
+            document,
+            &segments(&redaction.path),
+            String::new(),
+            redaction,
+            &mut redacted,
+        );
+    }
+    redacted
+}
+
+fn redact_value(
+    value: &mut Value,
+    segments: &[Segment<'_>],
+    path: String,
+    redaction: &Redaction,
+    redacted: &mut Vec<RedactedField>,
+) {
+    let Some((segment, rest)) = segments.split_first() else {
+        return;
+    };
+
+    match (segment, value) {
+        (Segment::Key(key), Value::Object(map)) => {
+            let path = join(&path, key);
+            if !rest.is_empty() {
+                if let Some(child) = map.get_mut(*key) {
+                    redact_value(child, rest, path, redaction, redacted);
+                }
+                return;
+            }
+            if !map.get(*key).is_some_and(|child| redaction.matches(child)) {
+                return;
+            }
+            match redaction.action {
+                RedactionAction::Remove => {
+                    map.remove(*key);
+                }
+                RedactionAction::Mask => {
+                    map.insert(key.to_string(), Value::String(MASK.to_string()));
+                }
+            }
+            redacted.push(RedactedField {
+                path,
+                action: redaction.action,
+            });
+        }
+        (Segment::Each, Value::Array(values)) => {
+            if !rest.is_empty() {
+                for (index, child) in values.iter_mut().enumerate() {
+                    let path = format!("{}[{}]", path, index);
+                    redact_value(child, rest, path, redaction, redacted);
+                }
+                return;
+            }
+            let mut index = 0;
+            values.retain_mut(|child| {
+                let path = format!("{}[{}]", path, index);
+                index += 1;
+                if !redaction.matches(child) {
+                    return true;
+                }
+                redacted.push(RedactedField {
+                    path,
+                    action: redaction.action,
+                });
+                match redaction.action {
+                    RedactionAction::Remove => false,
+                    RedactionAction::Mask => {
+                        *child = Value::String(MASK.to_string());
+                        true
+                    }
+                }
+            });
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{redact, Redaction, RedactionAction, MASK};
+
+    #[test]
+    fn mask_internal_endpoints() {
+        let mut document = json!({
+            "services": [{
+                "name": "api",
+                "endpoints": ["https://api.example.com", "https://db.corp.internal"]
+            }]
+        });
+
+        let redacted = redact(
+            &mut document,
+            &[Redaction::mask("services[].endpoints[]").containing(".internal")],
+        );
+
+        assert_eq!(
+            document["services"][0]["endpoints"],
+            json!(["https://api.example.com", MASK])
+        );
+        assert_eq!(redacted.len(), 1);
+        assert_eq!(redacted[0].path, "services[0].endpoints[1]");
+        assert_eq!(redacted[0].action, RedactionAction::Mask);
+    }
+
+    #[test]
+    fn remove_author_emails() {
+        let mut document = json!({
+            "components": [
+                { "name": "a", "authors": [{ "name": "Jane", "email": "jane@example.com" }] },
+                { "name": "b" }
+            ]
+        });
+
+        let redacted = redact(
+            &mut document,
+            &[Redaction::remove("components[].authors[].email")],
+        );
+
+        assert_eq!(
+            document["components"][0]["authors"][0],
+            json!({ "name": "Jane" })
+        );
+        let paths = redacted
+            .iter()
+            .map(|field| field.path.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(paths, ["components[0].authors[0].email"]);
+    }
+
+    #[test]
+    fn remove_list_elements_reports_original_indices() {
+        let mut document = json!({ "endpoints": ["a.internal", "public", "b.internal"] });
+
+        let redacted = redact(
+            &mut document,
+            &[Redaction::remove("endpoints[]").containing(".internal")],
+        );
+
+        assert_eq!(document, json!({ "endpoints": ["public"] }));
+        let paths = redacted
+            .iter()
+            .map(|field| field.path.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(paths, ["endpoints[0]", "endpoints[2]"]);
+    }
+}
@@ -0,0 +1,566 @@
+use serde::Serialize;
+
+use crate::{
+    options::ValidationOptions,
+    paths::{list_index, PathPattern},
+    prelude::*,
+    rules, run_checks,
+    validation::{
+        Severity, SpecVersion, Suggestion, ValidationErrors, ValidationResult, VersionSupport,
+    },
+    Bom,
+};
+
+/// The version of the JSON schema of the serialized report, see [`REPORT_SCHEMA`].
+///
+/// Within a major version fields are only added, never removed or changed. New rules and
+/// finding codes do not change the schema version.
+pub const REPORT_SCHEMA_VERSION: &str = "2.3";
+
+/// The JSON schema of the report serialized by [`ValidationReport::to_json`].
+pub const REPORT_SCHEMA: &str = include_str!("../schema/validation-report-2.3.schema.json");
+
+/// A position in the source document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Span {
+    pub line: usize,
+    pub column: usize,
+}
+
+/// A single finding of a check, flattened from the error tree.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Finding {
+    /// The id of the check that reported the finding.
+    pub rule: String,
+    pub severity: Severity,
+    /// The path of the offending field with the names of the serialized document, e.g.
+    /// `components[3].bom-ref`, see [`to_wire`](crate::paths::to_wire). Since report schema
+    /// version 2.0.
+    pub path: String,
+    /// The position in the source document, `None` if the report was created from a [`Bom`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub span: Option<Span>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    /// The English message of the finding.
+    pub message: String,
+    pub suppressed: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actual: Option<String>,
+    /// A fix of the finding, see [`suggested_fixes`](crate::patch::suggested_fixes). Since report
+    /// schema version 2.2.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suggestion: Option<Suggestion>,
+    /// Links to an explanation of the finding, see [`Rule::help_uri`](crate::rules::Rule::help_uri).
+    /// Since report schema version 2.1.
+    #[serde(rename = "helpUri", skip_serializing_if = "Option::is_none")]
+    pub help_uri: Option<String>,
+    /// The spec versions supporting the offending field, set if the validated one does not.
+    /// Since report schema version 2.3.
+    #[serde(rename = "versionSupport", skip_serializing_if = "Option::is_none")]
+    pub version_support: Option<VersionSupport>,
+}
+
+impl Finding {
+    fn collect(rule: &str, errors: &ValidationErrors) -> Vec<Finding> {
+        errors
+            .errors()
+            .into_iter()
+            .map(|(path, error)| Finding {
+                rule: rule.to_string(),
+                severity: error.severity,
+                path,
+                span: None,
+                code: error.code.clone(),
+                message: error.to_string(),
+                suppressed: error.suppressed,
+                expected: error.context.expected.clone(),
+                actual: error.context.actual.clone(),
+                suggestion: error.context.suggestion.clone(),
+                help_uri: error.help_uri.as_deref().map(str::to_string),
+                version_support: error.version_support,
+            })
+            .collect()
+    }
+}
+
+/// Why a check or a part of the document was not checked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SkipReason {
+    /// The check only applies from a later spec version on.
+    VersionGated,
+    /// The check does not apply to the document, e.g. due to the selected profile.
+    NotApplicable,
+    /// The check was disabled in the options.
+    Disabled,
+    /// The part of the document was excluded in the options.
+    ExcludedPath,
+    /// Only the included paths were checked, everything else was not.
+    OutsideIncludedPaths,
+}
+
+/// A check or part of the document that was not checked, recorded if
+/// [`ValidationOptions::record_skips`] is set.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Skipped {
+    /// The id of the skipped check.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rule: Option<String>,
+    /// The path pattern of the part of the document that was not checked.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    pub reason: SkipReason,
+    /// The first spec version a [`SkipReason::VersionGated`] check applies to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub since: Option<String>,
+}
+
+impl Skipped {
+    fn rule(id: &str, options: &ValidationOptions, version: SpecVersion) -> Self {
+        let since = rules::all()
+            .iter()
+            .find(|info| info.id == id)
+            .map(|info| info.since)
+            .filter(|since| version < *since);
+        let reason = match since {
+            _ if options.is_rule_disabled(id) => SkipReason::Disabled,
+            Some(_) => SkipReason::VersionGated,
+            None => SkipReason::NotApplicable,
+        };
+
+        Skipped {
+            rule: Some(id.to_string()),
+            path: None,
+            since: since
+                .filter(|_| reason == SkipReason::VersionGated)
+                .map(|since| since.to_string()),
+            reason,
+        }
+    }
+
+    fn path(path: Option<&PathPattern>, reason: SkipReason) -> Self {
+        Skipped {
+            rule: None,
+            path: path.map(|pattern| pattern.as_str().to_string()),
+            reason,
+            since: None,
+        }
+    }
+}
+
+/// The serialized form of a [`ValidationReport`], see [`REPORT_SCHEMA`].
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ReportDocument<'a> {
+    schema_version: &'static str,
+    spec_version: String,
+    passed: &'a [String],
+    failed: &'a [String],
+    skipped: &'a [String],
+    findings: &'a [Finding],
+    #[serde(skip_serializing_if = "<[Skipped]>::is_empty")]
+    skips: &'a [Skipped],
+}
+
+/// A full report of a validation, listing every check that was run, not only the failures.
+///
+/// Checks are identified by the id of their [`Rule`](crate::rules::Rule), the built-in
+/// validation of the model is identified as `model`. A check passes if it reports no errors,
+/// or only suppressed ones.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationReport {
+    /// The ids of all checks that ran without errors.
+    pub passed: Vec<String>,
+    /// The ids of all checks that reported errors.
+    pub failed: Vec<String>,
+    /// The ids of all checks that did not apply to the document or were disabled.
+    pub skipped: Vec<String>,
+    /// Why checks or parts of the document were not checked, only recorded if
+    /// [`ValidationOptions::record_skips`] is set.
+    pub skips: Vec<Skipped>,
+    /// All findings of all checks, including suppressed ones.
+    pub findings: Vec<Finding>,
+    /// The spec version the document was validated against.
+    pub version: SpecVersion,
+    /// The combined error tree of all checks, same as returned by [`validate_bom`](crate::validate_bom).
+    pub result: ValidationResult,
+}
+
+impl ValidationReport {
+    /// Returns the purl of the component the finding was reported for, if any.
+    fn component_purl<'a>(bom: &'a Bom, finding: &Finding) -> Option<&'a str> {
+        let component = if finding.path.starts_with("metadata.component.") {
+            bom.meta_data
+                .as_ref()
+                .and_then(|metadata| metadata.component.as_ref())
+        } else {
+            list_index(&finding.path, "components")
+                .and_then(|index| bom.components.as_ref()?.get(index))
+        };
+        component.and_then(|component| component.purl.as_deref())
+    }
+
+    /// Renders all findings as CSV, one row per finding with the columns `path`, `rule`,
+    /// `severity`, `code`, `message`, `suppressed` and `purl` of the component, e.g. for
+    /// spreadsheets.
+    ///
+    /// `bom` has to be the document the report was created for.
+    pub fn to_csv(&self, bom: &Bom) -> String {
+        let mut csv = String::from("path,rule,severity,code,message,suppressed,purl\r\n");
+        for finding in &self.findings {
+            let severity = match finding.severity {
+                Severity::Info => "info",
+                Severity::Warning => "warning",
+                Severity::Error => "error",
+            };
+            let row = [
+                finding.path.as_str(),
+                finding.rule.as_str(),
+                severity,
+                finding.code.as_deref().unwrap_or_default(),
+                finding.message.as_str(),
+                if finding.suppressed { "true" } else { "false" },
+                Self::component_purl(bom, finding).unwrap_or_default(),
+            ];
+            csv.push_str(&row.map(csv_field).join(","));
+            csv.push_str("\r\n");
+        }
+        csv
+    }
+
+    /// Serializes the report as JSON that conforms to [`REPORT_SCHEMA`].
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(&ReportDocument {
+            schema_version: REPORT_SCHEMA_VERSION,
+            spec_version: self.version.to_string(),
+            passed: &self.passed,
+            failed: &self.failed,
+            skipped: &self.skipped,
+            findings: &self.findings,
+            skips: &self.skips,
+        })
+    }
+}
+
+/// Quotes a CSV field if it contains a separator, quote or line break, see RFC 4180.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Validates the bom like [`validate_bom`](crate::validate_bom), but reports all checks.
+pub fn validate_report(
+    version: SpecVersion,
+    bom: &Bom,
+    options: &ValidationOptions,
+) -> ValidationReport {
+    let mut report = ValidationReport {
+        passed: Vec::new(),
+        failed: Vec::new(),
+        skipped: Vec::new(),
+        skips: Vec::new(),
+        findings: Vec::new(),
+        version,
+        result: Ok(()),
+    };
+
+    for (id, result) in run_checks(version, bom, options) {
+        if let Some(Err(errors)) = &result {
+            report.findings.extend(Finding::collect(&id, errors));
+        }
+        match result {
+            None => {
+                if options.records_skips() {
+                    report.skips.push(Skipped::rule(&id, options, version));
+                }
+                report.skipped.push(id);
+            }
+            Some(Err(errors)) if errors.has_unsuppressed() => {
+                report.failed.push(id);
+                report.result = ValidationErrors::merge_errors(report.result, Err(errors));
+            }
+            Some(result) => {
+                report.passed.push(id);
+                report.result = ValidationErrors::merge_errors(report.result, result);
+            }
+        }
+    }
+
+    if options.records_skips() {
+        report.skips.extend(
+            options
+                .excluded_paths()
+                .iter()
+                .map(|pattern| Skipped::path(Some(pattern), SkipReason::ExcludedPath)),
+        );
+        if !options.included_paths().is_empty() {
+            report
+                .skips
+                .push(Skipped::path(None, SkipReason::OutsideIncludedPaths));
+        }
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        options::ValidationOptions, suppress::Suppression, validation::SpecVersion, Bom, Component,
+        ComponentType, Metadata,
+    };
+
+    use super::{validate_report, SkipReason, Skipped, REPORT_SCHEMA, REPORT_SCHEMA_VERSION};
+
+    #[test]
+    fn suppress_and_exclude_reported_paths() {
+        let bom = Bom::parse_json(
+            r#"{ "metadata": { "authors": [
+                { "email": "jane@example.com" }, { "email": "jane@example.com" }
+            ] } }"#,
+        )
+        .expect("Failed to parse BOM");
+        let duplicates = |options: &ValidationOptions| {
+            validate_report(SpecVersion::V1_5, &bom, options)
+                .findings
+                .into_iter()
+                .filter(|finding| finding.code.as_deref() == Some("duplicate-contact"))
+                .collect::<Vec<_>>()
+        };
+
+        let findings = duplicates(&ValidationOptions::new());
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].path, "metadata.authors");
+        assert!(!findings[0].suppressed);
+
+        let suppression = Suppression::parse(&format!("duplicate-contact at {}", findings[0].path));
+        let suppressed = duplicates(&ValidationOptions::new().suppress(suppression));
+        assert!(suppressed.iter().all(|finding| finding.suppressed));
+
+        let excluded = ValidationOptions::new().exclude_paths([findings[0].path.as_str()]);
+        assert!(duplicates(&excluded).is_empty());
+    }
+
+    #[test]
+    fn report_lists_passed_failed_and_skipped_checks() {
+        let bom = Bom {
+            meta_data: Some(Metadata {
+                component: Some(Component {
+                    purl: Some(String::from("pkg:cargo/other@1.0.0")),
+                    ..Component::new(ComponentType::Application, "app")
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let report = validate_report(SpecVersion::V1_5, &bom, &ValidationOptions::new());
+
+        assert_eq!(
+            report.passed,
+            vec![
+                "model",
+                "hash-consistency",
+                "rating-consistency",
+                "affected-versions",
+                "composition-completeness",
+                "vulnerability-timestamps",
+                "tool-components",
+                "identity-confidence",
+                "formulation-references",
+                "external-reference-urls",
+                "release-notes",
+                "duplicate-contacts",
+                "identifier-consistency"
+            ]
+        );
+        assert_eq!(report.failed, vec!["metadata-component"]);
+        assert_eq!(
+            report.skipped,
+            vec![
+                "completeness",
+                "weak-hashes",
+                "purl-presence",
+                "document-kind",
+                "supplier-consistency",
+                "assemblies",
+                "hash-policy"
+            ]
+        );
+        assert!(report.result.is_err());
+    }
+
+    #[test]
+    fn records_skipped_checks_and_paths() {
+        let options = ValidationOptions::new()
+            .disable_rule("hash-consistency")
+            .exclude_paths(["components[*].purl"])
+            .record_skips(true);
+
+        let report = validate_report(SpecVersion::V1_4, &Bom::default(), &options);
+        let reason = |rule: &str| {
+            report
+                .skips
+                .iter()
+                .find(|skip| skip.rule.as_deref() == Some(rule))
+                .map(|skip| skip.reason)
+        };
+
+        assert!(report.skipped.contains(&String::from("hash-consistency")));
+        assert_eq!(reason("hash-consistency"), Some(SkipReason::Disabled));
+        assert_eq!(reason("tool-components"), Some(SkipReason::VersionGated));
+        assert_eq!(reason("completeness"), Some(SkipReason::NotApplicable));
+        assert_eq!(reason("model"), None);
+        assert!(report.skips.contains(&Skipped {
+            rule: None,
+            path: Some(String::from("components[*].purl")),
+            reason: SkipReason::ExcludedPath,
+            since: None,
+        }));
+
+        let report = validate_report(
+            SpecVersion::V1_4,
+            &Bom::default(),
+            &ValidationOptions::new(),
+        );
+        assert!(report.skips.is_empty());
+    }
+
+    #[test]
+    fn published_schemas_only_add_fields() {
+        let schemas = [
+            include_str!("../schema/validation-report-1.0.schema.json"),
+            include_str!("../schema/validation-report-1.1.schema.json"),
+            include_str!("../schema/validation-report-1.2.schema.json"),
+            include_str!("../schema/validation-report-2.0.schema.json"),
+            include_str!("../schema/validation-report-2.1.schema.json"),
+            include_str!("../schema/validation-report-2.2.schema.json"),
+            include_str!("../schema/validation-report-2.3.schema.json"),
+        ]
+        .map(|schema| {
+            serde_json::from_str::<serde_json::Value>(schema).expect("Failed to parse schema")
+        });
+        assert_eq!(
+            schemas.last(),
+            Some(&serde_json::from_str(REPORT_SCHEMA).expect("Failed to parse schema"))
+        );
+
+        for pair in schemas.windows(2) {
+            let version = |schema: &serde_json::Value| {
+                schema["properties"]["schemaVersion"]["const"]
+                    .as_str()
+                    .and_then(|version| version.split_once('.'))
+                    .map(|(major, _)| major.to_string())
+            };
+            if version(&pair[0]) != version(&pair[1]) {
+                continue;
+            }
+            for properties in ["/properties", "/definitions/finding/properties"] {
+                let old = pair[0]
+                    .pointer(properties)
+                    .and_then(|value| value.as_object());
+                let new = pair[1]
+                    .pointer(properties)
+                    .and_then(|value| value.as_object());
+                let (Some(old), Some(new)) = (old, new) else {
+                    panic!("Expected {} in both schemas", properties);
+                };
+                for key in old.keys().filter(|key| *key != "schemaVersion") {
+                    assert!(new.contains_key(key), "{} was removed", key);
+                    // severities are added to the enum
+                    if key != "severity" {
+                        assert_eq!(old[key], new[key], "{} was changed", key);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn serialized_report_matches_schema() {
+        let bom = Bom {
+            components: Some(vec![Component {
+                purl: Some(String::from("cargo/serde")),
+                ..Component::new(ComponentType::Library, "serde")
+            }]),
+            ..Default::default()
+        };
+        let report = validate_report(SpecVersion::V1_5, &bom, &ValidationOptions::new());
+        let json: serde_json::Value =
+            serde_json::from_str(&report.to_json().expect("Failed to serialize report"))
+                .expect("Failed to parse report");
+        let schema: serde_json::Value =
+            serde_json::from_str(REPORT_SCHEMA).expect("Failed to parse schema");
+
+        assert_eq!(
+            schema["properties"]["schemaVersion"]["const"],
+            REPORT_SCHEMA_VERSION
+        );
+        assert_eq!(json["schemaVersion"], REPORT_SCHEMA_VERSION);
+        assert_eq!(json["specVersion"], "1.5");
+
+        let known = |properties: &serde_json::Value, value: &serde_json::Value| {
+            value.as_object().is_some_and(|object| {
+                object
+                    .keys()
+                    .all(|key| properties.get(key.as_str()).is_some())
+            })
+        };
+        assert!(known(&schema["properties"], &json));
+        let finding = &json["findings"][0];
+        assert_eq!(finding["rule"], "model");
+        assert_eq!(finding["path"], "components[0].purl");
+        assert_eq!(finding["severity"], "error");
+        assert!(known(
+            &schema["definitions"]["finding"]["properties"],
+            finding
+        ));
+
+        let bom = Bom {
+            components: Some(vec![Component {
+                authors: Some(Vec::new()),
+                ..Component::new(ComponentType::Library, "serde")
+            }]),
+            ..Default::default()
+        };
+        let report = validate_report(SpecVersion::V1_5, &bom, &ValidationOptions::new());
+        let json: serde_json::Value =
+            serde_json::from_str(&report.to_json().expect("Failed to serialize report"))
+                .expect("Failed to parse report");
+        let unsupported = &json["findings"][0];
+        assert_eq!(unsupported["path"], "components[0].authors");
+        assert_eq!(unsupported["versionSupport"]["since"], "1.6");
+        assert!(known(
+            &schema["definitions"]["finding"]["properties"],
+            unsupported
+        ));
+    }
+
+    #[test]
+    fn renders_findings_as_csv() {
+        let bom = Bom {
+            components: Some(vec![Component {
+                purl: Some(String::from("cargo/serde,1")),
+                ..Component::new(ComponentType::Library, "serde")
+            }]),
+            ..Default::default()
+        };
+        let report = validate_report(SpecVersion::V1_5, &bom, &ValidationOptions::new());
+        let csv = report.to_csv(&bom);
+        let mut lines = csv.split("\r\n");
+
+        assert_eq!(
+            lines.next(),
+            Some("path,rule,severity,code,message,suppressed,purl")
+        );
+        let row = lines.next().expect("Expected a finding");
+        assert!(row.starts_with("components[0].purl,model,error,"));
+        assert!(row.ends_with(",false,\"cargo/serde,1\""));
+    }
+}
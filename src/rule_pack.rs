@@ -0,0 +1,399 @@
+//! Rule packs bundle the policy of an organization into one TOML file that can be distributed
+//! and loaded with [`ValidationOptions::load_rule_pack`], e.g.
+//!
+//! ```toml
+//! name = "acme-policy"
+//! version = "1.2.0"
+//! suppress = ["missing-publisher"]
+//!
+//! [severity]
+//! missing-supplier = "error"
+//!
+//! [[rules]]
+//! id = "acme-purls"
+//! path = "components[*].purl"
+//! required = true
+//! pattern = "pkg:maven/com.acme/*"
+//! message = "Components must be published to the internal registry"
+//!
+//! [[rules]]
+//! id = "acme-scopes"
+//! path = "components[*].scope"
+//! allowed = ["required", "optional"]
+//! severity = "error"
+//! ```
+//!
+//! Rules select fields with a [`PathPattern`] formatted like the [`paths`] constants. A field
+//! selected by a rule must be present if `required` is set, and its string values, or each
+//! string of a list, must match the glob `pattern` and be one of the `allowed` values.
+use std::{fs, path::Path};
+
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+use crate::{
+    config::ConfigError,
+    map::IndexMap,
+    options::ValidationOptions,
+    paths::{self, PathPattern},
+    rules::{Rule, RuleContext},
+    suppress::Suppression,
+    validation::{Severity, ValidationContext, ValidationError, ValidationResult},
+    Bom,
+};
+
+/// A bundle of declarative rules, severity overrides and suppressions, see the
+/// [module documentation](self).
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct RulePack {
+    /// The name of the pack, e.g. the policy it implements.
+    pub name: String,
+    /// The version of the pack.
+    pub version: Option<String>,
+    /// Findings to suppress in the form `CODE` or `CODE at PATH`.
+    pub suppress: Vec<String>,
+    /// Overrides the severity of findings by their code.
+    pub severity: IndexMap<String, Severity>,
+    /// The declarative rules of the pack.
+    pub rules: Vec<PackRule>,
+}
+
+/// A declarative rule of a [`RulePack`].
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct PackRule {
+    /// Identifies the rule, also the code of its findings unless `code` is set.
+    pub id: String,
+    /// Selects the checked fields, e.g. `components[*].purl`.
+    #[serde(deserialize_with = "deserialize_pattern")]
+    pub path: PathPattern,
+    /// Whether the selected fields must be present.
+    #[serde(default)]
+    pub required: bool,
+    /// A glob the values must match, `*` matches any text.
+    pub pattern: Option<String>,
+    /// The values the fields may have.
+    pub allowed: Option<Vec<String>>,
+    /// The severity of the findings, defaults to a warning.
+    #[serde(default = "default_severity")]
+    pub severity: Severity,
+    /// The code of the findings, defaults to the id of the rule.
+    pub code: Option<String>,
+    /// A free text message replacing the default message of the findings.
+    pub message: Option<String>,
+    /// Links to an explanation of the policy.
+    pub help_uri: Option<String>,
+}
+
+fn deserialize_pattern<'de, D>(deserializer: D) -> Result<PathPattern, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    String::deserialize(deserializer).map(|pattern| PathPattern::parse(&pattern))
+}
+
+fn default_severity() -> Severity {
+    Severity::Warning
+}
+
+fn matches_glob(glob: &str, value: &str) -> bool {
+    let Some((prefix, rest)) = glob.split_once('*') else {
+        return glob == value;
+    };
+    let Some(value) = value.strip_prefix(prefix) else {
+        return false;
+    };
+    (0..=value.len())
+        .filter(|index| value.is_char_boundary(*index))
+        .any(|index| matches_glob(rest, &value[index..]))
+}
+
+fn join(parent: &str, key: &str) -> String {
+    if parent.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", parent, key)
+    }
+}
+
+impl RulePack {
+    /// Parses the rule pack from a TOML string.
+    pub fn from_toml(input: &str) -> Result<Self, ConfigError> {
+        let pack: Self = toml::from_str(input).map_err(ConfigError::Parse)?;
+        pack.check()?;
+        Ok(pack)
+    }
+
+    /// Reads the rule pack from a TOML file.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, ConfigError> {
+        let input = fs::read_to_string(path).map_err(ConfigError::Io)?;
+        Self::from_toml(&input)
+    }
+
+    /// Rejects rules that cannot report anything and rules sharing an id.
+    fn check(&self) -> Result<(), ConfigError> {
+        for (index, rule) in self.rules.iter().enumerate() {
+            if !rule.required && rule.pattern.is_none() && rule.allowed.is_none() {
+                return Err(ConfigError::Invalid(format!(
+                    "rule '{}' has neither `required`, `pattern` nor `allowed`",
+                    rule.id
+                )));
+            }
+            if self.rules[..index].iter().any(|other| other.id == rule.id) {
+                return Err(ConfigError::Invalid(format!(
+                    "rule '{}' is declared more than once",
+                    rule.id
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the parsed suppressions.
+    pub fn suppressions(&self) -> Vec<Suppression> {
+        self.suppress
+            .iter()
+            .map(|suppression| Suppression::parse(suppression))
+            .collect()
+    }
+}
+
+impl PackRule {
+    fn error(&self, message: &str) -> ValidationError {
+        ValidationError::new(self.message.as_deref().unwrap_or(message))
+            .with_severity(self.severity)
+            .with_param("rule", &self.id)
+            .with_code(self.code.as_deref().unwrap_or(&self.id))
+    }
+
+    fn check_value(&self, value: &str) -> Option<ValidationError> {
+        if let Some(pattern) = &self.pattern {
+            if !matches_glob(pattern, value) {
+                return Some(
+                    self.error("pack_pattern_mismatch")
+                        .with_expected(pattern)
+                        .with_actual(value),
+                );
+            }
+        }
+        match &self.allowed {
+            Some(allowed) if !allowed.iter().any(|allowed| allowed == value) => Some(
+                self.error("pack_value_not_allowed")
+                    .with_expected(allowed.join(", "))
+                    .with_actual(value),
+            ),
+            _ => None,
+        }
+    }
+
+    /// Checks the fields of an object, `wire` is its path in the serialized document.
+    fn check_object(&self, object: &Map<String, Value>, wire: &str) -> ValidationResult {
+        let model = paths::from_wire(wire);
+        let mut context = ValidationContext::new();
+
+        // a missing field has no value to visit, so its path is derived from the pattern
+        if self.required {
+            if let Some(field) = self.path.as_str().rsplit('.').next() {
+                let path = join(&model, field);
+                let key = paths::to_wire(&path);
+                let key = key.rsplit('.').next().unwrap_or(field);
+                if self.path.matches(&path) && !object.contains_key(key) {
+                    context =
+                        context.add_field(field, Some(Err(self.error("pack_required_field"))));
+                }
+            }
+        }
+
+        for (key, value) in object {
+            let path = join(wire, key);
+            let model = paths::from_wire(&path);
+            let field = model.rsplit('.').next().unwrap_or(key);
+
+            if self.path.matches(&model) {
+                context = match value {
+                    Value::String(value) => {
+                        context.add_field(field, self.check_value(value).map(Err))
+                    }
+                    Value::Array(items) => {
+                        items
+                            .iter()
+                            .enumerate()
+                            .fold(context, |context, (index, item)| {
+                                let error = item
+                                    .as_str()
+                                    .and_then(|item| self.check_value(item))
+                                    .map(|error| Err(error.with_index(index)));
+                                context.add_field(field, error)
+                            })
+                    }
+                    _ => context,
+                };
+            } else if self.path.is_below(&model) {
+                context = match value {
+                    Value::Object(object) => {
+                        context.add_struct(field, Some(self.check_object(object, &path)))
+                    }
+                    Value::Array(items) => {
+                        let children = items
+                            .iter()
+                            .enumerate()
+                            .map(|(index, item)| match item {
+                                Value::Object(object) => {
+                                    self.check_object(object, &format!("{}[{}]", path, index))
+                                }
+                                _ => Ok(()),
+                            })
+                            .collect::<Vec<_>>();
+                        context.add_list(field, Some(children))
+                    }
+                    _ => context,
+                };
+            }
+        }
+
+        context.into()
+    }
+}
+
+impl Rule for PackRule {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn help_uri(&self) -> Option<&str> {
+        self.help_uri.as_deref()
+    }
+
+    fn check(&self, bom: &Bom, _ctx: &RuleContext) -> ValidationResult {
+        match bom.to_value() {
+            Value::Object(object) => self.check_object(&object, ""),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl ValidationOptions {
+    /// Applies a [`RulePack`], i.e. registers its rules and adds its suppressions and severity
+    /// overrides.
+    pub fn with_rule_pack(self, pack: &RulePack) -> Self {
+        let options = pack
+            .suppressions()
+            .into_iter()
+            .fold(self, |options, suppression| options.suppress(suppression));
+        let options = pack
+            .severity
+            .iter()
+            .fold(options, |options, (code, severity)| {
+                options.severity(code, *severity)
+            });
+        pack.rules
+            .iter()
+            .fold(options, |options, rule| options.add_rule(rule.clone()))
+    }
+
+    /// Reads the [`RulePack`] from a TOML file and applies it.
+    pub fn load_rule_pack<P: AsRef<Path>>(self, path: P) -> Result<Self, ConfigError> {
+        RulePack::load(path).map(|pack| self.with_rule_pack(&pack))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        config::ConfigError,
+        options::ValidationOptions,
+        testing::errors_at,
+        validate_bom,
+        validation::{Severity, SpecVersion},
+        Bom,
+    };
+
+    use super::RulePack;
+
+    const PACK: &str = r#"
+        name = "acme-policy"
+        version = "1.2.0"
+        suppress = ["missing-publisher"]
+
+        [severity]
+        missing-supplier = "error"
+
+        [[rules]]
+        id = "acme-purls"
+        path = "components[*].purl"
+        required = true
+        pattern = "pkg:maven/com.acme/*"
+        message = "Components must be published to the internal registry"
+        help-uri = "https://acme.example.com/policy#purls"
+
+        [[rules]]
+        id = "acme-bom-refs"
+        path = "components[*].bom_ref"
+        allowed = ["app", "lib"]
+        severity = "error"
+    "#;
+
+    #[test]
+    fn parse_rule_pack() {
+        let pack = RulePack::from_toml(PACK).expect("Failed to parse rule pack");
+
+        assert_eq!(pack.name, "acme-policy");
+        assert_eq!(pack.version.as_deref(), Some("1.2.0"));
+        assert_eq!(pack.severity["missing-supplier"], Severity::Error);
+        assert_eq!(pack.rules.len(), 2);
+        assert_eq!(pack.rules[0].severity, Severity::Warning);
+        assert_eq!(pack.rules[1].path.as_str(), "components[*].bom_ref");
+    }
+
+    #[test]
+    fn rejects_inconsistent_rule_packs() {
+        let empty = "[[rules]]\nid = \"noop\"\npath = \"components[*].purl\"";
+        assert!(matches!(
+            RulePack::from_toml(empty),
+            Err(ConfigError::Invalid(_))
+        ));
+
+        let duplicate = "[[rules]]\nid = \"a\"\npath = \"a\"\nrequired = true\n\
+                         [[rules]]\nid = \"a\"\npath = \"b\"\nrequired = true";
+        assert!(matches!(
+            RulePack::from_toml(duplicate),
+            Err(ConfigError::Invalid(_))
+        ));
+    }
+
+    #[test]
+    fn run_rules_of_pack() {
+        let pack = RulePack::from_toml(PACK).expect("Failed to parse rule pack");
+        let bom = Bom::parse_json(
+            r#"{ "components": [
+                { "type": "library", "name": "ok", "bom-ref": "lib", "purl": "pkg:maven/com.acme/ok@1.0" },
+                { "type": "library", "name": "missing", "bom-ref": "other" },
+                { "type": "library", "name": "foreign", "purl": "pkg:maven/org.example/foreign@1.0" }
+            ] }"#,
+        )
+        .expect("Failed to parse BOM");
+        let options = ValidationOptions::new().with_rule_pack(&pack);
+        let result = validate_bom(SpecVersion::V1_5, &bom, &options);
+
+        assert!(errors_at(&result, "components[0].purl").is_empty());
+        assert!(errors_at(&result, "components[0].bom_ref").is_empty());
+
+        let errors = errors_at(&result, "components[1].purl");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code.as_deref(), Some("acme-purls"));
+        assert_eq!(
+            errors[0].help_uri.as_deref(),
+            Some("https://acme.example.com/policy#purls")
+        );
+
+        let errors = errors_at(&result, "components[1].bom_ref");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].severity, Severity::Error);
+        assert_eq!(errors[0].context.expected.as_deref(), Some("app, lib"));
+
+        let errors = errors_at(&result, "components[2].purl");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].message.param("rule"), Some("acme-purls"));
+    }
+}
@@ -0,0 +1,668 @@
+mod affects;
+mod assemblies;
+mod completeness;
+mod compositions;
+mod contacts;
+mod document_kind;
+mod endpoints;
+mod evidence;
+mod formulation;
+mod groups;
+mod hashes;
+mod identifiers;
+mod metadata_component;
+mod qualifiers;
+mod ratings;
+mod references;
+mod release_notes;
+mod services;
+mod supplier;
+mod timestamp_consistency;
+mod tools;
+mod unicode;
+mod vulnerability_timestamps;
+
+pub use affects::AffectedVersionsRule;
+pub use assemblies::AssemblyRule;
+pub use completeness::{CompletenessRule, PurlPresenceRule};
+pub use compositions::CompositionCompletenessRule;
+pub use contacts::DuplicateContactsRule;
+pub use document_kind::DocumentKindRule;
+pub use endpoints::EndpointSchemeRule;
+pub use evidence::IdentityConfidenceRule;
+pub use formulation::FormulationReferencesRule;
+pub use groups::{GroupConventionRule, GroupPattern};
+pub use hashes::{HashConsistencyRule, HashPolicyRule, WeakHashRule};
+pub use identifiers::IdentifierConsistencyRule;
+pub use metadata_component::MetadataComponentRule;
+pub use qualifiers::PurlQualifierRule;
+pub use ratings::RatingConsistencyRule;
+pub use references::ExternalReferenceUrlRule;
+pub use release_notes::ReleaseNotesRule;
+pub use services::{MissingTrustFlagsRule, UnauthenticatedBoundaryRule};
+pub use supplier::SupplierConsistencyRule;
+pub use timestamp_consistency::TimestampConsistencyRule;
+pub use tools::ToolComponentsRule;
+pub use unicode::{UnicodeMode, UnicodeRule};
+pub(crate) use vulnerability_timestamps::validate_timestamp;
+pub use vulnerability_timestamps::VulnerabilityTimestampsRule;
+
+use alloc::{borrow::Cow, sync::Arc};
+
+use crate::{
+    graph::BomGraph,
+    options::{Profile, TimestampTolerance},
+    paths::*,
+    prelude::*,
+    validation::{Severity, SpecVersion, ValidationContext, ValidationErrors, ValidationResult},
+    Bom, Component, DocumentKind, HashAlgorithm, Metadata, Vulnerability,
+};
+
+/// Contextual information handed to every [`Rule`] when it is run.
+#[derive(Debug, Clone)]
+pub struct RuleContext {
+    /// The spec version the document is validated against.
+    pub version: SpecVersion,
+    /// The profile selected in the [`ValidationOptions`](crate::options::ValidationOptions).
+    pub profile: Profile,
+    /// The kind of the document, detected or selected in the options.
+    pub kind: DocumentKind,
+    /// How strictly timestamps are parsed, selected in the options.
+    pub timestamp_tolerance: TimestampTolerance,
+    /// The hash algorithms components must declare one digest of, selected in the options.
+    pub allowed_hash_algorithms: Option<Vec<HashAlgorithm>>,
+    graph: Option<Arc<BomGraph>>,
+}
+
+impl RuleContext {
+    pub fn new(version: SpecVersion) -> Self {
+        Self {
+            version,
+            profile: Profile::default(),
+            kind: DocumentKind::default(),
+            timestamp_tolerance: TimestampTolerance::default(),
+            allowed_hash_algorithms: None,
+            graph: None,
+        }
+    }
+
+    pub fn with_profile(mut self, profile: Profile) -> Self {
+        self.profile = profile;
+        self
+    }
+
+    pub fn with_kind(mut self, kind: DocumentKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    pub fn with_timestamp_tolerance(mut self, tolerance: TimestampTolerance) -> Self {
+        self.timestamp_tolerance = tolerance;
+        self
+    }
+
+    pub fn with_allowed_hash_algorithms(mut self, algorithms: Option<&[HashAlgorithm]>) -> Self {
+        self.allowed_hash_algorithms = algorithms.map(<[HashAlgorithm]>::to_vec);
+        self
+    }
+
+    /// Shares the graph of the validated document with all rules.
+    pub fn with_graph(mut self, graph: BomGraph) -> Self {
+        self.graph = Some(Arc::new(graph));
+        self
+    }
+
+    /// Returns the shared graph of the document, or builds it if none was set.
+    pub fn graph<'a>(&'a self, bom: &Bom) -> Cow<'a, BomGraph> {
+        match &self.graph {
+            Some(graph) => Cow::Borrowed(graph),
+            None => Cow::Owned(BomGraph::new(bom)),
+        }
+    }
+}
+
+/// A check that runs against the whole [`Bom`] in addition to the built-in validation.
+///
+/// Downstream crates can implement this trait to ship their own organization-specific checks,
+/// which are registered via [`ValidationOptions::add_rule`](crate::options::ValidationOptions::add_rule).
+/// Errors returned by a rule are merged into the same error tree as the built-in validation.
+/// Rules are shared across threads by a [`Validator`](crate::validator::Validator).
+pub trait Rule: Send + Sync {
+    fn check(&self, bom: &Bom, ctx: &RuleContext) -> ValidationResult;
+
+    /// Identifies the rule in a [`ValidationReport`](crate::report::ValidationReport),
+    /// defaults to the type name of the rule.
+    fn id(&self) -> &str {
+        core::any::type_name::<Self>()
+    }
+
+    /// Returns `false` if the rule does not apply to the document, it is reported as skipped.
+    fn applies(&self, _bom: &Bom, _ctx: &RuleContext) -> bool {
+        true
+    }
+
+    /// Links to an explanation of the findings of the rule, set on every finding without one.
+    /// Defaults to the [`RuleInfo::help_uri`] of the built-in or opt-in rule with the same id.
+    fn help_uri(&self) -> Option<&str> {
+        all()
+            .iter()
+            .chain(opt_in())
+            .find(|info| info.id == self.id())
+            .and_then(|info| info.help_uri)
+    }
+}
+
+/// Returns the built-in rules that run for every validated document.
+pub(crate) fn builtin() -> Vec<Box<dyn Rule>> {
+    vec![
+        Box::new(CompletenessRule),
+        Box::new(MetadataComponentRule),
+        Box::new(HashConsistencyRule),
+        Box::new(WeakHashRule),
+        Box::new(PurlPresenceRule),
+        Box::new(RatingConsistencyRule),
+        Box::new(AffectedVersionsRule),
+        Box::new(CompositionCompletenessRule),
+        Box::new(VulnerabilityTimestampsRule),
+        Box::new(ToolComponentsRule),
+        Box::new(IdentityConfidenceRule),
+        Box::new(FormulationReferencesRule),
+        Box::new(DocumentKindRule),
+        Box::new(SupplierConsistencyRule),
+        Box::new(ExternalReferenceUrlRule),
+        Box::new(AssemblyRule),
+        Box::new(ReleaseNotesRule),
+        Box::new(DuplicateContactsRule),
+        Box::new(HashPolicyRule),
+        Box::new(IdentifierConsistencyRule),
+    ]
+}
+
+/// Describes a built-in or opt-in rule, e.g. to generate documentation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RuleInfo {
+    /// The id the rule is reported with, see [`Rule::id`].
+    pub id: &'static str,
+    pub description: &'static str,
+    /// The highest severity of the findings reported by the rule.
+    pub severity: Severity,
+    /// The first spec version the rule applies to.
+    pub since: SpecVersion,
+    /// The profile the rule requires, [`Profile::Default`] rules always run.
+    pub profile: Profile,
+    /// The codes of the findings reported by the rule, e.g. `missing-supplier`.
+    pub codes: &'static [&'static str],
+    /// The paths findings are reported at, formatted like the constants of [`paths`](crate::paths).
+    pub paths: &'static [&'static str],
+    /// Links to an explanation of the findings, usually the relevant section of the spec.
+    pub help_uri: Option<&'static str>,
+}
+
+impl RuleInfo {
+    /// Returns `true` if the rule runs for documents of the given spec version and profile.
+    pub fn applies_to(&self, version: SpecVersion, profile: Profile) -> bool {
+        version >= self.since && (self.profile == Profile::Default || self.profile == profile)
+    }
+}
+
+/// Returns the link to a section of the JSON reference of the specification.
+macro_rules! spec_docs {
+    ($anchor:literal) => {
+        concat!("https://cyclonedx.org/docs/1.6/json/#", $anchor)
+    };
+}
+
+const BUILTIN_RULES: &[RuleInfo] = &[
+    RuleInfo {
+        id: "completeness",
+        description: "Warns about components without supplier, author or publisher",
+        severity: Severity::Warning,
+        since: SpecVersion::V1_3,
+        profile: Profile::Strict,
+        codes: &["missing-supplier", "missing-author", "missing-publisher"],
+        paths: &[COMPONENTS_SUPPLIER, COMPONENTS_AUTHOR, COMPONENTS_PUBLISHER],
+        help_uri: Some(spec_docs!("components_items_supplier")),
+    },
+    RuleInfo {
+        id: "metadata-component",
+        description: "Checks that metadata.component is the root of the document",
+        severity: Severity::Error,
+        since: SpecVersion::V1_3,
+        profile: Profile::Default,
+        codes: &[
+            "metadata-component-not-root",
+            "metadata-component-duplicated",
+            "metadata-component-mismatch",
+        ],
+        paths: &[
+            META_DATA_COMPONENT_BOM_REF,
+            META_DATA_COMPONENT_NAME,
+            META_DATA_COMPONENT_VERSION,
+        ],
+        help_uri: Some(spec_docs!("metadata_component")),
+    },
+    RuleInfo {
+        id: "hash-consistency",
+        description: "Flags multiple digests of the same algorithm with different values",
+        severity: Severity::Error,
+        since: SpecVersion::V1_3,
+        profile: Profile::Default,
+        codes: &["hash-conflict"],
+        paths: &[COMPONENTS_HASHES_CONTENT],
+        help_uri: Some(spec_docs!("components_items_hashes")),
+    },
+    RuleInfo {
+        id: "weak-hashes",
+        description: "Warns about components only declaring MD5 or SHA-1 digests",
+        severity: Severity::Warning,
+        since: SpecVersion::V1_3,
+        profile: Profile::Strict,
+        codes: &["weak-hash"],
+        paths: &[COMPONENTS_HASHES_ALG],
+        help_uri: Some(spec_docs!("components_items_hashes")),
+    },
+    RuleInfo {
+        id: "purl-presence",
+        description: "Warns about software components without purl",
+        severity: Severity::Warning,
+        since: SpecVersion::V1_3,
+        profile: Profile::Strict,
+        codes: &["missing-purl"],
+        paths: &[COMPONENTS_PURL],
+        help_uri: Some(spec_docs!("components_items_purl")),
+    },
+    RuleInfo {
+        id: "rating-consistency",
+        description: "Checks that rating scores are in range and match their severity",
+        severity: Severity::Error,
+        since: SpecVersion::V1_4,
+        profile: Profile::Default,
+        codes: &["rating-score-range", "rating-severity-mismatch"],
+        paths: &[
+            VULNERABILITIES_RATINGS_SCORE,
+            VULNERABILITIES_RATINGS_SEVERITY,
+            VULNERABILITIES_RATINGS_SOURCE_URL,
+        ],
+        help_uri: Some(spec_docs!("vulnerabilities_items_ratings")),
+    },
+    RuleInfo {
+        id: "affected-versions",
+        description:
+            "Checks that affected versions have either a version or a range and a valid status",
+        severity: Severity::Error,
+        since: SpecVersion::V1_4,
+        profile: Profile::Default,
+        codes: &["affected-version-or-range", "invalid-affected-status"],
+        paths: &[
+            VULNERABILITIES_AFFECTS_VERSIONS_VERSION,
+            VULNERABILITIES_AFFECTS_VERSIONS_STATUS,
+        ],
+        help_uri: Some(spec_docs!("vulnerabilities_items_affects")),
+    },
+    RuleInfo {
+        id: "composition-completeness",
+        description: "Checks that complete compositions list all dependencies of their assemblies",
+        severity: Severity::Error,
+        since: SpecVersion::V1_3,
+        profile: Profile::Default,
+        codes: &["composition-missing-dependency"],
+        paths: &[COMPOSITIONS_ASSEMBLIES, COMPOSITIONS_DEPENDENCIES],
+        help_uri: Some(spec_docs!("compositions")),
+    },
+    RuleInfo {
+        id: "vulnerability-timestamps",
+        description: "Checks the format and order of vulnerability timestamps",
+        severity: Severity::Error,
+        since: SpecVersion::V1_4,
+        profile: Profile::Default,
+        codes: &[
+            "invalid-timestamp",
+            "timestamp-order",
+            "rejected-missing-analysis",
+        ],
+        paths: &[
+            VULNERABILITIES_CREATED,
+            VULNERABILITIES_PUBLISHED,
+            VULNERABILITIES_UPDATED,
+            VULNERABILITIES_REJECTED,
+        ],
+        help_uri: Some(spec_docs!("vulnerabilities_items_created")),
+    },
+    RuleInfo {
+        id: "tool-components",
+        description: "Checks that tool components are applications with a version",
+        severity: Severity::Error,
+        since: SpecVersion::V1_5,
+        profile: Profile::Default,
+        codes: &["tool-component-type", "missing-tool-version"],
+        paths: &[
+            META_DATA_TOOL_COMPONENTS_COMPONENT_TYPE,
+            META_DATA_TOOL_COMPONENTS_VERSION,
+        ],
+        help_uri: Some(spec_docs!("metadata_tools")),
+    },
+    RuleInfo {
+        id: "identity-confidence",
+        description: "Warns when the confidence of an identity is not backed by its methods",
+        severity: Severity::Warning,
+        since: SpecVersion::V1_3,
+        profile: Profile::Default,
+        codes: &["identity-confidence"],
+        paths: &[COMPONENTS_EVIDENCE_IDENTITY_CONFIDENCE],
+        help_uri: Some(spec_docs!("components_items_evidence_identity")),
+    },
+    RuleInfo {
+        id: "formulation-references",
+        description: "Checks resource references, trigger types and task types of formulas",
+        severity: Severity::Error,
+        since: SpecVersion::V1_5,
+        profile: Profile::Default,
+        codes: &[
+            "unresolved-resource-reference",
+            "invalid-trigger-type",
+            "invalid-task-type",
+        ],
+        paths: &[
+            FORMULATION_WORKFLOWS_RESOURCE_REFERENCES_RESOURCE_REF,
+            FORMULATION_WORKFLOWS_TRIGGER_TRIGGER_TYPE,
+            FORMULATION_WORKFLOWS_TASK_TYPES,
+        ],
+        help_uri: Some(spec_docs!("formulation")),
+    },
+    RuleInfo {
+        id: "document-kind",
+        description: "Checks that a VEX document lists vulnerabilities",
+        severity: Severity::Error,
+        since: SpecVersion::V1_3,
+        profile: Profile::Default,
+        codes: &["missing-vulnerabilities"],
+        paths: &[VULNERABILITIES],
+        help_uri: None,
+    },
+    RuleInfo {
+        id: "supplier-consistency",
+        description:
+            "Warns when metadata.component and its entry in components have different suppliers",
+        severity: Severity::Warning,
+        since: SpecVersion::V1_3,
+        profile: Profile::Default,
+        codes: &["supplier-mismatch"],
+        paths: &[COMPONENTS_SUPPLIER],
+        help_uri: Some(spec_docs!("metadata_component_supplier")),
+    },
+    RuleInfo {
+        id: "external-reference-urls",
+        description: "Warns about external reference urls that do not match the reference type",
+        severity: Severity::Warning,
+        since: SpecVersion::V1_3,
+        profile: Profile::Default,
+        codes: &["reference-url-mismatch"],
+        paths: &[COMPONENTS_EXTERNAL_REFERENCES_URL],
+        help_uri: Some(spec_docs!("components_items_externalReferences")),
+    },
+    RuleInfo {
+        id: "assemblies",
+        description: "Checks that nested components neither contain themselves nor duplicate top-level components",
+        severity: Severity::Error,
+        since: SpecVersion::V1_3,
+        profile: Profile::Default,
+        codes: &["assembly-self-reference", "assembly-duplicate"],
+        paths: &[COMPONENTS_COMPONENTS, COMPONENTS_BOM_REF],
+        help_uri: Some(spec_docs!("components_items_components")),
+    },
+    RuleInfo {
+        id: "release-notes",
+        description: "Warns about blank and duplicated aliases and tags of release notes",
+        severity: Severity::Warning,
+        since: SpecVersion::V1_4,
+        profile: Profile::Default,
+        codes: &["blank-entry", "duplicate-entry"],
+        paths: &[
+            COMPONENTS_RELEASE_NOTES_ALIASES,
+            COMPONENTS_RELEASE_NOTES_TAGS,
+        ],
+        help_uri: Some(spec_docs!("components_items_releaseNotes")),
+    },
+    RuleInfo {
+        id: "duplicate-contacts",
+        description: "Warns about authors and supplier contacts listed more than once with the same email",
+        severity: Severity::Warning,
+        since: SpecVersion::V1_3,
+        profile: Profile::Default,
+        codes: &["duplicate-contact"],
+        paths: &[META_DATA_AUTHORS, COMPONENTS_SUPPLIER],
+        help_uri: Some(spec_docs!("metadata_authors")),
+    },
+    RuleInfo {
+        id: "hash-policy",
+        description: "Rejects components only declaring digests of algorithms the options do not allow",
+        severity: Severity::Error,
+        since: SpecVersion::V1_3,
+        profile: Profile::Default,
+        codes: &["disallowed-hash"],
+        paths: &[COMPONENTS_HASHES_ALG],
+        help_uri: Some(spec_docs!("components_items_hashes")),
+    },
+    RuleInfo {
+        id: "identifier-consistency",
+        description: "Warns when purl, cpe and swid of a component name different products or versions",
+        severity: Severity::Warning,
+        since: SpecVersion::V1_3,
+        profile: Profile::Default,
+        codes: &["identifier-mismatch"],
+        paths: &[COMPONENTS_CPE, COMPONENTS_SWID_NAME, COMPONENTS_SWID_VERSION],
+        help_uri: Some(spec_docs!("components_items_cpe")),
+    },
+];
+
+/// Describes opt-in rules shipped with the crate, which only run if added with
+/// [`ValidationOptions::add_rule`](crate::options::ValidationOptions::add_rule), e.g. the
+/// integrity checks of the `integrity` module.
+const OPT_IN_RULES: &[RuleInfo] = &[
+    RuleInfo {
+        id: "hash-verification",
+        description: "Recomputes the digests of file components from their local artifacts",
+        severity: Severity::Error,
+        since: SpecVersion::V1_3,
+        profile: Profile::Default,
+        codes: &["hash-mismatch", "artifact-unreadable"],
+        paths: &[COMPONENTS_HASHES, COMPONENTS_HASHES_CONTENT],
+        help_uri: Some(spec_docs!("components_items_hashes")),
+    },
+    RuleInfo {
+        id: "external-reference-hashes",
+        description: "Verifies the digests of external references against their content",
+        severity: Severity::Error,
+        since: SpecVersion::V1_3,
+        profile: Profile::Default,
+        codes: &["hash-mismatch", "artifact-unreadable"],
+        paths: &[
+            COMPONENTS_EXTERNAL_REFERENCES_URL,
+            COMPONENTS_EXTERNAL_REFERENCES_HASHES_CONTENT,
+        ],
+        help_uri: Some(spec_docs!("components_items_externalReferences")),
+    },
+    RuleInfo {
+        id: "expected-digest",
+        description: "Compares the digest of the document with an externally supplied digest",
+        severity: Severity::Error,
+        since: SpecVersion::V1_3,
+        profile: Profile::Default,
+        codes: &["digest-mismatch", "unsupported-digest-algorithm"],
+        paths: &[crate::error::DOCUMENT_KEY],
+        help_uri: None,
+    },
+];
+
+/// Returns the descriptions of all built-in rules, in the order they are run.
+pub fn all() -> &'static [RuleInfo] {
+    BUILTIN_RULES
+}
+
+/// Returns the descriptions of the opt-in rules shipped with the crate.
+pub fn opt_in() -> &'static [RuleInfo] {
+    OPT_IN_RULES
+}
+
+/// Runs `check` for each of `components` and the components nested within them, returns the
+/// results relative to the list.
+pub(crate) fn check_components<F>(
+    components: Option<&[Component]>,
+    check: &F,
+) -> Option<Vec<ValidationResult>>
+where
+    F: Fn(&Component) -> ValidationResult,
+{
+    components.map(|components| {
+        components
+            .iter()
+            .map(|component| {
+                let nested = ValidationContext::new()
+                    .add_list(
+                        "components",
+                        check_components(component.components.as_deref(), check),
+                    )
+                    .into();
+                ValidationErrors::merge_errors(check(component), nested)
+            })
+            .collect()
+    })
+}
+
+/// Runs the built-in rules that check a single component and its nested components, relative
+/// to the component.
+pub(crate) fn check_component(component: &Component, ctx: &RuleContext) -> ValidationResult {
+    let nested = ValidationContext::new()
+        .add_list(
+            "components",
+            check_components(component.components.as_deref(), &|component| {
+                check_component_only(component, ctx)
+            }),
+        )
+        .into();
+    ValidationErrors::merge_errors(check_component_only(component, ctx), nested)
+}
+
+fn check_component_only(component: &Component, ctx: &RuleContext) -> ValidationResult {
+    let mut results = vec![
+        hashes::check_consistency(component),
+        evidence::check_component(component),
+        references::check_component(component),
+        contacts::check_component(component),
+        identifiers::check_component(component),
+    ];
+    if let Some(allowed) = &ctx.allowed_hash_algorithms {
+        results.push(hashes::check_policy(component, allowed));
+    }
+    if ctx.version >= SpecVersion::V1_4 {
+        results.push(release_notes::check_component(component));
+    }
+    if ctx.profile == Profile::Strict {
+        results.push(completeness::check_component(component));
+        results.push(hashes::check_strength(component));
+        if ctx.kind == DocumentKind::Sbom {
+            results.push(completeness::check_purl(component));
+        }
+    }
+    results
+        .into_iter()
+        .fold(Ok(()), ValidationErrors::merge_errors)
+}
+
+/// Runs the built-in rules that check the metadata, relative to the metadata.
+pub(crate) fn check_metadata(metadata: &Metadata, ctx: &RuleContext) -> ValidationResult {
+    let tools = metadata
+        .tool_components
+        .as_ref()
+        .filter(|_| ctx.version >= SpecVersion::V1_5)
+        .map(|components| {
+            components
+                .iter()
+                .map(|component| {
+                    ValidationErrors::merge_errors(
+                        tools::check_tool(component),
+                        check_component(component, ctx),
+                    )
+                })
+                .collect::<Vec<_>>()
+        });
+
+    let result = ValidationContext::new()
+        .add_struct(
+            "component",
+            metadata
+                .component
+                .as_ref()
+                .map(|component| check_component(component, ctx)),
+        )
+        .add_list("tool_components", tools)
+        .into();
+    ValidationErrors::merge_errors(result, contacts::check_metadata(metadata))
+}
+
+/// Runs the built-in rules that check a single vulnerability, relative to the vulnerability.
+pub(crate) fn check_vulnerability(
+    vulnerability: &Vulnerability,
+    ctx: &RuleContext,
+) -> ValidationResult {
+    [
+        ratings::check_vulnerability(vulnerability),
+        affects::check_vulnerability(vulnerability),
+        vulnerability_timestamps::check_vulnerability(vulnerability, ctx),
+    ]
+    .into_iter()
+    .fold(Ok(()), ValidationErrors::merge_errors)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        options::{Profile, ValidationOptions},
+        testing::errors_at,
+        validate_bom,
+        validation::SpecVersion,
+        Bom,
+    };
+
+    use super::{all, builtin};
+
+    #[test]
+    fn registry_describes_all_builtin_rules() {
+        let described = all().iter().map(|info| info.id).collect::<Vec<_>>();
+        let builtin = builtin();
+        let ids = builtin.iter().map(|rule| rule.id()).collect::<Vec<_>>();
+
+        assert_eq!(described, ids);
+    }
+
+    #[test]
+    fn findings_link_to_rule_docs() {
+        let bom = Bom::parse_json(&format!(
+            r#"{{ "components": [{{ "type": "library", "name": "a", "hashes": [
+                {{ "alg": "SHA-1", "content": "{}" }}, {{ "alg": "SHA-1", "content": "{}" }}
+            ] }}] }}"#,
+            "a".repeat(40),
+            "b".repeat(40)
+        ))
+        .expect("Failed to parse BOM");
+
+        let result = validate_bom(SpecVersion::V1_5, &bom, &ValidationOptions::new());
+        let errors = errors_at(&result, "components[0].hashes[1].content");
+        assert_eq!(
+            errors[0].help_uri.as_deref(),
+            Some("https://cyclonedx.org/docs/1.6/json/#components_items_hashes")
+        );
+    }
+
+    #[test]
+    fn rule_info_applies_to_version_and_profile() {
+        let find = |id: &str| all().iter().find(|info| info.id == id).copied();
+        let tools = find("tool-components").expect("Expected tool-components");
+        let completeness = find("completeness").expect("Expected completeness");
+
+        assert!(!tools.applies_to(SpecVersion::V1_4, Profile::Default));
+        assert!(tools.applies_to(SpecVersion::V1_6, Profile::Strict));
+        assert!(!completeness.applies_to(SpecVersion::V1_6, Profile::Default));
+        assert!(completeness.applies_to(SpecVersion::V1_6, Profile::Strict));
+    }
+}
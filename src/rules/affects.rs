@@ -0,0 +1,124 @@
+use crate::{
+    prelude::*,
+    rules::{Rule, RuleContext},
+    validation::{SpecVersion, ValidationContext, ValidationError, ValidationResult},
+    Affect, AffectedVersion, Bom, Vulnerability,
+};
+
+/// The valid values of [`AffectedVersion::status`].
+const STATUSES: &[&str] = &["affected", "unaffected", "unknown"];
+
+/// Checks `vulnerabilities[].affects[].versions[]`: exactly one of `version` or `range` must
+/// be given and `status` must be one of the defined values.
+///
+/// Vulnerabilities are only checked from spec version 1.4 on, before they are reported as
+/// unsupported field by the built-in validation.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AffectedVersionsRule;
+
+fn check_version(version: &AffectedVersion) -> ValidationResult {
+    let version_or_range = (version.version.is_some() == version.range.is_some()).then(|| {
+        Err(ValidationError::new("affected_version_or_range")
+            .with_code("affected-version-or-range")
+            .with_expected("either version or range"))
+    });
+    let status = version
+        .status
+        .as_deref()
+        .filter(|status| !STATUSES.contains(status))
+        .map(|status| {
+            Err(ValidationError::new("invalid_affected_status")
+                .with_param("status", status)
+                .with_code("invalid-affected-status")
+                .with_expected(STATUSES.join(", "))
+                .with_actual(status))
+        });
+
+    ValidationContext::new()
+        .add_field("version", version_or_range)
+        .add_field("status", status)
+        .into()
+}
+
+fn check_affect(affect: &Affect) -> ValidationResult {
+    let children = affect
+        .versions
+        .as_ref()
+        .map(|versions| versions.iter().map(check_version).collect::<Vec<_>>());
+
+    ValidationContext::new()
+        .add_list("versions", children)
+        .into()
+}
+
+pub(super) fn check_vulnerability(vulnerability: &Vulnerability) -> ValidationResult {
+    let children = vulnerability
+        .affects
+        .as_ref()
+        .map(|affects| affects.iter().map(check_affect).collect::<Vec<_>>());
+
+    ValidationContext::new()
+        .add_list("affects", children)
+        .into()
+}
+
+impl Rule for AffectedVersionsRule {
+    fn id(&self) -> &str {
+        "affected-versions"
+    }
+
+    fn applies(&self, _bom: &Bom, ctx: &RuleContext) -> bool {
+        ctx.version >= SpecVersion::V1_4
+    }
+
+    fn check(&self, bom: &Bom, _ctx: &RuleContext) -> ValidationResult {
+        let children = bom.vulnerabilities.as_ref().map(|vulnerabilities| {
+            vulnerabilities
+                .iter()
+                .map(check_vulnerability)
+                .collect::<Vec<_>>()
+        });
+
+        ValidationContext::new()
+            .add_list("vulnerabilities", children)
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        options::ValidationOptions, testing::errors_at, validate_bom, validation::SpecVersion, Bom,
+    };
+
+    const VERSIONS: &str = r#"{ "vulnerabilities": [{ "id": "CVE-2024-1234", "affects": [
+        { "ref": "lib", "versions": [
+            { "version": "1.0.0", "status": "affected" },
+            { "range": "vers:cargo/<1.2.0", "status": "unaffected" },
+            { "version": "1.0.0", "range": "vers:cargo/<1.2.0" },
+            { "status": "fixed" }
+        ] }
+    ] }] }"#;
+
+    #[test]
+    fn reports_versions_per_index() {
+        let bom = Bom::parse_json(VERSIONS).expect("Failed to parse BOM");
+
+        let result = validate_bom(SpecVersion::V1_5, &bom, &ValidationOptions::new());
+        let path = |index: usize, field: &str| {
+            format!(
+                "vulnerabilities[0].affects[0].versions[{}].{}",
+                index, field
+            )
+        };
+
+        assert!(errors_at(&result, &path(0, "version")).is_empty());
+        assert!(errors_at(&result, &path(1, "version")).is_empty());
+        assert_eq!(errors_at(&result, &path(2, "version")).len(), 1);
+        assert_eq!(errors_at(&result, &path(3, "version")).len(), 1);
+        assert_eq!(
+            errors_at(&result, &path(3, "status"))[0].code.as_deref(),
+            Some("invalid-affected-status")
+        );
+    }
+}
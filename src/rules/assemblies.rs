@@ -0,0 +1,215 @@
+use crate::{
+    map::IndexMap,
+    prelude::*,
+    rules::{Rule, RuleContext},
+    validation::{ValidationContext, ValidationError, ValidationResult},
+    Bom, Component,
+};
+
+/// Checks the nested components of assemblies: a component must not contain itself
+/// transitively and nested components must not duplicate a top-level entry of `components[]`.
+///
+/// Components are identified by their bom-ref, or their purl if they have none. Both locations
+/// are reported, each referring to the other one.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AssemblyRule;
+
+fn key(component: &Component) -> Option<&str> {
+    component.bom_ref.as_deref().or(component.purl.as_deref())
+}
+
+/// Collects the findings of all assemblies by the path of the reported component.
+#[derive(Default)]
+struct Findings<'a> {
+    top_level: IndexMap<&'a str, usize>,
+    errors: IndexMap<String, Vec<ValidationError>>,
+}
+
+impl<'a> Findings<'a> {
+    fn report(&mut self, message: &str, code: &str, key: &str, path: &str, other: &str) {
+        for (at, refers_to) in [(path, other), (other, path)] {
+            self.errors.entry(at.to_string()).or_default().push(
+                ValidationError::new(message)
+                    .with_param("component", key)
+                    .with_param("path", refers_to)
+                    .with_code(code),
+            );
+        }
+    }
+
+    fn walk(
+        &mut self,
+        component: &'a Component,
+        path: &str,
+        ancestors: &mut Vec<(Option<&'a str>, String)>,
+    ) {
+        let key = key(component);
+        if let Some(key) = key {
+            if let Some((_, ancestor)) = ancestors.iter().find(|(other, _)| *other == Some(key)) {
+                let ancestor = ancestor.clone();
+                self.report(
+                    "assembly_self_reference",
+                    "assembly-self-reference",
+                    key,
+                    path,
+                    &ancestor,
+                );
+            } else if let Some(index) = self.top_level.get(key).filter(|_| !ancestors.is_empty()) {
+                let other = format!("components[{}]", index);
+                self.report(
+                    "assembly_duplicate",
+                    "assembly-duplicate",
+                    key,
+                    path,
+                    &other,
+                );
+            }
+        }
+
+        ancestors.push((key, path.to_string()));
+        for (index, child) in component.components.iter().flatten().enumerate() {
+            self.walk(child, &format!("{}.components[{}]", path, index), ancestors);
+        }
+        ancestors.pop();
+    }
+
+    fn result(&self, component: &Component, path: &str) -> ValidationResult {
+        let children = component.components.as_ref().map(|components| {
+            components
+                .iter()
+                .enumerate()
+                .map(|(index, child)| {
+                    self.result(child, &format!("{}.components[{}]", path, index))
+                })
+                .collect::<Vec<_>>()
+        });
+
+        self.errors
+            .get(path)
+            .into_iter()
+            .flatten()
+            .fold(ValidationContext::new(), |context, error| {
+                context.add_field("bom_ref", Some(Err(error.clone())))
+            })
+            .add_list("components", children)
+            .into()
+    }
+}
+
+impl Rule for AssemblyRule {
+    fn id(&self) -> &str {
+        "assemblies"
+    }
+
+    fn applies(&self, bom: &Bom, _ctx: &RuleContext) -> bool {
+        bom.components
+            .iter()
+            .flatten()
+            .any(|component| component.components.is_some())
+    }
+
+    fn check(&self, bom: &Bom, _ctx: &RuleContext) -> ValidationResult {
+        let Some(components) = &bom.components else {
+            return Ok(());
+        };
+
+        let mut findings = Findings::default();
+        for (index, component) in components.iter().enumerate() {
+            if let Some(key) = key(component) {
+                findings.top_level.entry(key).or_insert(index);
+            }
+        }
+        for (index, component) in components.iter().enumerate() {
+            findings.walk(
+                component,
+                &format!("components[{}]", index),
+                &mut Vec::new(),
+            );
+        }
+        if findings.errors.is_empty() {
+            return Ok(());
+        }
+
+        let children = components
+            .iter()
+            .enumerate()
+            .map(|(index, component)| findings.result(component, &format!("components[{}]", index)))
+            .collect::<Vec<_>>();
+
+        ValidationContext::new()
+            .add_list("components", Some(children))
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        rules::{Rule, RuleContext},
+        testing::errors_at,
+        validation::{SpecVersion, ValidationErrors},
+        Bom,
+    };
+
+    use super::AssemblyRule;
+
+    fn check(input: &str) -> Result<(), ValidationErrors> {
+        let bom = Bom::parse_json(input).expect("Failed to parse BOM");
+        AssemblyRule.check(&bom, &RuleContext::new(SpecVersion::V1_5))
+    }
+
+    #[test]
+    fn reports_component_containing_itself() {
+        let result = check(
+            r#"{ "components": [{ "type": "application", "name": "app", "bom-ref": "app",
+                "components": [{ "type": "library", "name": "lib", "bom-ref": "lib",
+                    "components": [{ "type": "application", "name": "app", "bom-ref": "app" }]
+                }]
+            }] }"#,
+        );
+
+        let nested = errors_at(&result, "components[0].components[0].components[0].bom_ref");
+        assert_eq!(nested.len(), 1);
+        assert_eq!(nested[0].code.as_deref(), Some("assembly-self-reference"));
+        assert_eq!(nested[0].message.param("path"), Some("components[0]"));
+
+        let root = errors_at(&result, "components[0].bom_ref");
+        assert_eq!(root.len(), 1);
+        assert_eq!(
+            root[0].message.param("path"),
+            Some("components[0].components[0].components[0]")
+        );
+    }
+
+    #[test]
+    fn reports_nested_component_duplicating_top_level_entry() {
+        let result = check(
+            r#"{ "components": [
+                { "type": "application", "name": "app", "components": [
+                    { "type": "library", "name": "lib", "purl": "pkg:cargo/lib@1.0.0" }
+                ] },
+                { "type": "library", "name": "lib", "purl": "pkg:cargo/lib@1.0.0" }
+            ] }"#,
+        );
+
+        let nested = errors_at(&result, "components[0].components[0].bom_ref");
+        assert_eq!(nested.len(), 1);
+        assert_eq!(nested[0].code.as_deref(), Some("assembly-duplicate"));
+        assert_eq!(nested[0].message.param("path"), Some("components[1]"));
+        assert_eq!(errors_at(&result, "components[1].bom_ref").len(), 1);
+    }
+
+    #[test]
+    fn accepts_distinct_nested_components() {
+        let result = check(
+            r#"{ "components": [
+                { "type": "application", "name": "app", "bom-ref": "app", "components": [
+                    { "type": "library", "name": "a", "bom-ref": "a" },
+                    { "type": "library", "name": "b", "bom-ref": "b" }
+                ] },
+                { "type": "library", "name": "c", "bom-ref": "c" }
+            ] }"#,
+        );
+        assert!(result.is_ok());
+    }
+}
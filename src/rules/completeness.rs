@@ -0,0 +1,187 @@
+use crate::{
+    options::Profile,
+    rules::{check_components, Rule, RuleContext},
+    validation::{ValidationContext, ValidationError, ValidationResult},
+    Bom, Component, DocumentKind,
+};
+
+/// Warns about software components without `purl`.
+///
+/// Like [`CompletenessRule`] only reported for [`Profile::Strict`] and only for
+/// [`DocumentKind::Sbom`], devices and firmware are not expected to have purls.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PurlPresenceRule;
+
+pub(super) fn check_purl(component: &Component) -> ValidationResult {
+    let missing = component.purl.is_none() && !component.component_type.is_hardware();
+
+    ValidationContext::new()
+        .add_field(
+            "purl",
+            missing
+                .then(|| Err(ValidationError::warning("missing_purl").with_code("missing-purl"))),
+        )
+        .into()
+}
+
+impl Rule for PurlPresenceRule {
+    fn id(&self) -> &str {
+        "purl-presence"
+    }
+
+    fn applies(&self, _bom: &Bom, ctx: &RuleContext) -> bool {
+        ctx.profile == Profile::Strict && ctx.kind == DocumentKind::Sbom
+    }
+
+    fn check(&self, bom: &Bom, _ctx: &RuleContext) -> ValidationResult {
+        let children = check_components(bom.components.as_deref(), &check_purl);
+
+        ValidationContext::new()
+            .add_list("components", children)
+            .into()
+    }
+}
+
+/// Warns about components without `supplier`, `author` (or `authors`) or `publisher`.
+///
+/// These fields are optional in the specification but required by most procurement policies,
+/// therefore findings are only reported for [`Profile::Strict`]. Documents not describing
+/// components, e.g. VEX documents, are skipped.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CompletenessRule;
+
+pub(super) fn check_component(component: &Component) -> ValidationResult {
+    ValidationContext::new()
+        .add_field(
+            "supplier",
+            component.supplier.is_none().then(|| {
+                Err(ValidationError::warning("missing_supplier").with_code("missing-supplier"))
+            }),
+        )
+        .add_field(
+            "author",
+            (component.author.is_none() && component.authors.is_none()).then(|| {
+                Err(ValidationError::warning("missing_author").with_code("missing-author"))
+            }),
+        )
+        .add_field(
+            "publisher",
+            component.publisher.is_none().then(|| {
+                Err(ValidationError::warning("missing_publisher").with_code("missing-publisher"))
+            }),
+        )
+        .into()
+}
+
+impl Rule for CompletenessRule {
+    fn id(&self) -> &str {
+        "completeness"
+    }
+
+    fn applies(&self, _bom: &Bom, ctx: &RuleContext) -> bool {
+        ctx.profile == Profile::Strict && ctx.kind.describes_components()
+    }
+
+    fn check(&self, bom: &Bom, ctx: &RuleContext) -> ValidationResult {
+        match ctx.profile {
+            Profile::Default => Ok(()),
+            Profile::Strict => {
+                let children = check_components(bom.components.as_deref(), &check_component);
+
+                ValidationContext::new()
+                    .add_list("components", children)
+                    .into()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        options::{Profile, ValidationOptions},
+        report::validate_report,
+        testing::errors_at,
+        validate_bom,
+        validation::{SpecVersion, ValidationErrors},
+        Bom, Component, ComponentType, DocumentKind, OrganizationalEntity, Service,
+    };
+
+    fn bom_with_component(component: Component) -> Bom {
+        Bom {
+            components: Some(vec![component]),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn default_profile_has_no_completeness_warnings() {
+        let bom = bom_with_component(Component::new(ComponentType::Library, "lib"));
+
+        let result = validate_bom(SpecVersion::V1_5, &bom, &ValidationOptions::new());
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn strict_profile_warns_about_missing_fields() {
+        let bom = bom_with_component(Component::new(ComponentType::Library, "lib"));
+        let options = ValidationOptions::new().profile(Profile::Strict);
+
+        let result = validate_bom(SpecVersion::V1_5, &bom, &options);
+        assert!(ValidationErrors::has_error(&result, "components"));
+    }
+
+    #[test]
+    fn strict_profile_accepts_complete_component() {
+        let bom = bom_with_component(Component {
+            supplier: Some(OrganizationalEntity {
+                name: Some(String::from("Acme Inc")),
+                contact: None,
+            }),
+            author: Some(String::from("Jane Doe")),
+            publisher: Some(String::from("Acme Inc")),
+            purl: Some(String::from("pkg:cargo/lib@1.0.0")),
+            ..Component::new(ComponentType::Library, "lib")
+        });
+        let options = ValidationOptions::new().profile(Profile::Strict);
+
+        let result = validate_bom(SpecVersion::V1_5, &bom, &options);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn strict_profile_expects_purls_of_software_only() {
+        let options = ValidationOptions::new().profile(Profile::Strict);
+
+        let sbom = bom_with_component(Component::new(ComponentType::Library, "lib"));
+        let result = validate_bom(SpecVersion::V1_5, &sbom, &options);
+        assert!(ValidationErrors::has_error(&result, "components"));
+        assert_eq!(
+            errors_at(&result, "components[0].purl")[0].code.as_deref(),
+            Some("missing-purl")
+        );
+
+        let hbom = bom_with_component(Component::new(ComponentType::Device, "board"));
+        assert_eq!(hbom.kind(), DocumentKind::Hbom);
+        let result = validate_bom(SpecVersion::V1_5, &hbom, &options);
+        assert!(errors_at(&result, "components[0].purl").is_empty());
+    }
+
+    #[test]
+    fn saasbom_skips_component_rules() {
+        let bom = Bom {
+            services: Some(vec![Service {
+                name: String::from("api"),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        };
+        assert_eq!(bom.kind(), DocumentKind::Saasbom);
+
+        let options = ValidationOptions::new().profile(Profile::Strict);
+        let report = validate_report(SpecVersion::V1_5, &bom, &options);
+        for id in ["completeness", "weak-hashes", "purl-presence"] {
+            assert!(report.skipped.contains(&String::from(id)));
+        }
+    }
+}
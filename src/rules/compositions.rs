@@ -0,0 +1,111 @@
+use alloc::collections::BTreeSet;
+
+use crate::{
+    prelude::*,
+    rules::{Rule, RuleContext},
+    validation::{ValidationContext, ValidationError, ValidationResult},
+    Bom, Composition,
+};
+
+/// Checks that compositions claiming complete dependencies are backed by the document.
+///
+/// Every bom-ref listed in `assemblies` or `dependencies` of a composition with
+/// `aggregate: complete` and a `dependencies` list must have an entry in `dependencies[]`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CompositionCompletenessRule;
+
+fn check_refs(refs: Option<&Vec<String>>, declared: &BTreeSet<&str>) -> Vec<ValidationError> {
+    refs.into_iter()
+        .flatten()
+        .enumerate()
+        .filter(|(_, bom_ref)| !declared.contains(bom_ref.as_str()))
+        .map(|(index, bom_ref)| {
+            ValidationError::new("composition_missing_dependency")
+                .with_param("ref", bom_ref)
+                .with_code("composition-missing-dependency")
+                .with_index(index)
+        })
+        .collect()
+}
+
+fn check_composition(composition: &Composition, declared: &BTreeSet<&str>) -> ValidationResult {
+    if composition.aggregate != "complete" || composition.dependencies.is_none() {
+        return Ok(());
+    }
+
+    let mut context = ValidationContext::new();
+    for error in check_refs(composition.assemblies.as_ref(), declared) {
+        context = context.add_field("assemblies", Some(Err(error)));
+    }
+    for error in check_refs(composition.dependencies.as_ref(), declared) {
+        context = context.add_field("dependencies", Some(Err(error)));
+    }
+    context.into()
+}
+
+impl Rule for CompositionCompletenessRule {
+    fn id(&self) -> &str {
+        "composition-completeness"
+    }
+
+    fn check(&self, bom: &Bom, _ctx: &RuleContext) -> ValidationResult {
+        let declared = bom
+            .dependencies
+            .iter()
+            .flatten()
+            .map(|dependency| dependency.dependency_ref.as_str())
+            .collect::<BTreeSet<_>>();
+
+        let children = bom.compositions.as_ref().map(|compositions| {
+            compositions
+                .iter()
+                .map(|composition| check_composition(composition, &declared))
+                .collect::<Vec<_>>()
+        });
+
+        ValidationContext::new()
+            .add_list("compositions", children)
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        options::ValidationOptions, testing::errors_at, validate_bom, validation::SpecVersion, Bom,
+    };
+
+    fn bom_with_composition(aggregate: &str) -> Bom {
+        let input = format!(
+            r#"{{
+                "dependencies": [{{ "ref": "app", "dependsOn": ["lib"] }}],
+                "compositions": [{{
+                    "aggregate": "{}",
+                    "assemblies": ["app", "lib"],
+                    "dependencies": ["app"]
+                }}]
+            }}"#,
+            aggregate
+        );
+        Bom::parse_json(&input).expect("Failed to parse BOM")
+    }
+
+    #[test]
+    fn flags_assemblies_without_dependency_entry() {
+        let bom = bom_with_composition("complete");
+
+        let result = validate_bom(SpecVersion::V1_5, &bom, &ValidationOptions::new());
+        let errors = errors_at(&result, "compositions[0].assemblies");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].context.index, Some(1));
+        assert!(errors_at(&result, "compositions[0].dependencies").is_empty());
+    }
+
+    #[test]
+    fn ignores_incomplete_compositions() {
+        let bom = bom_with_composition("incomplete");
+
+        let result = validate_bom(SpecVersion::V1_5, &bom, &ValidationOptions::new());
+        assert!(result.is_ok());
+    }
+}
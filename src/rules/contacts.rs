@@ -0,0 +1,169 @@
+use crate::{
+    map::IndexMap,
+    prelude::*,
+    rules::{check_components, Rule, RuleContext},
+    validation::{ValidationContext, ValidationError, ValidationErrors, ValidationResult},
+    Bom, Component, Metadata, OrganizationalContact,
+};
+
+/// Warns about contacts listed more than once with the same email, a frequent artifact of
+/// merged documents, in `metadata.authors` and the `contact` lists of suppliers.
+///
+/// Emails are compared ignoring case, every group of duplicates is reported once at the first
+/// repetition, with the indices of all its entries. Duplicates in the contacts of a supplier
+/// are reported at the `supplier` of the component, like the other findings about suppliers.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DuplicateContactsRule;
+
+fn check_contacts(contacts: Option<&Vec<OrganizationalContact>>) -> Vec<ValidationError> {
+    let mut groups = IndexMap::<String, Vec<usize>>::default();
+    for (index, contact) in contacts.into_iter().flatten().enumerate() {
+        if let Some(email) = contact.email.as_deref().map(str::trim) {
+            if !email.is_empty() {
+                groups.entry(email.to_lowercase()).or_default().push(index);
+            }
+        }
+    }
+
+    groups
+        .into_iter()
+        .filter(|(_, indices)| indices.len() > 1)
+        .map(|(email, indices)| {
+            let list = indices
+                .iter()
+                .map(|index| index.to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+            ValidationError::warning("duplicate_contact")
+                .with_param("email", email)
+                .with_param("indices", list)
+                .with_code("duplicate-contact")
+                .with_index(indices[1])
+        })
+        .collect()
+}
+
+fn add_errors(
+    context: ValidationContext,
+    field: &str,
+    errors: Vec<ValidationError>,
+) -> ValidationContext {
+    errors.into_iter().fold(context, |context, error| {
+        context.add_field(field, Some(Err(error)))
+    })
+}
+
+pub(super) fn check_component(component: &Component) -> ValidationResult {
+    let contacts = component
+        .supplier
+        .as_ref()
+        .and_then(|supplier| supplier.contact.as_ref());
+    add_errors(
+        ValidationContext::new(),
+        "supplier",
+        check_contacts(contacts),
+    )
+    .into()
+}
+
+pub(super) fn check_metadata(metadata: &Metadata) -> ValidationResult {
+    add_errors(
+        ValidationContext::new(),
+        "authors",
+        check_contacts(metadata.authors.as_ref()),
+    )
+    .into()
+}
+
+impl Rule for DuplicateContactsRule {
+    fn id(&self) -> &str {
+        "duplicate-contacts"
+    }
+
+    fn check(&self, bom: &Bom, _ctx: &RuleContext) -> ValidationResult {
+        let children = check_components(bom.components.as_deref(), &check_component);
+
+        let metadata = bom.meta_data.as_ref().map(|metadata| {
+            let component = ValidationContext::new()
+                .add_struct(
+                    "component",
+                    metadata.component.as_ref().map(check_component),
+                )
+                .into();
+            ValidationErrors::merge_errors(check_metadata(metadata), component)
+        });
+
+        ValidationContext::new()
+            .add_struct("meta_data", metadata)
+            .add_list("components", children)
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        options::ValidationOptions,
+        rules::{Rule, RuleContext},
+        testing::errors_at,
+        validate_bom,
+        validation::{Severity, SpecVersion},
+        Bom,
+    };
+
+    use super::DuplicateContactsRule;
+
+    #[test]
+    fn warns_about_contacts_with_same_email() {
+        let bom = Bom::parse_json(
+            r#"{
+                "metadata": { "authors": [
+                    { "name": "Jane", "email": "jane@example.com" },
+                    { "name": "John", "email": "john@example.com" },
+                    { "name": "Jane Doe", "email": "Jane@Example.com" },
+                    { "name": "J. Doe", "email": "jane@example.com" }
+                ] },
+                "components": [{ "type": "library", "name": "lib", "supplier": {
+                    "name": "Acme", "contact": [
+                        { "email": "sales@acme.com" }, { "email": "support@acme.com" }
+                    ]
+                } }]
+            }"#,
+        )
+        .expect("Failed to parse BOM");
+
+        let result = DuplicateContactsRule.check(&bom, &RuleContext::new(SpecVersion::V1_5));
+        let errors = errors_at(&result, "meta_data.authors");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].severity, Severity::Warning);
+        assert_eq!(errors[0].context.index, Some(2));
+        assert_eq!(errors[0].message.param("indices"), Some("0, 2, 3"));
+        assert!(errors_at(&result, "components[0].supplier").is_empty());
+    }
+
+    #[test]
+    fn combines_with_supplier_consistency() {
+        let bom = Bom::parse_json(
+            r#"{
+                "metadata": { "component": {
+                    "type": "application", "name": "app", "purl": "pkg:cargo/app@1.0.0",
+                    "supplier": { "name": "ACME" }
+                } },
+                "components": [{
+                    "type": "application", "name": "app", "purl": "pkg:cargo/app@1.0.0",
+                    "supplier": { "name": "Other", "contact": [
+                        { "email": "sales@other.com" }, { "email": "sales@other.com" }
+                    ] }
+                }]
+            }"#,
+        )
+        .expect("Failed to parse BOM");
+
+        let result = validate_bom(SpecVersion::V1_5, &bom, &ValidationOptions::new());
+        let codes = errors_at(&result, "components[0].supplier")
+            .iter()
+            .filter_map(|error| error.code.as_deref())
+            .collect::<Vec<_>>();
+        assert_eq!(codes, vec!["supplier-mismatch", "duplicate-contact"]);
+    }
+}
@@ -0,0 +1,92 @@
+use crate::{
+    prelude::*,
+    rules::{Rule, RuleContext},
+    validation::{ValidationContext, ValidationError, ValidationResult},
+    Bom, DocumentKind,
+};
+
+/// Checks that a VEX document lists `vulnerabilities`, only applies to documents validated as
+/// [`DocumentKind::Vex`].
+///
+/// A VEX document has no `components`, the component centric policy rules, e.g. the
+/// completeness checks, are skipped for it.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DocumentKindRule;
+
+impl Rule for DocumentKindRule {
+    fn id(&self) -> &str {
+        "document-kind"
+    }
+
+    fn applies(&self, _bom: &Bom, ctx: &RuleContext) -> bool {
+        ctx.kind == DocumentKind::Vex
+    }
+
+    fn check(&self, bom: &Bom, _ctx: &RuleContext) -> ValidationResult {
+        let missing = bom.vulnerabilities.as_ref().is_none_or(Vec::is_empty);
+
+        ValidationContext::new()
+            .add_field(
+                "vulnerabilities",
+                missing.then(|| {
+                    Err(ValidationError::new("missing_vulnerabilities")
+                        .with_code("missing-vulnerabilities"))
+                }),
+            )
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        options::{Profile, ValidationOptions},
+        report::validate_report,
+        validate_bom,
+        validation::{SpecVersion, ValidationErrors},
+        Bom, DocumentKind,
+    };
+
+    const VEX: &str = r#"{
+        "vulnerabilities": [{
+            "id": "CVE-2024-1234",
+            "affects": [{ "ref": "urn:cdx:3e671687-395b-41f5-a30f-a58921a69b79/1#pkg" }]
+        }]
+    }"#;
+
+    #[test]
+    fn detect_document_kind() {
+        let vex = Bom::parse_json(VEX).expect("Failed to parse BOM");
+        assert_eq!(vex.kind(), DocumentKind::Vex);
+        assert_eq!(Bom::default().kind(), DocumentKind::Sbom);
+    }
+
+    #[test]
+    fn vex_document_skips_component_rules() {
+        let bom = Bom::parse_json(VEX).expect("Failed to parse BOM");
+        let options = ValidationOptions::new().profile(Profile::Strict);
+
+        let report = validate_report(SpecVersion::V1_5, &bom, &options);
+        assert!(report.failed.is_empty());
+        assert!(report.passed.contains(&String::from("document-kind")));
+        assert!(report.skipped.contains(&String::from("completeness")));
+    }
+
+    #[test]
+    fn sbom_skips_vex_rule() {
+        let report = validate_report(
+            SpecVersion::V1_5,
+            &Bom::default(),
+            &ValidationOptions::new(),
+        );
+        assert!(report.skipped.contains(&String::from("document-kind")));
+    }
+
+    #[test]
+    fn vex_mode_requires_vulnerabilities() {
+        let options = ValidationOptions::new().document_kind(DocumentKind::Vex);
+
+        let result = validate_bom(SpecVersion::V1_5, &Bom::default(), &options);
+        assert!(ValidationErrors::has_error(&result, "vulnerabilities"));
+    }
+}
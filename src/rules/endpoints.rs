@@ -0,0 +1,131 @@
+use crate::{
+    prelude::*,
+    rules::{Rule, RuleContext},
+    validation::{ValidationContext, ValidationError, ValidationResult},
+    Bom, Service,
+};
+
+/// Opt-in [`Rule`] that warns about service endpoints using a scheme that is not allowed,
+/// e.g. plaintext `http` endpoints.
+///
+/// Only `https` is allowed by default, other schemes are allowed with
+/// [`EndpointSchemeRule::allow_schemes`].
+#[derive(Debug, Clone)]
+pub struct EndpointSchemeRule {
+    schemes: Vec<String>,
+}
+
+impl Default for EndpointSchemeRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EndpointSchemeRule {
+    /// Creates the rule only allowing `https` endpoints.
+    pub fn new() -> Self {
+        Self {
+            schemes: vec![String::from("https")],
+        }
+    }
+
+    /// Replaces the allowed schemes, e.g. `["https", "wss"]`.
+    pub fn allow_schemes<I, S>(mut self, schemes: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.schemes = schemes
+            .into_iter()
+            .map(|scheme| scheme.as_ref().to_ascii_lowercase())
+            .collect();
+        self
+    }
+
+    fn check_endpoint(&self, index: usize, endpoint: &str) -> Option<Result<(), ValidationError>> {
+        let scheme = endpoint
+            .split_once(':')
+            .map(|(scheme, _)| scheme.to_ascii_lowercase())
+            .unwrap_or_default();
+
+        (!self.schemes.contains(&scheme)).then(|| {
+            Err(ValidationError::warning("endpoint_scheme")
+                .with_param("scheme", &scheme)
+                .with_code("endpoint-scheme")
+                .with_expected(self.schemes.join(", "))
+                .with_actual(endpoint)
+                .with_index(index))
+        })
+    }
+
+    fn check_service(&self, service: &Service) -> ValidationResult {
+        service
+            .endpoints
+            .iter()
+            .flatten()
+            .enumerate()
+            .fold(ValidationContext::new(), |context, (index, endpoint)| {
+                context.add_field("endpoints", self.check_endpoint(index, endpoint))
+            })
+            .into()
+    }
+}
+
+impl Rule for EndpointSchemeRule {
+    fn id(&self) -> &str {
+        "endpoint-scheme"
+    }
+
+    fn check(&self, bom: &Bom, _ctx: &RuleContext) -> ValidationResult {
+        let children = bom.services.as_ref().map(|services| {
+            services
+                .iter()
+                .map(|service| self.check_service(service))
+                .collect::<Vec<_>>()
+        });
+
+        ValidationContext::new()
+            .add_list("services", children)
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        options::ValidationOptions,
+        testing::errors_at,
+        validate_bom,
+        validation::{Severity, SpecVersion},
+        Bom,
+    };
+
+    use super::EndpointSchemeRule;
+
+    const SERVICES: &str = r#"{ "services": [{ "name": "api", "endpoints": [
+        "https://api.example.com", "http://api.example.com", "wss://api.example.com/events"
+    ] }] }"#;
+
+    #[test]
+    fn warns_about_plaintext_endpoints() {
+        let bom = Bom::parse_json(SERVICES).expect("Failed to parse BOM");
+        let options = ValidationOptions::new().add_rule(EndpointSchemeRule::new());
+
+        let result = validate_bom(SpecVersion::V1_5, &bom, &options);
+        let errors = errors_at(&result, "services[0].endpoints");
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0].severity, Severity::Warning);
+        assert_eq!(errors[0].context.index, Some(1));
+        assert_eq!(errors[1].context.index, Some(2));
+    }
+
+    #[test]
+    fn accepts_allowed_schemes() {
+        let bom = Bom::parse_json(SERVICES).expect("Failed to parse BOM");
+        let options = ValidationOptions::new()
+            .add_rule(EndpointSchemeRule::new().allow_schemes(["https", "http", "WSS"]));
+
+        let result = validate_bom(SpecVersion::V1_5, &bom, &options);
+        assert!(result.is_ok());
+    }
+}
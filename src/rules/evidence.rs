@@ -0,0 +1,128 @@
+use crate::{
+    prelude::*,
+    rules::{check_components, Rule, RuleContext},
+    validation::{ValidationContext, ValidationError, ValidationResult},
+    Bom, Component, Identity,
+};
+
+/// Warns when the overall confidence of an identity evidence is not backed by its methods.
+///
+/// The methods are treated as independent evidence, their combined confidence is
+/// `1 - (1 - c1) * (1 - c2) * ...`. An identity claiming a higher confidence than that, e.g.
+/// `1.0` without any methods, is reported. Confidences outside of `0..=1` are already
+/// reported by the built-in validation and ignored here.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct IdentityConfidenceRule;
+
+/// Tolerance for rounded confidences, e.g. `0.99` for two methods of `0.9`.
+const TOLERANCE: f64 = 0.01;
+
+fn combined_confidence(identity: &Identity) -> f64 {
+    let doubt = identity
+        .methods
+        .iter()
+        .flatten()
+        .map(|method| 1.0 - method.confidence.clamp(0.0, 1.0))
+        .product::<f64>();
+    1.0 - doubt
+}
+
+fn check_identity(identity: &Identity) -> ValidationResult {
+    let error = identity
+        .confidence
+        .filter(|confidence| (0.0..=1.0).contains(confidence))
+        .and_then(|confidence| {
+            let combined = combined_confidence(identity);
+            (confidence > combined + TOLERANCE).then(|| {
+                Err(ValidationError::warning("identity_confidence")
+                    .with_param("confidence", confidence)
+                    .with_param("expected", format!("{:.2}", combined))
+                    .with_code("identity-confidence")
+                    .with_expected(format!("at most {:.2}", combined))
+                    .with_actual(confidence.to_string()))
+            })
+        });
+
+    ValidationContext::new()
+        .add_field("confidence", error)
+        .into()
+}
+
+pub(super) fn check_component(component: &Component) -> ValidationResult {
+    let children = component
+        .evidence
+        .as_ref()
+        .and_then(|evidence| evidence.identity.as_ref())
+        .map(|identities| identities.iter().map(check_identity).collect::<Vec<_>>());
+    let evidence = ValidationContext::new().add_list("identity", children);
+
+    ValidationContext::new()
+        .add_struct("evidence", Some(evidence.into()))
+        .into()
+}
+
+impl Rule for IdentityConfidenceRule {
+    fn id(&self) -> &str {
+        "identity-confidence"
+    }
+
+    fn check(&self, bom: &Bom, _ctx: &RuleContext) -> ValidationResult {
+        let children = check_components(bom.components.as_deref(), &check_component);
+
+        ValidationContext::new()
+            .add_list("components", children)
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        options::ValidationOptions, testing::errors_at, validate_bom, validation::SpecVersion, Bom,
+    };
+
+    fn codes_at(evidence: &str, path: &str) -> Vec<String> {
+        let input = format!(
+            r#"{{ "components": [{{ "type": "library", "name": "a", "evidence": {} }}] }}"#,
+            evidence
+        );
+        let bom = Bom::parse_json(&input).expect("Failed to parse BOM");
+        let result = validate_bom(SpecVersion::V1_6, &bom, &ValidationOptions::new());
+        errors_at(&result, path)
+            .into_iter()
+            .filter_map(|error| error.code.clone())
+            .collect()
+    }
+
+    #[test]
+    fn warns_about_confidence_without_methods() {
+        let evidence = r#"{ "identity": { "field": "purl", "confidence": 1.0 } }"#;
+        assert_eq!(
+            codes_at(evidence, "components[0].evidence.identity[0].confidence"),
+            vec!["identity-confidence"]
+        );
+    }
+
+    #[test]
+    fn accepts_confidence_backed_by_methods() {
+        let evidence = r#"{ "identity": [{ "field": "purl", "confidence": 0.99, "methods": [
+            { "technique": "manifest-analysis", "confidence": 0.9 },
+            { "technique": "hash-comparison", "confidence": 0.9 }
+        ] }] }"#;
+        assert!(codes_at(evidence, "components[0].evidence").is_empty());
+    }
+
+    #[test]
+    fn reports_confidence_out_of_range() {
+        let evidence = r#"{ "identity": { "field": "purl", "confidence": 0.5, "methods": [
+            { "technique": "filename", "confidence": 1.5 }
+        ] } }"#;
+        assert_eq!(
+            codes_at(
+                evidence,
+                "components[0].evidence.identity[0].methods[0].confidence"
+            ),
+            vec!["confidence-range"]
+        );
+    }
+}
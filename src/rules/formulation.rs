@@ -0,0 +1,298 @@
+use alloc::collections::BTreeSet;
+
+use crate::{
+    prelude::*,
+    rules::{Rule, RuleContext},
+    validation::{SpecVersion, ValidationContext, ValidationError, ValidationResult},
+    Bom, Formula, ResourceReference, Step, Task, Trigger, Workflow,
+};
+
+/// Checks the workflows of every formula, since spec version 1.5.
+///
+/// * every `resourceReferences[].ref` must match a bom-ref declared inside the same formula,
+///   i.e. of the formula itself, its components, services, workflows, triggers or tasks
+/// * `trigger.type` must be one of [`TRIGGER_TYPES`]
+/// * `taskTypes` of workflows and tasks must be one of [`TASK_TYPES`]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FormulationReferencesRule;
+
+pub const TRIGGER_TYPES: &[&str] = &["manual", "api", "webhook", "scheduled"];
+
+pub const TASK_TYPES: &[&str] = &[
+    "copy", "clone", "lint", "scan", "merge", "build", "test", "deliver", "deploy", "release",
+    "clean", "other",
+];
+
+/// Collects all bom-refs declared inside the formula.
+fn declared_refs(formula: &Formula) -> BTreeSet<&str> {
+    let workflows = formula.workflows.iter().flatten();
+    let tasks = workflows
+        .clone()
+        .flat_map(|workflow| workflow.tasks.iter().flatten());
+
+    formula
+        .bom_ref
+        .iter()
+        .chain(
+            formula
+                .components
+                .iter()
+                .flatten()
+                .filter_map(|component| component.bom_ref.as_ref()),
+        )
+        .chain(
+            formula
+                .services
+                .iter()
+                .flatten()
+                .filter_map(|service| service.bom_ref.as_ref()),
+        )
+        .chain(
+            workflows
+                .clone()
+                .filter_map(|workflow| workflow.bom_ref.as_ref()),
+        )
+        .chain(
+            workflows
+                .filter_map(|workflow| workflow.trigger.as_ref())
+                .filter_map(|trigger| trigger.bom_ref.as_ref()),
+        )
+        .chain(tasks.filter_map(|task| task.bom_ref.as_ref()))
+        .map(String::as_str)
+        .collect()
+}
+
+fn check_references(
+    references: Option<&Vec<ResourceReference>>,
+    declared: &BTreeSet<&str>,
+) -> Option<Vec<ValidationResult>> {
+    references.map(|references| {
+        references
+            .iter()
+            .map(|reference| {
+                let error = reference
+                    .resource_ref
+                    .as_deref()
+                    .filter(|resource_ref| !declared.contains(resource_ref))
+                    .map(|resource_ref| {
+                        Err(ValidationError::new("unresolved_resource_reference")
+                            .with_param("ref", resource_ref)
+                            .with_code("unresolved-resource-reference")
+                            .with_actual(resource_ref))
+                    });
+                ValidationContext::new()
+                    .add_field("resource_ref", error)
+                    .into()
+            })
+            .collect()
+    })
+}
+
+fn check_task_types(
+    context: ValidationContext,
+    task_types: Option<&Vec<String>>,
+) -> ValidationContext {
+    task_types
+        .into_iter()
+        .flatten()
+        .enumerate()
+        .filter(|(_, task_type)| !TASK_TYPES.contains(&task_type.as_str()))
+        .fold(context, |context, (index, task_type)| {
+            context.add_field(
+                "task_types",
+                Some(Err(ValidationError::new("invalid_task_type")
+                    .with_param("type", task_type)
+                    .with_code("invalid-task-type")
+                    .with_expected(TASK_TYPES.join(", "))
+                    .with_actual(task_type)
+                    .with_index(index))),
+            )
+        })
+}
+
+fn check_trigger(trigger: &Trigger, declared: &BTreeSet<&str>) -> ValidationResult {
+    let type_error = (!TRIGGER_TYPES.contains(&trigger.trigger_type.as_str())).then(|| {
+        Err(ValidationError::new("invalid_trigger_type")
+            .with_param("type", &trigger.trigger_type)
+            .with_code("invalid-trigger-type")
+            .with_expected(TRIGGER_TYPES.join(", "))
+            .with_actual(&trigger.trigger_type))
+    });
+
+    ValidationContext::new()
+        .add_field("trigger_type", type_error)
+        .add_list(
+            "resource_references",
+            check_references(trigger.resource_references.as_ref(), declared),
+        )
+        .into()
+}
+
+fn check_steps(
+    steps: Option<&Vec<Step>>,
+    declared: &BTreeSet<&str>,
+) -> Option<Vec<ValidationResult>> {
+    steps.map(|steps| {
+        steps
+            .iter()
+            .map(|step| {
+                ValidationContext::new()
+                    .add_list(
+                        "resource_references",
+                        check_references(step.resource_references.as_ref(), declared),
+                    )
+                    .into()
+            })
+            .collect()
+    })
+}
+
+fn check_task(task: &Task, declared: &BTreeSet<&str>) -> ValidationResult {
+    check_task_types(ValidationContext::new(), Some(&task.task_types))
+        .add_list("steps", check_steps(task.steps.as_ref(), declared))
+        .add_list(
+            "resource_references",
+            check_references(task.resource_references.as_ref(), declared),
+        )
+        .into()
+}
+
+fn check_workflow(workflow: &Workflow, declared: &BTreeSet<&str>) -> ValidationResult {
+    let tasks = workflow.tasks.as_ref().map(|tasks| {
+        tasks
+            .iter()
+            .map(|task| check_task(task, declared))
+            .collect::<Vec<_>>()
+    });
+
+    check_task_types(ValidationContext::new(), workflow.task_types.as_ref())
+        .add_struct(
+            "trigger",
+            workflow
+                .trigger
+                .as_ref()
+                .map(|trigger| check_trigger(trigger, declared)),
+        )
+        .add_list("tasks", tasks)
+        .add_list("steps", check_steps(workflow.steps.as_ref(), declared))
+        .add_list(
+            "resource_references",
+            check_references(workflow.resource_references.as_ref(), declared),
+        )
+        .into()
+}
+
+fn check_formula(formula: &Formula) -> ValidationResult {
+    let declared = declared_refs(formula);
+    let workflows = formula.workflows.as_ref().map(|workflows| {
+        workflows
+            .iter()
+            .map(|workflow| check_workflow(workflow, &declared))
+            .collect::<Vec<_>>()
+    });
+
+    ValidationContext::new()
+        .add_list("workflows", workflows)
+        .into()
+}
+
+impl Rule for FormulationReferencesRule {
+    fn id(&self) -> &str {
+        "formulation-references"
+    }
+
+    fn applies(&self, _bom: &Bom, ctx: &RuleContext) -> bool {
+        ctx.version >= SpecVersion::V1_5
+    }
+
+    fn check(&self, bom: &Bom, _ctx: &RuleContext) -> ValidationResult {
+        let children = bom
+            .formulation
+            .as_ref()
+            .map(|formulation| formulation.iter().map(check_formula).collect::<Vec<_>>());
+
+        ValidationContext::new()
+            .add_list("formulation", children)
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        options::ValidationOptions, testing::errors_at, validate_bom, validation::SpecVersion, Bom,
+    };
+
+    const FORMULATION: &str = r#"{ "formulation": [
+        { "bom-ref": "formula", "components": [{ "type": "application", "name": "rustc",
+            "bom-ref": "rustc" }], "workflows": [{
+            "bom-ref": "ci", "taskTypes": ["build", "compile"],
+            "trigger": { "type": "push", "resourceReferences": [{ "ref": "ci" }] },
+            "tasks": [{ "taskTypes": ["test"], "resourceReferences": [{ "ref": "rustc" }],
+                "steps": [{ "name": "cargo test", "resourceReferences": [{ "ref": "other" }] }] }]
+        }] },
+        { "bom-ref": "other" }
+    ] }"#;
+
+    fn codes_at(version: SpecVersion, path: &str) -> Vec<String> {
+        let bom = Bom::parse_json(FORMULATION).expect("Failed to parse BOM");
+        let result = validate_bom(version, &bom, &ValidationOptions::new());
+        errors_at(&result, path)
+            .into_iter()
+            .filter_map(|error| error.code.clone())
+            .collect()
+    }
+
+    #[test]
+    fn resolves_references_within_the_formula() {
+        let workflow = "formulation[0].workflows[0]";
+        assert!(codes_at(
+            SpecVersion::V1_5,
+            &format!("{}.trigger.resource_references[0].resource_ref", workflow)
+        )
+        .is_empty());
+        assert!(codes_at(
+            SpecVersion::V1_5,
+            &format!("{}.tasks[0].resource_references[0].resource_ref", workflow)
+        )
+        .is_empty());
+        assert_eq!(
+            codes_at(
+                SpecVersion::V1_5,
+                &format!(
+                    "{}.tasks[0].steps[0].resource_references[0].resource_ref",
+                    workflow
+                )
+            ),
+            vec!["unresolved-resource-reference"]
+        );
+    }
+
+    #[test]
+    fn checks_trigger_and_task_types() {
+        assert_eq!(
+            codes_at(
+                SpecVersion::V1_5,
+                "formulation[0].workflows[0].trigger.trigger_type"
+            ),
+            vec!["invalid-trigger-type"]
+        );
+        assert_eq!(
+            codes_at(SpecVersion::V1_5, "formulation[0].workflows[0].task_types"),
+            vec!["invalid-task-type"]
+        );
+        assert!(codes_at(
+            SpecVersion::V1_5,
+            "formulation[0].workflows[0].tasks[0].task_types"
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn formulation_is_not_supported_before_1_5() {
+        assert_eq!(
+            codes_at(SpecVersion::V1_4, "formulation"),
+            vec!["field-not-supported"]
+        );
+    }
+}
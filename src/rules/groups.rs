@@ -0,0 +1,227 @@
+use core::fmt;
+
+use serde::Deserialize;
+
+use crate::{
+    map::IndexMap,
+    prelude::*,
+    purl::Purl,
+    rules::{check_components, Rule, RuleContext},
+    validation::{ValidationContext, ValidationError, ValidationResult},
+    Bom, Component,
+};
+
+/// The convention the `group` of a component has to follow.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(from = "String")]
+pub enum GroupPattern {
+    /// A reverse domain name like `org.apache.commons`, as used by Java packages.
+    ReverseDns,
+    /// A scope like `@angular`, as used by npm packages.
+    Scope,
+    /// A glob where `*` matches any sequence of characters, e.g. `com.acme.*`.
+    Glob(String),
+}
+
+impl From<&str> for GroupPattern {
+    /// Parses `reverse-dns` and `scope`, any other value is a glob.
+    fn from(pattern: &str) -> Self {
+        match pattern {
+            "reverse-dns" => Self::ReverseDns,
+            "scope" => Self::Scope,
+            glob => Self::Glob(glob.to_string()),
+        }
+    }
+}
+
+impl From<String> for GroupPattern {
+    fn from(pattern: String) -> Self {
+        Self::from(pattern.as_str())
+    }
+}
+
+impl fmt::Display for GroupPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ReverseDns => write!(f, "reverse-dns"),
+            Self::Scope => write!(f, "scope"),
+            Self::Glob(glob) => write!(f, "{}", glob),
+        }
+    }
+}
+
+fn is_label(label: &str) -> bool {
+    label.starts_with(|c: char| c.is_ascii_lowercase())
+        && label
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '_' | '-'))
+}
+
+fn matches_glob(glob: &str, value: &str) -> bool {
+    let Some((prefix, rest)) = glob.split_once('*') else {
+        return glob == value;
+    };
+    let Some(value) = value.strip_prefix(prefix) else {
+        return false;
+    };
+    (0..=value.len())
+        .filter(|index| value.is_char_boundary(*index))
+        .any(|index| matches_glob(rest, &value[index..]))
+}
+
+impl GroupPattern {
+    /// Returns `true` if the group follows the convention.
+    pub fn matches(&self, group: &str) -> bool {
+        match self {
+            Self::ReverseDns => group.split('.').count() >= 2 && group.split('.').all(is_label),
+            Self::Scope => group.strip_prefix('@').is_some_and(|scope| {
+                !scope.is_empty()
+                    && scope.chars().all(|c| {
+                        c.is_ascii_lowercase()
+                            || c.is_ascii_digit()
+                            || matches!(c, '-' | '.' | '_' | '~')
+                    })
+            }),
+            Self::Glob(glob) => matches_glob(glob, group),
+        }
+    }
+}
+
+/// Opt-in [`Rule`] that warns about components whose `group` does not follow the conventions
+/// of their package ecosystem, e.g. a reverse domain name for `maven` packages.
+///
+/// The ecosystem is the type of the component purl, components without purl or group are not
+/// checked. The defaults cover `maven` and `npm`, other types or organization specific
+/// conventions are configured with [`GroupConventionRule::pattern`].
+#[derive(Debug, Clone)]
+pub struct GroupConventionRule {
+    patterns: IndexMap<String, GroupPattern>,
+}
+
+impl Default for GroupConventionRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GroupConventionRule {
+    /// Creates the rule with the built-in conventions.
+    pub fn new() -> Self {
+        let mut patterns = IndexMap::default();
+        patterns.insert(String::from("maven"), GroupPattern::ReverseDns);
+        patterns.insert(String::from("npm"), GroupPattern::Scope);
+        Self { patterns }
+    }
+
+    /// Sets the convention for groups of components with the given purl type, replacing the
+    /// built-in one.
+    pub fn pattern<T: AsRef<str>>(mut self, purl_type: T, pattern: GroupPattern) -> Self {
+        self.patterns
+            .insert(purl_type.as_ref().to_ascii_lowercase(), pattern);
+        self
+    }
+
+    fn check_component(&self, component: &Component) -> ValidationResult {
+        let mismatch = component
+            .group
+            .as_deref()
+            .zip(
+                component
+                    .purl
+                    .as_deref()
+                    .and_then(|purl| Purl::parse(purl).ok()),
+            )
+            .and_then(|(group, purl)| {
+                let pattern = self.patterns.get(&purl.purl_type)?;
+                (!pattern.matches(group)).then(|| {
+                    Err(ValidationError::warning("group_convention")
+                        .with_param("type", &purl.purl_type)
+                        .with_param("pattern", pattern)
+                        .with_code("group-convention")
+                        .with_expected(pattern)
+                        .with_actual(group))
+                })
+            });
+
+        ValidationContext::new().add_field("group", mismatch).into()
+    }
+}
+
+impl Rule for GroupConventionRule {
+    fn id(&self) -> &str {
+        "group-conventions"
+    }
+
+    fn check(&self, bom: &Bom, _ctx: &RuleContext) -> ValidationResult {
+        let children = check_components(bom.components.as_deref(), &|component| {
+            self.check_component(component)
+        });
+
+        ValidationContext::new()
+            .add_list("components", children)
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        options::ValidationOptions,
+        testing::errors_at,
+        validate_bom,
+        validation::{Severity, SpecVersion},
+        Bom,
+    };
+
+    use super::{GroupConventionRule, GroupPattern};
+
+    const COMPONENTS: &str = r#"{ "components": [
+        { "type": "library", "group": "org.apache.commons", "name": "commons-lang3",
+          "purl": "pkg:maven/org.apache.commons/commons-lang3@3.12.0" },
+        { "type": "library", "group": "Apache", "name": "commons-io",
+          "purl": "pkg:maven/Apache/commons-io@2.15.0" },
+        { "type": "library", "group": "angular", "name": "core",
+          "purl": "pkg:npm/%40angular/core@17.0.0" },
+        { "type": "library", "group": "acme", "name": "lib", "purl": "pkg:cargo/lib@1.0.0" }
+    ] }"#;
+
+    #[test]
+    fn warns_about_groups_violating_defaults() {
+        let bom = Bom::parse_json(COMPONENTS).expect("Failed to parse BOM");
+        let options = ValidationOptions::new().add_rule(GroupConventionRule::new());
+
+        let result = validate_bom(SpecVersion::V1_5, &bom, &options);
+        assert!(errors_at(&result, "components[0].group").is_empty());
+        let errors = errors_at(&result, "components[1].group");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].severity, Severity::Warning);
+        assert_eq!(errors[0].code.as_deref(), Some("group-convention"));
+        assert_eq!(errors_at(&result, "components[2].group").len(), 1);
+        assert!(errors_at(&result, "components[3].group").is_empty());
+    }
+
+    #[test]
+    fn overrides_patterns() {
+        let bom = Bom::parse_json(COMPONENTS).expect("Failed to parse BOM");
+        let rule = GroupConventionRule::new()
+            .pattern("maven", GroupPattern::from("org.*"))
+            .pattern("cargo", GroupPattern::Glob(String::from("acme*")));
+        let options = ValidationOptions::new().add_rule(rule);
+
+        let result = validate_bom(SpecVersion::V1_5, &bom, &options);
+        assert!(errors_at(&result, "components[0].group").is_empty());
+        assert_eq!(errors_at(&result, "components[1].group").len(), 1);
+        assert!(errors_at(&result, "components[3].group").is_empty());
+    }
+
+    #[test]
+    fn match_patterns() {
+        assert!(GroupPattern::ReverseDns.matches("com.acme-corp.internal_tools"));
+        assert!(!GroupPattern::ReverseDns.matches("acme"));
+        assert!(!GroupPattern::ReverseDns.matches("com..acme"));
+        assert!(GroupPattern::Scope.matches("@types"));
+        assert!(!GroupPattern::Scope.matches("@"));
+        assert!(GroupPattern::from("com.*.internal").matches("com.acme.internal"));
+        assert!(!GroupPattern::from("com.*.internal").matches("com.acme.public"));
+    }
+}
@@ -0,0 +1,311 @@
+use crate::{
+    options::Profile,
+    prelude::*,
+    rules::{check_components, Rule, RuleContext},
+    validation::{ValidationContext, ValidationError, ValidationResult},
+    Bom, Component, Hash, HashAlgorithm,
+};
+
+/// Flags components declaring multiple digests of the same algorithm with different values.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HashConsistencyRule;
+
+pub(super) fn check_consistency(component: &Component) -> ValidationResult {
+    let children = component.hashes.as_ref().map(|hashes| {
+        hashes
+            .iter()
+            .enumerate()
+            .map(|(index, hash)| check_conflict(hash, &hashes[..index]))
+            .collect::<Vec<_>>()
+    });
+
+    ValidationContext::new().add_list("hashes", children).into()
+}
+
+/// Checks a hash against all hashes declared before it.
+fn check_conflict(hash: &Hash, previous: &[Hash]) -> ValidationResult {
+    let conflict = previous
+        .iter()
+        .position(|other| {
+            other.alg == hash.alg && !other.content.eq_ignore_ascii_case(&hash.content)
+        })
+        .map(|index| {
+            Err(ValidationError::new("hash_conflict")
+                .with_param("alg", format!("{:?}", hash.alg))
+                .with_param("index", index)
+                .with_code("hash-conflict")
+                .with_expected(&previous[index].content)
+                .with_actual(&hash.content)
+                .with_index(index))
+        });
+
+    ValidationContext::new()
+        .add_field("content", conflict)
+        .into()
+}
+
+impl Rule for HashConsistencyRule {
+    fn id(&self) -> &str {
+        "hash-consistency"
+    }
+
+    fn check(&self, bom: &Bom, _ctx: &RuleContext) -> ValidationResult {
+        let children = check_components(bom.components.as_deref(), &check_consistency);
+
+        ValidationContext::new()
+            .add_list("components", children)
+            .into()
+    }
+}
+
+/// Warns about components that only declare weak hash algorithms, i.e. MD5 or SHA-1.
+///
+/// Weak digests are still allowed by the specification, therefore findings are only reported
+/// for [`Profile::Strict`]. Documents not describing components, e.g. VEX documents, are skipped.
+/// The algorithm of each weak digest is reported, like all other findings about digests.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WeakHashRule;
+
+pub(super) fn check_strength(component: &Component) -> ValidationResult {
+    let children = component
+        .hashes
+        .as_ref()
+        .filter(|hashes| hashes.iter().all(|hash| hash.alg.is_weak()))
+        .map(|hashes| {
+            hashes
+                .iter()
+                .map(|hash| {
+                    let weak = ValidationError::warning("weak_hashes")
+                        .with_param("alg", algorithm_names(core::iter::once(hash.alg)))
+                        .with_code("weak-hash");
+                    ValidationContext::new()
+                        .add_field("alg", Some(Err(weak)))
+                        .into()
+                })
+                .collect::<Vec<_>>()
+        });
+
+    ValidationContext::new().add_list("hashes", children).into()
+}
+
+impl Rule for WeakHashRule {
+    fn id(&self) -> &str {
+        "weak-hashes"
+    }
+
+    fn applies(&self, _bom: &Bom, ctx: &RuleContext) -> bool {
+        ctx.profile == Profile::Strict && ctx.kind.describes_components()
+    }
+
+    fn check(&self, bom: &Bom, _ctx: &RuleContext) -> ValidationResult {
+        let children = check_components(bom.components.as_deref(), &check_strength);
+
+        ValidationContext::new()
+            .add_list("components", children)
+            .into()
+    }
+}
+
+/// Rejects components whose digests all use algorithms outside of the policy selected with
+/// [`ValidationOptions::allow_hash_algorithms`](crate::options::ValidationOptions::allow_hash_algorithms),
+/// e.g. only MD5 when at least SHA-256 is required. Components without digests are not checked,
+/// otherwise the algorithm of each digest is reported.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HashPolicyRule;
+
+/// Returns the names of the algorithms as serialized, e.g. `SHA-256, SHA-512`.
+fn algorithm_names(algorithms: impl Iterator<Item = HashAlgorithm>) -> String {
+    algorithms
+        .filter_map(|algorithm| {
+            serde_json::to_value(algorithm)
+                .ok()
+                .and_then(|name| name.as_str().map(String::from))
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+pub(super) fn check_policy(component: &Component, allowed: &[HashAlgorithm]) -> ValidationResult {
+    let children = component
+        .hashes
+        .as_ref()
+        .filter(|hashes| !hashes.iter().any(|hash| allowed.contains(&hash.alg)))
+        .map(|hashes| {
+            let allowed = algorithm_names(allowed.iter().copied());
+            hashes
+                .iter()
+                .map(|hash| {
+                    let declared = algorithm_names(core::iter::once(hash.alg));
+                    let disallowed = ValidationError::new("disallowed_hashes")
+                        .with_param("alg", &declared)
+                        .with_param("allowed", &allowed)
+                        .with_code("disallowed-hash")
+                        .with_expected(&allowed)
+                        .with_actual(declared);
+                    ValidationContext::new()
+                        .add_field("alg", Some(Err(disallowed)))
+                        .into()
+                })
+                .collect::<Vec<_>>()
+        });
+
+    ValidationContext::new().add_list("hashes", children).into()
+}
+
+impl Rule for HashPolicyRule {
+    fn id(&self) -> &str {
+        "hash-policy"
+    }
+
+    fn applies(&self, _bom: &Bom, ctx: &RuleContext) -> bool {
+        ctx.allowed_hash_algorithms.is_some() && ctx.kind.describes_components()
+    }
+
+    fn check(&self, bom: &Bom, ctx: &RuleContext) -> ValidationResult {
+        let Some(allowed) = &ctx.allowed_hash_algorithms else {
+            return Ok(());
+        };
+        let children = check_components(bom.components.as_deref(), &|component| {
+            check_policy(component, allowed)
+        });
+
+        ValidationContext::new()
+            .add_list("components", children)
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        options::{Profile, ValidationOptions},
+        testing::errors_at,
+        validate_bom,
+        validation::{Severity, SpecVersion},
+        Bom, HashAlgorithm,
+    };
+
+    const SHA1_A: &str = "0000000000000000000000000000000000000000";
+    const SHA1_B: &str = "1111111111111111111111111111111111111111";
+    const SHA256: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+    fn bom_with_hashes(hashes: &[(&str, &str)]) -> Bom {
+        let hashes = hashes
+            .iter()
+            .map(|(alg, content)| format!(r#"{{ "alg": "{}", "content": "{}" }}"#, alg, content))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let input = format!(
+            r#"{{ "components": [{{ "type": "library", "name": "a", "hashes": [{}] }}] }}"#,
+            hashes
+        );
+        Bom::parse_json(&input).expect("Failed to parse BOM")
+    }
+
+    #[test]
+    fn flags_conflicting_digests() {
+        let bom = bom_with_hashes(&[("SHA-1", SHA1_A), ("SHA-256", SHA256), ("SHA-1", SHA1_B)]);
+
+        let result = validate_bom(SpecVersion::V1_5, &bom, &ValidationOptions::new());
+        let errors = errors_at(&result, "components[0].hashes[2].content");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code.as_deref(), Some("hash-conflict"));
+        assert!(errors_at(&result, "components[0].hashes[0].content").is_empty());
+    }
+
+    #[test]
+    fn accepts_repeated_identical_digests() {
+        let bom = bom_with_hashes(&[("SHA-1", SHA1_A), ("SHA-1", SHA1_A)]);
+
+        let result = validate_bom(SpecVersion::V1_5, &bom, &ValidationOptions::new());
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn strict_profile_warns_about_weak_hashes() {
+        let weak = bom_with_hashes(&[("SHA-1", SHA1_A)]);
+        let strong = bom_with_hashes(&[("SHA-1", SHA1_A), ("SHA-256", SHA256)]);
+        let strict = ValidationOptions::new().profile(Profile::Strict);
+
+        let result = validate_bom(SpecVersion::V1_5, &weak, &ValidationOptions::new());
+        assert!(result.is_ok());
+
+        let result = validate_bom(SpecVersion::V1_5, &weak, &strict);
+        let errors = errors_at(&result, "components[0].hashes[0].alg");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].severity, Severity::Warning);
+        assert_eq!(errors[0].message.param("alg"), Some("SHA-1"));
+
+        let result = validate_bom(SpecVersion::V1_5, &strong, &strict);
+        assert!(errors_at(&result, "components[0].hashes[0].alg").is_empty());
+    }
+
+    #[test]
+    fn strict_profile_warns_about_weak_hashes_of_nested_components() {
+        let bom = Bom::parse_json(&format!(
+            r#"{{ "components": [{{ "type": "library", "name": "a", "components": [
+                {{ "type": "library", "name": "b", "hashes": [{{ "alg": "SHA-1", "content": "{}" }}] }}
+            ] }}] }}"#,
+            SHA1_A
+        ))
+        .expect("Failed to parse BOM");
+        let strict = ValidationOptions::new().profile(Profile::Strict);
+
+        let result = validate_bom(SpecVersion::V1_5, &bom, &strict);
+        let errors = errors_at(&result, "components[0].components[0].hashes[0].alg");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code.as_deref(), Some("weak-hash"));
+        assert_eq!(
+            errors_at(&result, "components[0].components[0].supplier").len(),
+            1
+        );
+    }
+
+    #[test]
+    fn strict_profile_combines_weak_hashes_with_other_hash_findings() {
+        let strict = ValidationOptions::new().profile(Profile::Strict);
+
+        let conflict = bom_with_hashes(&[("SHA-1", SHA1_A), ("SHA-1", SHA1_B)]);
+        let result = validate_bom(SpecVersion::V1_5, &conflict, &strict);
+        assert_eq!(errors_at(&result, "components[0].hashes[0].alg").len(), 1);
+        assert_eq!(errors_at(&result, "components[0].hashes[1].alg").len(), 1);
+        assert_eq!(
+            errors_at(&result, "components[0].hashes[1].content").len(),
+            1
+        );
+
+        let invalid = bom_with_hashes(&[("MD5", "not a digest")]);
+        let result = validate_bom(SpecVersion::V1_5, &invalid, &strict);
+        assert_eq!(errors_at(&result, "components[0].hashes[0].alg").len(), 1);
+        assert!(!errors_at(&result, "components[0].hashes[0].content").is_empty());
+    }
+
+    #[test]
+    fn rejects_hashes_outside_of_policy() {
+        let weak = bom_with_hashes(&[("SHA-1", SHA1_A)]);
+        let strong = bom_with_hashes(&[("SHA-1", SHA1_A), ("SHA-256", SHA256)]);
+        let policy = ValidationOptions::new().allow_hash_algorithms([
+            HashAlgorithm::Sha256,
+            HashAlgorithm::Sha384,
+            HashAlgorithm::Sha512,
+        ]);
+
+        let result = validate_bom(SpecVersion::V1_5, &weak, &policy);
+        let errors = errors_at(&result, "components[0].hashes[0].alg");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].severity, Severity::Error);
+        assert_eq!(
+            errors[0].context.expected.as_deref(),
+            Some("SHA-256, SHA-384, SHA-512")
+        );
+        assert_eq!(errors[0].context.actual.as_deref(), Some("SHA-1"));
+
+        assert!(validate_bom(SpecVersion::V1_5, &strong, &policy).is_ok());
+
+        let invalid = bom_with_hashes(&[("MD5", "not a digest"), ("SHA-1", SHA1_A)]);
+        let result = validate_bom(SpecVersion::V1_5, &invalid, &policy);
+        assert_eq!(errors_at(&result, "components[0].hashes[0].alg").len(), 1);
+        assert_eq!(errors_at(&result, "components[0].hashes[1].alg").len(), 1);
+        assert!(!errors_at(&result, "components[0].hashes[0].content").is_empty());
+    }
+}
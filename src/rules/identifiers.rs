@@ -0,0 +1,268 @@
+use crate::{
+    prelude::*,
+    purl::Purl,
+    rules::{check_components, Rule, RuleContext},
+    validation::{ValidationContext, ValidationError, ValidationResult},
+    Bom, Component,
+};
+
+/// Warns when the purl, cpe and swid tag of a component encode different product names or
+/// versions, a common symptom of identifiers copied from another component.
+///
+/// The purl is the reference if present, otherwise the cpe. Names are compared ignoring case
+/// and the difference between `-`, `_` and spaces, versions ignoring a leading `v`. Coordinates
+/// that are not set, e.g. the `*` version of a cpe, are not compared.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct IdentifierConsistencyRule;
+
+/// The product name and version encoded in one of the identifiers of a component.
+#[derive(Debug)]
+struct Coordinates {
+    identifier: &'static str,
+    name: Option<String>,
+    version: Option<String>,
+}
+
+/// Splits a cpe 2.3 formatted string at unescaped colons, removing the escapes.
+fn split_formatted(cpe: &str) -> Vec<String> {
+    let mut parts = vec![String::new()];
+    let mut chars = cpe.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => parts
+                .last_mut()
+                .expect("At least one part")
+                .extend(chars.next()),
+            ':' => parts.push(String::new()),
+            c => parts.last_mut().expect("At least one part").push(c),
+        }
+    }
+    parts
+}
+
+/// Returns the product and version of a cpe, either in the 2.3 formatted string or the 2.2
+/// uri binding, e.g. `cpe:2.3:a:vendor:product:1.0:*:*:*:*:*:*:*` or `cpe:/a:vendor:product:1.0`.
+fn cpe_coordinates(cpe: &str) -> Option<Coordinates> {
+    let parts = match cpe.strip_prefix("cpe:2.3:") {
+        Some(formatted) => split_formatted(formatted),
+        None => cpe
+            .strip_prefix("cpe:/")?
+            .split(':')
+            .map(String::from)
+            .collect(),
+    };
+    let value = |index: usize| {
+        parts
+            .get(index)
+            .filter(|value| !matches!(value.as_str(), "" | "*" | "-"))
+            .cloned()
+    };
+
+    Some(Coordinates {
+        identifier: "cpe",
+        name: value(2),
+        version: value(3),
+    })
+}
+
+fn purl_coordinates(purl: &str) -> Option<Coordinates> {
+    let purl = Purl::parse(purl).ok()?;
+    Some(Coordinates {
+        identifier: "purl",
+        name: Some(purl.name),
+        version: purl.version,
+    })
+}
+
+fn normalize_name(name: &str) -> String {
+    name.chars()
+        .map(|c| match c {
+            '_' | ' ' => '-',
+            c => c.to_ascii_lowercase(),
+        })
+        .collect()
+}
+
+fn normalize_version(version: &str) -> String {
+    version
+        .strip_prefix(['v', 'V'])
+        .unwrap_or(version)
+        .to_ascii_lowercase()
+}
+
+/// Returns the mismatches of the coordinates with the reference, each with the field of the
+/// coordinates it belongs to.
+fn mismatches(
+    reference: &Coordinates,
+    other: &Coordinates,
+) -> Vec<(&'static str, ValidationError)> {
+    let mut errors = Vec::new();
+
+    if let (Some(expected), Some(name)) = (&reference.name, &other.name) {
+        if normalize_name(expected) != normalize_name(name) {
+            errors.push((
+                "name",
+                ValidationError::warning("identifier_name_mismatch")
+                    .with_param("name", name)
+                    .with_param("identifier", other.identifier)
+                    .with_param("expected", expected)
+                    .with_param("reference", reference.identifier)
+                    .with_code("identifier-mismatch")
+                    .with_expected(expected)
+                    .with_actual(name),
+            ));
+        }
+    }
+    if let (Some(expected), Some(version)) = (&reference.version, &other.version) {
+        if normalize_version(expected) != normalize_version(version) {
+            errors.push((
+                "version",
+                ValidationError::warning("identifier_version_mismatch")
+                    .with_param("version", version)
+                    .with_param("identifier", other.identifier)
+                    .with_param("expected", expected)
+                    .with_param("reference", reference.identifier)
+                    .with_code("identifier-mismatch")
+                    .with_expected(expected)
+                    .with_actual(version),
+            ));
+        }
+    }
+
+    errors
+}
+
+pub(super) fn check_component(component: &Component) -> ValidationResult {
+    let purl = component.purl.as_deref().and_then(purl_coordinates);
+    let cpe = component.cpe.as_deref().and_then(cpe_coordinates);
+    let swid = component.swid.as_ref().map(|swid| Coordinates {
+        identifier: "swid",
+        name: Some(swid.name.clone()),
+        version: swid.version.clone(),
+    });
+
+    let Some(reference) = purl.as_ref().or(cpe.as_ref()) else {
+        return Ok(());
+    };
+
+    let cpe_errors = cpe
+        .as_ref()
+        .filter(|cpe| cpe.identifier != reference.identifier)
+        .map(|cpe| mismatches(reference, cpe))
+        .unwrap_or_default();
+    let swid_errors = swid
+        .as_ref()
+        .map(|swid| mismatches(reference, swid))
+        .unwrap_or_default();
+
+    let cpe = cpe_errors
+        .into_iter()
+        .fold(ValidationContext::new(), |context, (_, error)| {
+            context.add_field("cpe", Some(Err(error)))
+        });
+    let swid = swid_errors
+        .into_iter()
+        .fold(ValidationContext::new(), |context, (field, error)| {
+            context.add_field(field, Some(Err(error)))
+        });
+
+    cpe.add_struct("swid", Some(swid.into())).into()
+}
+
+impl Rule for IdentifierConsistencyRule {
+    fn id(&self) -> &str {
+        "identifier-consistency"
+    }
+
+    fn check(&self, bom: &Bom, _ctx: &RuleContext) -> ValidationResult {
+        let children = check_components(bom.components.as_deref(), &check_component);
+
+        ValidationContext::new()
+            .add_list("components", children)
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        rules::{Rule, RuleContext},
+        testing::errors_at,
+        validation::{SpecVersion, ValidationResult},
+        Bom,
+    };
+
+    use super::{cpe_coordinates, IdentifierConsistencyRule};
+
+    fn check(component: &str) -> ValidationResult {
+        let bom = Bom::parse_json(&format!(r#"{{ "components": [{}] }}"#, component))
+            .expect("Failed to parse BOM");
+        IdentifierConsistencyRule.check(&bom, &RuleContext::new(SpecVersion::V1_5))
+    }
+
+    #[test]
+    fn parses_both_cpe_bindings() {
+        let formatted = cpe_coordinates(r"cpe:2.3:a:acme:web\:server:2.4.1:*:*:*:*:*:*:*")
+            .expect("Failed to parse cpe");
+        assert_eq!(formatted.name.as_deref(), Some("web:server"));
+        assert_eq!(formatted.version.as_deref(), Some("2.4.1"));
+
+        let uri = cpe_coordinates("cpe:/a:acme:server").expect("Failed to parse cpe");
+        assert_eq!(uri.name.as_deref(), Some("server"));
+        assert_eq!(uri.version, None);
+
+        assert!(cpe_coordinates("server:2.4.1").is_none());
+    }
+
+    #[test]
+    fn accepts_consistent_identifiers() {
+        let result = check(
+            r#"{ "type": "library", "name": "openssl",
+                "purl": "pkg:generic/open-ssl@v3.0.1",
+                "cpe": "cpe:2.3:a:openssl:open_ssl:3.0.1:*:*:*:*:*:*:*",
+                "swid": { "tagId": "openssl-3.0.1", "name": "Open SSL", "version": "3.0.1" } }"#,
+        );
+        assert!(result.is_ok());
+
+        let result = check(
+            r#"{ "type": "library", "name": "openssl", "purl": "pkg:generic/openssl",
+                "cpe": "cpe:2.3:a:openssl:openssl:*:*:*:*:*:*:*:*" }"#,
+        );
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn reports_identifiers_differing_from_purl() {
+        let result = check(
+            r#"{ "type": "library", "name": "openssl",
+                "purl": "pkg:generic/openssl@3.0.1",
+                "cpe": "cpe:2.3:a:openssl:openssl:1.1.1:*:*:*:*:*:*:*",
+                "swid": { "tagId": "curl-8.0", "name": "curl", "version": "3.0.1" } }"#,
+        );
+
+        let errors = errors_at(&result, "components[0].cpe");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code.as_deref(), Some("identifier-mismatch"));
+        assert_eq!(errors[0].message.param("expected"), Some("3.0.1"));
+        assert_eq!(
+            errors[0].to_string(),
+            "Version '1.1.1' of the cpe differs from '3.0.1' of the purl: expected 3.0.1, got \"1.1.1\""
+        );
+        assert_eq!(errors_at(&result, "components[0].swid.name").len(), 1);
+        assert!(errors_at(&result, "components[0].swid.version").is_empty());
+    }
+
+    #[test]
+    fn compares_swid_with_cpe_without_purl() {
+        let result = check(
+            r#"{ "type": "library", "name": "curl",
+                "cpe": "cpe:/a:haxx:curl:8.0.0",
+                "swid": { "tagId": "curl-7", "name": "curl", "version": "7.88.1" } }"#,
+        );
+
+        let errors = errors_at(&result, "components[0].swid.version");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].message.param("reference"), Some("cpe"));
+        assert!(errors_at(&result, "components[0].cpe").is_empty());
+    }
+}
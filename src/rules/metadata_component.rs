@@ -0,0 +1,225 @@
+use crate::{
+    purl::Purl,
+    rules::{Rule, RuleContext},
+    validation::{ValidationContext, ValidationError, ValidationResult},
+    Bom, Component,
+};
+
+/// Checks that `metadata.component` is consistent with the rest of the document.
+///
+/// * its bom-ref must be the root of the dependency graph, no other entry may depend on it
+/// * it must not be listed again in `components[]`, neither by bom-ref nor by purl
+/// * the name and version encoded in its purl must match its own name and version
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MetadataComponentRule;
+
+fn validate_root(bom_ref: &str, bom: &Bom) -> Option<Result<(), ValidationError>> {
+    bom.dependencies
+        .iter()
+        .flatten()
+        .find(|dependency| {
+            dependency
+                .depends_on
+                .iter()
+                .flatten()
+                .any(|depends_on| depends_on == bom_ref)
+        })
+        .map(|dependency| {
+            Err(ValidationError::new("metadata_component_not_root")
+                .with_param("dependency", &dependency.dependency_ref)
+                .with_code("metadata-component-not-root"))
+        })
+}
+
+fn validate_unique<F>(bom: &Bom, is_duplicate: F) -> Option<Result<(), ValidationError>>
+where
+    F: Fn(&Component) -> bool,
+{
+    bom.components
+        .iter()
+        .flatten()
+        .position(is_duplicate)
+        .map(|index| {
+            Err(ValidationError::new("metadata_component_duplicated")
+                .with_param("index", index)
+                .with_code("metadata-component-duplicated")
+                .with_index(index))
+        })
+}
+
+fn check_component(component: &Component, bom: &Bom) -> ValidationResult {
+    let purl = component
+        .purl
+        .as_deref()
+        .and_then(|purl| Purl::parse(purl).ok());
+
+    let name_error = purl
+        .as_ref()
+        .filter(|purl| purl.name != component.name)
+        .map(|purl| {
+            Err(ValidationError::new("name_mismatch")
+                .with_param("name", &component.name)
+                .with_param("purl_name", &purl.name)
+                .with_code("metadata-component-mismatch")
+                .with_expected(&purl.name)
+                .with_actual(&component.name))
+        });
+    let version_error = purl
+        .as_ref()
+        .and_then(|purl| Some((purl.version.as_ref()?, component.version.as_ref()?)))
+        .filter(|(purl_version, version)| purl_version != version)
+        .map(|(purl_version, version)| {
+            Err(ValidationError::new("version_mismatch")
+                .with_param("version", version)
+                .with_param("purl_version", purl_version)
+                .with_code("metadata-component-mismatch")
+                .with_expected(purl_version)
+                .with_actual(version))
+        });
+
+    ValidationContext::new()
+        .add_field(
+            "bom_ref",
+            component
+                .bom_ref
+                .as_deref()
+                .and_then(|bom_ref| validate_root(bom_ref, bom)),
+        )
+        .add_field(
+            "bom_ref",
+            component.bom_ref.as_deref().and_then(|bom_ref| {
+                validate_unique(bom, |other| other.bom_ref.as_deref() == Some(bom_ref))
+            }),
+        )
+        .add_field(
+            "purl",
+            component
+                .purl
+                .as_deref()
+                .and_then(|purl| validate_unique(bom, |other| other.purl.as_deref() == Some(purl))),
+        )
+        .add_field("name", name_error)
+        .add_field("version", version_error)
+        .into()
+}
+
+impl Rule for MetadataComponentRule {
+    fn id(&self) -> &str {
+        "metadata-component"
+    }
+
+    fn applies(&self, bom: &Bom, _ctx: &RuleContext) -> bool {
+        bom.meta_data
+            .as_ref()
+            .is_some_and(|meta_data| meta_data.component.is_some())
+    }
+
+    fn check(&self, bom: &Bom, _ctx: &RuleContext) -> ValidationResult {
+        let Some(component) = bom
+            .meta_data
+            .as_ref()
+            .and_then(|meta_data| meta_data.component.as_ref())
+        else {
+            return Ok(());
+        };
+
+        let meta_data = ValidationContext::new()
+            .add_struct("component", Some(check_component(component, bom)))
+            .into();
+
+        ValidationContext::new()
+            .add_struct("meta_data", Some(meta_data))
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        rules::{Rule, RuleContext},
+        validation::{SpecVersion, ValidationErrors, ValidationErrorsKind},
+        Bom, Component, ComponentType, Dependency, Metadata,
+    };
+
+    use super::MetadataComponentRule;
+
+    fn application() -> Component {
+        Component {
+            bom_ref: Some(String::from("app")),
+            version: Some(String::from("1.0.0")),
+            purl: Some(String::from("pkg:cargo/app@1.0.0")),
+            ..Component::new(ComponentType::Application, "app")
+        }
+    }
+
+    fn bom_with(component: Component) -> Bom {
+        Bom {
+            meta_data: Some(Metadata {
+                component: Some(component),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn component_errors(result: Result<(), ValidationErrors>) -> ValidationErrors {
+        let errors = result.expect_err("Expected errors");
+        let Some(ValidationErrorsKind::Struct(meta_data)) = errors.get("meta_data") else {
+            panic!("Expected meta_data errors");
+        };
+        let Some(ValidationErrorsKind::Struct(component)) = meta_data.get("component") else {
+            panic!("Expected component errors");
+        };
+        *component.clone()
+    }
+
+    fn check(bom: &Bom) -> Result<(), ValidationErrors> {
+        MetadataComponentRule.check(bom, &RuleContext::new(SpecVersion::V1_5))
+    }
+
+    #[test]
+    fn accepts_consistent_metadata_component() {
+        let mut bom = bom_with(application());
+        bom.components = Some(vec![Component::new(ComponentType::Library, "lib")]);
+        bom.dependencies = Some(vec![Dependency {
+            dependency_ref: String::from("app"),
+            depends_on: Some(vec![String::from("lib")]),
+        }]);
+
+        assert!(check(&bom).is_ok());
+    }
+
+    #[test]
+    fn fails_when_not_root_of_dependency_graph() {
+        let mut bom = bom_with(application());
+        bom.dependencies = Some(vec![Dependency {
+            dependency_ref: String::from("lib"),
+            depends_on: Some(vec![String::from("app")]),
+        }]);
+
+        assert!(component_errors(check(&bom)).contains_key("bom_ref"));
+    }
+
+    #[test]
+    fn fails_when_duplicated_in_components() {
+        let mut bom = bom_with(application());
+        bom.components = Some(vec![application()]);
+
+        let errors = component_errors(check(&bom));
+        assert!(errors.contains_key("bom_ref"));
+        assert!(errors.contains_key("purl"));
+    }
+
+    #[test]
+    fn fails_when_purl_coordinates_differ() {
+        let bom = bom_with(Component {
+            name: String::from("other"),
+            version: Some(String::from("2.0.0")),
+            ..application()
+        });
+
+        let errors = component_errors(check(&bom));
+        assert!(errors.contains_key("name"));
+        assert!(errors.contains_key("version"));
+    }
+}
@@ -0,0 +1,236 @@
+use crate::{
+    map::IndexMap,
+    prelude::*,
+    purl::Purl,
+    rules::{check_components, Rule, RuleContext},
+    validation::{ValidationContext, ValidationError, ValidationResult},
+    Bom, Component, Hash,
+};
+
+/// Opt-in [`Rule`] that enforces policies on the qualifiers of component purls.
+///
+/// A `checksum` qualifier that contradicts a hash of the same algorithm declared by the
+/// component is always an error. Packages of internal registries, see
+/// [`PurlQualifierRule::internal_namespaces`], need a `repository_url` qualifier so they
+/// cannot be confused with public packages of the same name, and further qualifiers can be
+/// required per purl type with [`PurlQualifierRule::require_qualifiers`].
+#[derive(Debug, Clone, Default)]
+pub struct PurlQualifierRule {
+    internal_namespaces: Vec<String>,
+    required: IndexMap<String, Vec<String>>,
+}
+
+/// Normalizes an algorithm name, so `SHA-256` of a hash matches `sha256` of a purl.
+fn normalize_algorithm(algorithm: &str) -> String {
+    algorithm
+        .chars()
+        .filter(|c| !matches!(c, '-' | '_'))
+        .collect::<String>()
+        .to_ascii_lowercase()
+}
+
+fn declared_hash<'a>(hashes: &'a [Hash], algorithm: &str) -> Option<&'a Hash> {
+    hashes.iter().find(|hash| {
+        serde_json::to_value(hash.alg)
+            .ok()
+            .and_then(|alg| alg.as_str().map(normalize_algorithm))
+            .is_some_and(|alg| alg == algorithm)
+    })
+}
+
+impl PurlQualifierRule {
+    /// Creates the rule only checking `checksum` qualifiers.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requires a `repository_url` qualifier for purls in the given namespaces, e.g.
+    /// `["com.acme", "@acme"]`. A namespace also matches all namespaces nested in it.
+    pub fn internal_namespaces<I, S>(mut self, namespaces: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.internal_namespaces = namespaces
+            .into_iter()
+            .map(|namespace| namespace.as_ref().to_string())
+            .collect();
+        self
+    }
+
+    /// Requires the given qualifiers for all purls of the type, e.g. `type` for `maven`.
+    pub fn require_qualifiers<T, I, S>(mut self, purl_type: T, qualifiers: I) -> Self
+    where
+        T: AsRef<str>,
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.required
+            .entry(purl_type.as_ref().to_ascii_lowercase())
+            .or_default()
+            .extend(
+                qualifiers
+                    .into_iter()
+                    .map(|qualifier| qualifier.as_ref().to_ascii_lowercase()),
+            );
+        self
+    }
+
+    fn is_internal(&self, purl: &Purl) -> bool {
+        let Some(namespace) = &purl.namespace else {
+            return false;
+        };
+        self.internal_namespaces.iter().any(|internal| {
+            namespace
+                .strip_prefix(internal.as_str())
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with(['.', '/']))
+        })
+    }
+
+    fn check_purl(&self, purl: &Purl, hashes: &[Hash]) -> Vec<ValidationError> {
+        let mut errors = Vec::new();
+
+        if self.is_internal(purl) && !purl.qualifiers.contains_key("repository_url") {
+            errors.push(
+                ValidationError::warning("purl_repository_url_missing")
+                    .with_param("namespace", purl.namespace.as_deref().unwrap_or_default())
+                    .with_code("missing-repository-url"),
+            );
+        }
+
+        for qualifier in self.required.get(&purl.purl_type).into_iter().flatten() {
+            if !purl.qualifiers.contains_key(qualifier) {
+                errors.push(
+                    ValidationError::warning("purl_qualifier_missing")
+                        .with_param("qualifier", qualifier)
+                        .with_param("type", &purl.purl_type)
+                        .with_code("missing-purl-qualifier"),
+                );
+            }
+        }
+
+        let checksums = purl
+            .qualifiers
+            .get("checksum")
+            .into_iter()
+            .flat_map(|checksums| checksums.split(','))
+            .filter_map(|checksum| checksum.split_once(':'));
+        for (algorithm, value) in checksums {
+            let Some(hash) = declared_hash(hashes, &normalize_algorithm(algorithm)) else {
+                continue;
+            };
+            if !hash.content.eq_ignore_ascii_case(value) {
+                errors.push(
+                    ValidationError::new("purl_checksum_mismatch")
+                        .with_param("alg", algorithm)
+                        .with_code("purl-checksum-mismatch")
+                        .with_expected(&hash.content)
+                        .with_actual(value),
+                );
+            }
+        }
+
+        errors
+    }
+
+    fn check_component(&self, component: &Component) -> ValidationResult {
+        let Some(Ok(purl)) = component.purl.as_deref().map(Purl::parse) else {
+            return Ok(());
+        };
+
+        self.check_purl(&purl, component.hashes.as_deref().unwrap_or_default())
+            .into_iter()
+            .fold(ValidationContext::new(), |context, error| {
+                context.add_field("purl", Some(Err(error)))
+            })
+            .into()
+    }
+}
+
+impl Rule for PurlQualifierRule {
+    fn id(&self) -> &str {
+        "purl-qualifiers"
+    }
+
+    fn check(&self, bom: &Bom, _ctx: &RuleContext) -> ValidationResult {
+        let children = check_components(bom.components.as_deref(), &|component| {
+            self.check_component(component)
+        });
+
+        ValidationContext::new()
+            .add_list("components", children)
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        options::ValidationOptions,
+        testing::errors_at,
+        validate_bom,
+        validation::{Severity, SpecVersion, ValidationErrors},
+        Bom,
+    };
+
+    use super::PurlQualifierRule;
+
+    const SHA256: &str = "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08";
+
+    fn validate(purl: &str, rule: PurlQualifierRule) -> Result<(), ValidationErrors> {
+        let bom = Bom::parse_json(&format!(
+            r#"{{ "components": [{{ "type": "library", "name": "lib", "purl": "{}",
+                "hashes": [{{ "alg": "SHA-256", "content": "{}" }}] }}] }}"#,
+            purl, SHA256
+        ))
+        .expect("Failed to parse BOM");
+        validate_bom(
+            SpecVersion::V1_5,
+            &bom,
+            &ValidationOptions::new().add_rule(rule),
+        )
+    }
+
+    #[test]
+    fn fails_on_contradicting_checksum() {
+        let result = validate(
+            "pkg:maven/org.example/lib@1.0.0?checksum=sha1:abc,sha256:0000",
+            PurlQualifierRule::new(),
+        );
+        let errors = errors_at(&result, "components[0].purl");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].severity, Severity::Error);
+        assert_eq!(errors[0].code.as_deref(), Some("purl-checksum-mismatch"));
+
+        let purl = format!("pkg:maven/org.example/lib@1.0.0?checksum=SHA256:{}", SHA256);
+        assert!(validate(&purl, PurlQualifierRule::new()).is_ok());
+    }
+
+    #[test]
+    fn requires_repository_url_for_internal_namespaces() {
+        let rule = PurlQualifierRule::new().internal_namespaces(["com.acme"]);
+
+        let result = validate("pkg:maven/com.acme.core/lib@1.0.0", rule.clone());
+        assert_eq!(
+            errors_at(&result, "components[0].purl")[0].code.as_deref(),
+            Some("missing-repository-url")
+        );
+        assert!(validate(
+            "pkg:maven/com.acme.core/lib@1.0.0?repository_url=repo.acme.com",
+            rule.clone()
+        )
+        .is_ok());
+        assert!(validate("pkg:maven/com.acmeinc/lib@1.0.0", rule).is_ok());
+    }
+
+    #[test]
+    fn requires_configured_qualifiers() {
+        let rule = PurlQualifierRule::new().require_qualifiers("maven", ["type", "classifier"]);
+
+        let result = validate("pkg:maven/org.example/lib@1.0.0?type=jar", rule.clone());
+        let errors = errors_at(&result, "components[0].purl");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code.as_deref(), Some("missing-purl-qualifier"));
+        assert!(validate("pkg:npm/lib@1.0.0", rule).is_ok());
+    }
+}
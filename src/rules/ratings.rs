@@ -0,0 +1,208 @@
+use crate::{
+    prelude::*,
+    rules::{Rule, RuleContext},
+    validate_uri,
+    validation::{SpecVersion, ValidationContext, ValidationError, ValidationResult},
+    Bom, Rating, RatingSeverity, ScoreMethod, Vulnerability,
+};
+
+/// Cross-checks the fields of every rating of a vulnerability.
+///
+/// * the score must be within the range of the scoring method, e.g. `0.0` to `10.0` for CVSS
+/// * the severity must match the severity band of the score, e.g. CVSSv3 `9.1` is `critical`
+/// * the url of the source must be a valid URI
+///
+/// Vulnerabilities are only checked from spec version 1.4 on, before they are reported as
+/// unsupported field by the built-in validation.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RatingConsistencyRule;
+
+/// Returns the range of scores of the method, `None` if the method has no numeric score.
+fn score_range(method: ScoreMethod) -> Option<(f64, f64)> {
+    match method {
+        ScoreMethod::CvssV2 | ScoreMethod::CvssV3 | ScoreMethod::CvssV31 | ScoreMethod::CvssV4 => {
+            Some((0.0, 10.0))
+        }
+        ScoreMethod::Owasp => Some((0.0, 9.0)),
+        ScoreMethod::Ssvc | ScoreMethod::Other => None,
+    }
+}
+
+/// Returns the severity band a score of the method falls into.
+fn severity_band(method: ScoreMethod, score: f64) -> Option<RatingSeverity> {
+    let severity = match method {
+        ScoreMethod::CvssV3 | ScoreMethod::CvssV31 | ScoreMethod::CvssV4 => match score {
+            s if s <= 0.0 => RatingSeverity::None,
+            s if s < 4.0 => RatingSeverity::Low,
+            s if s < 7.0 => RatingSeverity::Medium,
+            s if s < 9.0 => RatingSeverity::High,
+            _ => RatingSeverity::Critical,
+        },
+        ScoreMethod::CvssV2 => match score {
+            s if s < 4.0 => RatingSeverity::Low,
+            s if s < 7.0 => RatingSeverity::Medium,
+            _ => RatingSeverity::High,
+        },
+        ScoreMethod::Owasp => match score {
+            s if s < 3.0 => RatingSeverity::Low,
+            s if s < 6.0 => RatingSeverity::Medium,
+            _ => RatingSeverity::High,
+        },
+        ScoreMethod::Ssvc | ScoreMethod::Other => return None,
+    };
+    Some(severity)
+}
+
+fn severity_name(severity: RatingSeverity) -> String {
+    format!("{:?}", severity).to_lowercase()
+}
+
+fn check_score(rating: &Rating) -> Option<Result<(), ValidationError>> {
+    let (score, method) = (rating.score?, rating.method?);
+    let (min, max) = score_range(method)?;
+
+    (!(min..=max).contains(&score)).then(|| {
+        Err(ValidationError::new("rating_score_range")
+            .with_param("score", score)
+            .with_param("min", min)
+            .with_param("max", max)
+            .with_param("method", format!("{:?}", method))
+            .with_code("rating-score-range")
+            .with_expected(format!("{} to {}", min, max))
+            .with_actual(score))
+    })
+}
+
+fn check_severity(rating: &Rating) -> Option<Result<(), ValidationError>> {
+    let (score, method, severity) = (rating.score?, rating.method?, rating.severity?);
+    let (min, max) = score_range(method)?;
+    if severity == RatingSeverity::Unknown || !(min..=max).contains(&score) {
+        return None;
+    }
+    let expected = severity_band(method, score)?;
+
+    (severity != expected).then(|| {
+        Err(ValidationError::new("rating_severity_mismatch")
+            .with_param("severity", severity_name(severity))
+            .with_param("method", format!("{:?}", method))
+            .with_param("score", score)
+            .with_param("expected", severity_name(expected))
+            .with_code("rating-severity-mismatch")
+            .with_expected(severity_name(expected))
+            .with_actual(severity_name(severity)))
+    })
+}
+
+fn check_rating(rating: &Rating) -> ValidationResult {
+    let source = rating.source.as_ref().map(|source| {
+        ValidationContext::new()
+            .add_field("url", source.url.as_deref().map(validate_uri))
+            .into()
+    });
+
+    ValidationContext::new()
+        .add_field("score", check_score(rating))
+        .add_field("severity", check_severity(rating))
+        .add_struct("source", source)
+        .into()
+}
+
+pub(super) fn check_vulnerability(vulnerability: &Vulnerability) -> ValidationResult {
+    let children = vulnerability
+        .ratings
+        .as_ref()
+        .map(|ratings| ratings.iter().map(check_rating).collect::<Vec<_>>());
+
+    ValidationContext::new()
+        .add_list("ratings", children)
+        .into()
+}
+
+impl Rule for RatingConsistencyRule {
+    fn id(&self) -> &str {
+        "rating-consistency"
+    }
+
+    fn applies(&self, _bom: &Bom, ctx: &RuleContext) -> bool {
+        ctx.version >= SpecVersion::V1_4
+    }
+
+    fn check(&self, bom: &Bom, _ctx: &RuleContext) -> ValidationResult {
+        let children = bom.vulnerabilities.as_ref().map(|vulnerabilities| {
+            vulnerabilities
+                .iter()
+                .map(check_vulnerability)
+                .collect::<Vec<_>>()
+        });
+
+        ValidationContext::new()
+            .add_list("vulnerabilities", children)
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        options::ValidationOptions, testing::errors_at, validate_bom, validation::SpecVersion, Bom,
+    };
+
+    fn bom_with_rating(rating: &str) -> Bom {
+        let input = format!(
+            r#"{{ "vulnerabilities": [{{ "id": "CVE-2024-1234", "ratings": [{}] }}] }}"#,
+            rating
+        );
+        Bom::parse_json(&input).expect("Failed to parse BOM")
+    }
+
+    fn codes_at(bom: &Bom, path: &str) -> Vec<String> {
+        let result = validate_bom(SpecVersion::V1_5, bom, &ValidationOptions::new());
+        errors_at(&result, path)
+            .into_iter()
+            .filter_map(|error| error.code.clone())
+            .collect()
+    }
+
+    #[test]
+    fn accepts_consistent_rating() {
+        let bom = bom_with_rating(
+            r#"{ "source": { "name": "NVD", "url": "https://nvd.nist.gov/" },
+                 "score": 9.1, "severity": "critical", "method": "CVSSv31" }"#,
+        );
+
+        let result = validate_bom(SpecVersion::V1_5, &bom, &ValidationOptions::new());
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn flags_score_out_of_range() {
+        let bom = bom_with_rating(r#"{ "score": 10.5, "method": "CVSSv3" }"#);
+
+        assert_eq!(
+            codes_at(&bom, "vulnerabilities[0].ratings[0].score"),
+            vec!["rating-score-range"]
+        );
+    }
+
+    #[test]
+    fn flags_severity_not_matching_score() {
+        let bom = bom_with_rating(r#"{ "score": 9.1, "severity": "high", "method": "CVSSv31" }"#);
+        assert_eq!(
+            codes_at(&bom, "vulnerabilities[0].ratings[0].severity"),
+            vec!["rating-severity-mismatch"]
+        );
+
+        let bom = bom_with_rating(r#"{ "score": 9.1, "severity": "high", "method": "CVSSv2" }"#);
+        assert!(codes_at(&bom, "vulnerabilities[0].ratings[0].severity").is_empty());
+    }
+
+    #[test]
+    fn flags_invalid_source_url() {
+        let bom = bom_with_rating(r#"{ "source": { "url": "not a url" } }"#);
+
+        assert_eq!(
+            codes_at(&bom, "vulnerabilities[0].ratings[0].source.url"),
+            vec!["invalid-uri"]
+        );
+    }
+}
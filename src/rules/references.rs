@@ -0,0 +1,160 @@
+use crate::{
+    prelude::*,
+    rules::{check_components, Rule, RuleContext},
+    validation::{ValidationContext, ValidationError, ValidationResult},
+    Bom, Component, ExternalReference, ExternalReferenceType,
+};
+
+/// Warns about external references whose url does not plausibly match their type, usually a
+/// copy-paste mistake of the generator.
+///
+/// * `mailto:` urls are only expected for mailing lists, support and other references
+/// * `vcs` urls must use a scheme of a version control system or be `git@host:path`, and must
+///   not point to the issues of a repository
+/// * `issue-tracker` urls must not point to a git repository
+/// * `bom` urls must be `http(s)` urls or BOM-Links
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ExternalReferenceUrlRule;
+
+/// The schemes of version control urls, including the `git+https` form of purls.
+const VCS_SCHEMES: &[&str] = &[
+    "git",
+    "git+https",
+    "git+http",
+    "git+ssh",
+    "ssh",
+    "svn",
+    "svn+ssh",
+    "hg",
+    "http",
+    "https",
+];
+
+fn scheme(url: &str) -> Option<String> {
+    url.split_once(':')
+        .map(|(scheme, _)| scheme.to_ascii_lowercase())
+        .filter(|scheme| !scheme.contains(['/', '@']))
+}
+
+/// Returns why the url does not match the type, if it does not.
+fn mismatch(reference_type: ExternalReferenceType, url: &str) -> Option<&'static str> {
+    let scheme = scheme(url);
+    let lower = url.to_ascii_lowercase();
+    let path = lower.split(['?', '#']).next().unwrap_or_default();
+
+    match reference_type {
+        ExternalReferenceType::MailingList
+        | ExternalReferenceType::Support
+        | ExternalReferenceType::Other => None,
+        _ if scheme.as_deref() == Some("mailto") => Some("email address"),
+        ExternalReferenceType::Vcs => match scheme {
+            _ if path.contains("/issues") => Some("issue tracker"),
+            Some(scheme) if VCS_SCHEMES.contains(&scheme.as_str()) => None,
+            Some(_) => Some("unexpected scheme"),
+            // scp-like form, e.g. `git@github.com:owner/repo.git`
+            None if url.contains('@') && url.contains(':') => None,
+            None => Some("missing scheme"),
+        },
+        ExternalReferenceType::IssueTracker if path.ends_with(".git") => Some("repository"),
+        ExternalReferenceType::Bom => match scheme.as_deref() {
+            Some("http" | "https") => None,
+            Some("urn") if lower.starts_with("urn:cdx:") => None,
+            _ => Some("neither url nor BOM-Link"),
+        },
+        _ => None,
+    }
+}
+
+fn check_reference(reference: &ExternalReference) -> ValidationResult {
+    let reference_type = serde_json::to_value(reference.reference_type)
+        .ok()
+        .and_then(|value| value.as_str().map(String::from))
+        .unwrap_or_default();
+    let error = mismatch(reference.reference_type, &reference.url).map(|reason| {
+        Err(ValidationError::warning("reference_url_mismatch")
+            .with_param("type", &reference_type)
+            .with_param("reason", reason)
+            .with_code("reference-url-mismatch")
+            .with_expected(format!("{} url", reference_type))
+            .with_actual(&reference.url))
+    });
+
+    ValidationContext::new().add_field("url", error).into()
+}
+
+pub(super) fn check_component(component: &Component) -> ValidationResult {
+    let children = component
+        .external_references
+        .as_ref()
+        .map(|references| references.iter().map(check_reference).collect::<Vec<_>>());
+
+    ValidationContext::new()
+        .add_list("external_references", children)
+        .into()
+}
+
+impl Rule for ExternalReferenceUrlRule {
+    fn id(&self) -> &str {
+        "external-reference-urls"
+    }
+
+    fn check(&self, bom: &Bom, _ctx: &RuleContext) -> ValidationResult {
+        let children = check_components(bom.components.as_deref(), &check_component);
+
+        ValidationContext::new()
+            .add_list("components", children)
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        rules::{Rule, RuleContext},
+        testing::errors_at,
+        validation::{SpecVersion, ValidationResult},
+        Bom,
+    };
+
+    use super::ExternalReferenceUrlRule;
+
+    fn check(references: &str) -> ValidationResult {
+        let input = format!(
+            r#"{{ "components": [{{ "type": "library", "name": "a", "externalReferences": {} }}] }}"#,
+            references
+        );
+        let bom = Bom::parse_json(&input).expect("Failed to parse BOM");
+        ExternalReferenceUrlRule.check(&bom, &RuleContext::new(SpecVersion::V1_5))
+    }
+
+    #[test]
+    fn accepts_plausible_urls() {
+        let result = check(
+            r#"[
+                { "type": "vcs", "url": "https://github.com/serde-rs/serde" },
+                { "type": "vcs", "url": "git@github.com:serde-rs/serde.git" },
+                { "type": "issue-tracker", "url": "https://github.com/serde-rs/serde/issues" },
+                { "type": "mailing-list", "url": "mailto:list@example.com" },
+                { "type": "bom", "url": "urn:cdx:3e671687-395b-41f5-a30f-a58921a69b79/1" }
+            ]"#,
+        );
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn warns_about_mismatched_urls() {
+        let result = check(
+            r#"[
+                { "type": "vcs", "url": "https://github.com/serde-rs/serde/issues" },
+                { "type": "issue-tracker", "url": "mailto:bugs@example.com" },
+                { "type": "vcs", "url": "ftp://example.com/serde" },
+                { "type": "bom", "url": "sbom.json" }
+            ]"#,
+        );
+
+        for index in 0..4 {
+            let path = format!("components[0].external_references[{}].url", index);
+            assert_eq!(errors_at(&result, &path).len(), 1, "{}", path);
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use crate::{
+    prelude::*,
+    rules::{check_components, Rule, RuleContext},
+    validation::{SpecVersion, ValidationContext, ValidationError, ValidationResult},
+    Bom, Component,
+};
+
+/// Warns about blank and duplicated entries of the `aliases` and `tags` of release notes.
+///
+/// Entries are compared after trimming whitespace and ignoring case, only the repetitions of
+/// an entry are reported.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ReleaseNotesRule;
+
+fn check_entries(
+    context: ValidationContext,
+    field: &str,
+    entries: Option<&Vec<String>>,
+) -> ValidationContext {
+    let mut seen = Vec::new();
+    entries
+        .into_iter()
+        .flatten()
+        .enumerate()
+        .fold(context, |context, (index, entry)| {
+            let normalized = entry.trim().to_lowercase();
+            let error = if normalized.is_empty() {
+                Some(ValidationError::warning("blank_entry").with_code("blank-entry"))
+            } else if seen.contains(&normalized) {
+                Some(
+                    ValidationError::warning("duplicate_entry")
+                        .with_param("value", entry)
+                        .with_code("duplicate-entry")
+                        .with_actual(entry),
+                )
+            } else {
+                seen.push(normalized);
+                None
+            };
+            context.add_field(field, error.map(|error| Err(error.with_index(index))))
+        })
+}
+
+pub(super) fn check_component(component: &Component) -> ValidationResult {
+    let notes = component.release_notes.as_ref().map(|notes| {
+        let context = check_entries(ValidationContext::new(), "aliases", notes.aliases.as_ref());
+        check_entries(context, "tags", notes.tags.as_ref()).into()
+    });
+
+    ValidationContext::new()
+        .add_struct("release_notes", notes)
+        .into()
+}
+
+impl Rule for ReleaseNotesRule {
+    fn id(&self) -> &str {
+        "release-notes"
+    }
+
+    fn applies(&self, _bom: &Bom, ctx: &RuleContext) -> bool {
+        ctx.version >= SpecVersion::V1_4
+    }
+
+    fn check(&self, bom: &Bom, _ctx: &RuleContext) -> ValidationResult {
+        let children = check_components(bom.components.as_deref(), &check_component);
+
+        ValidationContext::new()
+            .add_list("components", children)
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        rules::{Rule, RuleContext},
+        testing::errors_at,
+        validation::SpecVersion,
+        Bom,
+    };
+
+    use super::ReleaseNotesRule;
+
+    #[test]
+    fn warns_about_blank_and_duplicated_entries() {
+        let bom = Bom::parse_json(
+            r#"{ "components": [{ "type": "library", "name": "lib", "releaseNotes": {
+                "type": "major",
+                "aliases": ["Bionic", "bionic ", "Beaver"],
+                "tags": ["security", " ", "performance"]
+            } }] }"#,
+        )
+        .expect("Failed to parse BOM");
+
+        let result = ReleaseNotesRule.check(&bom, &RuleContext::new(SpecVersion::V1_5));
+        let aliases = errors_at(&result, "components[0].release_notes.aliases");
+        assert_eq!(aliases.len(), 1);
+        assert_eq!(aliases[0].code.as_deref(), Some("duplicate-entry"));
+        assert_eq!(aliases[0].context.index, Some(1));
+
+        let tags = errors_at(&result, "components[0].release_notes.tags");
+        assert_eq!(tags.len(), 1);
+        assert_eq!(tags[0].code.as_deref(), Some("blank-entry"));
+        assert_eq!(tags[0].context.index, Some(1));
+    }
+}
@@ -0,0 +1,128 @@
+use crate::{
+    prelude::*,
+    rules::{Rule, RuleContext},
+    validation::{ValidationContext, ValidationError, ValidationResult},
+    Bom, Service,
+};
+
+/// Runs the check on every service of the BOM.
+fn check_services(bom: &Bom, check: impl Fn(&Service) -> ValidationResult) -> ValidationResult {
+    let children = bom
+        .services
+        .as_ref()
+        .map(|services| services.iter().map(&check).collect::<Vec<_>>());
+
+    ValidationContext::new()
+        .add_list("services", children)
+        .into()
+}
+
+/// Opt-in [`Rule`] that flags services with `authenticated: false` that cross a trust
+/// boundary, i.e. declare `x-trust-boundary: true`.
+#[derive(Debug, Clone, Default)]
+pub struct UnauthenticatedBoundaryRule;
+
+impl UnauthenticatedBoundaryRule {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Rule for UnauthenticatedBoundaryRule {
+    fn id(&self) -> &str {
+        "unauthenticated-trust-boundary"
+    }
+
+    fn check(&self, bom: &Bom, _ctx: &RuleContext) -> ValidationResult {
+        check_services(bom, |service| {
+            let unauthenticated =
+                service.authenticated == Some(false) && service.trust_boundary == Some(true);
+            ValidationContext::new()
+                .add_field(
+                    "authenticated",
+                    unauthenticated.then(|| {
+                        Err(ValidationError::new("unauthenticated_trust_boundary")
+                            .with_code("unauthenticated-trust-boundary"))
+                    }),
+                )
+                .into()
+        })
+    }
+}
+
+/// Opt-in [`Rule`] that warns about services declaring neither `authenticated` nor
+/// `x-trust-boundary`, which threat models cannot classify.
+#[derive(Debug, Clone, Default)]
+pub struct MissingTrustFlagsRule;
+
+impl MissingTrustFlagsRule {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Rule for MissingTrustFlagsRule {
+    fn id(&self) -> &str {
+        "missing-trust-flags"
+    }
+
+    fn check(&self, bom: &Bom, _ctx: &RuleContext) -> ValidationResult {
+        check_services(bom, |service| {
+            let missing = service.authenticated.is_none() && service.trust_boundary.is_none();
+            ValidationContext::new()
+                .add_field(
+                    "trust_boundary",
+                    missing.then(|| {
+                        Err(ValidationError::warning("missing_trust_flags")
+                            .with_code("missing-trust-flags"))
+                    }),
+                )
+                .into()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        options::ValidationOptions,
+        testing::errors_at,
+        validate_bom,
+        validation::{Severity, SpecVersion, ValidationErrors},
+        Bom,
+    };
+
+    use super::{MissingTrustFlagsRule, UnauthenticatedBoundaryRule};
+
+    const SERVICES: &str = r#"{ "services": [
+        { "name": "public", "authenticated": false, "x-trust-boundary": true },
+        { "name": "internal", "authenticated": false, "x-trust-boundary": false },
+        { "name": "login", "authenticated": true, "x-trust-boundary": true },
+        { "name": "unknown" }
+    ] }"#;
+
+    fn validate(options: &ValidationOptions) -> Result<(), ValidationErrors> {
+        let bom = Bom::parse_json(SERVICES).expect("Failed to parse BOM");
+        validate_bom(SpecVersion::V1_5, &bom, options)
+    }
+
+    #[test]
+    fn flags_unauthenticated_service_crossing_trust_boundary() {
+        let result = validate(&ValidationOptions::new().add_rule(UnauthenticatedBoundaryRule));
+
+        let errors = errors_at(&result, "services[0].authenticated");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].severity, Severity::Error);
+        assert_eq!(result.expect_err("Expected findings").errors().len(), 1);
+    }
+
+    #[test]
+    fn warns_about_services_without_trust_flags() {
+        let result = validate(&ValidationOptions::new().add_rule(MissingTrustFlagsRule));
+
+        let errors = errors_at(&result, "services[3].trust_boundary");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].severity, Severity::Warning);
+        assert_eq!(result.expect_err("Expected findings").errors().len(), 1);
+    }
+}
@@ -0,0 +1,127 @@
+use crate::{
+    rules::{check_components, Rule, RuleContext},
+    validation::{ValidationContext, ValidationError, ValidationResult},
+    Bom, Component,
+};
+
+/// Warns when the supplier of `metadata.component` differs from the supplier of the same
+/// component listed in `components[]`, a frequent artifact of naively merged documents.
+///
+/// Components are the same if they share the bom-ref or the
+/// [`ComponentIdentity`](crate::identity::ComponentIdentity). Missing suppliers are not
+/// reported, see the completeness checks.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SupplierConsistencyRule;
+
+fn supplier_name(component: &Component) -> Option<&str> {
+    component.supplier.as_ref()?.name.as_deref()
+}
+
+fn is_same(root: &Component, other: &Component) -> bool {
+    match (&root.bom_ref, &other.bom_ref) {
+        (Some(root_ref), Some(other_ref)) if root_ref == other_ref => true,
+        _ => root.identity() == other.identity(),
+    }
+}
+
+impl Rule for SupplierConsistencyRule {
+    fn id(&self) -> &str {
+        "supplier-consistency"
+    }
+
+    fn applies(&self, bom: &Bom, _ctx: &RuleContext) -> bool {
+        bom.meta_data
+            .as_ref()
+            .and_then(|meta_data| meta_data.component.as_ref())
+            .is_some_and(|component| supplier_name(component).is_some())
+    }
+
+    fn check(&self, bom: &Bom, _ctx: &RuleContext) -> ValidationResult {
+        let Some((root, expected)) = bom
+            .meta_data
+            .as_ref()
+            .and_then(|meta_data| meta_data.component.as_ref())
+            .and_then(|component| Some((component, supplier_name(component)?)))
+        else {
+            return Ok(());
+        };
+
+        let children = check_components(bom.components.as_deref(), &|component| {
+            let mismatch = supplier_name(component)
+                .filter(|supplier| is_same(root, component) && *supplier != expected);
+            ValidationContext::new()
+                .add_field(
+                    "supplier",
+                    mismatch.map(|supplier| {
+                        Err(ValidationError::warning("supplier_mismatch")
+                            .with_param("supplier", supplier)
+                            .with_param("expected", expected)
+                            .with_code("supplier-mismatch")
+                            .with_expected(expected)
+                            .with_actual(supplier))
+                    }),
+                )
+                .into()
+        });
+
+        ValidationContext::new()
+            .add_list("components", children)
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        rules::{Rule, RuleContext},
+        testing::errors_at,
+        validation::{Severity, SpecVersion},
+        Bom, Component, ComponentType, Metadata, OrganizationalEntity,
+    };
+
+    use super::SupplierConsistencyRule;
+
+    fn component(purl: &str, supplier: &str) -> Component {
+        Component {
+            purl: Some(purl.to_string()),
+            supplier: Some(OrganizationalEntity {
+                name: Some(supplier.to_string()),
+                contact: None,
+            }),
+            ..Component::new(ComponentType::Application, "app")
+        }
+    }
+
+    fn bom(components: Vec<Component>) -> Bom {
+        Bom {
+            meta_data: Some(Metadata {
+                component: Some(component("pkg:cargo/app@1.0.0", "ACME")),
+                ..Default::default()
+            }),
+            components: Some(components),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn warns_about_conflicting_supplier() {
+        let bom = bom(vec![
+            component("pkg:cargo/lib@1.0.0", "Other"),
+            component("pkg:cargo/app@1.0.0", "ACME Inc."),
+        ]);
+
+        let result = SupplierConsistencyRule.check(&bom, &RuleContext::new(SpecVersion::V1_5));
+        assert!(errors_at(&result, "components[0].supplier").is_empty());
+        let errors = errors_at(&result, "components[1].supplier");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].severity, Severity::Warning);
+    }
+
+    #[test]
+    fn accepts_matching_supplier() {
+        let bom = bom(vec![component("pkg:cargo/app@1.0.0", "ACME")]);
+
+        let result = SupplierConsistencyRule.check(&bom, &RuleContext::new(SpecVersion::V1_5));
+        assert!(result.is_ok());
+    }
+}
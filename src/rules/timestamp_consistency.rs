@@ -0,0 +1,165 @@
+use crate::{
+    prelude::*,
+    rules::{vulnerability_timestamps::parse_timestamp, Rule, RuleContext},
+    validation::{ValidationContext, ValidationError, ValidationResult},
+    Bom, Component,
+};
+
+/// Opt-in [`Rule`] that warns when `metadata.timestamp` predates a timestamp of data the BOM
+/// contains, i.e. the `timestamp` of component release notes or the `created` and `published`
+/// timestamps of vulnerabilities.
+///
+/// Timestamps that fail to parse are left to the other checks.
+#[derive(Debug, Clone, Default)]
+pub struct TimestampConsistencyRule;
+
+impl TimestampConsistencyRule {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+/// Returns a warning if `timestamp` is after the BOM timestamp.
+fn check_timestamp(
+    timestamp: Option<&String>,
+    bom_timestamp: (&str, (i64, u32)),
+    ctx: &RuleContext,
+) -> Option<Result<(), ValidationError>> {
+    let timestamp = timestamp?;
+    let parsed = parse_timestamp(timestamp, ctx.timestamp_tolerance)?;
+    let (bom_timestamp, created) = bom_timestamp;
+    (parsed > created).then(|| {
+        Err(ValidationError::warning("timestamp_after_bom")
+            .with_param("timestamp", bom_timestamp)
+            .with_code("timestamp-after-bom")
+            .with_expected(format!("not after {}", bom_timestamp))
+            .with_actual(timestamp.as_str()))
+    })
+}
+
+fn check_component(
+    component: &Component,
+    bom_timestamp: (&str, (i64, u32)),
+    ctx: &RuleContext,
+) -> ValidationResult {
+    let release_notes = component.release_notes.as_ref().map(|release_notes| {
+        ValidationContext::new()
+            .add_field(
+                "timestamp",
+                check_timestamp(release_notes.timestamp.as_ref(), bom_timestamp, ctx),
+            )
+            .into()
+    });
+    let children = component.components.as_ref().map(|components| {
+        components
+            .iter()
+            .map(|component| check_component(component, bom_timestamp, ctx))
+            .collect::<Vec<_>>()
+    });
+
+    ValidationContext::new()
+        .add_struct("release_notes", release_notes)
+        .add_list("components", children)
+        .into()
+}
+
+impl Rule for TimestampConsistencyRule {
+    fn id(&self) -> &str {
+        "timestamp-consistency"
+    }
+
+    fn check(&self, bom: &Bom, ctx: &RuleContext) -> ValidationResult {
+        let Some(timestamp) = bom
+            .meta_data
+            .as_ref()
+            .and_then(|metadata| metadata.timestamp.as_deref())
+        else {
+            return Ok(());
+        };
+        let Some(created) = parse_timestamp(timestamp, ctx.timestamp_tolerance) else {
+            return Ok(());
+        };
+        let bom_timestamp = (timestamp, created);
+
+        let components = bom.components.as_ref().map(|components| {
+            components
+                .iter()
+                .map(|component| check_component(component, bom_timestamp, ctx))
+                .collect::<Vec<_>>()
+        });
+        let vulnerabilities = bom.vulnerabilities.as_ref().map(|vulnerabilities| {
+            vulnerabilities
+                .iter()
+                .map(|vulnerability| {
+                    ValidationContext::new()
+                        .add_field(
+                            "created",
+                            check_timestamp(vulnerability.created.as_ref(), bom_timestamp, ctx),
+                        )
+                        .add_field(
+                            "published",
+                            check_timestamp(vulnerability.published.as_ref(), bom_timestamp, ctx),
+                        )
+                        .into()
+                })
+                .collect::<Vec<_>>()
+        });
+
+        ValidationContext::new()
+            .add_list("components", components)
+            .add_list("vulnerabilities", vulnerabilities)
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        options::ValidationOptions, testing::errors_at, validate_bom, validation::SpecVersion, Bom,
+    };
+
+    use super::TimestampConsistencyRule;
+
+    fn validate(input: &str) -> Result<(), crate::validation::ValidationErrors> {
+        let bom = Bom::parse_json(input).expect("Failed to parse BOM");
+        let options = ValidationOptions::new().add_rule(TimestampConsistencyRule::new());
+        validate_bom(SpecVersion::V1_5, &bom, &options)
+    }
+
+    #[test]
+    fn warns_about_data_newer_than_the_bom() {
+        let result = validate(
+            r#"{
+                "metadata": { "timestamp": "2024-01-01T00:00:00Z" },
+                "components": [{
+                    "type": "library",
+                    "name": "lib",
+                    "releaseNotes": { "type": "major", "timestamp": "2024-02-01T00:00:00Z" }
+                }],
+                "vulnerabilities": [{
+                    "created": "2023-12-01T00:00:00Z",
+                    "published": "2024-01-01T00:30:00+01:00"
+                }, {
+                    "created": "2024-01-01T00:00:01Z"
+                }]
+            }"#,
+        );
+
+        let errors = errors_at(&result, "components[0].release_notes.timestamp");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code.as_deref(), Some("timestamp-after-bom"));
+        assert_eq!(
+            errors[0].message.param("timestamp"),
+            Some("2024-01-01T00:00:00Z")
+        );
+        assert!(errors_at(&result, "vulnerabilities[0].created").is_empty());
+        assert!(errors_at(&result, "vulnerabilities[0].published").is_empty());
+        assert_eq!(errors_at(&result, "vulnerabilities[1].created").len(), 1);
+    }
+
+    #[test]
+    fn ignores_boms_without_timestamp() {
+        let result = validate(r#"{ "vulnerabilities": [{ "created": "2024-01-01T00:00:00Z" }] }"#);
+        assert!(result.is_ok());
+    }
+}
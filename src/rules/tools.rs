@@ -0,0 +1,116 @@
+use crate::{
+    prelude::*,
+    rules::{Rule, RuleContext},
+    validation::{SpecVersion, ValidationContext, ValidationError, ValidationResult},
+    Bom, Component, ComponentType,
+};
+
+/// Checks the components listed in `metadata.tools.components`, since spec version 1.5.
+///
+/// Tools are expected to be of type `application` or `library`, a missing `version` is
+/// reported as a warning because it weakens the provenance of the BOM.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ToolComponentsRule;
+
+pub(super) fn check_tool(component: &Component) -> ValidationResult {
+    let type_error = (!matches!(
+        component.component_type,
+        ComponentType::Application | ComponentType::Library
+    ))
+    .then(|| {
+        Err(ValidationError::new("tool_component_type")
+            .with_code("tool-component-type")
+            .with_expected("application or library")
+            .with_actual(format!("{:?}", component.component_type)))
+    });
+    let version_warning = component.version.is_none().then(|| {
+        Err(ValidationError::warning("missing_tool_version")
+            .with_param("name", &component.name)
+            .with_code("missing-tool-version"))
+    });
+
+    ValidationContext::new()
+        .add_field("component_type", type_error)
+        .add_field("version", version_warning)
+        .into()
+}
+
+impl Rule for ToolComponentsRule {
+    fn id(&self) -> &str {
+        "tool-components"
+    }
+
+    fn applies(&self, _bom: &Bom, ctx: &RuleContext) -> bool {
+        ctx.version >= SpecVersion::V1_5
+    }
+
+    fn check(&self, bom: &Bom, _ctx: &RuleContext) -> ValidationResult {
+        let Some(metadata) = &bom.meta_data else {
+            return Ok(());
+        };
+
+        let children = metadata
+            .tool_components
+            .as_ref()
+            .map(|components| components.iter().map(check_tool).collect::<Vec<_>>());
+        let metadata = ValidationContext::new().add_list("tool_components", children);
+
+        ValidationContext::new()
+            .add_struct("meta_data", Some(metadata.into()))
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        options::ValidationOptions, testing::errors_at, validate_bom, validation::SpecVersion, Bom,
+    };
+
+    fn codes_at(input: &str, path: &str) -> Vec<String> {
+        let bom = Bom::parse_json(input).expect("Failed to parse BOM");
+        let result = validate_bom(SpecVersion::V1_5, &bom, &ValidationOptions::new());
+        errors_at(&result, path)
+            .into_iter()
+            .filter_map(|error| error.code.clone())
+            .collect()
+    }
+
+    #[test]
+    fn checks_tool_components() {
+        let input = r#"{ "metadata": { "tools": { "components": [
+            { "type": "application", "name": "cargo-cyclonedx", "version": "0.5.0" },
+            { "type": "container", "name": "builder" }
+        ] } } }"#;
+
+        assert!(codes_at(input, "meta_data.tool_components[0]").is_empty());
+        assert_eq!(
+            codes_at(input, "meta_data.tool_components[1].component_type"),
+            vec!["tool-component-type"]
+        );
+        assert_eq!(
+            codes_at(input, "meta_data.tool_components[1].version"),
+            vec!["missing-tool-version"]
+        );
+    }
+
+    #[test]
+    fn validates_tool_components_as_components() {
+        let input = r#"{ "metadata": { "tools": { "components": [
+            { "type": "library", "name": "syft", "version": "1.0.0", "purl": "syft" }
+        ] } } }"#;
+
+        assert!(!codes_at(input, "meta_data.tool_components[0].purl").is_empty());
+    }
+
+    #[test]
+    fn ignores_legacy_tools() {
+        let input = r#"{ "metadata": { "tools": [ { "vendor": "anchore", "name": "syft" } ] } }"#;
+        let bom = Bom::parse_json(input).expect("Failed to parse BOM");
+
+        assert!(bom
+            .meta_data
+            .and_then(|meta| meta.tool_components)
+            .is_none());
+    }
+}
@@ -0,0 +1,206 @@
+use serde_json::{Map, Value};
+
+use crate::{
+    paths,
+    prelude::*,
+    rules::{Rule, RuleContext},
+    validation::{Severity, ValidationContext, ValidationError, ValidationResult},
+    Bom,
+};
+
+/// Selects how [`UnicodeRule`] reports suspicious characters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UnicodeMode {
+    /// Rejects strings with suspicious characters with an error.
+    Strict,
+    /// Warns about strings with suspicious characters.
+    #[default]
+    Lenient,
+}
+
+/// Opt-in [`Rule`] that checks all string fields for characters used to spoof the metadata
+/// of a supply chain, e.g. a name that renders differently than it compares.
+///
+/// * control characters other than tab, line feed and carriage return
+/// * bidirectional formatting characters, e.g. `U+202E` right-to-left override
+/// * `U+FFFD` replacement characters, left behind by decoding lone surrogates lossily
+///
+/// Each string is reported once, for the first suspicious character.
+#[derive(Debug, Clone, Default)]
+pub struct UnicodeRule {
+    mode: UnicodeMode,
+}
+
+/// Returns the message and code of a suspicious character.
+fn classify(c: char) -> Option<(&'static str, &'static str)> {
+    match c {
+        '\t' | '\n' | '\r' => None,
+        '\u{202A}'..='\u{202E}'
+        | '\u{2066}'..='\u{2069}'
+        | '\u{200E}'
+        | '\u{200F}'
+        | '\u{061C}' => Some(("bidi_character", "bidi-character")),
+        '\u{FFFD}' => Some(("replacement_character", "replacement-character")),
+        c if c.is_control() => Some(("control_character", "control-character")),
+        _ => None,
+    }
+}
+
+impl UnicodeRule {
+    /// Creates the rule warning about suspicious characters.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates the rule rejecting suspicious characters.
+    pub fn strict() -> Self {
+        Self {
+            mode: UnicodeMode::Strict,
+        }
+    }
+
+    fn severity(&self) -> Severity {
+        match self.mode {
+            UnicodeMode::Strict => Severity::Error,
+            UnicodeMode::Lenient => Severity::Warning,
+        }
+    }
+
+    fn check_string(&self, value: &str) -> Option<ValidationError> {
+        let (position, c, (message, code)) = value
+            .chars()
+            .enumerate()
+            .find_map(|(position, c)| Some((position, c, classify(c)?)))?;
+
+        Some(
+            ValidationError::new(message)
+                .with_severity(self.severity())
+                .with_param("character", format!("U+{:04X}", c as u32))
+                .with_param("position", position)
+                .with_code(code)
+                .with_actual(value.escape_default()),
+        )
+    }
+
+    /// Checks the fields of an object, `wire` is its path in the serialized document.
+    fn check_object(&self, object: &Map<String, Value>, wire: &str) -> ValidationResult {
+        object
+            .iter()
+            .fold(ValidationContext::new(), |context, (key, value)| {
+                let path = if wire.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", wire, key)
+                };
+                let model = paths::from_wire(&path);
+                let field = model.rsplit('.').next().unwrap_or(key);
+
+                match value {
+                    Value::String(value) => {
+                        context.add_field(field, self.check_string(value).map(Err))
+                    }
+                    Value::Object(object) => {
+                        context.add_struct(field, Some(self.check_object(object, &path)))
+                    }
+                    Value::Array(items) if items.iter().any(Value::is_object) => {
+                        let list = format!("{}[]", path);
+                        let children = items
+                            .iter()
+                            .map(|item| match item {
+                                Value::Object(object) => self.check_object(object, &list),
+                                _ => Ok(()),
+                            })
+                            .collect::<Vec<_>>();
+                        context.add_list(field, Some(children))
+                    }
+                    Value::Array(items) => {
+                        items
+                            .iter()
+                            .enumerate()
+                            .fold(context, |context, (index, item)| {
+                                let error = item
+                                    .as_str()
+                                    .and_then(|item| self.check_string(item))
+                                    .map(|error| Err(error.with_index(index)));
+                                context.add_field(field, error)
+                            })
+                    }
+                    _ => context,
+                }
+            })
+            .into()
+    }
+}
+
+impl Rule for UnicodeRule {
+    fn id(&self) -> &str {
+        "unicode"
+    }
+
+    fn check(&self, bom: &Bom, _ctx: &RuleContext) -> ValidationResult {
+        match bom.to_value() {
+            Value::Object(object) => self.check_object(&object, ""),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        options::ValidationOptions,
+        testing::errors_at,
+        validate_bom,
+        validation::{Severity, SpecVersion, ValidationResult},
+        Bom,
+    };
+
+    use super::UnicodeRule;
+
+    fn validate(rule: UnicodeRule) -> ValidationResult {
+        let bom = Bom::parse_json(
+            r#"{ "components": [{
+                "type": "library",
+                "name": "left\u202Etfel",
+                "bom-ref": "lib\u0007",
+                "externalReferences": [{ "type": "website", "url": "https://example.com/\uFFFD" }],
+                "components": [{ "type": "library", "name": "nested\u202E" }]
+            }], "services": [{ "name": "api", "endpoints": ["https://api.example.com", "https://\u200Fevil"] }] }"#,
+        )
+        .expect("Failed to parse BOM");
+        validate_bom(
+            SpecVersion::V1_5,
+            &bom,
+            &ValidationOptions::new().add_rule(rule),
+        )
+    }
+
+    #[test]
+    fn warns_about_suspicious_characters() {
+        let result = validate(UnicodeRule::new());
+
+        let name = errors_at(&result, "components[0].name");
+        assert_eq!(name.len(), 1);
+        assert_eq!(name[0].severity, Severity::Warning);
+        assert_eq!(name[0].code.as_deref(), Some("bidi-character"));
+        assert_eq!(name[0].message.param("character"), Some("U+202E"));
+
+        let bom_ref = errors_at(&result, "components[0].bom_ref");
+        assert_eq!(bom_ref[0].code.as_deref(), Some("control-character"));
+        let url = errors_at(&result, "components[0].external_references[0].url");
+        assert_eq!(url[0].code.as_deref(), Some("replacement-character"));
+        let nested = errors_at(&result, "components[0].components[0].name");
+        assert_eq!(nested.len(), 1);
+        let endpoints = errors_at(&result, "services[0].endpoints");
+        assert_eq!(endpoints.len(), 1);
+        assert_eq!(endpoints[0].context.index, Some(1));
+    }
+
+    #[test]
+    fn rejects_suspicious_characters_in_strict_mode() {
+        let result = validate(UnicodeRule::strict());
+
+        let name = errors_at(&result, "components[0].name");
+        assert_eq!(name[0].severity, Severity::Error);
+    }
+}
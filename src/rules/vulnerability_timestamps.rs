@@ -0,0 +1,368 @@
+use crate::{
+    options::TimestampTolerance,
+    prelude::*,
+    rules::{Rule, RuleContext},
+    validation::{
+        validate_since, SpecVersion, ValidationContext, ValidationError, ValidationResult,
+    },
+    Bom, Vulnerability,
+};
+
+/// Checks the timestamps of every vulnerability and the analysis of rejected ones.
+///
+/// * `created <= published <= updated`, as far as they are present
+/// * `rejected` is only allowed from spec version 1.5 on
+/// * a rejected vulnerability needs an analysis `state`, and a `justification` if the state
+///   is `not_affected`
+#[derive(Debug, Clone, Copy, Default)]
+pub struct VulnerabilityTimestampsRule;
+
+/// Parses an RFC 3339 timestamp, e.g. `2024-01-31T12:00:00.5+01:00`, into seconds since the
+/// Unix epoch and nanoseconds. See [`TimestampTolerance::Lenient`] for the additionally
+/// accepted ISO 8601 forms.
+pub(super) fn parse_timestamp(input: &str, tolerance: TimestampTolerance) -> Option<(i64, u32)> {
+    let lenient = tolerance == TimestampTolerance::Lenient;
+    let number = |range: core::ops::Range<usize>| -> Option<i64> {
+        let digits = input.get(range)?;
+        digits
+            .bytes()
+            .all(|byte| byte.is_ascii_digit())
+            .then(|| digits.parse().ok())?
+    };
+    let bytes = input.as_bytes();
+    if bytes.len() < if lenient { 19 } else { 20 }
+        || bytes[4] != b'-'
+        || bytes[7] != b'-'
+        || !(matches!(bytes[10], b'T' | b't') || lenient && bytes[10] == b' ')
+        || bytes[13] != b':'
+        || bytes[16] != b':'
+    {
+        return None;
+    }
+
+    let (year, month, day) = (number(0..4)?, number(5..7)?, number(8..10)?);
+    let (hour, minute, second) = (number(11..13)?, number(14..16)?, number(17..19)?);
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) || hour > 23 || minute > 59 {
+        return None;
+    }
+    // leap seconds are allowed by RFC 3339
+    if second > 60 {
+        return None;
+    }
+
+    let mut rest = &input[19..];
+    let mut nanos = 0;
+    if let Some(fraction) = rest.strip_prefix('.') {
+        let len = fraction
+            .bytes()
+            .take_while(|byte| byte.is_ascii_digit())
+            .count();
+        if len == 0 {
+            return None;
+        }
+        let digits = &fraction[..len.min(9)];
+        nanos = digits.parse::<u32>().ok()? * 10u32.pow(9 - digits.len() as u32);
+        rest = &fraction[len..];
+    }
+
+    let offset = match rest {
+        "Z" | "z" => 0,
+        "" if lenient => 0,
+        _ => {
+            let bytes = rest.as_bytes();
+            let minutes = match bytes.len() {
+                6 if bytes[3] == b':' => rest.get(4..6)?,
+                5 if lenient => rest.get(3..5)?,
+                3 if lenient => "00",
+                _ => return None,
+            };
+            let sign = match bytes[0] {
+                b'+' => 1,
+                b'-' => -1,
+                _ => return None,
+            };
+            let hours = rest.get(1..3)?.parse::<i64>().ok()?;
+            let minutes = minutes.parse::<i64>().ok()?;
+            sign * (hours * 3600 + minutes * 60)
+        }
+    };
+
+    // days since the epoch of the civil date, see http://howardhinnant.github.io/date_algorithms.html
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month_index = (month + 9) % 12;
+    let day_of_year = (153 * month_index + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era * 146_097 + day_of_era - 719_468;
+
+    Some((
+        days * 86_400 + hour * 3600 + minute * 60 + second - offset,
+        nanos,
+    ))
+}
+
+/// Rewrites a timestamp only accepted by [`TimestampTolerance::Lenient`] as RFC 3339, and
+/// returns whether it keeps its meaning. A timestamp without offset is assumed to be UTC.
+fn to_rfc3339(input: &str) -> Option<(String, bool)> {
+    parse_timestamp(input, TimestampTolerance::Lenient)?;
+
+    let (date_time, rest) = input.split_at(19);
+    let fraction_len = rest.strip_prefix('.').map_or(0, |fraction| {
+        1 + fraction.bytes().take_while(u8::is_ascii_digit).count()
+    });
+    let (fraction, offset) = rest.split_at(fraction_len);
+    let (offset, safe) = match offset.len() {
+        0 => (String::from("Z"), false),
+        3 => (format!("{}:00", offset), true),
+        5 => (format!("{}:{}", &offset[..3], &offset[3..]), true),
+        _ => (offset.to_string(), true),
+    };
+    Some((
+        format!(
+            "{}T{}{}{}",
+            &date_time[..10],
+            &date_time[11..],
+            fraction,
+            offset
+        ),
+        safe,
+    ))
+}
+
+/// Checks that `input` parses as timestamp with the given `tolerance`, used for the
+/// `metadata.timestamp` of the document.
+pub(crate) fn validate_timestamp(
+    input: &str,
+    tolerance: TimestampTolerance,
+) -> Result<(), ValidationError> {
+    match parse_timestamp(input, tolerance) {
+        Some(_) => Ok(()),
+        None => Err(invalid_timestamp(input, tolerance)),
+    }
+}
+
+fn invalid_timestamp(input: &str, tolerance: TimestampTolerance) -> ValidationError {
+    let expected = match tolerance {
+        TimestampTolerance::Strict => "RFC 3339 timestamp",
+        TimestampTolerance::Lenient => "ISO 8601 timestamp",
+    };
+    let error = ValidationError::new("invalid_timestamp")
+        .with_code("invalid-timestamp")
+        .with_expected(expected)
+        .with_actual(input);
+    match to_rfc3339(input) {
+        Some((timestamp, safe)) => error.with_suggestion(timestamp, safe),
+        None => error,
+    }
+}
+
+pub(super) fn check_vulnerability(
+    vulnerability: &Vulnerability,
+    ctx: &RuleContext,
+) -> ValidationResult {
+    let tolerance = ctx.timestamp_tolerance;
+    let mut context = ValidationContext::new();
+
+    // the latest valid timestamp seen so far, in the order created, published, updated
+    let mut previous: Option<(&str, (i64, u32))> = None;
+    let timestamps = [
+        ("created", &vulnerability.created),
+        ("published", &vulnerability.published),
+        ("updated", &vulnerability.updated),
+    ];
+    for (field, timestamp) in timestamps {
+        let Some(timestamp) = timestamp else {
+            continue;
+        };
+        let Some(parsed) = parse_timestamp(timestamp, tolerance) else {
+            context = context.add_field(field, Some(Err(invalid_timestamp(timestamp, tolerance))));
+            continue;
+        };
+
+        match previous {
+            Some((other, earlier)) if parsed < earlier => {
+                let error = ValidationError::new("timestamp_order")
+                    .with_param("field", field)
+                    .with_param("other", other)
+                    .with_code("timestamp-order")
+                    .with_expected(format!("not before {}", other))
+                    .with_actual(timestamp);
+                context = context.add_field(field, Some(Err(error)));
+            }
+            _ => previous = Some((field, parsed)),
+        }
+    }
+
+    if let Some(rejected) = &vulnerability.rejected {
+        context = context.add_field(
+            "rejected",
+            validate_since("rejected", true, ctx.version, SpecVersion::V1_5),
+        );
+        if parse_timestamp(rejected, tolerance).is_none() {
+            context = context.add_field(
+                "rejected",
+                Some(Err(invalid_timestamp(rejected, tolerance))),
+            );
+        }
+
+        let analysis = vulnerability.analysis.as_ref();
+        let state = analysis.and_then(|analysis| analysis.state.as_deref());
+        let missing = match state {
+            None => Some("state"),
+            Some("not_affected")
+                if analysis.is_some_and(|analysis| analysis.justification.is_none()) =>
+            {
+                Some("justification")
+            }
+            Some(_) => None,
+        };
+        context = context.add_field(
+            "analysis",
+            missing.map(|field| {
+                Err(ValidationError::new("rejected_missing_analysis")
+                    .with_param("field", field)
+                    .with_code("rejected-missing-analysis"))
+            }),
+        );
+    }
+
+    context.into()
+}
+
+impl Rule for VulnerabilityTimestampsRule {
+    fn id(&self) -> &str {
+        "vulnerability-timestamps"
+    }
+
+    fn applies(&self, _bom: &Bom, ctx: &RuleContext) -> bool {
+        ctx.version >= SpecVersion::V1_4
+    }
+
+    fn check(&self, bom: &Bom, ctx: &RuleContext) -> ValidationResult {
+        let children = bom.vulnerabilities.as_ref().map(|vulnerabilities| {
+            vulnerabilities
+                .iter()
+                .map(|vulnerability| check_vulnerability(vulnerability, ctx))
+                .collect::<Vec<_>>()
+        });
+
+        ValidationContext::new()
+            .add_list("vulnerabilities", children)
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        options::{TimestampTolerance, ValidationOptions},
+        testing::errors_at,
+        validate_bom,
+        validation::SpecVersion,
+        Bom,
+    };
+
+    use super::{parse_timestamp, to_rfc3339};
+
+    fn codes_at(version: SpecVersion, vulnerability: &str, path: &str) -> Vec<String> {
+        let input = format!(r#"{{ "vulnerabilities": [{}] }}"#, vulnerability);
+        let bom = Bom::parse_json(&input).expect("Failed to parse BOM");
+        let result = validate_bom(version, &bom, &ValidationOptions::new());
+        errors_at(&result, path)
+            .into_iter()
+            .filter_map(|error| error.code.clone())
+            .collect()
+    }
+
+    #[test]
+    fn parses_timestamps() {
+        let strict = |input| parse_timestamp(input, TimestampTolerance::Strict);
+        assert_eq!(strict("1970-01-01T00:00:00Z"), Some((0, 0)));
+        assert_eq!(
+            strict("2024-03-01T01:00:00.25+01:00"),
+            Some((1_709_251_200, 250_000_000))
+        );
+        assert_eq!(strict("2024-13-01T00:00:00Z"), None);
+        assert_eq!(strict("2024-01-01 00:00:00"), None);
+        assert_eq!(strict("2024-01-01T00:00:00"), None);
+    }
+
+    #[test]
+    fn suggests_rfc_3339_timestamps() {
+        let suggest = to_rfc3339;
+        assert_eq!(
+            suggest("2024-01-01 12:00:00.5+0100"),
+            Some((String::from("2024-01-01T12:00:00.5+01:00"), true))
+        );
+        assert_eq!(
+            suggest("2024-01-01T12:00:00-05"),
+            Some((String::from("2024-01-01T12:00:00-05:00"), true))
+        );
+        assert_eq!(
+            suggest("2024-01-01 12:00:00"),
+            Some((String::from("2024-01-01T12:00:00Z"), false))
+        );
+        assert_eq!(suggest("2024/01/01"), None);
+    }
+
+    #[test]
+    fn parses_iso_8601_timestamps_leniently() {
+        let lenient = |input| parse_timestamp(input, TimestampTolerance::Lenient);
+        assert_eq!(lenient("1970-01-01 00:00:00"), Some((0, 0)));
+        assert_eq!(
+            lenient("1970-01-01T01:00:00.123456789123+0100"),
+            Some((0, 123_456_789))
+        );
+        assert_eq!(lenient("1970-01-01T01:00:00+01"), Some((0, 0)));
+        assert_eq!(lenient("1970-01-01T00:00:00+01:0"), None);
+
+        let vulnerability = r#"{ "vulnerabilities": [{ "created": "2024-01-02 00:00:00" }] }"#;
+        let bom = Bom::parse_json(vulnerability).expect("Failed to parse BOM");
+        let options = ValidationOptions::new().timestamp_tolerance(TimestampTolerance::Lenient);
+        assert!(validate_bom(SpecVersion::V1_5, &bom, &options).is_ok());
+        assert!(validate_bom(SpecVersion::V1_5, &bom, &ValidationOptions::new()).is_err());
+    }
+
+    #[test]
+    fn flags_timestamps_out_of_order() {
+        let vulnerability = r#"{
+            "created": "2024-01-02T00:00:00Z",
+            "published": "2024-01-01T00:00:00Z",
+            "updated": "2024-01-03T00:00:00+01:00"
+        }"#;
+
+        assert_eq!(
+            codes_at(
+                SpecVersion::V1_5,
+                vulnerability,
+                "vulnerabilities[0].published"
+            ),
+            vec!["timestamp-order"]
+        );
+        assert!(codes_at(
+            SpecVersion::V1_5,
+            vulnerability,
+            "vulnerabilities[0].updated"
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn rejected_vulnerability_requires_analysis() {
+        let rejected = r#"{ "rejected": "2024-01-01T00:00:00Z",
+            "analysis": { "state": "not_affected" } }"#;
+        assert_eq!(
+            codes_at(SpecVersion::V1_5, rejected, "vulnerabilities[0].analysis"),
+            vec!["rejected-missing-analysis"]
+        );
+        assert_eq!(
+            codes_at(SpecVersion::V1_4, rejected, "vulnerabilities[0].rejected"),
+            vec!["field-not-supported"]
+        );
+
+        let analysed = r#"{ "rejected": "2024-01-01T00:00:00Z",
+            "analysis": { "state": "false_positive" } }"#;
+        assert!(codes_at(SpecVersion::V1_5, analysed, "vulnerabilities[0]").is_empty());
+    }
+}
@@ -0,0 +1,154 @@
+//! Strategies to generate arbitrary documents, e.g. for property tests or fuzzing.
+//!
+//! The model implements [`Arbitrary`] itself when the `arbitrary` feature is enabled, these
+//! documents are adversarial: they are structurally valid, but contain arbitrary content.
+//! [`LikelyValid`] generates documents that pass the validation of every spec version.
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::{Bom, Component, ComponentType, Hash, HashAlgorithm, Metadata, Tool, ToolKind};
+
+/// A document that is valid in all spec versions.
+#[derive(Debug)]
+pub struct LikelyValid(pub Bom);
+
+const LOWERCASE: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+const DIGITS: &[u8] = b"0123456789";
+const HEX_DIGITS: &[u8] = b"0123456789abcdef";
+
+/// Generates a string of the given characters with a length in the given range.
+fn string_of(u: &mut Unstructured<'_>, chars: &[u8], min: usize, max: usize) -> Result<String> {
+    let len = u.int_in_range(min..=max)?;
+    (0..len)
+        .map(|_| u.choose(chars).map(|c| *c as char))
+        .collect()
+}
+
+fn hash(u: &mut Unstructured<'_>) -> Result<Hash> {
+    let alg = *u.choose(&[
+        HashAlgorithm::Md5,
+        HashAlgorithm::Sha1,
+        HashAlgorithm::Sha256,
+        HashAlgorithm::Sha512,
+    ])?;
+    let len = alg.hex_len();
+    Ok(Hash {
+        alg,
+        content: string_of(u, HEX_DIGITS, len, len)?,
+    })
+}
+
+fn component(u: &mut Unstructured<'_>) -> Result<Component> {
+    let name = string_of(u, LOWERCASE, 1, 12)?;
+    let version = format!(
+        "{}.{}.{}",
+        u.int_in_range(0..=9u8)?,
+        u.int_in_range(0..=99u8)?,
+        u.int_in_range(0..=99u8)?
+    );
+    let mut hashes = (0..u.int_in_range(0..=2)?)
+        .map(|_| hash(u))
+        .collect::<Result<Vec<_>>>()?;
+    // a valid component declares a single digest per algorithm
+    hashes.dedup_by_key(|hash| hash.alg);
+
+    Ok(Component {
+        purl: Some(format!("pkg:cargo/{}@{}", name, version)),
+        version: Some(version),
+        hashes: (!hashes.is_empty()).then_some(hashes),
+        ..Component::new(ComponentType::Library, name)
+    })
+}
+
+fn metadata(u: &mut Unstructured<'_>) -> Result<Metadata> {
+    let tools = (0..u.int_in_range(0..=2)?)
+        .map(|_| {
+            Ok(Tool {
+                vendor: Option::<String>::arbitrary(u)?,
+                name: Some(string_of(u, LOWERCASE, 1, 4)?),
+                kind: ToolKind::ScrewDriver,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Metadata {
+        // spec version 1.4 limits the timestamp to 4 characters, later ones require RFC 3339
+        timestamp: None,
+        tools: Some(tools),
+        tool_components: None,
+        component: None,
+        authors: None,
+    })
+}
+
+impl<'a> Arbitrary<'a> for LikelyValid {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let components = (0..u.int_in_range(0..=8)?)
+            .map(|_| component(u))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(LikelyValid(Bom {
+            serial_number: Some(string_of(u, DIGITS, 1, 4)?),
+            version: Some(u.int_in_range(1..=100)?),
+            meta_data: Some(metadata(u)?),
+            components: Some(components),
+            ..Default::default()
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arbitrary::{Arbitrary, Unstructured};
+
+    use crate::{options::ValidationOptions, validate_bom, validation::SpecVersion, Bom};
+
+    use super::LikelyValid;
+
+    const VERSIONS: [SpecVersion; 4] = [
+        SpecVersion::V1_3,
+        SpecVersion::V1_4,
+        SpecVersion::V1_5,
+        SpecVersion::V1_6,
+    ];
+
+    /// Generates deterministic pseudo random input bytes for the given seed.
+    fn input(seed: u64) -> Vec<u8> {
+        let mut state = seed.wrapping_mul(6364136223846793005).wrapping_add(1);
+        (0..4096)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                state as u8
+            })
+            .collect()
+    }
+
+    #[test]
+    fn validation_never_panics() {
+        let options = ValidationOptions::new();
+        for seed in 0..200 {
+            let input = input(seed);
+            let Ok(bom) = Bom::arbitrary(&mut Unstructured::new(&input)) else {
+                continue;
+            };
+            for version in VERSIONS {
+                let _ = validate_bom(version, &bom, &options);
+            }
+        }
+    }
+
+    #[test]
+    fn likely_valid_documents_are_valid_in_all_versions() {
+        let options = ValidationOptions::new();
+        for seed in 0..200 {
+            let input = input(seed);
+            let LikelyValid(bom) = LikelyValid::arbitrary(&mut Unstructured::new(&input))
+                .expect("Failed to generate document");
+            for version in VERSIONS {
+                let result = validate_bom(version, &bom, &options);
+                assert!(result.is_ok(), "{:?} is invalid: {:?}", bom, result);
+            }
+        }
+    }
+}
@@ -0,0 +1,170 @@
+use crate::{paths, prelude::*, validation::ValidationErrors, Bom, Property};
+
+/// Name of the property that suppresses findings on the element it is attached to.
+///
+/// The value is the code of the suppressed finding, e.g. `missing-supplier`. Put on the BOM
+/// itself it suppresses the finding everywhere, put on a component only within that component.
+pub const SUPPRESS_PROPERTY: &str = "cdx:validator:suppress";
+
+/// Suppresses all findings with the given code, optionally only below the given path.
+///
+/// Suppressed findings are not dropped, they stay in the report with
+/// [`ValidationError::suppressed`](crate::validation::ValidationError::suppressed) set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Suppression {
+    /// The code of the finding, e.g. `missing-supplier`.
+    pub code: String,
+    /// The path the suppression is restricted to, e.g. `components[12]`.
+    pub path: Option<String>,
+}
+
+impl Suppression {
+    /// Creates a suppression of all findings with the given code.
+    pub fn new<T: ToString>(code: T) -> Self {
+        Self {
+            code: code.to_string(),
+            path: None,
+        }
+    }
+
+    /// Restricts the suppression to findings at or below the given path, named either like the
+    /// model or like the serialized document as in a
+    /// [`ValidationReport`](crate::report::ValidationReport), e.g. `metadata.component.bom-ref`.
+    pub fn at<T: ToString>(mut self, path: T) -> Self {
+        self.path = Some(paths::from_wire(&path.to_string()));
+        self
+    }
+
+    /// Parses a suppression in the form `CODE` or `CODE at PATH`.
+    pub fn parse(input: &str) -> Self {
+        match input.split_once(" at ") {
+            Some((code, path)) => Self::new(code.trim()).at(path.trim()),
+            None => Self::new(input.trim()),
+        }
+    }
+
+    /// Returns `true` if the finding with the given code at the given path is suppressed.
+    pub fn matches(&self, code: &str, path: &str) -> bool {
+        if self.code != code {
+            return false;
+        }
+
+        match &self.path {
+            None => true,
+            Some(prefix) => path.strip_prefix(prefix.as_str()).is_some_and(|remainder| {
+                remainder.is_empty() || remainder.starts_with('.') || remainder.starts_with('[')
+            }),
+        }
+    }
+}
+
+fn from_properties(properties: Option<&Vec<Property>>, path: Option<&str>) -> Vec<Suppression> {
+    properties
+        .into_iter()
+        .flatten()
+        .filter(|property| property.name == SUPPRESS_PROPERTY)
+        .filter_map(|property| property.value.as_deref())
+        .map(|code| Suppression {
+            code: code.trim().to_string(),
+            path: path.map(String::from),
+        })
+        .collect()
+}
+
+/// Collects all suppressions declared by [`SUPPRESS_PROPERTY`] properties in the document.
+pub fn collect(bom: &Bom) -> Vec<Suppression> {
+    let mut suppressions = from_properties(bom.properties.as_ref(), None);
+
+    if let Some(component) = bom
+        .meta_data
+        .as_ref()
+        .and_then(|meta_data| meta_data.component.as_ref())
+    {
+        suppressions.extend(from_properties(
+            component.properties.as_ref(),
+            Some("meta_data.component"),
+        ));
+    }
+
+    for (index, component) in bom.components.iter().flatten().enumerate() {
+        suppressions.extend(from_properties(
+            component.properties.as_ref(),
+            Some(&format!("components[{}]", index)),
+        ));
+    }
+
+    suppressions
+}
+
+/// Marks all findings matched by one of the suppressions as suppressed.
+pub fn apply(errors: &mut ValidationErrors, suppressions: &[Suppression]) {
+    errors.for_each_error_mut(|path, error| {
+        if let Some(code) = &error.code {
+            if suppressions
+                .iter()
+                .any(|suppression| suppression.matches(code, path))
+            {
+                error.suppressed = true;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Bom, Component, ComponentType, Property};
+
+    use super::{collect, Suppression, SUPPRESS_PROPERTY};
+
+    #[test]
+    fn parse_suppression() {
+        assert_eq!(
+            Suppression::parse("CDX-LIC-002 at components[12]"),
+            Suppression::new("CDX-LIC-002").at("components[12]")
+        );
+        assert_eq!(
+            Suppression::parse("missing-supplier"),
+            Suppression::new("missing-supplier")
+        );
+    }
+
+    #[test]
+    fn matches_path_prefix() {
+        let suppression = Suppression::new("invalid-purl").at("components[1]");
+
+        assert!(suppression.matches("invalid-purl", "components[1].purl"));
+        assert!(!suppression.matches("invalid-purl", "components[12].purl"));
+        assert!(!suppression.matches("missing-supplier", "components[1].supplier"));
+        assert!(Suppression::new("invalid-purl").matches("invalid-purl", "components[12].purl"));
+    }
+
+    #[test]
+    fn collect_from_properties() {
+        let suppress = Property {
+            name: String::from(SUPPRESS_PROPERTY),
+            value: Some(String::from("missing-supplier")),
+        };
+        let bom = Bom {
+            components: Some(vec![
+                Component::new(ComponentType::Library, "serde"),
+                Component {
+                    properties: Some(vec![suppress.clone()]),
+                    ..Component::new(ComponentType::Library, "zlib")
+                },
+            ]),
+            properties: Some(vec![Property {
+                value: Some(String::from("missing-author")),
+                ..suppress
+            }]),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            collect(&bom),
+            vec![
+                Suppression::new("missing-author"),
+                Suppression::new("missing-supplier").at("components[1]"),
+            ]
+        );
+    }
+}
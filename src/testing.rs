@@ -0,0 +1,263 @@
+//! Helpers for concise tests against the error tree, mainly used through the
+//! [`assert_valid!`](crate::assert_valid) and [`assert_has_error!`](crate::assert_has_error)
+//! macros, and a generator of synthetic documents of any size for benchmarks.
+use crate::{
+    prelude::*,
+    validation::{ValidationError, ValidationResult},
+    Bom, Component, ComponentType, Dependency, Hash, HashAlgorithm, License, LicenseChoice,
+    Metadata, Tool, ToolKind,
+};
+
+/// Returns all errors of the result at exactly the given path, formatted with the names of
+/// either the serialized document or the model, e.g. `metadata.tools[1].kind` or
+/// `meta_data.tools[1].kind`.
+pub fn errors_at<'a>(result: &'a ValidationResult, path: &str) -> Vec<&'a ValidationError> {
+    match result {
+        Ok(()) => Vec::new(),
+        Err(errors) => errors
+            .errors()
+            .into_iter()
+            .zip(errors.model_errors())
+            .filter(|((wire, _), (model, _))| wire == path || model == path)
+            .map(|((_, error), _)| error)
+            .collect(),
+    }
+}
+
+/// Formats all errors of the result one per line, prefixed with their path.
+pub fn format_errors(result: &ValidationResult) -> String {
+    match result {
+        Ok(()) => String::from("no errors"),
+        Err(errors) => errors
+            .errors()
+            .into_iter()
+            .map(|(path, error)| format!("  {}: {}", path, error))
+            .collect::<Vec<_>>()
+            .join("\n"),
+    }
+}
+
+/// Panics unless the result contains an error at the given path, with the given code if set.
+#[track_caller]
+pub fn assert_has_error(result: &ValidationResult, path: &str, code: Option<&str>) {
+    let found = errors_at(result, path)
+        .into_iter()
+        .any(|error| code.is_none() || error.code.as_deref() == code);
+
+    if !found {
+        panic!(
+            "Expected an error at '{}'{}, but got:\n{}",
+            path,
+            code.map(|code| format!(" with code '{}'", code))
+                .unwrap_or_default(),
+            format_errors(result)
+        );
+    }
+}
+
+/// Generates a [`Bom`] resembling the output of a typical SBOM tool, e.g. for benchmarks.
+///
+/// Every component has a bom-ref, a version, a purl, a license and a hash, and depends on
+/// up to three of the components generated before it. The output is deterministic.
+#[derive(Debug, Clone)]
+pub struct SyntheticBom {
+    components: usize,
+    invalid_every: Option<usize>,
+}
+
+impl SyntheticBom {
+    /// Creates a generator of documents with the given number of components.
+    pub fn new(components: usize) -> Self {
+        Self {
+            components,
+            invalid_every: None,
+        }
+    }
+
+    /// Makes every n-th component invalid, by a malformed purl and hash, so that
+    /// validation reports findings.
+    pub fn invalid_every(mut self, n: usize) -> Self {
+        self.invalid_every = Some(n.max(1));
+        self
+    }
+
+    /// Returns the generated [`Bom`].
+    pub fn build(&self) -> Bom {
+        let components = (0..self.components)
+            .map(|index| self.component(index))
+            .collect::<Vec<_>>();
+        let dependencies = (0..self.components)
+            .map(|index| Dependency {
+                dependency_ref: format!("pkg-{}", index),
+                depends_on: (index > 0).then(|| {
+                    [index / 2, index / 3, index - 1]
+                        .iter()
+                        .map(|dependency| format!("pkg-{}", dependency))
+                        .collect::<Vec<_>>()
+                }),
+            })
+            .collect();
+
+        Bom {
+            version: Some(1),
+            meta_data: Some(Metadata {
+                timestamp: Some(String::from("2024-01-01T00:00:00Z")),
+                tools: Some(vec![Tool {
+                    vendor: Some(String::from("acme")),
+                    name: Some(String::from("gen")),
+                    kind: ToolKind::ScrewDriver,
+                }]),
+                ..Default::default()
+            }),
+            components: Some(components),
+            dependencies: Some(dependencies),
+            ..Default::default()
+        }
+    }
+
+    /// Returns the generated [`Bom`] serialized as JSON.
+    pub fn to_json(&self) -> String {
+        self.build().to_value().to_string()
+    }
+
+    fn component(&self, index: usize) -> Component {
+        let invalid = self.invalid_every.is_some_and(|n| index % n == n - 1);
+        let name = format!("package-{}", index % 1000);
+        let version = format!("{}.{}.{}", index / 1000, index % 100, index % 7);
+
+        let mut component = Component::new(ComponentType::Library, &name);
+        component.bom_ref = Some(format!("pkg-{}", index));
+        component.group = Some(format!("org.group{}", index % 50));
+        component.purl = Some(match invalid {
+            true => format!("pkg:/{}@{}", name, version),
+            false => format!("pkg:cargo/{}@{}", name, version),
+        });
+        component.licenses = Some(vec![LicenseChoice::License(License {
+            id: Some(String::from(
+                ["MIT", "Apache-2.0", "BSD-3-Clause"][index % 3],
+            )),
+            ..Default::default()
+        })]);
+        component.hashes = Some(vec![Hash {
+            alg: HashAlgorithm::Sha256,
+            content: match invalid {
+                true => String::from("not-a-digest"),
+                false => format!("{:064x}", index),
+            },
+        }]);
+        component.version = Some(version);
+        component
+    }
+}
+
+/// Asserts that a [`Bom`](crate::Bom) is valid for the given spec version, e.g.
+/// `assert_valid!(bom, V1_5)`. Optionally takes a reference to the
+/// [`ValidationOptions`](crate::options::ValidationOptions) as third argument.
+#[macro_export]
+macro_rules! assert_valid {
+    ($bom:expr, $version:ident) => {
+        $crate::assert_valid!($bom, $version, &$crate::options::ValidationOptions::new())
+    };
+    ($bom:expr, $version:ident, $options:expr) => {{
+        let result =
+            $crate::validate_bom($crate::validation::SpecVersion::$version, &$bom, $options);
+        if result.is_err() {
+            panic!(
+                "Expected a valid BOM, but got:\n{}",
+                $crate::testing::format_errors(&result)
+            );
+        }
+    }};
+}
+
+/// Asserts that a [`ValidationResult`] contains an error at the given path, e.g.
+/// `assert_has_error!(result, "meta_data.tools[1].kind", code = "tool-kind")`.
+#[macro_export]
+macro_rules! assert_has_error {
+    ($result:expr, $path:expr) => {
+        $crate::testing::assert_has_error(&$result, $path, None)
+    };
+    ($result:expr, $path:expr, code = $code:expr) => {
+        $crate::testing::assert_has_error(&$result, $path, Some($code))
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        options::ValidationOptions, validate_bom, validation::SpecVersion, Bom, Metadata, Tool,
+        ToolKind,
+    };
+
+    use super::SyntheticBom;
+
+    fn bom_with_tool(kind: ToolKind) -> Bom {
+        Bom {
+            meta_data: Some(Metadata {
+                tools: Some(vec![
+                    Tool {
+                        vendor: None,
+                        name: Some(String::from("dig")),
+                        kind: ToolKind::ScrewDriver,
+                    },
+                    Tool {
+                        vendor: None,
+                        name: Some(String::from("dig")),
+                        kind,
+                    },
+                ]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn assert_valid_bom() {
+        assert_valid!(bom_with_tool(ToolKind::ScrewDriver), V1_5);
+    }
+
+    #[test]
+    fn assert_error_with_code() {
+        let bom = bom_with_tool(ToolKind::Hammer);
+        let result = validate_bom(SpecVersion::V1_5, &bom, &ValidationOptions::new());
+
+        assert_has_error!(result, "meta_data.tools[1].kind");
+        assert_has_error!(result, "meta_data.tools[1].kind", code = "tool-kind");
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected an error at 'meta_data.tools[0].kind'")]
+    fn assert_error_fails_for_other_path() {
+        let bom = bom_with_tool(ToolKind::Hammer);
+        let result = validate_bom(SpecVersion::V1_5, &bom, &ValidationOptions::new());
+
+        assert_has_error!(result, "meta_data.tools[0].kind");
+    }
+
+    #[test]
+    fn synthetic_bom_is_valid() {
+        let bom = SyntheticBom::new(100).build();
+
+        assert_eq!(bom.components.as_ref().map(Vec::len), Some(100));
+        assert_valid!(bom, V1_5);
+    }
+
+    #[test]
+    fn synthetic_bom_with_invalid_components() {
+        let bom = SyntheticBom::new(100).invalid_every(10).build();
+        let result = validate_bom(SpecVersion::V1_5, &bom, &ValidationOptions::new());
+
+        assert_has_error!(result, "components[9].purl");
+        assert_has_error!(result, "components[99].hashes[0].content");
+        assert!(super::errors_at(&result, "components[0].purl").is_empty());
+    }
+
+    #[test]
+    fn synthetic_bom_round_trips_json() {
+        let generator = SyntheticBom::new(10);
+        let bom = Bom::parse_json(&generator.to_json()).expect("Failed to parse BOM");
+
+        assert_eq!(bom.to_value(), generator.build().to_value());
+    }
+}
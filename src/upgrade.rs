@@ -0,0 +1,207 @@
+//! Reports what needs to change in a document to validate against a newer spec version, e.g.
+//! when upgrading from 1.5 to 1.6.
+//!
+//! The document is validated against both versions, every finding that only appears in the
+//! target version is a change to make. Version gated fields, see `since` and `until` of
+//! `#[derive(Validate)]`, and the deprecated fields of the [`deprecations`](crate::deprecations)
+//! registry are reported as fields to rename or remove.
+use serde::Serialize;
+
+use crate::{
+    options::ValidationOptions,
+    prelude::*,
+    validate_bom,
+    validation::{SpecVersion, ValidationError, ValidationErrors},
+    Bom,
+};
+
+/// What needs to be done with a field to upgrade the document.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase", tag = "action")]
+pub enum UpgradeAction {
+    /// The field is deprecated in favor of another field.
+    Rename { replacement: String },
+    /// The field is deprecated or not supported by the target version.
+    Remove,
+    /// The value is not valid in the target version, e.g. an enum value that changed.
+    Adjust,
+}
+
+/// A single change needed to upgrade the document.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpgradeChange {
+    /// The path of the field, e.g. `components[0].author`.
+    pub path: String,
+    #[serde(flatten)]
+    pub action: UpgradeAction,
+    /// The code of the finding in the target version, e.g. `field-deprecated`.
+    pub code: Option<String>,
+    pub message: String,
+}
+
+/// The changes needed to upgrade a document from one spec version to another.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpgradeReport {
+    pub from: String,
+    pub to: String,
+    pub changes: Vec<UpgradeChange>,
+}
+
+impl UpgradeReport {
+    /// Returns `true` if the document validates against the target version as well as
+    /// against the current one.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+fn action(error: &ValidationError) -> UpgradeAction {
+    match (error.code.as_deref(), error.message.param("replacement")) {
+        (Some("field-deprecated"), Some(replacement)) => UpgradeAction::Rename {
+            replacement: replacement.to_string(),
+        },
+        (Some("field-deprecated"), None) => UpgradeAction::Remove,
+        _ if error.is_unsupported_feature() => UpgradeAction::Remove,
+        _ => UpgradeAction::Adjust,
+    }
+}
+
+fn findings(result: &Result<(), ValidationErrors>) -> Vec<(String, &ValidationError)> {
+    match result {
+        Ok(()) => Vec::new(),
+        Err(errors) => errors.errors(),
+    }
+}
+
+/// Validates the bom against both spec versions and returns the changes needed to validate
+/// against `to` without any new findings.
+pub fn upgrade_report(
+    bom: &Bom,
+    from: SpecVersion,
+    to: SpecVersion,
+    options: &ValidationOptions,
+) -> UpgradeReport {
+    let current = validate_bom(from, bom, options);
+    let target = validate_bom(to, bom, options);
+    let current = findings(&current)
+        .into_iter()
+        .map(|(path, error)| (path, error.to_string()))
+        .collect::<Vec<_>>();
+
+    let changes = findings(&target)
+        .into_iter()
+        .filter(|(_, error)| !error.suppressed)
+        .filter(|(path, error)| {
+            let message = error.to_string();
+            !current
+                .iter()
+                .any(|(other_path, other)| other_path == path && *other == message)
+        })
+        .map(|(path, error)| UpgradeChange {
+            path,
+            action: action(error),
+            code: error.code.clone(),
+            message: error.to_string(),
+        })
+        .collect();
+
+    UpgradeReport {
+        from: from.to_string(),
+        to: to.to_string(),
+        changes,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{options::ValidationOptions, validation::SpecVersion, Bom};
+
+    use super::{upgrade_report, UpgradeAction};
+
+    #[test]
+    fn report_fields_to_rename() {
+        let bom = Bom::parse_json(
+            r#"{ "components": [
+                { "type": "library", "name": "a", "author": "Jane Doe" },
+                { "type": "library", "name": "b" }
+            ] }"#,
+        )
+        .expect("Failed to parse BOM");
+
+        let report = upgrade_report(
+            &bom,
+            SpecVersion::V1_5,
+            SpecVersion::V1_6,
+            &ValidationOptions::new(),
+        );
+
+        assert_eq!(report.changes.len(), 1);
+        assert_eq!(report.changes[0].path, "components[0].author");
+        assert_eq!(
+            report.changes[0].action,
+            UpgradeAction::Rename {
+                replacement: String::from("authors")
+            }
+        );
+        assert_eq!(report.changes[0].code.as_deref(), Some("field-deprecated"));
+    }
+
+    #[test]
+    fn report_fields_to_remove_and_adjust() {
+        let bom =
+            Bom::parse_json(r#"{ "metadata": { "timestamp": "abcde" }, "vulnerabilities": [] }"#)
+                .expect("Failed to parse BOM");
+
+        let report = upgrade_report(
+            &bom,
+            SpecVersion::V1_5,
+            SpecVersion::V1_3,
+            &ValidationOptions::new(),
+        );
+
+        let changes = report
+            .changes
+            .iter()
+            .map(|change| (change.path.as_str(), &change.action))
+            .collect::<Vec<_>>();
+        assert!(changes.contains(&("vulnerabilities", &UpgradeAction::Remove)));
+        assert!(report
+            .changes
+            .iter()
+            .all(|change| change.path != "metadata.timestamp"));
+
+        let report = upgrade_report(
+            &bom,
+            SpecVersion::V1_5,
+            SpecVersion::V1_4,
+            &ValidationOptions::new(),
+        );
+        assert!(report
+            .changes
+            .iter()
+            .any(|change| change.path == "metadata.timestamp"
+                && change.action == UpgradeAction::Adjust));
+    }
+
+    #[test]
+    fn serialize_report() {
+        let bom = Bom::parse_json(
+            r#"{ "components": [{ "type": "library", "name": "a", "author": "Jane" }] }"#,
+        )
+        .expect("Failed to parse BOM");
+
+        let report = upgrade_report(
+            &bom,
+            SpecVersion::V1_5,
+            SpecVersion::V1_6,
+            &ValidationOptions::new(),
+        );
+        let json = serde_json::to_value(&report).expect("Failed to serialize report");
+
+        assert_eq!(json["from"], "1.5");
+        assert_eq!(json["changes"][0]["action"], "rename");
+        assert_eq!(json["changes"][0]["replacement"], "authors");
+    }
+}
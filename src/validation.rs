@@ -1,12 +1,57 @@
-use std::collections::BTreeMap;
+use alloc::{
+    collections::{btree_map, BTreeMap},
+    fmt,
+};
 
-use indexmap::{map::Entry::Vacant, IndexMap};
+use indexmap::map::Entry::{Occupied, Vacant};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, PartialEq, Copy, Clone)]
+use crate::{
+    map::IndexMap,
+    message::{Locale, Message},
+    options::ValidationOptions,
+    paths,
+    prelude::*,
+    Bom, Component,
+};
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Copy, Clone)]
 pub enum SpecVersion {
     V1_3,
     V1_4,
     V1_5,
+    V1_6,
+}
+
+impl SpecVersion {
+    /// Returns the spec version for a version string, e.g. `1.5`.
+    pub fn parse(version: &str) -> Option<Self> {
+        match version {
+            "1.3" => Some(SpecVersion::V1_3),
+            "1.4" => Some(SpecVersion::V1_4),
+            "1.5" => Some(SpecVersion::V1_5),
+            "1.6" => Some(SpecVersion::V1_6),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for SpecVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let version = match self {
+            SpecVersion::V1_3 => "1.3",
+            SpecVersion::V1_4 => "1.4",
+            SpecVersion::V1_5 => "1.5",
+            SpecVersion::V1_6 => "1.6",
+        };
+        write!(f, "{}", version)
+    }
+}
+
+impl Serialize for SpecVersion {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
 }
 
 /// TODO: the `Result` is not meant to be used as shortcut or to raise errors, rather to collect all errors
@@ -20,9 +65,7 @@ pub struct ValidationContext {
 
 impl ValidationContext {
     pub fn new() -> Self {
-        Self {
-            state: std::result::Result::Ok(()),
-        }
+        Self { state: Ok(()) }
     }
 
     pub fn add_field(self, field_name: &str, error: Option<Result<(), ValidationError>>) -> Self {
@@ -45,6 +88,16 @@ impl ValidationContext {
         }
     }
 
+    pub fn add_lineage(self, field_name: &str, error: Option<Result<(), ValidationError>>) -> Self {
+        if let Some(Err(error)) = error {
+            Self {
+                state: ValidationErrors::merge_lineage(self.state, field_name, Err(error)),
+            }
+        } else {
+            self
+        }
+    }
+
     pub fn add_list(
         self,
         field_name: &str,
@@ -73,38 +126,474 @@ impl ValidationContext {
         }
     }
 
+    /// Validates a field captured by [`field!`](crate::field), the key is taken from the field.
+    pub fn field<T, F>(self, field: Field<'_, T>, validate: F) -> Self
+    where
+        F: FnOnce(&T) -> Result<(), ValidationError>,
+    {
+        self.add_field(field.name, Some(validate(field.value)))
+    }
+
+    /// Validates an optional field captured by [`field!`](crate::field) if it is present.
+    pub fn optional_field<T, F>(self, field: Field<'_, Option<T>>, validate: F) -> Self
+    where
+        F: FnOnce(&T) -> Result<(), ValidationError>,
+    {
+        self.add_field(field.name, field.value.as_ref().map(validate))
+    }
+
     pub fn inner(&self) -> ValidationResult {
         self.state.clone()
     }
 }
 
+impl Default for ValidationContext {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl From<ValidationContext> for ValidationResult {
     fn from(builder: ValidationContext) -> Self {
-        builder.inner()
+        builder.state
     }
 }
 
+pub use cyclonedx_validation_derive::Validate;
+
+/// A reference to a struct field together with its name, created by [`field!`](crate::field).
+#[derive(Debug, Clone, Copy)]
+pub struct Field<'a, T> {
+    pub name: &'static str,
+    pub value: &'a T,
+}
+
+/// Captures a struct field with its name, so the key in the error tree can't drift from the
+/// name of the field, e.g. `field!(self.serial_number)` has the name `serial_number`.
+#[macro_export]
+macro_rules! field {
+    ($base:ident . $name:ident) => {
+        $crate::validation::Field {
+            name: stringify!($name),
+            value: &$base.$name,
+        }
+    };
+}
+
 /// The trait that SBOM structs need to implement to validate their content.
+///
+/// The trait is object safe, so elements of different types can be validated as
+/// `&dyn Validate`, e.g. by plugins, see [`validate_dyn`].
 pub trait Validate {
-    fn validate(&self, version: SpecVersion) -> ValidationResult;
+    fn validate(&self, version: SpecVersion, options: &ValidationOptions) -> ValidationResult;
+
+    /// Validates the element located at `path` of the document, e.g. `components[3]`, used to
+    /// skip the subtrees not selected by the [`ValidationOptions`]. Defaults to
+    /// [`Validate::validate`], the derived implementation skips unselected fields.
+    fn validate_at(
+        &self,
+        version: SpecVersion,
+        options: &ValidationOptions,
+        path: &str,
+    ) -> ValidationResult {
+        let _ = path;
+        self.validate(version, options)
+    }
+}
+
+/// The path of a struct being validated by the derived [`Validate`], to check which of its
+/// fields are selected by the [`ValidationOptions`].
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy)]
+pub struct PathScope<'a> {
+    options: &'a ValidationOptions,
+    path: &'a str,
+}
+
+impl<'a> PathScope<'a> {
+    pub fn new(options: &'a ValidationOptions, path: &'a str) -> Self {
+        Self { options, path }
+    }
+
+    /// Returns the path of the field, empty if the options do not select any paths.
+    pub fn child(&self, key: &str) -> String {
+        if self.options.selects_paths() {
+            join_path(self.path, key)
+        } else {
+            String::new()
+        }
+    }
+
+    /// Returns `true` if the field itself is validated.
+    pub fn selects(&self, key: &str) -> bool {
+        !self.options.selects_paths() || self.options.is_path_selected(&self.child(key))
+    }
+
+    /// Returns `true` if the nested element of the field is traversed.
+    pub fn traverses(&self, key: &str) -> bool {
+        !self.options.selects_paths() || self.options.is_path_traversed(&self.child(key))
+    }
+}
+
+/// Validates an element through a trait object.
+pub fn validate_dyn(
+    element: &dyn Validate,
+    version: SpecVersion,
+    options: &ValidationOptions,
+) -> ValidationResult {
+    element.validate(version, options)
+}
+
+/// Validates a nested element, used by the derived [`Validate`] for `nested` fields.
+///
+/// With the `tracing` feature the validation runs in a span named after the element.
+#[doc(hidden)]
+pub fn validate_nested<T: Validate + ?Sized>(
+    name: &str,
+    element: &T,
+    version: SpecVersion,
+    options: &ValidationOptions,
+    path: &str,
+) -> ValidationResult {
+    #[cfg(feature = "tracing")]
+    let _span = tracing::debug_span!("validate", element = name).entered();
+    #[cfg(not(feature = "tracing"))]
+    let _ = name;
+
+    element.validate_at(version, options, path)
+}
+
+/// Validates all elements of a list, used by the derived [`Validate`] for `each` fields.
+///
+/// With the `tracing` feature every element is validated in its own span, e.g. `components[42]`.
+#[doc(hidden)]
+pub fn validate_each<'a, T: Validate + 'a>(
+    name: &str,
+    elements: impl IntoIterator<Item = &'a T>,
+    version: SpecVersion,
+    options: &ValidationOptions,
+    path: &str,
+) -> Vec<ValidationResult> {
+    elements
+        .into_iter()
+        .enumerate()
+        .map(|(index, element)| {
+            let path = match options.selects_paths() {
+                true => format!("{}[{}]", path, index),
+                false => String::new(),
+            };
+            if options.selects_paths() && !options.is_path_traversed(&path) {
+                return Ok(());
+            }
+
+            #[cfg(feature = "tracing")]
+            let _span =
+                tracing::debug_span!("validate", element = %format_args!("{}[{}]", name, index))
+                    .entered();
+            #[cfg(not(feature = "tracing"))]
+            let _ = (name, index);
+
+            element.validate_at(version, options, &path)
+        })
+        .collect()
+}
+
+/// Reports a present field that was only introduced in the spec version `since`.
+pub fn validate_since(
+    field: &str,
+    present: bool,
+    version: SpecVersion,
+    since: SpecVersion,
+) -> Option<Result<(), ValidationError>> {
+    (present && version < since).then(|| {
+        Err(ValidationError::new("field_since")
+            .with_param("field", field)
+            .with_param("since", since)
+            .with_code("field-not-supported")
+            .with_version_support(VersionSupport::since(since)))
+    })
+}
+
+/// Reports a present field that is deprecated after the spec version `until`.
+pub fn validate_until(
+    field: &str,
+    present: bool,
+    version: SpecVersion,
+    until: SpecVersion,
+) -> Option<Result<(), ValidationError>> {
+    (present && version > until).then(|| {
+        Err(ValidationError::warning("field_until")
+            .with_param("field", field)
+            .with_param("until", until)
+            .with_code("field-deprecated"))
+    })
+}
+
+/// Reports a present field that is deprecated after the spec version `until` in favor of
+/// the field `replacement`.
+pub fn validate_replaced(
+    field: &str,
+    replacement: &str,
+    present: bool,
+    version: SpecVersion,
+    until: SpecVersion,
+) -> Option<Result<(), ValidationError>> {
+    (present && version > until).then(|| {
+        Err(ValidationError::warning("field_replaced")
+            .with_param("field", field)
+            .with_param("until", until)
+            .with_param("replacement", replacement)
+            .with_code("field-deprecated"))
+    })
+}
+
+/// The severity of a single [`ValidationError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// The finding is an advisory of the [`lint`](crate::lint) pass, e.g. a recommended practice.
+    Info,
+    /// The finding does not make the document invalid, but should be looked at.
+    Warning,
+    /// The finding makes the document invalid.
+    #[default]
+    Error,
+}
+
+/// The maximum number of characters of an offending value kept in [`ErrorContext::actual`].
+const SNIPPET_LEN: usize = 64;
+
+/// Structured details of a [`ValidationError`], so reports don't need to parse the message.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize)]
+pub struct ErrorContext {
+    /// A snippet of the offending value.
+    pub actual: Option<String>,
+    /// A description of what was expected instead, e.g. `RFC 3339 timestamp`.
+    pub expected: Option<String>,
+    /// The index of the offending element, e.g. of a duplicate entry.
+    pub index: Option<usize>,
+    /// A fix replacing the offending value, see [`patch::suggested_fixes`](crate::patch::suggested_fixes).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suggestion: Option<Suggestion>,
+}
+
+/// A machine-applicable fix of a [`ValidationError`], replacing the offending value with the
+/// form the validator knows to be correct, e.g. the canonical form of a purl.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Suggestion {
+    pub value: serde_json::Value,
+    /// Whether the fix keeps the meaning of the value, only safe fixes are applied
+    /// automatically. A timestamp without offset assumed to be UTC is not safe, for example.
+    pub safe: bool,
+}
+
+/// The spec versions a field or value is available in, attached to findings about a feature
+/// the validated spec version does not support, see [`ValidationErrors::unsupported_features`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VersionSupport {
+    /// The first spec version supporting the feature, `None` if all earlier ones do.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub since: Option<SpecVersion>,
+    /// The first spec version no longer supporting the feature.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub removed_in: Option<SpecVersion>,
+}
+
+impl VersionSupport {
+    /// A feature introduced in the given spec version.
+    pub fn since(since: SpecVersion) -> Self {
+        Self {
+            since: Some(since),
+            removed_in: None,
+        }
+    }
+
+    /// A feature removed in the given spec version.
+    pub fn removed_in(removed_in: SpecVersion) -> Self {
+        Self {
+            since: None,
+            removed_in: Some(removed_in),
+        }
+    }
+
+    /// Returns `true` if the feature is available in the given spec version.
+    pub fn supports(&self, version: SpecVersion) -> bool {
+        self.since.is_none_or(|since| version >= since)
+            && self
+                .removed_in
+                .is_none_or(|removed_in| version < removed_in)
+    }
 }
 
 /// A single validation error with a message, useful to log / display for user.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct ValidationError {
-    pub message: String,
+    /// The message as template key plus parameters, rendered on display.
+    pub message: Message,
+    pub severity: Severity,
+    /// Identifies the kind of finding, e.g. `missing-supplier`, used to suppress it.
+    pub code: Option<String>,
+    /// Whether the finding was suppressed, suppressed findings are kept in the report.
+    pub suppressed: bool,
+    /// Structured details about the offending value.
+    pub context: Box<ErrorContext>,
+    /// The spec version the document was validated against, set when the finding is reported.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<SpecVersion>,
+    /// The file the document was read from, set by [`validate_file`](crate::validate_file).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<Box<str>>,
+    /// Links to an explanation of the finding, set from [`Rule::help_uri`](crate::rules::Rule::help_uri).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub help_uri: Option<Box<str>>,
+    /// Set if the finding is about a field or value the spec version does not support, as
+    /// opposed to an ordinary format error.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version_support: Option<VersionSupport>,
 }
 
 impl ValidationError {
+    /// Creates a new [`ValidationError`] with the given template key or free text message.
     pub fn new<T: ToString>(message: T) -> Self {
         Self {
-            message: message.to_string(),
+            message: Message::new(message),
+            severity: Severity::Error,
+            code: None,
+            suppressed: false,
+            context: Box::default(),
+            version: None,
+            file: None,
+            help_uri: None,
+            version_support: None,
         }
     }
+
+    /// Creates a new [`ValidationError`] with severity [`Severity::Warning`].
+    pub fn warning<T: ToString>(message: T) -> Self {
+        Self {
+            severity: Severity::Warning,
+            ..Self::new(message)
+        }
+    }
+
+    /// Adds a parameter referenced by the message template.
+    pub fn with_param<N: ToString, V: ToString>(mut self, name: N, value: V) -> Self {
+        self.message = self.message.with_param(name, value);
+        self
+    }
+
+    /// Sets the [`Severity`] of the finding.
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    /// Sets the code identifying the kind of finding.
+    pub fn with_code<T: ToString>(mut self, code: T) -> Self {
+        self.code = Some(code.to_string());
+        self
+    }
+
+    /// Sets the offending value, long values are shortened to a snippet.
+    pub fn with_actual<T: ToString>(mut self, actual: T) -> Self {
+        let actual = actual.to_string();
+        self.context.actual = Some(match actual.char_indices().nth(SNIPPET_LEN) {
+            Some((end, _)) => format!("{}...", &actual[..end]),
+            None => actual,
+        });
+        self
+    }
+
+    /// Sets the description of the expected value.
+    pub fn with_expected<T: ToString>(mut self, expected: T) -> Self {
+        self.context.expected = Some(expected.to_string());
+        self
+    }
+
+    /// Sets the index of the offending element.
+    pub fn with_index(mut self, index: usize) -> Self {
+        self.context.index = Some(index);
+        self
+    }
+
+    /// Suggests to replace the offending value with `value`, see [`Suggestion`].
+    pub fn with_suggestion<V: Into<serde_json::Value>>(mut self, value: V, safe: bool) -> Self {
+        self.context.suggestion = Some(Suggestion {
+            value: value.into(),
+            safe,
+        });
+        self
+    }
+
+    /// Sets the spec version the finding was reported for.
+    pub fn with_version(mut self, version: SpecVersion) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    /// Sets the link to an explanation of the finding.
+    pub fn with_help_uri<T: ToString>(mut self, help_uri: T) -> Self {
+        self.help_uri = Some(help_uri.to_string().into());
+        self
+    }
+
+    /// Sets the file the finding was reported for.
+    pub fn with_file<T: ToString>(mut self, file: T) -> Self {
+        self.file = Some(file.to_string().into());
+        self
+    }
+
+    /// Marks the finding as about a feature only available in the given spec versions.
+    pub fn with_version_support(mut self, version_support: VersionSupport) -> Self {
+        self.version_support = Some(version_support);
+        self
+    }
+
+    /// Returns `true` if the finding is about a feature the spec version does not support.
+    pub fn is_unsupported_feature(&self) -> bool {
+        self.version_support.is_some()
+    }
+}
+
+impl ValidationError {
+    /// Renders the message in the given locale, followed by the expected and actual value
+    /// if known, e.g. `Invalid timestamp: expected RFC 3339 timestamp, got "2024/02/04"`.
+    pub fn localize(&self, locale: Locale) -> String {
+        let expected = self.context.expected.as_ref();
+        let actual = self
+            .context
+            .actual
+            .as_ref()
+            .map(|actual| format!("{:?}", actual));
+        let context = match (expected, actual) {
+            (Some(expected), Some(actual)) => Message::new("context_expected_actual")
+                .with_param("expected", expected)
+                .with_param("actual", actual),
+            (Some(expected), None) => {
+                Message::new("context_expected").with_param("expected", expected)
+            }
+            (None, Some(actual)) => Message::new("context_actual").with_param("actual", actual),
+            (None, None) => return self.message.localize(locale),
+        };
+
+        format!(
+            "{}{}",
+            self.message.localize(locale),
+            context.localize(locale)
+        )
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.localize(Locale::En))
+    }
 }
 
 /// Implements possible hierarchy of a structured SBOM to collect all [`ValidationError`] in.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum ValidationErrorsKind {
     /// Collects all field validation errors in context of a struct
     Struct(Box<ValidationErrors>),
@@ -114,19 +603,252 @@ pub enum ValidationErrorsKind {
     Field(Vec<ValidationError>),
     /// Represents an error for an Enum variant.
     Enum(ValidationError),
+    /// Contains the list of violations of a field between two revisions of the same document.
+    Lineage(Vec<ValidationError>),
+    /// Contains entries of different kinds for the same name, e.g. a field error at
+    /// `components` next to the errors of its elements, see [`ValidationErrors::merge`].
+    Mixed(Vec<ValidationErrorsKind>),
+}
+
+impl ValidationErrorsKind {
+    /// Combines two entries, e.g. appends all errors of two fields.
+    ///
+    /// Entries are combined like in [`ValidationErrors::merge`], except that all errors are
+    /// kept, even if equal.
+    fn extend(self, other: ValidationErrorsKind) -> ValidationErrorsKind {
+        match (self, other) {
+            (ValidationErrorsKind::Struct(mut errors), ValidationErrorsKind::Struct(other)) => {
+                errors.extend(*other);
+                ValidationErrorsKind::Struct(errors)
+            }
+            (ValidationErrorsKind::List(mut children), ValidationErrorsKind::List(other)) => {
+                for (index, child) in other {
+                    match children.entry(index) {
+                        btree_map::Entry::Vacant(entry) => {
+                            entry.insert(child);
+                        }
+                        btree_map::Entry::Occupied(mut entry) => entry.get_mut().extend(*child),
+                    }
+                }
+                ValidationErrorsKind::List(children)
+            }
+            (ValidationErrorsKind::Lineage(mut errors), ValidationErrorsKind::Lineage(other)) => {
+                errors.extend(other);
+                ValidationErrorsKind::Lineage(errors)
+            }
+            (errors, other) => errors.combine(other, Vec::extend, ValidationErrorsKind::extend),
+        }
+    }
+
+    /// Combines two entries with the semantics of [`ValidationErrors::merge`].
+    fn merge(self, other: ValidationErrorsKind) -> ValidationErrorsKind {
+        fn push_unique(errors: &mut Vec<ValidationError>, other: Vec<ValidationError>) {
+            for error in other {
+                if !errors.contains(&error) {
+                    errors.push(error);
+                }
+            }
+        }
+
+        match (self, other) {
+            (ValidationErrorsKind::Struct(errors), ValidationErrorsKind::Struct(other)) => {
+                ValidationErrorsKind::Struct(Box::new(errors.merge(*other)))
+            }
+            (ValidationErrorsKind::List(mut children), ValidationErrorsKind::List(other)) => {
+                for (index, child) in other {
+                    let merged = match children.remove(&index) {
+                        Some(existing) => existing.merge(*child),
+                        None => *child,
+                    };
+                    children.insert(index, Box::new(merged));
+                }
+                ValidationErrorsKind::List(children)
+            }
+            (ValidationErrorsKind::Lineage(mut errors), ValidationErrorsKind::Lineage(other)) => {
+                push_unique(&mut errors, other);
+                ValidationErrorsKind::Lineage(errors)
+            }
+            (ValidationErrorsKind::Enum(error), ValidationErrorsKind::Enum(other))
+                if error == other =>
+            {
+                ValidationErrorsKind::Enum(error)
+            }
+            (errors, other) => errors.combine(other, push_unique, ValidationErrorsKind::merge),
+        }
+    }
+
+    /// Combines entries that are not of the same kind.
+    ///
+    /// The errors of field and enum entries are joined into a field with `push`. Entries of
+    /// different kinds are kept side by side in a [`ValidationErrorsKind::Mixed`] entry, an
+    /// entry of the same kind as one already present is combined with it by `combine`.
+    fn combine(
+        self,
+        other: ValidationErrorsKind,
+        push: fn(&mut Vec<ValidationError>, Vec<ValidationError>),
+        combine: fn(ValidationErrorsKind, ValidationErrorsKind) -> ValidationErrorsKind,
+    ) -> ValidationErrorsKind {
+        match (self, other) {
+            (ValidationErrorsKind::Field(mut errors), ValidationErrorsKind::Field(other)) => {
+                push(&mut errors, other);
+                ValidationErrorsKind::Field(errors)
+            }
+            (
+                errors @ (ValidationErrorsKind::Field(_) | ValidationErrorsKind::Enum(_)),
+                other @ (ValidationErrorsKind::Field(_) | ValidationErrorsKind::Enum(_)),
+            ) => ValidationErrorsKind::Field(errors.into_field()).combine(
+                ValidationErrorsKind::Field(other.into_field()),
+                push,
+                combine,
+            ),
+            (errors, other) => {
+                let mut kinds = errors.into_kinds();
+                for kind in other.into_kinds() {
+                    match kinds.iter().position(|existing| existing.same_kind(&kind)) {
+                        Some(position) => {
+                            let existing = kinds.remove(position);
+                            kinds.insert(position, combine(existing, kind));
+                        }
+                        None => kinds.push(kind),
+                    }
+                }
+                ValidationErrorsKind::Mixed(kinds)
+            }
+        }
+    }
+
+    fn collect_errors<'a>(&'a self, path: String, errors: &mut Vec<(String, &'a ValidationError)>) {
+        match self {
+            ValidationErrorsKind::Struct(nested) => nested.collect_errors(&path, errors),
+            ValidationErrorsKind::List(children) => {
+                for (index, child) in children {
+                    child.collect_errors(&format!("{}[{}]", path, index), errors);
+                }
+            }
+            ValidationErrorsKind::Field(vec) | ValidationErrorsKind::Lineage(vec) => {
+                errors.extend(vec.iter().map(|error| (path.clone(), error)));
+            }
+            ValidationErrorsKind::Enum(error) => errors.push((path, error)),
+            ValidationErrorsKind::Mixed(kinds) => {
+                for kind in kinds {
+                    kind.collect_errors(path.clone(), errors);
+                }
+            }
+        }
+    }
+
+    /// Keeps only the errors for which `f` returns `true`, returns `false` if the entry is left
+    /// without any errors.
+    fn retain_errors<F>(&mut self, path: &str, f: &mut F) -> bool
+    where
+        F: FnMut(&str, &ValidationError) -> bool,
+    {
+        match self {
+            ValidationErrorsKind::Struct(nested) => {
+                nested.retain_errors(path, f);
+                !nested.is_empty()
+            }
+            ValidationErrorsKind::List(children) => {
+                children.retain(|index, child| {
+                    child.retain_errors(&format!("{}[{}]", path, index), f);
+                    !child.is_empty()
+                });
+                !children.is_empty()
+            }
+            ValidationErrorsKind::Field(vec) | ValidationErrorsKind::Lineage(vec) => {
+                vec.retain(|error| f(path, error));
+                !vec.is_empty()
+            }
+            ValidationErrorsKind::Enum(error) => f(path, error),
+            ValidationErrorsKind::Mixed(kinds) => {
+                kinds.retain_mut(|kind| kind.retain_errors(path, f));
+                !kinds.is_empty()
+            }
+        }
+    }
+
+    fn visit_errors_mut<F>(&mut self, path: &str, f: &mut F)
+    where
+        F: FnMut(&str, &mut ValidationError),
+    {
+        match self {
+            ValidationErrorsKind::Struct(nested) => nested.visit_errors_mut(path, f),
+            ValidationErrorsKind::List(children) => {
+                for (index, child) in children {
+                    child.visit_errors_mut(&format!("{}[{}]", path, index), f);
+                }
+            }
+            ValidationErrorsKind::Field(vec) | ValidationErrorsKind::Lineage(vec) => {
+                vec.iter_mut().for_each(|error| f(path, error));
+            }
+            ValidationErrorsKind::Enum(error) => f(path, error),
+            ValidationErrorsKind::Mixed(kinds) => {
+                for kind in kinds {
+                    kind.visit_errors_mut(path, f);
+                }
+            }
+        }
+    }
+
+    /// Returns the errors of a field or enum entry.
+    fn into_field(self) -> Vec<ValidationError> {
+        match self {
+            ValidationErrorsKind::Field(errors) => errors,
+            ValidationErrorsKind::Enum(error) => vec![error],
+            _ => unreachable!("Expected a field or enum entry"),
+        }
+    }
+
+    /// Returns the entries of a [`ValidationErrorsKind::Mixed`] entry, or the entry itself.
+    fn into_kinds(self) -> Vec<ValidationErrorsKind> {
+        match self {
+            ValidationErrorsKind::Mixed(kinds) => kinds,
+            kind => vec![kind],
+        }
+    }
+
+    /// Returns `true` if both entries can be combined without a [`ValidationErrorsKind::Mixed`]
+    /// entry, field and enum entries are joined into a field.
+    fn same_kind(&self, other: &ValidationErrorsKind) -> bool {
+        matches!(
+            (self, other),
+            (
+                ValidationErrorsKind::Field(_) | ValidationErrorsKind::Enum(_),
+                ValidationErrorsKind::Field(_) | ValidationErrorsKind::Enum(_)
+            ) | (
+                ValidationErrorsKind::Struct(_),
+                ValidationErrorsKind::Struct(_)
+            ) | (ValidationErrorsKind::List(_), ValidationErrorsKind::List(_))
+                | (
+                    ValidationErrorsKind::Lineage(_),
+                    ValidationErrorsKind::Lineage(_)
+                )
+        )
+    }
 }
 
-#[derive(Debug, Clone, PartialEq, Default)]
+/// The tree of all errors of a validation.
+///
+/// The order of the tree is deterministic: entries keep the order they were added in, which
+/// for derived [`Validate`] implementations is the declaration order of the fields, and list
+/// elements are ordered by ascending index. See [`ValidationErrors::to_snapshot_string`] for a
+/// canonical text form, e.g. for snapshot tests.
+#[derive(Debug, Clone, PartialEq, Default, Serialize)]
+#[serde(transparent)]
 pub struct ValidationErrors {
     /// Maps a name to a set of context errors.
     inner: IndexMap<String, ValidationErrorsKind>,
+    /// The model struct the paths of the tree are relative to, the [`Bom`] if not set.
+    #[serde(skip)]
+    model: Option<&'static str>,
 }
 
 impl ValidationErrors {
     /// Generates an empty list of errors.
     pub fn new() -> Self {
         ValidationErrors {
-            inner: IndexMap::new(),
+            inner: IndexMap::default(),
+            model: None,
         }
     }
 
@@ -168,6 +890,25 @@ impl ValidationErrors {
         }
     }
 
+    /// Returns new [`ValidationErrors`] with possible lineage violation of a field.
+    pub fn merge_lineage(
+        parent: Result<(), ValidationErrors>,
+        field_name: &str,
+        error: Result<(), ValidationError>,
+    ) -> ValidationResult {
+        match error {
+            Ok(()) => parent,
+            Err(error) => {
+                parent
+                    .and_then(|_| Err(ValidationErrors::new()))
+                    .map_err(|mut parent_errors| {
+                        parent_errors.add_lineage(field_name, error);
+                        parent_errors
+                    })
+            }
+        }
+    }
+
     /// Returns new [`ValidationErrors`] with results for all nested fields.
     pub fn merge_struct(
         parent: Result<(), ValidationErrors>,
@@ -213,6 +954,71 @@ impl ValidationErrors {
         }
     }
 
+    /// Returns [`ValidationErrors`] with all errors of `child` merged into `parent`.
+    pub fn merge_errors(
+        parent: Result<(), ValidationErrors>,
+        child: Result<(), ValidationErrors>,
+    ) -> ValidationResult {
+        match child {
+            Ok(()) => parent,
+            Err(errors) => {
+                parent
+                    .and_then(|_| Err(ValidationErrors::new()))
+                    .map_err(|mut parent_errors| {
+                        parent_errors.extend(errors);
+                        parent_errors
+                    })
+            }
+        }
+    }
+
+    /// Combines two independently created trees into one, e.g. the results of the schema
+    /// pass, the semantic pass and of custom rule packs.
+    ///
+    /// * entries only present in one tree are kept, new entries of `other` come last
+    /// * structs are merged recursively, lists element by element by their index
+    /// * the errors of a field are appended, an error equal to one already present is dropped,
+    ///   so a finding reported by both passes appears once
+    /// * an enum error combined with a different error becomes a field with both errors
+    /// * entries of different shape at the same path, e.g. a field error at `components` and
+    ///   element errors at `components[0]`, are kept side by side in a
+    ///   [`ValidationErrorsKind::Mixed`] entry
+    pub fn merge(mut self, other: ValidationErrors) -> ValidationErrors {
+        for (name, errors_kind) in other.inner {
+            match self.inner.entry(name) {
+                Vacant(entry) => {
+                    entry.insert(errors_kind);
+                }
+                Occupied(mut entry) => {
+                    let existing = core::mem::replace(
+                        entry.get_mut(),
+                        ValidationErrorsKind::Field(Vec::new()),
+                    );
+                    *entry.get_mut() = existing.merge(errors_kind);
+                }
+            }
+        }
+        self
+    }
+
+    /// Merges all entries of `other` into this tree, combining entries of the same name.
+    fn extend(&mut self, other: ValidationErrors) {
+        for (name, errors_kind) in other.inner {
+            match self.inner.entry(name) {
+                Vacant(entry) => {
+                    entry.insert(errors_kind);
+                }
+                Occupied(mut entry) => {
+                    let existing = core::mem::replace(
+                        entry.get_mut(),
+                        ValidationErrorsKind::Field(Vec::new()),
+                    );
+                    *entry.get_mut() = existing.extend(errors_kind);
+                }
+            }
+        }
+    }
+
     /// Adds a nested object kind
     fn add_nested(&mut self, nested_name: &str, errors_kind: ValidationErrorsKind) {
         if let Vacant(entry) = self.inner.entry(nested_name.to_string()) {
@@ -231,6 +1037,19 @@ impl ValidationErrors {
         }
     }
 
+    /// Adds a single lineage [`ValidationError`] for a field.
+    fn add_lineage(&mut self, field_name: &str, validation_error: ValidationError) {
+        if let ValidationErrorsKind::Lineage(ref mut vec) = self
+            .inner
+            .entry(field_name.to_string())
+            .or_insert_with(|| ValidationErrorsKind::Lineage(vec![]))
+        {
+            vec.push(validation_error);
+        } else {
+            panic!("Found a non-lineage ValidationErrorsKind");
+        }
+    }
+
     /// Adds a single field [`ValidationError`].
     fn add_field(&mut self, field_name: &str, validation_error: ValidationError) {
         if let ValidationErrorsKind::Field(ref mut vec) = self
@@ -255,14 +1074,301 @@ impl ValidationErrors {
         self.inner.contains_key(key)
     }
 
+    /// Returns the errors collected for the given key.
+    pub fn get(&self, key: &str) -> Option<&ValidationErrorsKind> {
+        self.inner.get(key)
+    }
+
     pub fn is_empty(&self) -> bool {
         self.inner.is_empty()
     }
+
+    /// Returns all errors of the tree together with their path in the serialized document, e.g.
+    /// `components[3].bom-ref`.
+    pub fn errors(&self) -> Vec<(String, &ValidationError)> {
+        self.model_errors()
+            .into_iter()
+            .map(|(path, error)| match self.model {
+                Some(model) => (paths::to_wire_at(model, &path), error),
+                None => (paths::to_wire(&path), error),
+            })
+            .collect()
+    }
+
+    /// Returns all errors of the tree together with their path formatted with the field names
+    /// of the model, e.g. `components[3].bom_ref`.
+    pub(crate) fn model_errors(&self) -> Vec<(String, &ValidationError)> {
+        let mut errors = Vec::new();
+        self.collect_errors("", &mut errors);
+        errors
+    }
+
+    /// Marks the paths of the tree as relative to the given model struct, e.g. `Component`.
+    pub(crate) fn relative_to(mut self, model: &'static str) -> Self {
+        self.model = Some(model);
+        self
+    }
+
+    fn collect_errors<'a>(&'a self, prefix: &str, errors: &mut Vec<(String, &'a ValidationError)>) {
+        for (name, errors_kind) in &self.inner {
+            errors_kind.collect_errors(join_path(prefix, name), errors);
+        }
+    }
+
+    /// Keeps only the errors for which `f` returns `true`, entries left without any errors are
+    /// removed from the tree.
+    ///
+    /// `f` is called with the path formatted with the field names of the model like the
+    /// constants of [`paths`], e.g. `components[3].bom_ref`.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&str, &ValidationError) -> bool,
+    {
+        self.retain_errors("", &mut f);
+    }
+
+    fn retain_errors<F>(&mut self, prefix: &str, f: &mut F)
+    where
+        F: FnMut(&str, &ValidationError) -> bool,
+    {
+        self.inner
+            .retain(|name, errors_kind| errors_kind.retain_errors(&join_path(prefix, name), f));
+    }
+
+    /// Calls `f` with the path and a mutable reference of every error in the tree, the path is
+    /// formatted like in [`ValidationErrors::retain`].
+    pub fn for_each_error_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&str, &mut ValidationError),
+    {
+        self.visit_errors_mut("", &mut f);
+    }
+
+    fn visit_errors_mut<F>(&mut self, prefix: &str, f: &mut F)
+    where
+        F: FnMut(&str, &mut ValidationError),
+    {
+        for (name, errors_kind) in &mut self.inner {
+            errors_kind.visit_errors_mut(&join_path(prefix, name), f);
+        }
+    }
+
+    /// Returns all errors of the tree with their path and message rendered in the given locale.
+    pub fn localize(&self, locale: Locale) -> Vec<(String, String)> {
+        self.errors()
+            .into_iter()
+            .map(|(path, error)| (path, error.localize(locale)))
+            .collect()
+    }
+
+    /// Re-indexes the tree by the identity of the components of `bom` the errors belong to.
+    ///
+    /// Components are identified by their [`ComponentIdentity`](crate::identity::ComponentIdentity),
+    /// e.g. their purl, or `name@version` if they have no identifier.
+    /// The errors of the metadata component are grouped the same way, all other errors of
+    /// the document are grouped under the key `bom`.
+    pub fn group_by_component(&self, bom: &Bom) -> IndexMap<String, ValidationErrors> {
+        let mut groups: IndexMap<String, ValidationErrors> = IndexMap::default();
+        let mut rest = self.clone();
+
+        if let Some(ValidationErrorsKind::Struct(meta_data)) = rest.inner.get_mut("meta_data") {
+            let component = meta_data.inner.shift_remove("component");
+            if meta_data.is_empty() {
+                rest.inner.shift_remove("meta_data");
+            }
+            if let (Some(ValidationErrorsKind::Struct(errors)), Some(component)) = (
+                component,
+                bom.meta_data
+                    .as_ref()
+                    .and_then(|meta_data| meta_data.component.as_ref()),
+            ) {
+                groups
+                    .entry(component_identity(component))
+                    .or_default()
+                    .extend(*errors);
+            }
+        }
+
+        if let Some(ValidationErrorsKind::List(children)) = rest.inner.shift_remove("components") {
+            let components = bom.components.as_deref().unwrap_or_default();
+            for (index, errors) in children {
+                let identity = components
+                    .get(index)
+                    .map(component_identity)
+                    .unwrap_or_else(|| format!("components[{}]", index));
+                groups.entry(identity).or_default().extend(*errors);
+            }
+        }
+
+        if !rest.is_empty() {
+            groups.entry(String::from("bom")).or_default().extend(rest);
+        }
+
+        groups
+    }
+
+    /// Returns all findings about fields or values the validated spec version does not
+    /// support, e.g. the ones blocking a conversion to an earlier spec version.
+    pub fn unsupported_features(&self) -> Vec<(String, &ValidationError)> {
+        self.errors()
+            .into_iter()
+            .filter(|(_, error)| error.is_unsupported_feature())
+            .collect()
+    }
+
+    /// Returns `true` if the tree contains at least one error that is not suppressed.
+    pub fn has_unsuppressed(&self) -> bool {
+        self.errors().iter().any(|(_, error)| !error.suppressed)
+    }
+
+    /// Returns a canonical text form of the tree with one error per line, e.g.
+    /// `components[1].purl: error [invalid-purl] Purl must start with 'pkg:'`.
+    ///
+    /// Lines follow the order of the tree, identical lines are only listed once in the
+    /// position of their first occurrence.
+    pub fn to_snapshot_string(&self) -> String {
+        let mut lines: Vec<String> = Vec::new();
+        for (path, error) in self.errors() {
+            let mut line = format!("{}: {}", path, severity_label(error.severity));
+            if let Some(code) = &error.code {
+                line.push_str(&format!(" [{}]", code));
+            }
+            line.push_str(&format!(" {}", error));
+            if error.suppressed {
+                line.push_str(" (suppressed)");
+            }
+
+            if !lines.contains(&line) {
+                lines.push(line);
+            }
+        }
+        lines.join("\n")
+    }
+
+    /// Compares the findings of this tree with those of `other`, e.g. the expected with the
+    /// actual errors of a test.
+    ///
+    /// Findings are matched by their path and code, or message key if they have no code.
+    /// Findings of `other` without a match are added, those of `self` removed, matched
+    /// findings that differ in any other detail, e.g. the severity, are changed.
+    pub fn diff<'a>(&'a self, other: &'a ValidationErrors) -> ErrorTreeDiff<'a> {
+        fn id(error: &ValidationError) -> &str {
+            error.code.as_deref().unwrap_or(&error.message.key)
+        }
+
+        let mut removed = self.errors();
+        let mut diff = ErrorTreeDiff::default();
+
+        for (path, error) in other.errors() {
+            let position = removed
+                .iter()
+                .position(|(old_path, old)| *old_path == path && id(old) == id(error));
+            match position.map(|position| removed.remove(position)) {
+                Some((_, old)) if old == error => {}
+                Some((_, old)) => diff.changed.push(ChangedFinding {
+                    path,
+                    old,
+                    new: error,
+                }),
+                None => diff.added.push((path, error)),
+            }
+        }
+
+        diff.removed = removed;
+        diff
+    }
+}
+
+/// A finding present in both trees compared by [`ValidationErrors::diff`], with different
+/// details.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChangedFinding<'a> {
+    pub path: String,
+    pub old: &'a ValidationError,
+    pub new: &'a ValidationError,
+}
+
+/// The findings that differ between two [`ValidationErrors`], see [`ValidationErrors::diff`].
+///
+/// Displays one finding per line prefixed with `+`, `-` or `~`, e.g. to explain a failed
+/// assertion.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ErrorTreeDiff<'a> {
+    pub added: Vec<(String, &'a ValidationError)>,
+    pub removed: Vec<(String, &'a ValidationError)>,
+    pub changed: Vec<ChangedFinding<'a>>,
+}
+
+impl ErrorTreeDiff<'_> {
+    /// Returns `true` if both trees contain the same findings.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+impl fmt::Display for ErrorTreeDiff<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (path, error) in &self.added {
+            writeln!(
+                f,
+                "+ {}: {} {}",
+                path,
+                severity_label(error.severity),
+                error
+            )?;
+        }
+        for (path, error) in &self.removed {
+            writeln!(
+                f,
+                "- {}: {} {}",
+                path,
+                severity_label(error.severity),
+                error
+            )?;
+        }
+        for ChangedFinding { path, old, new } in &self.changed {
+            writeln!(
+                f,
+                "~ {}: {} {} => {} {}",
+                path,
+                severity_label(old.severity),
+                old,
+                severity_label(new.severity),
+                new
+            )?;
+        }
+        Ok(())
+    }
+}
+
+fn severity_label(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Info => "info",
+        Severity::Warning => "warning",
+        Severity::Error => "error",
+    }
+}
+
+fn component_identity(component: &Component) -> String {
+    component.identity().to_string()
+}
+
+fn join_path(prefix: &str, name: &str) -> String {
+    if prefix.is_empty() {
+        name.to_string()
+    } else {
+        format!("{}.{}", prefix, name)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{ValidationError, ValidationErrors};
+    use crate::{message::Locale, Bom, Component, ComponentType};
+
+    use super::{
+        Severity, ValidationContext, ValidationError, ValidationErrors, ValidationErrorsKind,
+        ValidationResult,
+    };
 
     #[test]
     fn has_error() {
@@ -291,4 +1397,350 @@ mod tests {
         errors.add_field("hello", ValidationError::new("again"));
         assert!(!errors.is_empty());
     }
+
+    #[test]
+    fn merge_errors() {
+        let mut first = ValidationErrors::new();
+        first.add_field("name", ValidationError::new("too long"));
+        let mut second = ValidationErrors::new();
+        second.add_field("name", ValidationError::new("invalid char"));
+        second.add_field("version", ValidationError::new("missing"));
+
+        let result = ValidationErrors::merge_errors(Err(first), Err(second));
+        let errors = result.expect_err("Expected merged errors");
+
+        assert!(errors.contains_key("version"));
+        assert_eq!(
+            errors.inner["name"],
+            ValidationErrorsKind::Field(vec![
+                ValidationError::new("too long"),
+                ValidationError::new("invalid char"),
+            ])
+        );
+    }
+
+    #[test]
+    fn merge_independent_trees() {
+        let schema = ValidationContext::new()
+            .add_field("name", Some(Err(ValidationError::new("too long"))))
+            .add_list(
+                "components",
+                Some(vec![Ok(()), Err(field_errors("purl", "invalid purl"))]),
+            )
+            .add_enum("kind", Some(Err(ValidationError::new("unknown kind"))));
+        let rules = ValidationContext::new()
+            .add_field("name", Some(Err(ValidationError::new("too long"))))
+            .add_field("version", Some(Err(ValidationError::new("missing"))))
+            .add_list(
+                "components",
+                Some(vec![Err(field_errors("supplier", "missing supplier"))]),
+            )
+            .add_field("kind", Some(Err(ValidationError::new("deprecated"))));
+        let schema: ValidationResult = schema.into();
+        let rules: ValidationResult = rules.into();
+
+        let merged = schema
+            .expect_err("Expected schema errors")
+            .merge(rules.expect_err("Expected rule errors"));
+        let paths = merged
+            .errors()
+            .into_iter()
+            .map(|(path, error)| format!("{}: {}", path, error))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            paths,
+            vec![
+                "name: too long",
+                "components[0].supplier: missing supplier",
+                "components[1].purl: invalid purl",
+                "kind: unknown kind",
+                "kind: deprecated",
+                "version: missing",
+            ]
+        );
+    }
+
+    #[test]
+    fn merge_keeps_both_on_shape_conflict() {
+        let list: ValidationResult = ValidationContext::new()
+            .add_list(
+                "components",
+                Some(vec![Err(field_errors("purl", "invalid purl"))]),
+            )
+            .into();
+        let field: ValidationResult = ValidationContext::new()
+            .add_field("components", Some(Err(ValidationError::new("missing"))))
+            .into();
+
+        let merged = list
+            .expect_err("Expected list errors")
+            .merge(field.expect_err("Expected field errors"));
+        assert!(matches!(
+            merged.get("components"),
+            Some(ValidationErrorsKind::Mixed(kinds)) if kinds.len() == 2
+        ));
+        assert_eq!(
+            merged.to_snapshot_string(),
+            "components[0].purl: error invalid purl\ncomponents: error missing"
+        );
+
+        let merged = merged.clone().merge(merged);
+        assert_eq!(merged.errors().len(), 2);
+    }
+
+    fn field_errors(field: &str, message: &str) -> ValidationErrors {
+        let mut errors = ValidationErrors::new();
+        errors.add_field(field, ValidationError::new(message));
+        errors
+    }
+
+    #[test]
+    fn merge_errors_with_different_shapes() {
+        let list: ValidationResult = ValidationContext::new()
+            .add_list(
+                "hashes",
+                Some(vec![Err(field_errors("content", "invalid digest"))]),
+            )
+            .add_enum("kind", Some(Err(ValidationError::new("unknown kind"))))
+            .into();
+        let field: ValidationResult = ValidationContext::new()
+            .add_field("hashes", Some(Err(ValidationError::new("weak algorithm"))))
+            .add_enum("kind", Some(Err(ValidationError::new("deprecated"))))
+            .into();
+
+        let errors = ValidationErrors::merge_errors(list, field).expect_err("Expected errors");
+        let paths = errors
+            .errors()
+            .into_iter()
+            .map(|(path, error)| (path, error.message.key.as_ref()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            paths,
+            vec![
+                ("hashes[0].content".to_string(), "invalid digest"),
+                ("hashes".to_string(), "weak algorithm"),
+                ("kind".to_string(), "unknown kind"),
+                ("kind".to_string(), "deprecated"),
+            ]
+        );
+        assert_eq!(
+            errors.get("kind"),
+            Some(&ValidationErrorsKind::Field(vec![
+                ValidationError::new("unknown kind"),
+                ValidationError::new("deprecated"),
+            ]))
+        );
+
+        let mut lineage = ValidationErrors::new();
+        lineage.add_lineage("version", ValidationError::new("version not increased"));
+        let errors =
+            ValidationErrors::merge_errors(Err(field_errors("version", "invalid")), Err(lineage))
+                .expect_err("Expected errors");
+        assert_eq!(
+            errors.get("version"),
+            Some(&ValidationErrorsKind::Mixed(vec![
+                ValidationErrorsKind::Field(vec![ValidationError::new("invalid")]),
+                ValidationErrorsKind::Lineage(vec![ValidationError::new("version not increased")]),
+            ]))
+        );
+    }
+
+    #[test]
+    fn merge_errors_into_ok() {
+        let mut errors = ValidationErrors::new();
+        errors.add_field("name", ValidationError::new("too long"));
+
+        let result = ValidationErrors::merge_errors(Ok(()), Err(errors.clone()));
+        assert_eq!(result, Err(errors));
+        assert_eq!(ValidationErrors::merge_errors(Ok(()), Ok(())), Ok(()));
+    }
+
+    #[test]
+    fn errors_with_paths() {
+        let component: ValidationResult = ValidationContext::new()
+            .add_field("purl", Some(Err(ValidationError::new("invalid"))))
+            .into();
+        let result: ValidationResult = ValidationContext::new()
+            .add_list("components", Some(vec![Ok(()), component]))
+            .add_enum("type", Some(Err(ValidationError::new("unknown"))))
+            .into();
+        let errors = result.expect_err("Expected errors");
+
+        let paths = errors
+            .errors()
+            .into_iter()
+            .map(|(path, _)| path)
+            .collect::<Vec<_>>();
+        assert_eq!(paths, vec!["components[1].purl", "type"]);
+    }
+
+    #[test]
+    fn display_with_context() {
+        let error = ValidationError::new("Invalid timestamp")
+            .with_expected("RFC 3339 timestamp")
+            .with_actual("2024/02/04");
+        assert_eq!(
+            error.to_string(),
+            "Invalid timestamp: expected RFC 3339 timestamp, got \"2024/02/04\""
+        );
+
+        let error = ValidationError::new("Too long").with_actual("a".repeat(100));
+        assert_eq!(error.context.actual, Some(format!("{}...", "a".repeat(64))));
+    }
+
+    #[test]
+    fn localize_with_context() {
+        let error = ValidationError::new("hash_not_hex")
+            .with_expected("hex string")
+            .with_actual("xyz");
+
+        assert_eq!(
+            error.localize(Locale::De),
+            "Hash-Inhalt muss eine Hex-Zeichenkette sein: erwartet hex string, erhalten \"xyz\""
+        );
+    }
+
+    #[test]
+    fn group_by_component() {
+        let bom = Bom {
+            components: Some(vec![
+                Component {
+                    version: Some(String::from("1.3")),
+                    ..Component::new(ComponentType::Library, "zlib")
+                },
+                Component {
+                    purl: Some(String::from("pkg:cargo/serde@1.0.196")),
+                    ..Component::new(ComponentType::Library, "serde")
+                },
+            ]),
+            ..Default::default()
+        };
+        let component: ValidationResult = ValidationContext::new()
+            .add_field("supplier", Some(Err(ValidationError::new("missing"))))
+            .into();
+        let result: ValidationResult = ValidationContext::new()
+            .add_field("serial_number", Some(Err(ValidationError::new("missing"))))
+            .add_list("components", Some(vec![component.clone(), component]))
+            .into();
+
+        let groups = result
+            .expect_err("Expected errors")
+            .group_by_component(&bom);
+
+        assert_eq!(
+            groups.keys().collect::<Vec<_>>(),
+            vec!["zlib@1.3", "pkg:cargo/serde@1.0.196", "bom"]
+        );
+        assert!(groups["zlib@1.3"].contains_key("supplier"));
+        assert!(groups["bom"].contains_key("serial_number"));
+    }
+
+    #[test]
+    fn field_macro_captures_name() {
+        struct Tool {
+            name: Option<String>,
+            vendor: String,
+        }
+        let tool = Tool {
+            name: Some(String::from("hammer")),
+            vendor: String::from("acme"),
+        };
+
+        let result: ValidationResult = ValidationContext::new()
+            .optional_field(crate::field!(tool.name), |_| {
+                Err(ValidationError::new("invalid"))
+            })
+            .field(crate::field!(tool.vendor), |_| Ok(()))
+            .into();
+
+        assert!(ValidationErrors::has_error(&result, "name"));
+        assert!(!ValidationErrors::has_error(&result, "vendor"));
+    }
+
+    #[test]
+    fn snapshot_string() {
+        let component: ValidationResult = ValidationContext::new()
+            .add_field(
+                "purl",
+                Some(Err(
+                    ValidationError::new("purl_scheme").with_code("invalid-purl")
+                )),
+            )
+            .add_field(
+                "purl",
+                Some(Err(
+                    ValidationError::new("purl_scheme").with_code("invalid-purl")
+                )),
+            )
+            .into();
+        let result: ValidationResult = ValidationContext::new()
+            .add_field(
+                "serial_number",
+                Some(Err(ValidationError::warning("missing"))),
+            )
+            .add_list("components", Some(vec![Ok(()), component]))
+            .into();
+
+        assert_eq!(
+            result.expect_err("Expected errors").to_snapshot_string(),
+            "serialNumber: warning missing\n\
+             components[1].purl: error [invalid-purl] Purl must start with 'pkg:'"
+        );
+    }
+
+    #[test]
+    fn diff_of_equal_trees_is_empty() {
+        let expected = field_errors("name", "too long");
+        let actual = field_errors("name", "too long");
+
+        let diff = expected.diff(&actual);
+        assert!(diff.is_empty());
+        assert_eq!(diff.to_string(), "");
+    }
+
+    #[test]
+    fn diff_added_removed_and_changed_findings() {
+        let expected = ValidationContext::new()
+            .add_field(
+                "name",
+                Some(Err(
+                    ValidationError::new("too long").with_code("name-length")
+                )),
+            )
+            .add_field("version", Some(Err(ValidationError::new("missing"))))
+            .add_list(
+                "components",
+                Some(vec![Ok(()), Err(field_errors("purl", "invalid purl"))]),
+            );
+        let actual = ValidationContext::new()
+            .add_field(
+                "name",
+                Some(Err(
+                    ValidationError::warning("too long").with_code("name-length")
+                )),
+            )
+            .add_list(
+                "components",
+                Some(vec![Ok(()), Err(field_errors("purl", "invalid purl"))]),
+            )
+            .add_field("group", Some(Err(ValidationError::new("blank"))));
+        let expected = ValidationResult::from(expected).expect_err("Expected errors");
+        let actual = ValidationResult::from(actual).expect_err("Expected errors");
+
+        let diff = expected.diff(&actual);
+        assert_eq!(diff.added.len(), 1);
+        assert_eq!(diff.added[0].0, "group");
+        assert_eq!(diff.removed.len(), 1);
+        assert_eq!(diff.removed[0].0, "version");
+        assert_eq!(diff.changed.len(), 1);
+        assert_eq!(diff.changed[0].path, "name");
+        assert_eq!(diff.changed[0].new.severity, Severity::Warning);
+        assert_eq!(
+            diff.to_string(),
+            "+ group: error blank\n\
+             - version: error missing\n\
+             ~ name: error too long => warning too long\n"
+        );
+    }
 }
@@ -0,0 +1,81 @@
+use alloc::sync::Arc;
+
+use crate::{
+    options::ValidationOptions,
+    report::{validate_report, ValidationReport},
+    validate_bom, validate_bytes,
+    validation::{SpecVersion, ValidationErrors, ValidationResult},
+    Bom,
+};
+
+/// A validator with a fixed set of [`ValidationOptions`], shared across threads.
+///
+/// The options, including all registered rules, are set up once and shared by all clones of
+/// the validator, e.g. by the request handlers of a web server. Cloning is cheap.
+#[derive(Clone, Default)]
+pub struct Validator {
+    options: Arc<ValidationOptions>,
+}
+
+impl Validator {
+    pub fn new(options: ValidationOptions) -> Self {
+        Self {
+            options: Arc::new(options),
+        }
+    }
+
+    /// Returns the options all validations run with.
+    pub fn options(&self) -> &ValidationOptions {
+        &self.options
+    }
+
+    /// Validates the bom, see [`validate_bom`].
+    pub fn validate(&self, version: SpecVersion, bom: &Bom) -> Result<(), ValidationErrors> {
+        validate_bom(version, bom, &self.options)
+    }
+
+    /// Parses and validates an untrusted JSON document, see [`validate_bytes`].
+    pub fn validate_bytes(&self, version: SpecVersion, input: &[u8]) -> ValidationResult {
+        validate_bytes(version, input, &self.options)
+    }
+
+    /// Validates the bom and reports all checks, see [`validate_report`].
+    pub fn report(&self, version: SpecVersion, bom: &Bom) -> ValidationReport {
+        validate_report(version, bom, &self.options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        options::{Profile, ValidationOptions},
+        validation::SpecVersion,
+    };
+
+    use super::Validator;
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn validator_is_shared_across_threads() {
+        assert_send_sync::<Validator>();
+
+        let validator = Validator::new(ValidationOptions::new().profile(Profile::Strict));
+        let valid = br#"{ "serialNumber": "1" }"#;
+        let invalid = br#"{ "components": [{ "type": "library", "name": "a", "purl": "a" }] }"#;
+
+        std::thread::scope(|scope| {
+            for _ in 0..4 {
+                let validator = validator.clone();
+                scope.spawn(move || {
+                    assert!(validator.validate_bytes(SpecVersion::V1_5, valid).is_ok());
+                    assert!(validator
+                        .validate_bytes(SpecVersion::V1_5, invalid)
+                        .is_err());
+                });
+            }
+        });
+
+        assert_eq!(validator.options().selected_profile(), Profile::Strict);
+    }
+}
@@ -0,0 +1,53 @@
+//! JavaScript bindings for `wasm32-unknown-unknown`, e.g. built with
+//! `cargo rustc --lib --crate-type cdylib --target wasm32-unknown-unknown --features wasm`.
+use wasm_bindgen::prelude::*;
+
+use crate::{options::ValidationOptions, validate_bytes, validation::SpecVersion};
+
+/// Validates a JSON document against the given spec version, e.g. `1.5`.
+///
+/// Returns the error tree serialized as JSON, which is an empty object for a valid document.
+#[wasm_bindgen(js_name = validateJson)]
+pub fn validate_json(input: &str, spec_version: &str) -> Result<String, JsError> {
+    error_tree(input, spec_version).map_err(|message| JsError::new(&message))
+}
+
+fn error_tree(input: &str, spec_version: &str) -> Result<String, String> {
+    let version = SpecVersion::parse(spec_version)
+        .ok_or_else(|| format!("Unsupported spec version '{}'", spec_version))?;
+
+    let errors = validate_bytes(version, input.as_bytes(), &ValidationOptions::new())
+        .err()
+        .unwrap_or_default();
+
+    serde_json::to_string(&errors).map_err(|error| error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::error_tree;
+
+    #[test]
+    fn serializes_error_tree() {
+        assert_eq!(
+            error_tree(r#"{ "serialNumber": "1" }"#, "1.5"),
+            Ok(String::from("{}"))
+        );
+
+        let json = error_tree(
+            r#"{ "components": [{ "type": "library", "name": "a", "purl": "a" }] }"#,
+            "1.5",
+        )
+        .expect("Failed to serialize");
+        let tree: serde_json::Value = serde_json::from_str(&json).expect("Failed to parse");
+        assert_eq!(
+            tree["components"]["list"]["0"]["purl"]["field"][0]["code"],
+            "invalid-purl"
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_spec_version() {
+        assert!(error_tree("{}", "2.0").is_err());
+    }
+}
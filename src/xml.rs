@@ -0,0 +1,298 @@
+//! Incremental validation of XML documents, e.g. huge SBOMs that should not be loaded as a
+//! whole.
+//!
+//! The document is read with a pull parser, every top-level component of `<components>` is
+//! validated with [`validate_component`] as soon as its element closes and dropped
+//! afterwards, so memory is bounded by the largest component, not by the document. Only the
+//! attributes and text fields of a component, e.g. `<name>` or `<purl>`, and its `<hashes>`
+//! are read, nested components and all other elements are skipped. Like
+//! [`validate_bytes`](crate::validate_bytes), documents larger than [`MAX_INPUT_LEN`] are
+//! rejected.
+use std::io::BufRead;
+
+use quick_xml::{
+    events::{BytesStart, Event},
+    Reader,
+};
+use serde_json::{Map, Value};
+
+use crate::{
+    error::DOCUMENT_KEY,
+    options::ValidationOptions,
+    validate_component,
+    validation::{SpecVersion, ValidationContext, ValidationError, ValidationResult},
+    Component, MAX_INPUT_LEN,
+};
+
+/// The elements enclosing a top-level component.
+const COMPONENT: [&str; 3] = ["bom", "components", "component"];
+
+/// The component element currently read.
+#[derive(Default)]
+struct PendingComponent {
+    fields: Map<String, Value>,
+    hashes: Vec<Value>,
+    /// The `alg` attribute of the currently read `<hash>`.
+    hash_alg: Option<String>,
+    text: String,
+}
+
+impl PendingComponent {
+    fn new(element: &BytesStart) -> Result<Self, quick_xml::Error> {
+        let mut pending = Self::default();
+        for name in ["type", "bom-ref"] {
+            if let Some(attribute) = element.try_get_attribute(name)? {
+                pending
+                    .fields
+                    .insert(name.to_string(), attribute.unescape_value()?.into());
+            }
+        }
+        Ok(pending)
+    }
+
+    fn finish(mut self) -> Result<Component, serde_json::Error> {
+        if !self.hashes.is_empty() {
+            self.fields
+                .insert(String::from("hashes"), Value::Array(self.hashes));
+        }
+        serde_json::from_value(Value::Object(self.fields))
+    }
+}
+
+fn local_name(element: &BytesStart) -> String {
+    String::from_utf8_lossy(element.local_name().as_ref()).into_owned()
+}
+
+fn is_component(stack: &[String]) -> bool {
+    stack.iter().map(String::as_str).eq(COMPONENT)
+}
+
+/// Returns the open elements inside the current component.
+fn component_path(stack: &[String]) -> &[String] {
+    stack.get(COMPONENT.len()..).unwrap_or_default()
+}
+
+/// Returns `true` for the path of a `<hash>` relative to its component.
+fn is_hash(path: &[String]) -> bool {
+    path.iter().map(String::as_str).eq(["hashes", "hash"])
+}
+
+fn validate_pending(
+    version: SpecVersion,
+    pending: PendingComponent,
+    options: &ValidationOptions,
+) -> ValidationResult {
+    match pending.finish() {
+        Ok(component) => validate_component(version, &component, options),
+        Err(error) => ValidationContext::new()
+            .add_field(
+                "element",
+                Some(Err(ValidationError::new("invalid_element")
+                    .with_param("error", error)
+                    .with_code("invalid-element"))),
+            )
+            .into(),
+    }
+}
+
+/// The state of a running validation.
+struct XmlValidator<'a> {
+    version: SpecVersion,
+    options: &'a ValidationOptions,
+    /// The local names of all open elements.
+    stack: Vec<String>,
+    pending: Option<PendingComponent>,
+    results: Vec<ValidationResult>,
+}
+
+impl XmlValidator<'_> {
+    fn start(&mut self, element: &BytesStart) -> Result<(), quick_xml::Error> {
+        self.stack.push(local_name(element));
+        if is_component(&self.stack) {
+            self.pending = Some(PendingComponent::new(element)?);
+        } else if let Some(pending) = self.pending.as_mut() {
+            pending.text.clear();
+            if is_hash(component_path(&self.stack)) {
+                pending.hash_alg = element
+                    .try_get_attribute("alg")?
+                    .map(|alg| alg.unescape_value().map(String::from))
+                    .transpose()?;
+            }
+        }
+        Ok(())
+    }
+
+    fn end(&mut self) {
+        if is_component(&self.stack) {
+            if let Some(pending) = self.pending.take() {
+                let result = validate_pending(self.version, pending, self.options);
+                self.results.push(result);
+            }
+        } else if let Some(pending) = self.pending.as_mut() {
+            let text = core::mem::take(&mut pending.text);
+            match component_path(&self.stack) {
+                [field] if !matches!(field.as_str(), "hashes" | "components" | "releaseNotes") => {
+                    pending.fields.insert(field.clone(), Value::String(text));
+                }
+                path if is_hash(path) => {
+                    let mut hash = Map::new();
+                    if let Some(alg) = pending.hash_alg.take() {
+                        hash.insert(String::from("alg"), Value::String(alg));
+                    }
+                    hash.insert(String::from("content"), Value::String(text));
+                    pending.hashes.push(Value::Object(hash));
+                }
+                _ => {}
+            }
+        }
+        self.stack.pop();
+    }
+
+    fn handle(&mut self, event: Event) -> Result<(), quick_xml::Error> {
+        match event {
+            Event::Start(element) => self.start(&element)?,
+            Event::Empty(element) => {
+                self.start(&element)?;
+                self.end();
+            }
+            Event::End(_) => self.end(),
+            Event::Text(text) => {
+                if let Some(pending) = self.pending.as_mut() {
+                    pending.text.push_str(&text.unescape()?);
+                }
+            }
+            Event::CData(data) => {
+                if let Some(pending) = self.pending.as_mut() {
+                    pending.text.push_str(&String::from_utf8_lossy(&data));
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+/// Validates the components of an XML document read from `reader` one by one, see the
+/// [module](self) documentation.
+///
+/// Findings are reported at the position of the component, e.g. `components[3].purl`. A
+/// document that is not well-formed or larger than [`MAX_INPUT_LEN`] is reported as error at
+/// [`DOCUMENT_KEY`], the components read until then are reported as well.
+pub fn validate_xml<R: BufRead>(
+    version: SpecVersion,
+    reader: R,
+    options: &ValidationOptions,
+) -> ValidationResult {
+    // reads one byte more than allowed to detect larger documents without reading them whole
+    let mut reader = Reader::from_reader(reader.take(MAX_INPUT_LEN as u64 + 1));
+    reader.config_mut().trim_text(true);
+
+    let mut validator = XmlValidator {
+        version,
+        options,
+        stack: Vec::new(),
+        pending: None,
+        results: Vec::new(),
+    };
+    let mut buffer = Vec::new();
+    let error = loop {
+        match reader.read_event_into(&mut buffer) {
+            Ok(Event::Eof) => break None,
+            Ok(event) => {
+                if let Err(error) = validator.handle(event) {
+                    break Some(error);
+                }
+            }
+            Err(error) => break Some(error),
+        }
+        buffer.clear();
+    };
+
+    let document = if reader.get_ref().limit() == 0 {
+        Some(Err(ValidationError::new("input_too_large")
+            .with_param("max", MAX_INPUT_LEN)
+            .with_param("actual", format!("more than {}", MAX_INPUT_LEN))
+            .with_code("input-too-large")))
+    } else {
+        error.map(|error| {
+            Err(ValidationError::new("invalid_xml")
+                .with_param("error", error)
+                .with_code("invalid-xml"))
+        })
+    };
+    let results = validator.results;
+
+    ValidationContext::new()
+        .add_field(DOCUMENT_KEY, document)
+        .add_list("components", (!results.is_empty()).then_some(results))
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        options::ValidationOptions, testing::errors_at, validation::SpecVersion, MAX_INPUT_LEN,
+    };
+
+    use super::validate_xml;
+
+    const BOM: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <bom xmlns="http://cyclonedx.org/schema/bom/1.5" version="1">
+          <components>
+            <component type="library" bom-ref="serde">
+              <name>serde</name>
+              <version>1.0.196</version>
+              <purl>pkg:cargo/serde@1.0.196</purl>
+            </component>
+            <component type="library">
+              <name>broken</name>
+              <purl>cargo/broken</purl>
+              <hashes>
+                <hash alg="SHA-256">not-hex</hash>
+              </hashes>
+              <components>
+                <component type="library"><name>nested</name><purl>nested</purl></component>
+              </components>
+            </component>
+          </components>
+        </bom>"#;
+
+    #[test]
+    fn validates_components_as_they_close() {
+        let result = validate_xml(SpecVersion::V1_5, BOM.as_bytes(), &ValidationOptions::new());
+
+        assert!(errors_at(&result, "components[0].purl").is_empty());
+        assert_eq!(errors_at(&result, "components[1].purl").len(), 1);
+        assert!(!errors_at(&result, "components[1].hashes[0].content").is_empty());
+        assert!(errors_at(&result, "document").is_empty());
+    }
+
+    #[test]
+    fn reports_malformed_document() {
+        let input = r#"<bom><components><component type="library"><name>a</name></component>
+            <component></bom>"#;
+        let result = validate_xml(
+            SpecVersion::V1_5,
+            input.as_bytes(),
+            &ValidationOptions::new(),
+        );
+
+        assert_eq!(errors_at(&result, "document").len(), 1);
+    }
+
+    #[test]
+    fn rejects_too_large_document() {
+        let mut input = String::from(r#"<bom><components><component type="library">"#);
+        input.push_str(&" ".repeat(MAX_INPUT_LEN));
+        input.push_str("<name>a</name></component></components></bom>");
+        let result = validate_xml(
+            SpecVersion::V1_5,
+            input.as_bytes(),
+            &ValidationOptions::new(),
+        );
+
+        let errors = errors_at(&result, "document");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code.as_deref(), Some("input-too-large"));
+    }
+}